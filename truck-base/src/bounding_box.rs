@@ -262,6 +262,68 @@ where
     pub fn center(&self) -> V { self.0.mid(self.1) }
 }
 
+impl<F, V> BoundingBox<V>
+where
+    F: BaseFloat,
+    V: MetricSpace<Metric = F> + Index<usize, Output = F> + Bounded<F> + Copy + PartialEq,
+{
+    /// Returns the smallest bounding box including both `self` and `other`.
+    ///
+    /// This is the same as `self + other`.
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, bounding_box::*};
+    /// let bbx0 = BoundingBox::from_iter(&[Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)]);
+    /// let bbx1 = BoundingBox::from_iter(&[Vector2::new(2.0, -1.0), Vector2::new(3.0, 0.5)]);
+    /// let union = bbx0.union(&bbx1);
+    /// assert_eq!(union.min(), &Vector2::new(0.0, -1.0));
+    /// assert_eq!(union.max(), &Vector2::new(3.0, 1.0));
+    /// ```
+    #[inline(always)]
+    pub fn union(&self, other: &Self) -> Self { self + other }
+
+    /// Returns the intersection of `self` and `other`,
+    /// or `None` if they do not intersect.
+    ///
+    /// Unlike `self ^ other`, the emptiness is checked for all components.
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, bounding_box::*};
+    /// let bbx0 = BoundingBox::from_iter(&[Vector2::new(0.0, 0.0), Vector2::new(2.0, 2.0)]);
+    /// let bbx1 = BoundingBox::from_iter(&[Vector2::new(1.0, 1.0), Vector2::new(3.0, 3.0)]);
+    /// let intersection = bbx0.intersection(&bbx1).unwrap();
+    /// assert_eq!(intersection.min(), &Vector2::new(1.0, 1.0));
+    /// assert_eq!(intersection.max(), &Vector2::new(2.0, 2.0));
+    ///
+    /// // separated only in the second component
+    /// let bbx2 = BoundingBox::from_iter(&[Vector2::new(1.0, 3.0), Vector2::new(3.0, 4.0)]);
+    /// assert!(bbx0.intersection(&bbx2).is_none());
+    /// ```
+    #[inline(always)]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let res = self ^ other;
+        match res.0.max(&res.1) == res.1 {
+            true => Some(res),
+            false => None,
+        }
+    }
+
+    /// Returns whether `point` is in the bounding box, boundary inclusive.
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, bounding_box::*};
+    /// let bdd_box = BoundingBox::from_iter(&[Point2::new(0.0, 0.0), Point2::new(1.0, 2.0)]);
+    /// assert!(bdd_box.contains(&Point2::new(0.5, 1.5)));
+    /// assert!(bdd_box.contains(&Point2::new(1.0, 0.0)));
+    /// assert!(!bdd_box.contains(&Point2::new(0.5, 2.5)));
+    /// assert!(!BoundingBox::new().contains(&Point2::new(0.0, 0.0)));
+    /// ```
+    #[inline(always)]
+    pub fn contains(&self, point: &V) -> bool {
+        self.0.min(point) == self.0 && self.1.max(point) == self.1
+    }
+}
+
 impl<'a, F, V> FromIterator<&'a V> for BoundingBox<V>
 where
     F: BaseFloat,
@@ -661,3 +723,170 @@ where
         }
     }
 }
+
+/// oriented bounding box, the parallelepiped `center + axes * [-1, 1]^3`
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct OrientedBoundingBox {
+    center: Point3<f64>,
+    axes: Matrix3<f64>,
+}
+
+impl OrientedBoundingBox {
+    /// Creates the box `center + axes * [-1, 1]^3`, i.e. the columns of `axes` are
+    /// the half edges of the box.
+    #[inline(always)]
+    pub fn new(center: Point3<f64>, axes: Matrix3<f64>) -> Self { Self { center, axes } }
+
+    /// Creates the oriented bounding box of `points` by the principal component analysis.
+    ///
+    /// The edges of the box are parallel to the eigenvectors of the covariance matrix.
+    /// Returns `None` if `points` is empty.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, bounding_box::*, cgmath64::*, tolerance::*};
+    /// // the points on the diagonal of the unit cube
+    /// let points: Vec<_> = (0..=10)
+    ///     .map(|i| Point3::new(1.0, 1.0, 1.0) * (i as f64 / 10.0))
+    ///     .collect();
+    /// let obb = OrientedBoundingBox::from_points(&points).unwrap();
+    /// assert_near!(obb.center(), Point3::new(0.5, 0.5, 0.5));
+    /// // the box is a segment, much thinner than the axis-aligned box.
+    /// assert_near!(obb.radius(), f64::sqrt(3.0) / 2.0);
+    /// let mut lengths = [0, 1, 2].map(|i| obb.axes()[i].magnitude());
+    /// lengths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_near!(lengths[0], 0.0);
+    /// assert_near!(lengths[1], 0.0);
+    /// assert_near!(lengths[2], f64::sqrt(3.0) / 2.0);
+    ///
+    /// assert!(OrientedBoundingBox::from_points(&[]).is_none());
+    /// ```
+    pub fn from_points<'a, I: IntoIterator<Item = &'a Point3<f64>>>(points: I) -> Option<Self> {
+        let points: Vec<Point3<f64>> = points.into_iter().copied().collect();
+        if points.is_empty() {
+            return None;
+        }
+        let n = points.len() as f64;
+        let mean = points.iter().fold(Vector3::zero(), |sum, pt| sum + pt.to_vec()) / n;
+        let mut covariance = [[0.0; 3]; 3];
+        points.iter().for_each(|pt| {
+            let v = pt.to_vec() - mean;
+            (0..3).for_each(|i| (0..3).for_each(|j| covariance[i][j] += v[i] * v[j] / n));
+        });
+        let eigenvectors = symmetric_eigenvectors(covariance);
+        let mut center = Point3::from_vec(mean);
+        let mut axes = Matrix3::zero();
+        (0..3).for_each(|i| {
+            let e = eigenvectors[i];
+            let (min, max) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |x, pt| {
+                let t = (pt.to_vec() - mean).dot(e);
+                (f64::min(x.0, t), f64::max(x.1, t))
+            });
+            center += e * (min + max) / 2.0;
+            axes[i] = e * (max - min) / 2.0;
+        });
+        Some(Self { center, axes })
+    }
+
+    /// Returns the center of the box.
+    #[inline(always)]
+    pub fn center(&self) -> Point3<f64> { self.center }
+
+    /// Returns the matrix whose columns are the half edges of the box.
+    #[inline(always)]
+    pub fn axes(&self) -> Matrix3<f64> { self.axes }
+
+    /// Returns the eight corners of the box.
+    pub fn corners(&self) -> [Point3<f64>; 8] {
+        let sign = |i: usize, j: usize| if (i >> j) & 1 == 0 { -1.0 } else { 1.0 };
+        let corner = |i: usize| {
+            self.center + self.axes * Vector3::new(sign(i, 0), sign(i, 1), sign(i, 2))
+        };
+        [0, 1, 2, 3, 4, 5, 6, 7].map(corner)
+    }
+
+    /// Returns the radius of the circumscribed sphere whose center is the center of the box.
+    #[inline(always)]
+    pub fn radius(&self) -> f64 {
+        self.corners()
+            .iter()
+            .fold(0.0, |r, pt| f64::max(r, pt.distance(self.center)))
+    }
+
+    /// Returns the image of the box by the affine transformation `matrix`.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, bounding_box::*, cgmath64::*, tolerance::*};
+    /// let obb = OrientedBoundingBox::new(Point3::new(1.0, 0.0, 0.0), Matrix3::identity());
+    /// let matrix = Matrix4::from_translation(Vector3::new(0.0, 2.0, 0.0))
+    ///     * Matrix4::from_nonuniform_scale(2.0, 1.0, 1.0);
+    /// let obb = obb.transformed(matrix);
+    /// assert_near!(obb.center(), Point3::new(2.0, 2.0, 0.0));
+    /// let bdd_box = obb.bounding_box();
+    /// assert_near!(*bdd_box.min(), Point3::new(0.0, 1.0, -1.0));
+    /// assert_near!(*bdd_box.max(), Point3::new(4.0, 3.0, 1.0));
+    /// ```
+    pub fn transformed(&self, matrix: Matrix4<f64>) -> Self {
+        let linear = Matrix3::from_cols(
+            matrix[0].truncate(),
+            matrix[1].truncate(),
+            matrix[2].truncate(),
+        );
+        Self {
+            center: matrix.transform_point(self.center),
+            axes: linear * self.axes,
+        }
+    }
+
+    /// Returns the axis-aligned bounding box including the box.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> BoundingBox<Point3<f64>> { self.corners().iter().collect() }
+}
+
+/// Returns the unit eigenvectors of the symmetric matrix `a` as the columns by the Jacobi method.
+/// # Examples
+/// ```
+/// use truck_base::{assert_near, bounding_box::symmetric_eigenvectors, cgmath64::*, tolerance::*};
+/// let a = [[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]];
+/// let eigenvectors = symmetric_eigenvectors(a);
+/// let mat = Matrix3::from(a);
+/// (0..3).for_each(|i| {
+///     let v = eigenvectors[i];
+///     assert_near!(v.magnitude(), 1.0);
+///     assert_near!(mat * v, v * v.dot(mat * v));
+/// });
+/// ```
+pub fn symmetric_eigenvectors(mut a: [[f64; 3]; 3]) -> Matrix3<f64> {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diag = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= f64::EPSILON * f64::EPSILON * diag || off == 0.0 {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = f64::signum(theta) / (theta.abs() + f64::sqrt(theta * theta + 1.0));
+            let c = 1.0 / f64::sqrt(t * t + 1.0);
+            let s = t * c;
+            for row in &mut a {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (ap, aq) = (a[p], a[q]);
+            a[p] = [0, 1, 2].map(|k| c * ap[k] - s * aq[k]);
+            a[q] = [0, 1, 2].map(|k| s * ap[k] + c * aq[k]);
+            for row in &mut v {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+    Matrix3::new(
+        v[0][0], v[1][0], v[2][0], v[0][1], v[1][1], v[2][1], v[0][2], v[1][2], v[2][2],
+    )
+}
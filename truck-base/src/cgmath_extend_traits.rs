@@ -270,3 +270,110 @@ impl<S: BaseFloat> Homogeneous<S> for Vector4<S> {
     #[inline(always)]
     fn from_point(point: Self::Point) -> Self { point.to_homogeneous() }
 }
+
+/// Angles between vectors robust to the rounding errors.
+///
+/// The name `clamped_angle` avoids the conflict with [`InnerSpace::angle`].
+/// # Examples
+/// ```
+/// use truck_base::cgmath64::*;
+/// use std::f64::consts::PI;
+/// let v = Vector3::new(0.1, 0.1, 0.7);
+/// assert_eq!(v.clamped_angle(v * 3.0), Rad(0.0));
+/// assert_eq!(v.clamped_angle(-v * 3.0), Rad(PI));
+/// ```
+pub trait ClampedAngle<S: BaseFloat>: InnerSpace<Scalar = S> {
+    /// Returns the angle between `self` and `other` in `[0, PI]`.
+    ///
+    /// The cosine `dot / (|self| |other|)` is clamped to `[-1, 1]`, so the angle is not `NaN`
+    /// even if the rounding error pushes the cosine of the parallel vectors over `1`.
+    #[inline(always)]
+    fn clamped_angle(self, other: Self) -> Rad<S> {
+        let cos = self.dot(other) / (self.magnitude() * other.magnitude());
+        Rad(S::acos(S::max(S::min(cos, S::one()), -S::one())))
+    }
+}
+impl<S: BaseFloat> ClampedAngle<S> for Vector2<S> {}
+impl<S: BaseFloat> ClampedAngle<S> for Vector3<S> {}
+impl<S: BaseFloat> ClampedAngle<S> for Vector4<S> {}
+
+/// Signed angles between vectors in 2D and 3D.
+/// # Examples
+/// ```
+/// use truck_base::cgmath64::*;
+/// use std::f64::consts::PI;
+/// let (x, y) = (Vector2::unit_x(), Vector2::unit_y());
+/// assert_eq!(x.signed_angle(y, ()), Rad(PI / 2.0));
+/// assert_eq!(y.signed_angle(x, ()), Rad(-PI / 2.0));
+///
+/// let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+/// assert_eq!(x.signed_angle(y, z), Rad(PI / 2.0));
+/// assert_eq!(x.signed_angle(y, -z), Rad(-PI / 2.0));
+/// ```
+pub trait SignedAngle<S: BaseFloat>: ClampedAngle<S> {
+    /// The reference axis: `()` in 2D, and the vector in 3D.
+    type Axis;
+    /// Returns the angle from `self` to `other` in `[-PI, PI]`.
+    ///
+    /// The angle is positive if the rotation from `self` to `other` is counterclockwise
+    /// around `axis`.
+    fn signed_angle(self, other: Self, axis: Self::Axis) -> Rad<S>;
+}
+
+impl<S: BaseFloat> SignedAngle<S> for Vector2<S> {
+    type Axis = ();
+    #[inline(always)]
+    fn signed_angle(self, other: Self, _: ()) -> Rad<S> {
+        let angle = self.clamped_angle(other);
+        match self.perp_dot(other) < S::zero() {
+            true => -angle,
+            false => angle,
+        }
+    }
+}
+
+impl<S: BaseFloat> SignedAngle<S> for Vector3<S> {
+    type Axis = Vector3<S>;
+    #[inline(always)]
+    fn signed_angle(self, other: Self, axis: Vector3<S>) -> Rad<S> {
+        let angle = self.clamped_angle(other);
+        match self.cross(other).dot(axis) < S::zero() {
+            true => -angle,
+            false => angle,
+        }
+    }
+}
+
+/// Orthonormal bases of the planes perpendicular to 3D vectors.
+/// # Examples
+/// ```
+/// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+/// let n = Vector3::new(1.0, 2.0, 3.0);
+/// let (u, v) = n.orthonormal_basis();
+/// assert_near!(u.magnitude(), 1.0);
+/// assert_near!(v.magnitude(), 1.0);
+/// assert_near!(u.dot(n), 0.0);
+/// assert_near!(u.cross(v), n.normalize());
+/// ```
+pub trait OrthonormalBasis: Sized {
+    /// Returns the unit vectors `(u, v)` such that `(u, v, self.normalize())` is
+    /// a right-handed orthonormal basis.
+    ///
+    /// `u` is the projection of the x-axis to the plane perpendicular to `self`,
+    /// or the one of the y-axis if `self` is nearly parallel to the x-axis.
+    /// The result is `NaN` if `self` is zero.
+    fn orthonormal_basis(self) -> (Self, Self);
+}
+
+impl<S: BaseFloat> OrthonormalBasis for Vector3<S> {
+    #[inline(always)]
+    fn orthonormal_basis(self) -> (Self, Self) {
+        let n = self.normalize();
+        let e = match S::abs(n.x) < S::from(0.9).unwrap() {
+            true => Vector3::unit_x(),
+            false => Vector3::unit_y(),
+        };
+        let u = (e - n * n.dot(e)).normalize();
+        (u, n.cross(u))
+    }
+}
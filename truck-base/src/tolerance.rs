@@ -9,12 +9,32 @@ pub const TOLERANCE: f64 = 1.0e-6;
 pub const TOLERANCE2: f64 = TOLERANCE * TOLERANCE;
 
 /// Defines a tolerance in the whole package
+///
+/// The methods with the suffix `_with` compare by the tolerance given by the caller
+/// instead of `TOLERANCE`, e.g. for the data with large or tiny coordinates.
+/// # Examples
+/// ```
+/// use truck_base::{cgmath64::*, tolerance::*};
+/// let p = Point3::new(1.0e5, 0.0, 0.0);
+/// let q = Point3::new(1.0e5 + 1.0e-3, 0.0, 0.0);
+/// assert!(!p.near(&q));
+/// assert!(p.near_with(&q, 1.0e-2));
+/// assert!(!p.near2_with(&q, 1.0e-2));
+/// ```
 pub trait Tolerance: AbsDiffEq<Epsilon = f64> + Debug {
     /// The "distance" is less than `TOLERANCE`.
-    fn near(&self, other: &Self) -> bool { self.abs_diff_eq(other, TOLERANCE) }
+    fn near(&self, other: &Self) -> bool { self.near_with(other, TOLERANCE) }
 
     /// The "distance" is less than `TOLERANCR2`.
-    fn near2(&self, other: &Self) -> bool { self.abs_diff_eq(other, TOLERANCE2) }
+    fn near2(&self, other: &Self) -> bool { self.near_with(other, TOLERANCE2) }
+
+    /// The "distance" is less than `tol`.
+    #[inline(always)]
+    fn near_with(&self, other: &Self, tol: f64) -> bool { self.abs_diff_eq(other, tol) }
+
+    /// The "distance" is less than `tol * tol`.
+    #[inline(always)]
+    fn near2_with(&self, other: &Self, tol: f64) -> bool { self.abs_diff_eq(other, tol * tol) }
 }
 
 impl<T: AbsDiffEq<Epsilon = f64> + Debug> Tolerance for T {}
@@ -82,6 +102,32 @@ pub trait Origin: Tolerance + Zero {
     /// near origin in square order
     #[inline(always)]
     fn so_small2(&self) -> bool { self.near2(&Self::zero()) }
+
+    /// near origin within `tol`
+    #[inline(always)]
+    fn so_small_with(&self, tol: f64) -> bool { self.near_with(&Self::zero(), tol) }
 }
 
 impl<T: Tolerance + Zero> Origin for T {}
+
+#[test]
+fn near_with_test() {
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let q = Point3::new(1.0 + 1.0e-4, 2.0, 3.0 - 1.0e-4);
+    assert!(!p.near(&q));
+    assert!(p.near_with(&q, 1.0e-3));
+    assert!(!p.near_with(&q, 1.0e-5));
+    assert!(p.near2_with(&q, 1.0e-1));
+    assert!(!p.near2_with(&q, 1.0e-3));
+
+    // the defaults are not changed.
+    let r = Point3::new(1.0 + TOLERANCE / 2.0, 2.0, 3.0);
+    assert!(p.near(&r));
+    assert_eq!(p.near(&r), p.near_with(&r, TOLERANCE));
+    assert_eq!(p.near2(&r), p.near2_with(&r, TOLERANCE));
+
+    let v = q - p;
+    assert!(!v.so_small());
+    assert!(v.so_small_with(1.0e-3));
+    assert!(!v.so_small_with(1.0e-5));
+}
@@ -0,0 +1,47 @@
+use std::f64::consts::PI;
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
+
+#[test]
+fn clamped_angle_test() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let w = Vector3::new(3.0, 0.0, -1.0);
+    // orthogonal
+    assert_near!(v.clamped_angle(w).0, PI / 2.0);
+    // parallel
+    assert_near!(v.clamped_angle(v * 2.0).0, 0.0);
+    // anti-parallel
+    assert_near!(v.clamped_angle(-v * 2.0).0, PI);
+    // same as the angle of cgmath for the general vectors
+    let u = Vector3::new(1.0, -1.0, 0.5);
+    assert_near!(v.clamped_angle(u).0, v.angle(u).0);
+
+    // the cosine of the near-parallel vectors exceeds 1 by the rounding error.
+    let v = Vector3::new(0.1, 0.1, 0.7);
+    let w = v * 3.0;
+    let cos = v.dot(w) / (v.magnitude() * w.magnitude());
+    assert!(f64::acos(cos).is_nan());
+    assert_eq!(v.clamped_angle(w), Rad(0.0));
+    assert_eq!(v.clamped_angle(-w), Rad(PI));
+
+    let v = Vector4::new(0.1, 0.1, 0.7, 0.0);
+    assert!(!v.clamped_angle(v * 3.0).0.is_nan());
+    assert_near!(v.clamped_angle(v * 3.0).0, 0.0);
+}
+
+#[test]
+fn signed_angle_test() {
+    let v = Vector2::new(1.0, 1.0);
+    assert_near!(v.signed_angle(Vector2::new(-1.0, 1.0), ()).0, PI / 2.0);
+    assert_near!(v.signed_angle(Vector2::new(1.0, -1.0), ()).0, -PI / 2.0);
+    assert_near!(v.signed_angle(v * 3.0, ()).0, 0.0);
+    assert_near!(f64::abs(v.signed_angle(-v, ()).0), PI);
+
+    let axis = Vector3::new(0.0, 0.0, 2.0);
+    let v = Vector3::new(1.0, 0.0, 1.0);
+    let w = Vector3::new(0.0, 1.0, 1.0);
+    // the angle in 3D space, not the angle of the projections
+    assert_near!(v.signed_angle(w, axis).0, PI / 3.0);
+    assert_near!(w.signed_angle(v, axis).0, -PI / 3.0);
+    assert_near!(v.signed_angle(w, -axis).0, -PI / 3.0);
+    assert_near!(v.signed_angle(v * 3.0, axis).0, 0.0);
+}
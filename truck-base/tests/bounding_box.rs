@@ -0,0 +1,58 @@
+use truck_base::{bounding_box::*, cgmath64::*};
+
+fn bdd_box(min: [f64; 3], max: [f64; 3]) -> BoundingBox<Vector3> {
+    BoundingBox::from_iter(&[Vector3::from(min), Vector3::from(max)])
+}
+
+#[test]
+fn disjoint_boxes() {
+    let bbx0 = bdd_box([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+    let bbx1 = bdd_box([0.0, 0.0, 2.0], [1.0, 1.0, 3.0]);
+    assert!(bbx0.intersection(&bbx1).is_none());
+    assert!(bbx1.intersection(&bbx0).is_none());
+    let union = bbx0.union(&bbx1);
+    assert_eq!(union, bdd_box([0.0, 0.0, 0.0], [1.0, 1.0, 3.0]));
+    assert_eq!(union.center(), Vector3::new(0.5, 0.5, 1.5));
+    assert_eq!(union.diagonal(), Vector3::new(1.0, 1.0, 3.0));
+    assert!(union.contains(&Vector3::new(0.5, 0.5, 1.5)));
+    assert!(!bbx0.contains(&Vector3::new(0.5, 0.5, 1.5)));
+    assert!(!bbx1.contains(&Vector3::new(0.5, 0.5, 1.5)));
+}
+
+#[test]
+fn overlapping_boxes() {
+    let bbx0 = bdd_box([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+    let bbx1 = bdd_box([1.0, -1.0, 1.5], [3.0, 1.0, 4.0]);
+    let intersection = bbx0.intersection(&bbx1).unwrap();
+    assert_eq!(intersection, bdd_box([1.0, 0.0, 1.5], [2.0, 1.0, 2.0]));
+    assert_eq!(bbx1.intersection(&bbx0), Some(intersection.clone()));
+    assert_eq!(bbx0.union(&bbx1), bdd_box([0.0, -1.0, 0.0], [3.0, 2.0, 4.0]));
+    let point = intersection.center();
+    assert!(bbx0.contains(&point) && bbx1.contains(&point));
+
+    // boxes sharing a face intersect in a degenerate box.
+    let bbx2 = bdd_box([2.0, 0.0, 0.0], [3.0, 2.0, 2.0]);
+    let face = bbx0.intersection(&bbx2).unwrap();
+    assert_eq!(face.diagonal(), Vector3::new(0.0, 2.0, 2.0));
+}
+
+#[test]
+fn nested_boxes() {
+    let outer = bdd_box([-2.0, -2.0, -2.0], [2.0, 2.0, 2.0]);
+    let inner = bdd_box([-1.0, 0.0, 0.5], [1.0, 1.0, 1.5]);
+    assert_eq!(outer.union(&inner), outer);
+    assert_eq!(outer.intersection(&inner), Some(inner.clone()));
+    assert!(outer > inner);
+    assert!(outer.contains(inner.min()) && outer.contains(inner.max()));
+    assert!(!inner.contains(outer.min()));
+}
+
+#[test]
+fn empty_boxes() {
+    let empty = BoundingBox::<Vector3>::new();
+    let bbx = bdd_box([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+    assert!(empty.intersection(&bbx).is_none());
+    assert!(bbx.intersection(&empty).is_none());
+    assert_eq!(empty.union(&bbx), bbx);
+    assert!(!empty.contains(&Vector3::new(0.0, 0.0, 0.0)));
+}
@@ -0,0 +1,51 @@
+use std::f64::consts::PI;
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
+
+/// the `i`th sample rotation
+fn rotation(i: usize) -> Quaternion {
+    let t = i as f64;
+    let axis = Vector3::new(f64::sin(t), f64::cos(2.0 * t), f64::sin(3.0 * t) + 1.5);
+    let angle = Rad(f64::sin(5.0 * t) * PI);
+    Quaternion::from_axis_angle(axis.normalize(), angle)
+}
+
+#[test]
+fn matrix_round_trip() {
+    (0..100).for_each(|i| {
+        let q = rotation(i);
+        let matrix = Matrix3::from(q);
+        let q0 = Quaternion::from(matrix);
+        // `q` and `-q` express the same rotation.
+        assert!(q0.near(&q) || q0.near(&-q), "{q0:?} {q:?}");
+        assert_near!(Matrix3::from(q0), matrix);
+        assert_near!(Matrix4::from(q0), Matrix4::from(matrix));
+    });
+
+    let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let q = Quaternion::from_axis_angle(axis, Rad(2.0 * PI / 3.0));
+    let matrix = Matrix4::from(q);
+    assert_near!(matrix.transform_vector(Vector3::unit_x()), Vector3::unit_y());
+    assert_near!(matrix.transform_vector(Vector3::unit_y()), Vector3::unit_z());
+    assert_near!(matrix, Matrix4::from_axis_angle(axis, Rad(2.0 * PI / 3.0)));
+}
+
+#[test]
+fn slerp_test() {
+    (0..100).for_each(|i| {
+        let (q0, q1) = (rotation(i), rotation(i + 100));
+        let start = q0.slerp(q1, 0.0);
+        let end = q0.slerp(q1, 1.0);
+        assert_near!(Matrix3::from(start), Matrix3::from(q0));
+        assert_near!(Matrix3::from(end), Matrix3::from(q1));
+        // the interpolated quaternion is a rotation.
+        let mid = q0.slerp(q1, 0.5);
+        assert_near!(mid.magnitude(), 1.0);
+    });
+
+    // the rotation around the fixed axis is interpolated by the angle.
+    let q0 = Quaternion::from_axis_angle(Vector3::unit_z(), Rad(0.0));
+    let q1 = Quaternion::from_axis_angle(Vector3::unit_z(), Rad(PI / 2.0));
+    let q = q0.slerp(q1, 0.5);
+    let vec = Matrix3::from(q) * Vector3::unit_x();
+    assert_near!(vec, Vector3::new(f64::sqrt(0.5), f64::sqrt(0.5), 0.0));
+}
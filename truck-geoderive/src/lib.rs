@@ -4,20 +4,76 @@ use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro_error::proc_macro_error;
 use quote::*;
+use syn::spanned::Spanned;
 use syn::*;
 
-fn top_type_of_enumeration<'a>(
-    variants: impl IntoIterator<Item = &'a Variant> + 'a,
-) -> TokenStream2 {
-    let variant = variants.into_iter().next().expect("empty enum!");
+fn field_type_of_variant(variant: &Variant) -> &Type {
     let vec: Vec<_> = variant.fields.iter().collect();
     match vec.len() {
         0 => panic!("empty field!"),
-        1 => vec[0].ty.to_token_stream(),
+        1 => &vec[0].ty,
         _ => unimplemented!(),
     }
 }
 
+fn top_type_of_enumeration<'a>(
+    variants: impl IntoIterator<Item = &'a Variant> + 'a,
+) -> TokenStream2 {
+    let variant = variants.into_iter().next().expect("empty enum!");
+    field_type_of_variant(variant).to_token_stream()
+}
+
+/// Generates the assertion that all variants have the same associated types `assoc_types`
+/// of the trait `trait_name` as the first variant.
+///
+/// The associated types are resolved only by the type checker, after the expansion of the macro.
+/// So the assertion is not a `compile_error!` but a trait bound, which is reported with
+/// a custom message by `#[diagnostic::on_unimplemented]`. The assertion is a generic function
+/// with the generics of the enum, since the types of the fields may depend on them.
+fn check_associated_types<'a>(
+    enum_name: &Ident,
+    generics: &Generics,
+    variants: impl IntoIterator<Item = &'a Variant> + Copy + 'a,
+    trait_name: &TokenStream2,
+    assoc_types: &[TokenStream2],
+) -> TokenStream2 {
+    let top_ty = &top_type_of_enumeration(variants);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let checks: Vec<_> = variants
+        .into_iter()
+        .skip(1)
+        .flat_map(|variant| {
+            let field_ty = field_type_of_variant(variant);
+            assoc_types.iter().map(move |assoc| {
+                quote_spanned! { field_ty.span() =>
+                    assert_same_associated_type::<
+                        <#field_ty as #trait_name>::#assoc,
+                        <#top_ty as #trait_name>::#assoc,
+                    >();
+                }
+            })
+        })
+        .collect();
+    let message = format!(
+        "all variants of `{}` must have the same associated types of `{}`",
+        enum_name,
+        trait_name.to_string().replace(' ', ""),
+    );
+    quote! {
+        #[allow(dead_code)]
+        const _: () = {
+            #[diagnostic::on_unimplemented(
+                message = #message,
+                label = "`{Self}` is different from `{T}`, the associated type of the first variant"
+            )]
+            trait SameAssociatedType<T> {}
+            impl<T> SameAssociatedType<T> for T {}
+            fn assert_same_associated_type<T: SameAssociatedType<U>, U>() {}
+            fn check #impl_generics () #where_clause { #(#checks)* }
+        };
+    }
+}
+
 fn enumerate_impl_return_something<'a>(
     variants: impl IntoIterator<Item = &'a Variant> + 'a,
     method: TokenStream2,
@@ -194,7 +250,7 @@ pub fn derive_bounded_curve(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { BoundedCurve };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let methods = methods! {
@@ -203,7 +259,7 @@ pub fn derive_bounded_curve(input: TokenStream) -> TokenStream {
             };
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #(#methods)*
                 }
             }
@@ -215,7 +271,7 @@ pub fn derive_bounded_curve(input: TokenStream) -> TokenStream {
             }
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #[inline(always)]
                     fn parameter_range(&self) -> (f64, f64) { self.0.parameter_range() }
                 }
@@ -232,7 +288,7 @@ pub fn derive_bounded_surface(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { BoundedSurface };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let methods = methods! {
@@ -241,7 +297,7 @@ pub fn derive_bounded_surface(input: TokenStream) -> TokenStream {
             };
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #(#methods)*
                 }
             }
@@ -253,7 +309,7 @@ pub fn derive_bounded_surface(input: TokenStream) -> TokenStream {
             }
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #[inline(always)]
                     fn parameter_range(&self) -> ((f64, f64), (f64, f64)) {
                         self.0.parameter_range()
@@ -272,7 +328,7 @@ pub fn derive_cut(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { Cut };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let methods = methods! {
@@ -281,7 +337,7 @@ pub fn derive_cut(input: TokenStream) -> TokenStream {
             };
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #(#methods)*
                 }
             }
@@ -293,7 +349,7 @@ pub fn derive_cut(input: TokenStream) -> TokenStream {
             }
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #[inline(always)]
                     fn cut(&mut self, t: f64) -> Self { Self(self.0.cut(t)) }
                 }
@@ -310,7 +366,7 @@ pub fn derive_invertible(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { Invertible };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let methods = methods! {
@@ -320,7 +376,7 @@ pub fn derive_invertible(input: TokenStream) -> TokenStream {
             };
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #(#methods)*
                 }
             }
@@ -332,7 +388,7 @@ pub fn derive_invertible(input: TokenStream) -> TokenStream {
             }
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #[inline(always)]
                     fn invert(&mut self) { self.0.invert() }
                     #[inline(always)]
@@ -351,20 +407,28 @@ pub fn derive_parameter_division_1d(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { ParameterDivision1D };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }],
+            );
             let methods = methods! {
                 variants, trait_name,
                 fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>),
             };
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -375,7 +439,7 @@ pub fn derive_parameter_division_1d(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     #[inline(always)]
                     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
@@ -395,7 +459,7 @@ pub fn derive_parameter_division_2d(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { ParameterDivision2D };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let methods = methods! {
@@ -404,7 +468,7 @@ pub fn derive_parameter_division_2d(input: TokenStream) -> TokenStream {
             };
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #(#methods)*
                 }
             }
@@ -416,7 +480,7 @@ pub fn derive_parameter_division_2d(input: TokenStream) -> TokenStream {
             }
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     #[inline(always)]
                     fn parameter_division(&self, range: ((f64, f64), (f64, f64)), tol: f64) -> (Vec<f64>, Vec<f64>) {
                         self.0.parameter_division(range, tol)
@@ -429,16 +493,57 @@ pub fn derive_parameter_division_2d(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Derives `ParametricCurve`.
+///
+/// For an enum, all variants must have the same associated types `Point` and `Vector`.
+/// ```compile_fail,E0277
+/// use truck_base::cgmath64::*;
+/// use truck_geotrait::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct Curve2D;
+/// impl ParametricCurve for Curve2D {
+///     type Point = Point2;
+///     type Vector = Vector2;
+///     fn subs(&self, t: f64) -> Point2 { Point2::new(t, 0.0) }
+///     fn der(&self, _: f64) -> Vector2 { Vector2::unit_x() }
+///     fn der2(&self, _: f64) -> Vector2 { Vector2::zero() }
+/// }
+///
+/// #[derive(Clone, Debug)]
+/// struct Curve3D;
+/// impl ParametricCurve for Curve3D {
+///     type Point = Point3;
+///     type Vector = Vector3;
+///     fn subs(&self, t: f64) -> Point3 { Point3::new(t, 0.0, 0.0) }
+///     fn der(&self, _: f64) -> Vector3 { Vector3::unit_x() }
+///     fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+/// }
+///
+/// // error: all variants of `MixedCurve` must have the same associated types of `ParametricCurve`
+/// #[derive(Clone, Debug, ParametricCurve)]
+/// enum MixedCurve {
+///     Curve2D(Curve2D),
+///     Curve3D(Curve3D),
+/// }
+/// ```
 #[proc_macro_error]
 #[proc_macro_derive(ParametricCurve)]
 pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { ParametricCurve };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }, quote! { Vector }],
+            );
             let methods = methods!(
                 variants,
                 trait_name,
@@ -448,11 +553,12 @@ pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     type Vector = <#top_ty as #trait_name>::Vector;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -463,7 +569,7 @@ pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     type Vector = <#field_type as #trait_name>::Vector;
                     #[inline(always)]
@@ -486,10 +592,17 @@ pub fn derive_parametric_surface(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { ParametricSurface };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }, quote! { Vector }],
+            );
             let methods = methods!(
                 variants,
                 trait_name,
@@ -502,11 +615,12 @@ pub fn derive_parametric_surface(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     type Vector = <#top_ty as #trait_name>::Vector;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -517,7 +631,7 @@ pub fn derive_parametric_surface(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name for #ty {
+                impl #gen truck_geotrait::#trait_name for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     type Vector = <#field_type as #trait_name>::Vector;
                     #[inline(always)]
@@ -547,7 +661,7 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
     let trait_name0 = quote! { ParametricSurface };
     let trait_name1 = quote! { ParametricSurface3D };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let methods0 = methods!(
@@ -567,14 +681,14 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name0 for #ty {
+                impl #gen truck_geotrait::#trait_name0 for #ty #ty_gen #where_clause {
                     type Point = Point3;
                     type Vector = Vector3;
                     #(#methods0)*
                 }
 
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name1 for #ty {
+                impl #gen truck_geotrait::#trait_name1 for #ty #ty_gen #where_clause {
                     #(#methods1)*
                 }
             }
@@ -586,7 +700,7 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
             }
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name0 for #ty {
+                impl #gen truck_geotrait::#trait_name0 for #ty #ty_gen #where_clause {
                     type Point = Point3;
                     type Vector = Vector3;
                     #[inline(always)]
@@ -603,7 +717,7 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                     fn vvder(&self, s: f64, t: f64) -> Self::Vector { self.0.vvder(s, t) }
                 }
                 #[automatically_derived]
-                impl #gen truck_geotrait::#trait_name1 for #ty {
+                impl #gen truck_geotrait::#trait_name1 for #ty #ty_gen #where_clause {
                     #[inline(always)]
                     fn normal(&self, u: f64, v: f64) -> Vector3 { self.0.normal(u, v) }
                 }
@@ -620,10 +734,17 @@ pub fn derive_snp_d1(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { SearchNearestParameter::<D1> };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }],
+            );
             let methods = methods!(
                 variants,
                 trait_name,
@@ -636,10 +757,11 @@ pub fn derive_snp_d1(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchNearestParameter<D1> for #ty {
+                impl #gen truck_geotrait::SearchNearestParameter<D1> for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -650,7 +772,7 @@ pub fn derive_snp_d1(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchNearestParameter<D1> for #ty {
+                impl #gen truck_geotrait::SearchNearestParameter<D1> for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     #[inline(always)]
                     fn search_nearest_parameter<H: Into<SPHint1D>>(
@@ -675,10 +797,17 @@ pub fn derive_snp_d2(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { SearchNearestParameter::<D2> };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }],
+            );
             let methods = methods!(
                 variants,
                 trait_name,
@@ -691,10 +820,11 @@ pub fn derive_snp_d2(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchNearestParameter<D2> for #ty {
+                impl #gen truck_geotrait::SearchNearestParameter<D2> for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -705,7 +835,7 @@ pub fn derive_snp_d2(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchNearestParameter<D2> for #ty {
+                impl #gen truck_geotrait::SearchNearestParameter<D2> for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     #[inline(always)]
                     fn search_nearest_parameter<H: Into<SPHint2D>>(
@@ -730,10 +860,17 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { SearchParameter::<D1> };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }],
+            );
             let methods = methods!(
                 variants,
                 trait_name,
@@ -746,10 +883,11 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchParameter<D1> for #ty {
+                impl #gen truck_geotrait::SearchParameter<D1> for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -760,7 +898,7 @@ pub fn derive_sp_d1(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchParameter<D1> for #ty {
+                impl #gen truck_geotrait::SearchParameter<D1> for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     #[inline(always)]
                     fn search_parameter<H: Into<SPHint1D>>(
@@ -785,10 +923,17 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let trait_name = quote! { SearchParameter::<D2> };
     let ty = input.ident;
-    let gen = input.generics;
+    let (gen, ty_gen, where_clause) = input.generics.split_for_impl();
     match input.data {
         Data::Enum(DataEnum { ref variants, .. }) => {
             let top_ty = top_type_of_enumeration(variants);
+            let check = check_associated_types(
+                &ty,
+                &input.generics,
+                variants,
+                &trait_name,
+                &[quote! { Point }],
+            );
             let methods = methods!(
                 variants,
                 trait_name,
@@ -801,10 +946,11 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
             );
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchParameter<D2> for #ty {
+                impl #gen truck_geotrait::SearchParameter<D2> for #ty #ty_gen #where_clause {
                     type Point = <#top_ty as #trait_name>::Point;
                     #(#methods)*
                 }
+                #check
             }
         }
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -815,7 +961,7 @@ pub fn derive_sp_d2(input: TokenStream) -> TokenStream {
             let field_type = &field[0].ty;
             quote! {
                 #[automatically_derived]
-                impl #gen truck_geotrait::SearchParameter<D2> for #ty {
+                impl #gen truck_geotrait::SearchParameter<D2> for #ty #ty_gen #where_clause {
                     type Point = <#field_type as #trait_name>::Point;
                     #[inline(always)]
                     fn search_parameter<H: Into<SPHint2D>>(
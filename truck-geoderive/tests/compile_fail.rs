@@ -0,0 +1,5 @@
+#[test]
+fn different_associated_types() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
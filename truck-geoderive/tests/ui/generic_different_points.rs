@@ -0,0 +1,19 @@
+use truck_base::cgmath64::*;
+use truck_geotrait::*;
+
+struct Curve<P>(P);
+
+impl<P> ParameterDivision1D for Curve<P> {
+    type Point = P;
+    fn parameter_division(&self, _: (f64, f64), _: f64) -> (Vec<f64>, Vec<P>) {
+        (Vec::new(), Vec::new())
+    }
+}
+
+#[derive(ParameterDivision1D)]
+enum GenericCurve<C: ParameterDivision1D<Point = Point3>> {
+    Generic(C),
+    Planar(Curve<Point2>),
+}
+
+fn main() {}
@@ -170,6 +170,98 @@ the number of control points: {1}"
     /// ```
     #[error("The vector of control points and the one of weights have different length.")]
     DifferentLength,
+    /// The degree of the curve cannot be reduced within the tolerance.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let mut bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0)],
+    /// );
+    /// assert!(matches!(bspcurve.try_reduce_degree(0.1), Err(Error::CannotReduceDegree)));
+    /// ```
+    #[error("The degree of the curve cannot be reduced within the tolerance.")]
+    CannotReduceDegree,
+    /// The parameter range is inverted, degenerate, or out of the domain.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)],
+    /// );
+    /// assert!(matches!(bspcurve.try_subcurve((0.6, 0.4)), Err(Error::InvalidRange(_, _))));
+    /// ```
+    #[error("The range [{0}, {1}] is inverted, degenerate, or out of the domain.")]
+    InvalidRange(f64, f64),
+    /// The given points are fewer than the control points to be fitted.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let points = vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)];
+    /// let params = vec![(0.0, 0.0), (1.0, 1.0)];
+    /// assert!(matches!(
+    ///     BSplineSurface::try_fit_scattered(&points, &params, (1, 1), (2, 2), 0.0),
+    ///     Err(Error::TooFewPoints(2, 4)),
+    /// ));
+    /// ```
+    #[error("The {0} points are fewer than the {1} control points.")]
+    TooFewPoints(usize, usize),
+    /// The linear system of the fitting has no unique finite solution.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// // all the points are near the corner, so the other control points are not determined.
+    /// let params: Vec<(f64, f64)> = (0..100)
+    ///     .map(|k| (0.01 * (k % 10) as f64, 0.01 * (k / 10) as f64))
+    ///     .collect();
+    /// let points: Vec<Point3> = params.iter().map(|(u, v)| Point3::new(*u, *v, 0.0)).collect();
+    /// assert!(matches!(
+    ///     BSplineSurface::try_fit_scattered(&points, &params, (1, 1), (4, 4), 0.0),
+    ///     Err(Error::SingularSystem),
+    /// ));
+    /// ```
+    #[error("The points do not determine the control points of the fitting.")]
+    SingularSystem,
+    /// Two consecutive points to be interpolated coincide.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let points = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(2.0, 0.0),
+    /// ];
+    /// assert_eq!(
+    ///     BSplineCurve::try_interpolate(&points, 2),
+    ///     Err(Error::CoincidentPoints),
+    /// );
+    /// ```
+    #[error("The consecutive points to be interpolated must be distinct.")]
+    CoincidentPoints,
+    /// The deviation of the approximation, the first field, exceeds the tolerance.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let points = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(2.0, 0.0),
+    /// ];
+    /// assert!(matches!(
+    ///     BSplineCurve::try_approximate(&points, 1, 2, 0.1),
+    ///     Err(Error::OutOfTolerance(_)),
+    /// ));
+    /// ```
+    #[error("The deviation {0} of the approximation exceeds the tolerance.")]
+    OutOfTolerance(f64),
 }
 
 #[test]
@@ -187,5 +279,12 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::DifferentLength).unwrap();
+    writeln!(stderr, "{}\n", Error::CannotReduceDegree).unwrap();
+    writeln!(stderr, "{}\n", Error::InvalidRange(1.0, 0.0)).unwrap();
+    writeln!(stderr, "{}\n", Error::TooFewPoints(2, 4)).unwrap();
+    writeln!(stderr, "{}\n", Error::SingularSystem).unwrap();
+    writeln!(stderr, "{}\n", Error::CoincidentPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::OutOfTolerance(0.5)).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
@@ -46,3 +46,6 @@ pub use specifieds::*;
 /// Declares some decorators
 pub mod decorators;
 pub use decorators::*;
+
+/// Solvers of small dense linear systems
+pub mod matrix;
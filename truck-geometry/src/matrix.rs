@@ -0,0 +1,52 @@
+use crate::*;
+
+/// Solves the linear system `a * x = b` by the cofactor inverse of `a`.
+///
+/// Returns `None` if the absolute value of the determinant of `a` is less than [`TOLERANCE2`].
+/// # Examples
+/// ```
+/// use truck_geometry::*;
+/// // 2x + y = 5, x - 3y = -1
+/// let a = Matrix2::new(2.0, 1.0, 1.0, -3.0);
+/// let x = matrix::solve2(a, Vector2::new(5.0, -1.0)).unwrap();
+/// assert_near!(x, Vector2::new(2.0, 1.0));
+///
+/// // singular matrix
+/// let a = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+/// assert_eq!(matrix::solve2(a, Vector2::new(1.0, 0.0)), None);
+/// ```
+pub fn solve2(a: Matrix2, b: Vector2) -> Option<Vector2> {
+    let det = a.determinant();
+    match det.abs() < TOLERANCE2 {
+        true => None,
+        false => a.invert().map(|inv| inv * b),
+    }
+}
+
+/// Solves the linear system `a * x = b` by the cofactor inverse of `a`.
+///
+/// Returns `None` if the absolute value of the determinant of `a` is less than [`TOLERANCE2`].
+/// # Examples
+/// ```
+/// use truck_geometry::*;
+/// // x + 2y + 3z = 14, 2x - y + z = 3, 3x + y - 2z = -1
+/// let a = Matrix3::new(1.0, 2.0, 3.0, 2.0, -1.0, 1.0, 3.0, 1.0, -2.0);
+/// let a = a.transpose(); // `Matrix3::new` takes the columns
+/// let x = matrix::solve3(a, Vector3::new(14.0, 3.0, -1.0)).unwrap();
+/// assert_near!(x, Vector3::new(1.0, 2.0, 3.0));
+///
+/// // singular matrix: the third column is the sum of the others
+/// let a = Matrix3::from_cols(
+///     Vector3::new(1.0, 0.0, 1.0),
+///     Vector3::new(2.0, 1.0, 3.0),
+///     Vector3::new(3.0, 1.0, 4.0),
+/// );
+/// assert_eq!(matrix::solve3(a, Vector3::new(1.0, 2.0, 3.0)), None);
+/// ```
+pub fn solve3(a: Matrix3, b: Vector3) -> Option<Vector3> {
+    let det = a.determinant();
+    match det.abs() < TOLERANCE2 {
+        true => None,
+        false => a.invert().map(|inv| inv * b),
+    }
+}
@@ -1,3 +1,4 @@
+use super::bspsurface::solve_linear_system;
 use super::*;
 use crate::errors::Error;
 use std::ops::*;
@@ -273,6 +274,79 @@ impl<V: Homogeneous<f64>> BSplineCurve<V> {
             .collect();
         BSplineCurve::new_unchecked(curve.knot_vec, control_points)
     }
+
+    /// Returns the weights, the last components of the control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Vector4::new(1.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(1.0, 1.0, 0.0, 1.0),
+    ///     Vector4::new(0.0, 2.0, 0.0, 2.0),
+    /// ];
+    /// let curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert_eq!(curve.weights(), vec![1.0, 1.0, 2.0]);
+    /// ```
+    #[inline(always)]
+    pub fn weights(&self) -> Vec<f64> { self.control_points.iter().map(|pt| pt.weight()).collect() }
+
+    /// Constructs the curve in homogeneous coordinates from the curve of the points
+    /// and the weights of the control points.
+    ///
+    /// The control points of the result are the points multiplied by the weights.
+    /// # Failures
+    /// the length of `curve.control_points()` and `weights` must be the same.
+    #[inline(always)]
+    pub fn try_homogenize(curve: BSplineCurve<V::Point>, weights: Vec<f64>) -> Result<Self> {
+        if curve.control_points.len() != weights.len() {
+            return Err(Error::DifferentLength);
+        }
+        let control_points = curve
+            .control_points
+            .into_iter()
+            .zip(weights)
+            .map(|(pt, w)| V::from_point_weight(pt, w))
+            .collect();
+        Ok(BSplineCurve::new_unchecked(curve.knot_vec, control_points))
+    }
+
+    /// Constructs the curve in homogeneous coordinates from the curve of the points
+    /// and the weights of the control points.
+    ///
+    /// The control points of the result are the points multiplied by the weights.
+    /// # Panics
+    /// the length of `curve.control_points()` and `weights` must be the same.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let points = vec![
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// ];
+    /// let weights = vec![1.0, 0.5 * f64::sqrt(2.0), 1.0];
+    /// let curve = BSplineCurve::new(KnotVec::bezier_knot(2), points.clone());
+    /// let curve = BSplineCurve::<Vector4>::homogenize(curve, weights.clone());
+    ///
+    /// // round trip
+    /// assert_eq!(curve.weights(), weights);
+    /// curve
+    ///     .control_points()
+    ///     .iter()
+    ///     .zip(&points)
+    ///     .for_each(|(v, pt)| assert_near!(v.to_point(), *pt));
+    ///
+    /// // the quarter of the unit circle
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_near!(curve.subs(t).to_point().to_vec().magnitude(), 1.0);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn homogenize(curve: BSplineCurve<V::Point>, weights: Vec<f64>) -> Self {
+        Self::try_homogenize(curve, weights).unwrap_or_else(|e| panic!("{}", e))
+    }
 }
 
 impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
@@ -436,6 +510,24 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// // bspcurve does not change as a curve
     /// assert!(bspcurve.near2_as_curve(&org_curve));
     /// ```
+    /// Knots can be added at the end points and at the existing knots as well.
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 2.0, 0.0),
+    ///     Vector3::new(2.0, -1.0, 1.0),
+    ///     Vector3::new(3.0, 1.0, -1.0),
+    ///     Vector3::new(4.0, 0.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    /// bspcurve.add_knot(0.0).add_knot(0.3).add_knot(0.4).add_knot(1.0);
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// assert_eq!(bspcurve.knot_vec().len(), org_curve.knot_vec().len() + 4);
+    /// assert_eq!(bspcurve.knot_vec().multiplicity(6), 2);
+    /// ```
     /// # Remarks
     /// If the added knot `x` is out of the range of the knot vector, then the knot vector will extended.
     /// ```
@@ -583,6 +675,30 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert_eq!(bspcurve.knot_vec(), &KnotVec::bezier_knot(2));
     /// assert_eq!(bspcurve.control_point(1), &Vector2::new(0.5, 0.5));
     /// ```
+    /// The curve does not change by the degree elevation.
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 2.0, 0.0),
+    ///     Vector3::new(2.0, -1.0, 1.0),
+    ///     Vector3::new(3.0, 3.0, -1.0),
+    ///     Vector3::new(4.0, 0.0, 2.0),
+    ///     Vector3::new(5.0, 1.0, 0.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.elevate_degree();
+    /// assert_eq!(bspcurve.degree(), org_curve.degree() + 1);
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    ///
+    /// const N: usize = 100; // sample size in test
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     assert_near2!(bspcurve.subs(t), org_curve.subs(t));
+    /// }
+    /// ```
     pub fn elevate_degree(&mut self) -> &mut Self {
         let mut result = CurveCollector::Singleton;
         for mut bezier in self.bezier_decomposition() {
@@ -775,6 +891,58 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         result
     }
 
+    /// Splits `self` at the parameter `t` into two clamped curves.
+    ///
+    /// The first curve is defined on `[front, t]` and the second one on `[t, back]`,
+    /// and they coincide with `self` on each range. Returns `None` if `t` is not in the
+    /// interior of the parameter range, since one of the results would be degenerate.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the NURBS representation of the unit circle
+    /// let knot_vec = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0]
+    /// );
+    /// let ctrl_pts = vec![
+    ///     Vector4::new(0.0, -2.0, 0.0, 2.0),
+    ///     Vector4::new(1.0, -1.0, 0.0, 1.0),
+    ///     Vector4::new(1.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(1.0, 1.0, 0.0, 1.0),
+    ///     Vector4::new(0.0, 2.0, 0.0, 2.0),
+    ///     Vector4::new(-1.0, 1.0, 0.0, 1.0),
+    ///     Vector4::new(-1.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(-1.0, -1.0, 0.0, 1.0),
+    ///     Vector4::new(0.0, -2.0, 0.0, 2.0),
+    /// ];
+    /// let bspline = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let (bspline0, bspline1) = bspline.split(0.5).unwrap();
+    /// assert_eq!(bspline0.parameter_range(), (0.0, 0.5));
+    /// assert_eq!(bspline1.parameter_range(), (0.5, 1.0));
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = 0.5 * (i as f64) / (N as f64);
+    ///     assert_near2!(bspline.subs(t), bspline0.subs(t));
+    ///     assert_near2!(bspline.subs(t + 0.5), bspline1.subs(t + 0.5));
+    /// }
+    ///
+    /// // the curve cannot be split at the end points.
+    /// assert!(bspline.split(0.0).is_none());
+    /// assert!(bspline.split(1.0).is_none());
+    /// assert!(bspline.split(1.5).is_none());
+    /// ```
+    pub fn split(&self, t: f64) -> Option<(BSplineCurve<P>, BSplineCurve<P>)> {
+        let (front, back) = self.parameter_range();
+        if t < front || t.near(&front) || t > back || t.near(&back) {
+            return None;
+        }
+        let mut curve0 = self.clone();
+        curve0.clamp();
+        let curve1 = curve0.cut(t);
+        Some((curve0, curve1))
+    }
+
     /// Makes the curve locally injective.
     /// # Example
     /// ```
@@ -802,6 +970,8 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert!(flag);
     ///
     /// bspcurve.make_locally_injective().knot_normalize();
+    /// // the end point is kept.
+    /// assert_near!(bspcurve.subs(1.0), Vector3::new(0.0, 0.0, 1.0));
     /// let mut flag = false;
     /// for i in 0..=N {
     ///     let t = 1.0 * (i as f64) / (N as f64);
@@ -834,7 +1004,7 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
             if bezier.is_const() {
                 x += bezier.knot_vec.range_length();
             } else {
-                self.concat(bezier.knot_translate(-x));
+                *self = self.concat(bezier.knot_translate(-x));
             }
         }
         self
@@ -897,6 +1067,158 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     pub fn near2_as_curve(&self, other: &BSplineCurve<P>) -> bool {
         self.sub_near_as_curve(other, 1, |x, y| x.near2(y))
     }
+
+    /// Returns whether the front point and the back point of the curve are near or not.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the arc of the unit circle in the homogeneous coordinates
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(0.5, 0.866025403784438, 0.5),
+    ///     Vector3::new(-0.5, 0.866025403784438, 1.0),
+    ///     Vector3::new(-1.0, 0.0, 0.5),
+    ///     Vector3::new(-0.5, -0.866025403784438, 1.0),
+    /// ];
+    /// let arc = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(!arc.is_closed());
+    ///
+    /// // the unit circle
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(0.5, 0.866025403784438, 0.5),
+    ///     Vector3::new(-0.5, 0.866025403784438, 1.0),
+    ///     Vector3::new(-1.0, 0.0, 0.5),
+    ///     Vector3::new(-0.5, -0.866025403784438, 1.0),
+    ///     Vector3::new(0.5, -0.866025403784438, 0.5),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    /// ];
+    /// let circle = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(circle.is_closed());
+    /// ```
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool { self.front().near(&self.back()) }
+
+    /// Returns whether the curve is closed and its derivation is continuous at the seam.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::uniform_knot(2, 4);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(-1.0, 1.0),
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(0.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(bspcurve.is_periodic());
+    ///
+    /// // the curve is still closed, but has a corner at the seam.
+    /// *bspcurve.control_point_mut(4) = Point2::new(-1.0, -1.0);
+    /// assert!(bspcurve.is_closed());
+    /// assert!(!bspcurve.is_periodic());
+    /// ```
+    pub fn is_periodic(&self) -> bool {
+        let (t0, t1) = self.parameter_range();
+        let (der0, der1) = (self.der(t0), self.der(t1));
+        self.is_closed() && (P::origin() + der0).near(&(P::origin() + der1))
+    }
+
+    /// Makes the curve closed by moving the back point to the front point.
+    ///
+    /// The curve is clamped before, and the last control point is replaced by the first one.
+    /// So, only the last span of the curve is changed.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::uniform_knot(2, 2);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    /// assert!(!bspcurve.is_closed());
+    ///
+    /// bspcurve.make_closed();
+    /// assert!(bspcurve.is_closed());
+    /// assert_near!(bspcurve.back(), Point2::new(0.0, 0.0));
+    /// // the first span is not changed.
+    /// assert_near!(bspcurve.subs(0.25), org_curve.subs(0.25));
+    /// ```
+    pub fn make_closed(&mut self) -> &mut Self {
+        if !self.is_clamped() {
+            self.clamp();
+        }
+        let n = self.control_points.len();
+        self.control_points[n - 1] = self.control_points[0];
+        self
+    }
+
+    /// Makes the curve periodic, closed with the continuous derivation at the seam.
+    ///
+    /// The curve is closed by [`BSplineCurve::make_closed`], and the second and the second last
+    /// control points are moved so that the derivations at both ends become their average.
+    /// Knots are added in advance if the curve has less than four control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::uniform_knot(2, 3);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(-1.0, 1.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(!bspcurve.is_closed());
+    ///
+    /// bspcurve.make_periodic();
+    /// assert!(bspcurve.is_periodic());
+    /// assert_near!(bspcurve.back(), Point2::new(0.0, 0.0));
+    /// // the middle control point is not moved.
+    /// assert_eq!(bspcurve.control_point(2), &Point2::new(1.0, 1.0));
+    ///
+    /// // the line segment is divided before
+    /// let mut line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)],
+    /// );
+    /// line.make_periodic();
+    /// assert!(line.is_periodic());
+    /// ```
+    pub fn make_periodic(&mut self) -> &mut Self {
+        while self.control_points.len() < 4 {
+            // divides the longest span
+            let knots = &self.knot_vec;
+            let i = (1..knots.len())
+                .max_by(|i, j| {
+                    let (a, b) = (knots[*i] - knots[*i - 1], knots[*j] - knots[*j - 1]);
+                    a.partial_cmp(&b).unwrap()
+                })
+                .unwrap();
+            let x = (knots[i - 1] + knots[i]) / 2.0;
+            self.add_knot(x);
+        }
+        self.make_closed();
+        let (t0, t1) = self.parameter_range();
+        let (der0, der1) = (self.der(t0), self.der(t1));
+        let der = (der0 + der1) / 2.0;
+        let (n, degree, knots) = (self.control_points.len(), self.degree(), &self.knot_vec);
+        let a0 = (knots[degree + 1] - knots[1]) / degree as f64;
+        let a1 = (knots[n + degree - 1] - knots[n - 1]) / degree as f64;
+        self.control_points[1] += (der - der0) * a0;
+        self.control_points[n - 2] -= (der - der1) * a1;
+        self
+    }
 }
 
 impl<P: ControlPoint<f64>> ParameterTransform for BSplineCurve<P> {
@@ -978,12 +1300,108 @@ fn cut_random_test() {
     truck_geotrait::cut_random_test(&curve, 10);
 }
 
+impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
+    /// Returns the curve restricted to the parameter interval `[range.0, range.1]`.
+    ///
+    /// The boundary knots are inserted up to full multiplicity,
+    /// and the control points outside the interval are dropped.
+    /// If `range` coincides with the parameter range, returns the clone of `self`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::uniform_knot(3, 4);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 3.0),
+    ///     Point2::new(4.0, 0.0),
+    ///     Point2::new(5.0, 1.0),
+    ///     Point2::new(6.0, 2.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let subcurve = bspcurve.subcurve((0.3, 0.6));
+    /// assert_eq!(subcurve.parameter_range(), (0.3, 0.6));
+    /// assert_eq!(subcurve.knot_vec().multiplicity(0), 4);
+    /// assert_eq!(subcurve.knot_vec().multiplicity(subcurve.knot_vec().len() - 1), 4);
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = 0.3 + 0.3 * i as f64 / N as f64;
+    ///     assert_near!(subcurve.subs(t), bspcurve.subs(t));
+    /// }
+    ///
+    /// // the full domain
+    /// assert_eq!(bspcurve.subcurve((0.0, 1.0)), bspcurve);
+    /// ```
+    /// # Panics
+    /// Panic occurs if `range` is inverted, degenerate, or out of the parameter range.
+    #[inline(always)]
+    pub fn subcurve(&self, range: (f64, f64)) -> BSplineCurve<P> {
+        self.try_subcurve(range).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the curve restricted to the parameter interval `[range.0, range.1]`.
+    /// # Failures
+    /// Returns [`Error::InvalidRange`] if `range` is inverted, degenerate,
+    /// or out of the parameter range.
+    ///
+    /// [`Error::InvalidRange`]: errors/enum.Error.html#variant.InvalidRange
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)],
+    /// );
+    /// assert!(bspcurve.try_subcurve((0.25, 0.75)).is_ok());
+    /// assert_eq!(bspcurve.try_subcurve((0.75, 0.25)), Err(Error::InvalidRange(0.75, 0.25)));
+    /// assert_eq!(bspcurve.try_subcurve((0.5, 1.5)), Err(Error::InvalidRange(0.5, 1.5)));
+    /// ```
+    pub fn try_subcurve(&self, (t0, t1): (f64, f64)) -> Result<BSplineCurve<P>> {
+        let (s0, s1) = self.parameter_range();
+        let outside = (t0 < s0 && !t0.near(&s0)) || (t1 > s1 && !t1.near(&s1));
+        if t1 < t0 || t0.near(&t1) || outside {
+            return Err(Error::InvalidRange(t0, t1));
+        }
+        let mut curve = self.clone();
+        if !t0.near(&s0) {
+            curve = curve.cut(t0);
+        }
+        if !t1.near(&s1) {
+            curve.cut(t1);
+        }
+        Ok(curve)
+    }
+}
+
 impl<P: ControlPoint<f64> + Tolerance> Concat<BSplineCurve<P>> for BSplineCurve<P> {
     type Output = BSplineCurve<P>;
     /// Concats two B-spline curves.
+    ///
+    /// The degrees of the curves are synchronized by the degree elevation,
+    /// and the knot vectors are clamped before concatting.
     /// # Examples
     /// ```
     /// use truck_geometry::*;
+    /// let curve0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)],
+    /// );
+    /// let mut curve1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(1.0, 1.0), Point2::new(2.0, 2.0), Point2::new(3.0, 1.0)],
+    /// );
+    /// curve1.knot_translate(1.0);
+    ///
+    /// let curve = curve0.try_concat(&curve1).unwrap();
+    /// assert_eq!(curve.degree(), 2);
+    /// assert_eq!(curve.parameter_range(), (0.0, 2.0));
+    /// assert_near!(curve.subs(0.5), curve0.subs(0.5));
+    /// assert_near!(curve.subs(1.5), curve1.subs(1.5));
     /// ```
     /// # Failure
     /// If the back of the knot vector of `self` does not coincides with the front of the one of `other`,
@@ -1000,6 +1418,26 @@ impl<P: ControlPoint<f64> + Tolerance> Concat<BSplineCurve<P>> for BSplineCurve<
     ///
     /// assert_eq!(bspcurve0.try_concat(&mut bspcurve1), Err(ConcatError::DisconnectedParameters(1.0, 2.0)));
     /// ```
+    /// If the end point of `self` and the start point of `other` are not near,
+    /// ```
+    /// use truck_geometry::*;
+    /// use truck_geotrait::traits::ConcatError;
+    ///
+    /// let bspcurve0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)],
+    /// );
+    /// let mut bspcurve1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(1.0, 1.0 + 1.0e-3), Point2::new(2.0, 2.0)],
+    /// );
+    /// bspcurve1.knot_translate(1.0);
+    ///
+    /// assert_eq!(
+    ///     bspcurve0.try_concat(&bspcurve1),
+    ///     Err(ConcatError::DisconnectedPoints(Point2::new(1.0, 1.0), Point2::new(1.0, 1.001))),
+    /// );
+    /// ```
     fn try_concat(&self, other: &BSplineCurve<P>) -> std::result::Result<Self, ConcatError<P>> {
         let mut curve0 = self.clone();
         let mut curve1 = other.clone();
@@ -1023,6 +1461,33 @@ impl<P: ControlPoint<f64> + Tolerance> Concat<BSplineCurve<P>> for BSplineCurve<
     }
 }
 
+#[test]
+fn clamped_knot_vec_test() {
+    let knot_vec = KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    let ctrl_pts = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 2.0),
+        Vector2::new(2.0, -1.0),
+        Vector2::new(3.0, 1.0),
+        Vector2::new(4.0, 0.0),
+    ];
+    let curve = BSplineCurve::new(knot_vec.clone(), ctrl_pts);
+    let (front, back) = (knot_vec[3], knot_vec[knot_vec.len() - 4]);
+
+    // clamps the curve by the knot insertion
+    let mut clamped = curve.clone().cut(front);
+    clamped.cut(back);
+
+    let mut knot_vec = knot_vec;
+    knot_vec.to_clamped(3);
+    assert_eq!(clamped.knot_vec(), &knot_vec);
+    const N: usize = 100;
+    for i in 0..=N {
+        let t = front + (back - front) * i as f64 / N as f64;
+        assert_near2!(clamped.subs(t), curve.subs(t));
+    }
+}
+
 #[test]
 fn concat_positive_test() {
     let mut part0 = BSplineCurve::new(
@@ -1065,6 +1530,29 @@ fn concat_negative_test() {
     );
 }
 
+#[test]
+fn make_locally_injective_test() {
+    // the constant part is in the knot span `[1.0, 3.0]`.
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 3.0, 4.0, 4.0, 4.0]);
+    let ctrl_pts = vec![
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let mut curve = org_curve.clone();
+    curve.make_locally_injective();
+    // the part after the constant part is concatenated, not dropped.
+    assert_eq!(curve.parameter_range(), (0.0, 2.0));
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert_near!(curve.subs(t), org_curve.subs(t));
+        assert_near!(curve.subs(1.0 + t), org_curve.subs(3.0 + t));
+    }
+}
+
 impl<P> ParameterDivision1D for BSplineCurve<P>
 where P: ControlPoint<f64>
         + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
@@ -1235,15 +1723,748 @@ where
     }
 }
 
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Returns the length of the curve on the parameter range `range`.
+    ///
+    /// The magnitude of the derivation is integrated on each knot span by the adaptive Simpson method,
+    /// refining until the successive estimates agree within `tol`.
+    /// If `range.0 > range.1`, the range is swapped.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // a straight line with non-uniform speed
+    /// let ctrl_pts = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(0.1, 0.2, 0.2),
+    ///     Point3::new(1.0, 2.0, 2.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// let length = bspcurve.arc_length((0.0, 1.0), 1.0e-8);
+    /// assert_near!(length, 3.0);
+    /// let length = bspcurve.arc_length((0.3, 0.8), 1.0e-8);
+    /// assert_near!(length, bspcurve.subs(0.3).distance(bspcurve.subs(0.8)));
+    /// ```
+    /// The curve may stop, i.e. the derivation may vanish, and the knot spans may be degenerate.
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(1.0, 2.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert_near!(bspcurve.arc_length((0.0, 1.0), 1.0e-8), 3.0);
+    /// assert_eq!(bspcurve.arc_length((0.5, 0.5), 1.0e-8), 0.0);
+    /// ```
+    pub fn arc_length(&self, range: (f64, f64), tol: f64) -> f64 {
+        let (t0, t1) = match range.0 <= range.1 {
+            true => range,
+            false => (range.1, range.0),
+        };
+        let speed = |t: f64| self.der(t).magnitude();
+        let mut knots = vec![t0];
+        knots.extend(self.knot_vec.iter().copied().filter(|t| t0 < *t && *t < t1));
+        knots.push(t1);
+        let len = knots.len();
+        knots
+            .windows(2)
+            .filter(|span| span[0] < span[1])
+            .map(|span| {
+                let (a, b) = (span[0], span[1]);
+                let (fa, fm, fb) = (speed(a), speed((a + b) / 2.0), speed(b));
+                let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+                adaptive_simpson(&speed, (a, b), (fa, fm, fb), whole, tol / len as f64, 50)
+            })
+            .sum()
+    }
+}
+
+fn adaptive_simpson(
+    f: &impl Fn(f64) -> f64,
+    (a, b): (f64, f64),
+    (fa, fm, fb): (f64, f64, f64),
+    whole: f64,
+    tol: f64,
+    depth: usize,
+) -> f64 {
+    let m = (a + b) / 2.0;
+    let (flm, frm) = (f((a + m) / 2.0), f((m + b) / 2.0));
+    let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+    let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+    let delta = left + right - whole;
+    if depth == 0 || delta.abs() <= 15.0 * tol {
+        left + right + delta / 15.0
+    } else {
+        adaptive_simpson(f, (a, m), (fa, flm, fm), left, tol / 2.0, depth - 1)
+            + adaptive_simpson(f, (m, b), (fm, frm, fb), right, tol / 2.0, depth - 1)
+    }
+}
+
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Reduces the degree by one if the curve can be represented by the lower degree curve
+    /// within the tolerance `tol`.
+    ///
+    /// The multiplicity of each knot is decreased by one, keeping the interior knots,
+    /// and the control points are fitted to the curve by the least squares method.
+    /// The result is accepted if the distance from the original curve is at most `tol`
+    /// on the sample points. The knot vector will be clamped, and the redundant knots
+    /// can be removed by [`BSplineCurve::optimize`].
+    /// # Failures
+    /// The following errors are returned without changing `self`.
+    /// - If the degree is zero or the fitted curve is not within `tol`,
+    ///   returns [`Error::CannotReduceDegree`].
+    /// - If the normal equation of the least squares is singular,
+    ///   returns [`Error::SingularSystem`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 2.0, 0.0),
+    ///     Vector3::new(2.0, -1.0, 1.0),
+    ///     Vector3::new(3.0, 3.0, -1.0),
+    ///     Vector3::new(4.0, 0.0, 2.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let mut bspcurve = org_curve.clone();
+    /// bspcurve.elevate_degree();
+    /// assert_eq!(bspcurve.degree(), 4);
+    ///
+    /// bspcurve.try_reduce_degree(1.0e-6).unwrap().optimize();
+    /// assert_eq!(bspcurve.degree(), 3);
+    /// assert_eq!(bspcurve.knot_vec(), org_curve.knot_vec());
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    ///
+    /// // The cubic curve cannot be reduced any more.
+    /// assert_eq!(bspcurve.try_reduce_degree(1.0e-6), Err(Error::CannotReduceDegree));
+    /// assert_eq!(bspcurve.degree(), 3);
+    /// ```
+    pub fn try_reduce_degree(&mut self, tol: f64) -> Result<&mut Self> {
+        let degree = self.degree();
+        if degree == 0 {
+            return Err(Error::CannotReduceDegree);
+        }
+        let mut curve = self.clone();
+        curve.clamp();
+        let (knots, mults) = curve.knot_vec.to_single_multi();
+        let last = knots.len() - 1;
+        let mults = mults
+            .into_iter()
+            .enumerate()
+            .map(|(i, mult)| match i == 0 || i == last {
+                true => degree,
+                false => usize::min(usize::max(mult - 1, 1), degree),
+            })
+            .collect();
+        let knot_vec = KnotVec::from_single_multi(knots.clone(), mults)?;
+        let new_degree = degree - 1;
+        let n = knot_vec.len() - degree;
+
+        // sample points inside the knot spans
+        let division = degree + 2;
+        let params: Vec<f64> = knots
+            .windows(2)
+            .flat_map(|span| {
+                (0..division).map(move |j| {
+                    span[0] + (span[1] - span[0]) * (j as f64 + 0.5) / division as f64
+                })
+            })
+            .collect();
+
+        let points: Vec<P> = params.iter().map(|t| curve.subs(*t)).collect();
+        let reduced = fit_control_points(&points, &params, &knot_vec, new_degree, n)?;
+
+        let division = 2 * division;
+        let within_tol = knots.windows(2).all(|span| {
+            (0..=division).all(|j| {
+                let t = span[0] + (span[1] - span[0]) * j as f64 / division as f64;
+                (reduced.subs(t) - curve.subs(t)).magnitude() <= tol
+            })
+        });
+        match within_tol {
+            true => {
+                *self = reduced;
+                Ok(self)
+            }
+            false => Err(Error::CannotReduceDegree),
+        }
+    }
+
+    /// Creates the clamped curve of `degree` passing through `points`.
+    ///
+    /// The parameters of the points are given by the chord length in the range `[0, 1]`,
+    /// and the interior knots are the averages of the parameters. The control points are
+    /// the solution of the banded system given by the basis functions at the parameters.
+    /// # Failures
+    /// - If the points are fewer than `degree + 1`, returns [`Error::TooFewPoints`].
+    /// - If two consecutive points coincide, returns [`Error::CoincidentPoints`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the points on a sine wave
+    /// let points: Vec<Point3> = (0..=12)
+    ///     .map(|i| {
+    ///         let x = i as f64 * 0.5;
+    ///         Point3::new(x, f64::sin(x), 0.0)
+    ///     })
+    ///     .collect();
+    /// let bspcurve = BSplineCurve::try_interpolate(&points, 3).unwrap();
+    /// assert_eq!(bspcurve.degree(), 3);
+    /// assert_eq!(bspcurve.control_points().len(), points.len());
+    /// assert_eq!(bspcurve.parameter_range(), (0.0, 1.0));
+    ///
+    /// // the parameters by the chord length
+    /// let chords: Vec<f64> = points.windows(2).map(|p| p[0].distance(p[1])).collect();
+    /// let total: f64 = chords.iter().sum();
+    /// let params = chords.iter().scan(0.0, |t, chord| {
+    ///     *t += chord / total;
+    ///     Some(*t)
+    /// });
+    /// std::iter::once(0.0)
+    ///     .chain(params)
+    ///     .zip(&points)
+    ///     .for_each(|(t, pt)| assert_near!(bspcurve.subs(t), *pt));
+    ///
+    /// // the curve is near the sine wave between the points.
+    /// let pt = bspcurve.subs(0.5);
+    /// assert!(f64::abs(pt.y - f64::sin(pt.x)) < 0.01);
+    ///
+    /// // the cubic curve needs at least four points.
+    /// assert_eq!(
+    ///     BSplineCurve::try_interpolate(&points[..3], 3),
+    ///     Err(errors::Error::TooFewPoints(3, 4)),
+    /// );
+    /// ```
+    pub fn try_interpolate(points: &[P], degree: usize) -> Result<Self> {
+        let len = points.len();
+        if len < degree + 1 {
+            return Err(Error::TooFewPoints(len, degree + 1));
+        }
+        let params = chord_length_parameters(points)?;
+
+        let interior: Vec<f64> = match degree {
+            0 => params.windows(2).map(|t| (t[0] + t[1]) / 2.0).collect(),
+            _ => (1..len - degree)
+                .map(|j| params[j..j + degree].iter().sum::<f64>() / degree as f64)
+                .collect(),
+        };
+        let mut knots = vec![0.0; degree + 1];
+        knots.extend(interior);
+        knots.extend(vec![1.0; degree + 1]);
+        let knot_vec = KnotVec::from(knots);
+
+        // the collocation matrix, whose non-zero entries are near the diagonal
+        let mut matrix = params
+            .iter()
+            .map(|t| knot_vec.try_bspline_basis_functions(degree, *t))
+            .collect::<Result<Vec<_>>>()?;
+        let mut rhs: Vec<P::Diff> = points.iter().map(|pt| pt.to_vec()).collect();
+        // Gaussian elimination in the band, the collocation matrix is totally positive.
+        for k in 0..len {
+            let end = usize::min(k + degree + 1, len);
+            if matrix[k][k].so_small() {
+                return Err(Error::CoincidentPoints);
+            }
+            for i in (k + 1)..end {
+                let coef = matrix[i][k] / matrix[k][k];
+                let (upper, lower) = matrix.split_at_mut(i);
+                lower[0][k..end]
+                    .iter_mut()
+                    .zip(&upper[k][k..end])
+                    .for_each(|(a, b)| *a -= coef * b);
+                let sub = rhs[k] * coef;
+                rhs[i] -= sub;
+            }
+        }
+        for k in (0..len).rev() {
+            let end = usize::min(k + degree + 1, len);
+            let sum = ((k + 1)..end).fold(rhs[k], |sum, j| sum - rhs[j] * matrix[k][j]);
+            rhs[k] = sum / matrix[k][k];
+        }
+        let control_points = rhs.into_iter().map(|v| P::origin() + v).collect();
+        Ok(BSplineCurve::new_unchecked(knot_vec, control_points))
+    }
+
+    /// Creates the clamped curve of `degree` passing through `points`.
+    /// # Panics
+    /// Panic occurs if the points are fewer than `degree + 1` or two consecutive points coincide.
+    /// See [`BSplineCurve::try_interpolate`] for the details.
+    #[inline(always)]
+    pub fn interpolate(points: &[P], degree: usize) -> Self {
+        Self::try_interpolate(points, degree).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fits the clamped curve of `degree` with `n_ctrl` control points to `points`
+    /// by the least squares, and returns the curve and the maximum deviation from the points.
+    ///
+    /// The parameters of the points are given by the chord length as
+    /// [`BSplineCurve::try_interpolate`], and the interior knots are fixed so that every knot span
+    /// contains some parameters. Then, the least squares is iterated with moving each parameter
+    /// to the foot of the perpendicular from the point to the fitted curve, so the deviation
+    /// is the distance between each point and the curve.
+    /// The degree is lowered to `n_ctrl - 1` if the control points are too few for it.
+    /// # Failures
+    /// - If `n_ctrl` is zero, returns [`Error::EmptyControlPoints`].
+    /// - If the points are fewer than `n_ctrl`, returns [`Error::TooFewPoints`].
+    /// - If two consecutive points coincide, returns [`Error::CoincidentPoints`].
+    /// - If the maximum deviation exceeds `tol`, returns [`Error::OutOfTolerance`]
+    ///   with the deviation, so that the caller can retry with more control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use std::f64::consts::PI;
+    /// // noisy points on a sine wave
+    /// const NOISE: f64 = 1.0e-3;
+    /// let points: Vec<Point3> = (0..100)
+    ///     .map(|i| {
+    ///         let x = PI * i as f64 / 99.0;
+    ///         let [nx, ny, nz] = HashGen::hash3(i as f64);
+    ///         let noise = Vector3::new(nx - 0.5, ny - 0.5, nz - 0.5) * 2.0 * NOISE;
+    ///         Point3::new(x, f64::sin(x), 0.0) + noise
+    ///     })
+    ///     .collect();
+    ///
+    /// let (bspcurve, deviation) = BSplineCurve::try_approximate(&points, 3, 6, 0.01).unwrap();
+    /// assert_eq!(bspcurve.degree(), 3);
+    /// assert_eq!(bspcurve.control_points().len(), 6);
+    /// assert!(deviation < 0.01, "deviation: {deviation}");
+    /// let pt = bspcurve.subs(0.5);
+    /// assert!(f64::abs(pt.y - f64::sin(pt.x)) < 0.01);
+    ///
+    /// // iterate on the number of control points until the tolerance is satisfied.
+    /// let mut n_ctrl = 2;
+    /// let (bspcurve, deviation) = loop {
+    ///     match BSplineCurve::try_approximate(&points, 3, n_ctrl, 0.01) {
+    ///         Ok(res) => break res,
+    ///         Err(errors::Error::OutOfTolerance(deviation)) => {
+    ///             assert!(deviation > 0.01);
+    ///             n_ctrl += 1;
+    ///         }
+    ///         Err(error) => panic!("{error}"),
+    ///     }
+    /// };
+    /// assert!(deviation <= 0.01);
+    /// assert!(bspcurve.control_points().len() <= 6);
+    /// ```
+    pub fn try_approximate(
+        points: &[P],
+        degree: usize,
+        n_ctrl: usize,
+        tol: f64,
+    ) -> Result<(Self, f64)> {
+        if n_ctrl == 0 {
+            return Err(Error::EmptyControlPoints);
+        }
+        let len = points.len();
+        if len < n_ctrl {
+            return Err(Error::TooFewPoints(len, n_ctrl));
+        }
+        let mut params = chord_length_parameters(points)?;
+        let degree = usize::min(degree, n_ctrl - 1);
+
+        // the interior knots by the averages of the parameters, cf. The NURBS Book (9.69)
+        let d = len as f64 / (n_ctrl - degree) as f64;
+        let interior = (1..n_ctrl - degree).map(|j| {
+            let i = (j as f64 * d) as usize;
+            let alpha = j as f64 * d - i as f64;
+            (1.0 - alpha) * params[i - 1] + alpha * params[i]
+        });
+        let mut knots = vec![0.0; degree + 1];
+        knots.extend(interior);
+        knots.extend(vec![1.0; degree + 1]);
+        let knot_vec = KnotVec::from(knots);
+
+        // the least squares alternated with the correction of the parameters,
+        // cf. Hoschek, Intrinsic parametrization for approximation (1988)
+        let mut curve = fit_control_points(points, &params, &knot_vec, degree, n_ctrl)?;
+        for _ in 0..PARAMETER_CORRECTION {
+            params.iter_mut().zip(points).for_each(|(t, pt)| {
+                *t = (0..NEWTON_TRIALS).fold(*t, |t, _| {
+                    let vec = curve.subs(t) - *pt;
+                    let (der, der2) = (curve.der(t), curve.der2(t));
+                    let denom = der.dot(der) + vec.dot(der2);
+                    match denom.so_small() {
+                        true => t,
+                        false => f64::clamp(t - vec.dot(der) / denom, 0.0, 1.0),
+                    }
+                })
+            });
+            curve = fit_control_points(points, &params, &knot_vec, degree, n_ctrl)?;
+        }
+
+        let deviation = points
+            .iter()
+            .zip(&params)
+            .fold(0.0, |max, (pt, t)| f64::max(max, (curve.subs(*t) - *pt).magnitude()));
+        match deviation <= tol {
+            true => Ok((curve, deviation)),
+            false => Err(Error::OutOfTolerance(deviation)),
+        }
+    }
+
+    /// Fits the clamped curve of `degree` with `n_ctrl` control points to `points`
+    /// by the least squares, and returns the curve and the maximum deviation from the points.
+    /// # Panics
+    /// Panic occurs if the fitting fails. See [`BSplineCurve::try_approximate`] for the details.
+    #[inline(always)]
+    pub fn approximate(points: &[P], degree: usize, n_ctrl: usize, tol: f64) -> (Self, f64) {
+        Self::try_approximate(points, degree, n_ctrl, tol).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// the number of the corrections of the parameters in [`BSplineCurve::try_approximate`]
+const PARAMETER_CORRECTION: usize = 10;
+/// the number of the Newton steps in a correction of the parameters
+const NEWTON_TRIALS: usize = 3;
+
+/// Solves the normal equation of the least squares fitting `points` at `params`.
+fn fit_control_points<P>(
+    points: &[P],
+    params: &[f64],
+    knot_vec: &KnotVec,
+    degree: usize,
+    n_ctrl: usize,
+) -> Result<BSplineCurve<P>>
+where
+    P: ControlPoint<f64>,
+{
+    let mut matrix = vec![vec![0.0; n_ctrl]; n_ctrl];
+    let mut rhs = vec![P::Diff::zero(); n_ctrl];
+    for (pt, t) in points.iter().zip(params) {
+        let basis = knot_vec.try_bspline_basis_functions(degree, *t)?;
+        for i in 0..n_ctrl {
+            for j in 0..n_ctrl {
+                matrix[i][j] += basis[i] * basis[j];
+            }
+            rhs[i] += pt.to_vec() * basis[i];
+        }
+    }
+    let rows = rhs.into_iter().map(|v| vec![P::origin() + v]).collect();
+    let control_points = solve_linear_system(matrix, rows)
+        .ok_or(Error::SingularSystem)?
+        .into_iter()
+        .map(|row| row[0])
+        .collect();
+    Ok(BSplineCurve::new_unchecked(knot_vec.clone(), control_points))
+}
+
+/// Returns the parameters in `[0, 1]` of `points` proportional to the chord length.
+fn chord_length_parameters<P>(points: &[P]) -> Result<Vec<f64>>
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let chords: Vec<f64> = points.windows(2).map(|p| (p[1] - p[0]).magnitude()).collect();
+    if chords.iter().any(|chord| chord.so_small()) {
+        return Err(Error::CoincidentPoints);
+    }
+    let total: f64 = chords.iter().sum();
+    let mut params = Vec::with_capacity(points.len());
+    params.push(0.0);
+    chords.iter().fold(0.0, |t, chord| {
+        let t = t + chord / total;
+        params.push(t);
+        t
+    });
+    if let Some(last) = params.last_mut() {
+        *last = 1.0;
+    }
+    Ok(params)
+}
+
+impl<P: ControlPoint<f64, Diff = Vector2>> BSplineCurve<P> {
+    /// Returns the signed curvature of the planar curve at the parameter `t`.
+    ///
+    /// The curvature is `(x'y'' - y'x'') / (x'^2 + y'^2)^{3/2}`, positive if the curve turns
+    /// counterclockwise. The unsigned curvature is its absolute value.
+    /// Returns `0.0` at the parameter where the derivation vanishes.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the parabola y = x^2 on -1 <= x <= 1
+    /// let ctrl_pts = vec![
+    ///     Point2::new(-1.0, 1.0),
+    ///     Point2::new(0.0, -1.0),
+    ///     Point2::new(1.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// assert_near!(bspcurve.curvature(0.5), 2.0);
+    /// assert_near!(bspcurve.curvature(1.0), 2.0 / f64::powf(5.0, 1.5));
+    ///
+    /// // the inverse curve turns clockwise.
+    /// let inverse = bspcurve.inverse();
+    /// assert_near!(inverse.curvature(0.5), -2.0);
+    ///
+    /// // the curve stops at the end points.
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// assert_eq!(bspcurve.curvature(0.0), 0.0);
+    /// ```
+    #[inline(always)]
+    pub fn curvature(&self, t: f64) -> f64 { planar_curvature(self.der(t), self.der2(t)) }
+}
+
+impl<P: ControlPoint<f64, Diff = Vector3>> BSplineCurve<P> {
+    /// Returns the Frenet frame `(tangent, normal, binormal)` at the parameter `t`.
+    ///
+    /// The tangent is the normalized first derivation, and the normal is the normalized
+    /// component of the second derivation perpendicular to the tangent.
+    /// At the point where the curvature vanishes, the normal is not determined by the
+    /// derivations, so an arbitrary unit vector perpendicular to the tangent is adopted.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the parabola z = x^2 on the xz-plane
+    /// let ctrl_pts = vec![
+    ///     Point3::new(-1.0, 0.0, 1.0),
+    ///     Point3::new(0.0, 0.0, -1.0),
+    ///     Point3::new(1.0, 0.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts);
+    /// let (tangent, normal, binormal) = bspcurve.frenet_frame(0.5);
+    /// assert_near!(tangent, Vector3::unit_x());
+    /// assert_near!(normal, Vector3::unit_z());
+    /// assert_near!(binormal, -Vector3::unit_y());
+    /// ```
+    #[inline(always)]
+    pub fn frenet_frame(&self, t: f64) -> (Vector3, Vector3, Vector3) {
+        frenet_frame(self.der(t), self.der2(t), None)
+    }
+    /// Returns the Frenet frames at the parameters `ts`.
+    ///
+    /// At the point where the curvature vanishes, e.g. the inflection point, the normal
+    /// of the previous frame is projected and carried to the current frame.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the curve has the straight segment in the middle.
+    /// let ctrl_pts = vec![
+    ///     Point3::new(-1.0, 0.0, 1.0),
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(3.0, 0.0, 0.0),
+    ///     Point3::new(3.0, 0.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::uniform_knot(2, 4), ctrl_pts);
+    /// let ts = [0.0, 0.5, 1.0];
+    /// let frames = bspcurve.frenet_frames(&ts);
+    /// // the normal at the start is on the xz-plane.
+    /// assert!(frames[0].1.y.so_small());
+    /// // the normal at the start is carried to the middle.
+    /// assert_near!(frames[1].0, Vector3::unit_x());
+    /// assert_near!(frames[1].1, Vector3::unit_z());
+    /// assert_near!(frames[2].0, Vector3::unit_z());
+    /// assert_near!(frames[2].1, -Vector3::unit_x());
+    /// ```
+    pub fn frenet_frames(&self, ts: &[f64]) -> Vec<(Vector3, Vector3, Vector3)> {
+        let mut prev_normal = None;
+        ts.iter()
+            .map(|t| {
+                let frame = frenet_frame(self.der(*t), self.der2(*t), prev_normal);
+                prev_normal = Some(frame.1);
+                frame
+            })
+            .collect()
+    }
+    /// Returns the rotation minimizing frames `(tangent, normal, binormal)` at the parameters `ts`.
+    ///
+    /// The first frame is the Frenet frame, and the following frames are computed by the double
+    /// reflection method, so the frames do not twist around the tangent. The parameters `ts`
+    /// should be sorted and dense enough to trace the curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the planar curve on the xy-plane
+    /// let ctrl_pts = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 2.0, 0.0),
+    ///     Point3::new(2.0, -2.0, 0.0),
+    ///     Point3::new(3.0, 0.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+    /// const N: usize = 100;
+    /// let ts: Vec<f64> = (0..=N).map(|i| i as f64 / N as f64).collect();
+    /// let frames = bspcurve.rotation_minimizing_frames(&ts);
+    /// // the binormal of the planar curve is kept perpendicular to the plane
+    /// // even across the inflection point.
+    /// frames.iter().for_each(|(_, _, binormal)| assert!(binormal.z < -0.99));
+    /// ```
+    pub fn rotation_minimizing_frames(&self, ts: &[f64]) -> Vec<(Vector3, Vector3, Vector3)> {
+        let mut frames = Vec::with_capacity(ts.len());
+        let mut iter = ts.iter();
+        let (mut t0, mut frame) = match iter.next() {
+            Some(t) => (*t, self.frenet_frame(*t)),
+            None => return frames,
+        };
+        frames.push(frame);
+        for t1 in iter {
+            let (tangent0, normal0, _) = frame;
+            let tangent1 = self.der(*t1).normalize();
+            // the first reflection by the bisecting plane of the two points
+            let v1 = self.subs(*t1) - self.subs(t0);
+            let (normal, tangent) = match v1.so_small() {
+                true => (normal0, tangent0),
+                false => (reflect(normal0, v1), reflect(tangent0, v1)),
+            };
+            // the second reflection making the tangent coincide
+            let v2 = tangent1 - tangent;
+            let normal = match v2.so_small() {
+                true => normal,
+                false => reflect(normal, v2),
+            };
+            frame = (tangent1, normal, tangent1.cross(normal));
+            frames.push(frame);
+            t0 = *t1;
+        }
+        frames
+    }
+}
+
+/// the reflection of `vec` by the plane whose normal is `axis`
+#[inline(always)]
+fn reflect(vec: Vector3, axis: Vector3) -> Vector3 {
+    vec - axis * (2.0 * axis.dot(vec) / axis.magnitude2())
+}
+
+/// Frenet frame from the first and second derivations.
+/// If the curvature vanishes, the projection of `prev_normal` or an arbitrary vector is adopted
+/// as the normal.
+fn frenet_frame(
+    der: Vector3,
+    der2: Vector3,
+    prev_normal: Option<Vector3>,
+) -> (Vector3, Vector3, Vector3) {
+    let tangent = der.normalize();
+    let perp = der2 - tangent * tangent.dot(der2);
+    let normal = match perp.so_small() {
+        false => perp.normalize(),
+        true => {
+            let perp = prev_normal
+                .map(|normal| normal - tangent * tangent.dot(normal))
+                .filter(|perp| !perp.so_small());
+            let perp = perp.unwrap_or_else(|| tangent.orthonormal_basis().0);
+            perp.normalize()
+        }
+    };
+    (tangent, normal, tangent.cross(normal))
+}
+
 impl<P> BSplineCurve<P>
 where P: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> + Copy
 {
     /// Returns the bounding box including all control points.
+    ///
+    /// Since a B-spline curve lies in the convex hull of its control points,
+    /// the returned box includes the whole curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 3.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(KnotVec::bezier_knot(3), ctrl_pts);
+    /// let bdb = bspcurve.roughly_bounding_box();
+    /// assert_eq!(bdb.min(), &Point2::new(0.0, -1.0));
+    /// assert_eq!(bdb.max(), &Point2::new(3.0, 3.0));
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let mut bdb0 = bdb.clone();
+    ///     bdb0.push(&bspcurve.subs(i as f64 / N as f64));
+    ///     assert_eq!(bdb0, bdb);
+    /// }
+    /// ```
     #[inline(always)]
     pub fn roughly_bounding_box(&self) -> BoundingBox<P> { self.control_points.iter().collect() }
 }
 
+impl BSplineCurve<Point2> {
+    /// Returns the pairs of parameters `(s, t)` such that `self.subs(s)` and `other.subs(t)` meet.
+    ///
+    /// The curves are subdivided recursively while the bounding boxes of their control points
+    /// overlap, and the roots are refined by Newton's method.
+    /// The roots whose points are closer than `tol` are regarded as the same one.
+    /// If the curves overlap, each overlapping part is represented by the pairs of its both ends.
+    /// The returned vector is sorted.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let curve0 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)],
+    /// );
+    /// let curve1 = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.5), Point2::new(2.0, 0.5)],
+    /// );
+    /// let res = curve0.intersection(&curve1, TOLERANCE);
+    /// assert_eq!(res.len(), 2);
+    /// for (s, t) in res {
+    ///     assert_near!(curve0.subs(s), curve1.subs(t));
+    ///     assert_near!(curve0.subs(s)[1], 0.5);
+    /// }
+    /// ```
+    pub fn intersection(&self, other: &Self, tol: f64) -> Vec<(f64, f64)> {
+        planar_curve_intersection(self, other, tol, Self::roughly_bounding_box)
+    }
+}
+
 impl<P: Clone> Invertible for BSplineCurve<P> {
+    /// Inverts the direction of the curve by reversing the control points and mirroring the
+    /// knot vector, i.e. the inverted curve `c` satisfies `c(t) = self(t_min + t_max - t)`,
+    /// where `(t_min, t_max)` is the parameter range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the unit circle as a NURBS curve on the parameter range [1, 3]
+    /// let mut knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0]);
+    /// knot_vec.transform(2.0, 1.0);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     Vector3::new(-1.0, 0.0, 1.0),
+    ///     Vector3::new(0.0, -1.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let circle = NURBSCurve::new(bspcurve.clone());
+    ///
+    /// let inverted = NURBSCurve::new(Invertible::inverse(&bspcurve));
+    /// assert_eq!(inverted.parameter_range(), (1.0, 3.0));
+    /// const N: usize = 100; // sample size
+    /// for i in 0..=N {
+    ///     let t = 1.0 + 2.0 * i as f64 / N as f64;
+    ///     assert_near!(inverted.subs(t), circle.subs(1.0 + 3.0 - t));
+    ///     assert_near!(inverted.subs(t).to_vec().magnitude(), 1.0);
+    /// }
+    ///
+    /// // inverting twice is the identity
+    /// let mut twice = bspcurve.clone();
+    /// Invertible::invert(&mut twice);
+    /// Invertible::invert(&mut twice);
+    /// assert_eq!(twice.knot_vec(), bspcurve.knot_vec());
+    /// assert_eq!(twice.control_points(), bspcurve.control_points());
+    /// ```
     #[inline(always)]
     fn invert(&mut self) { self.invert(); }
 }
@@ -1468,3 +2689,124 @@ fn cubic_bezier_interpolation_test() {
     assert_near!(der.front(), der0);
     assert_near!(der.back(), der1);
 }
+
+#[test]
+fn closed_circle_and_arc_test() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0]);
+    let c = f64::sqrt(3.0) / 2.0;
+    let ctrl_pts = vec![
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(0.5, c, 0.5),
+        Vector3::new(-0.5, c, 1.0),
+        Vector3::new(-1.0, 0.0, 0.5),
+        Vector3::new(-0.5, -c, 1.0),
+        Vector3::new(0.5, -c, 0.5),
+        Vector3::new(1.0, 0.0, 1.0),
+    ];
+    let circle = NURBSCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    assert!(circle.non_rationalized().is_closed());
+    (0..=30).for_each(|i| {
+        let t = 3.0 * i as f64 / 30.0;
+        assert_near!(circle.subs(t).distance(Point2::new(0.0, 0.0)), 1.0);
+    });
+
+    assert!(circle.is_closed());
+    assert!(circle.is_periodic());
+
+    // the open arc, the upper half of the circle
+    let nurbs_arc = NURBSCurve::new(circle.non_rationalized().subcurve((0.0, 2.0)));
+    assert!(!nurbs_arc.is_closed());
+    assert!(!nurbs_arc.is_periodic());
+    let mut arc = nurbs_arc.into_non_rationalized();
+    assert!(!arc.is_closed());
+    arc.make_closed();
+    assert!(arc.is_closed());
+    assert!(!arc.is_periodic());
+    assert_near!(arc.subs(0.5).to_point(), circle.subs(0.5));
+    arc.make_periodic();
+    assert!(arc.is_periodic());
+    assert!(NURBSCurve::new(arc).is_closed());
+}
+
+#[test]
+fn helix_frames_test() {
+    use std::f64::consts::PI;
+    // the helix (cos t, sin t, t / 4)
+    let points: Vec<Point3> = (0..=40)
+        .map(|i| {
+            let t = 4.0 * PI * i as f64 / 40.0;
+            Point3::new(f64::cos(t), f64::sin(t), t / 4.0)
+        })
+        .collect();
+    let helix = BSplineCurve::interpolate(&points, 3);
+    const N: usize = 200;
+    let ts: Vec<f64> = (0..=N).map(|i| i as f64 / N as f64).collect();
+    let is_orthonormal = |(tangent, normal, binormal): &(Vector3, Vector3, Vector3)| {
+        assert_near!(tangent.magnitude(), 1.0);
+        assert_near!(normal.magnitude(), 1.0);
+        assert_near!(binormal.magnitude(), 1.0);
+        assert!(tangent.dot(*normal).so_small());
+        assert!(normal.dot(*binormal).so_small());
+        assert!(binormal.dot(*tangent).so_small());
+        assert_near!(tangent.cross(*normal), *binormal);
+    };
+
+    let frenet_frames = helix.frenet_frames(&ts);
+    frenet_frames.iter().for_each(is_orthonormal);
+    ts.iter().zip(&frenet_frames).for_each(|(t, frame)| {
+        let (tangent, normal, binormal) = helix.frenet_frame(*t);
+        assert_near!(tangent, frame.0);
+        assert_near!(normal, frame.1);
+        assert_near!(binormal, frame.2);
+        assert_near!(tangent, helix.der(*t).normalize());
+        // the normal of the helix points to the axis, except near the ends.
+        if (0.1..0.9).contains(t) {
+            let pt = helix.subs(*t);
+            let to_axis = Vector3::new(-pt.x, -pt.y, 0.0).normalize();
+            assert!(normal.dot(to_axis) > 0.95);
+        }
+    });
+
+    let rm_frames = helix.rotation_minimizing_frames(&ts);
+    assert_eq!(rm_frames.len(), ts.len());
+    rm_frames.iter().for_each(is_orthonormal);
+    ts.iter().zip(&rm_frames).for_each(|(t, frame)| {
+        assert_near!(frame.0, helix.der(*t).normalize());
+    });
+    assert_near!(rm_frames[0].1, frenet_frames[0].1);
+    // the normal changes continuously.
+    rm_frames
+        .windows(2)
+        .for_each(|frames| assert!(frames[0].1.dot(frames[1].1) > 0.99));
+}
+
+#[test]
+fn overlapping_intersection_test() {
+    let curve0 = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)],
+    );
+    // coincident curves
+    let res = curve0.intersection(&curve0, TOLERANCE);
+    assert_eq!(res.len(), 2);
+    assert_near!(res[0].0, 0.0);
+    assert_near!(res[0].1, 0.0);
+    assert_near!(res[1].0, 1.0);
+    assert_near!(res[1].1, 1.0);
+
+    // the overlap of the collinear segments is from (1, 0) to (2, 0)
+    let segment0 = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)],
+    );
+    let segment1 = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point2::new(3.0, 0.0), Point2::new(1.0, 0.0)],
+    );
+    let res = segment0.intersection(&segment1, TOLERANCE);
+    assert_eq!(res.len(), 2);
+    assert_near!(res[0].0, 0.5);
+    assert_near!(res[0].1, 1.0);
+    assert_near!(res[1].0, 1.0);
+    assert_near!(res[1].1, 0.5);
+}
@@ -171,6 +171,72 @@ impl<P> BSplineSurface<P> {
         self.control_points[row_idx].iter()
     }
 
+    /// Replaces the control points in the `column_idx`th row, i.e. the ones iterated by
+    /// [`ctrl_pts_row_iter`](#method.ctrl_pts_row_iter).
+    /// # Failures
+    /// Returns [`Error::DifferentLength`] if the length of `pts` is not the number of rows.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let row = [Vector2::new(1.0, -1.0), Vector2::new(1.0, 2.0)];
+    /// bspsurface.set_ctrl_pts_row(1, &row).unwrap();
+    /// assert!(bspsurface.ctrl_pts_row_iter(1).eq(&row));
+    ///
+    /// let too_long = [Vector2::new(0.0, 0.0); 3];
+    /// assert_eq!(bspsurface.set_ctrl_pts_row(1, &too_long), Err(Error::DifferentLength));
+    /// assert!(bspsurface.ctrl_pts_row_iter(1).eq(&row));
+    /// ```
+    pub fn set_ctrl_pts_row(&mut self, column_idx: usize, pts: &[P]) -> Result<()>
+    where P: Clone {
+        if pts.len() != self.control_points.len() {
+            return Err(Error::DifferentLength);
+        }
+        self.control_points
+            .iter_mut()
+            .zip(pts)
+            .for_each(|(vec, pt)| vec[column_idx] = pt.clone());
+        Ok(())
+    }
+
+    /// Replaces the control points in the `row_idx`th column, i.e. the ones iterated by
+    /// [`ctrl_pts_column_iter`](#method.ctrl_pts_column_iter).
+    /// # Failures
+    /// Returns [`Error::DifferentLength`] if the length of `pts` is not the number of columns.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use errors::Error;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let column = [Vector2::new(0.0, 2.0), Vector2::new(1.0, 3.0), Vector2::new(2.0, 2.0)];
+    /// bspsurface.set_ctrl_pts_column(1, &column).unwrap();
+    /// assert!(bspsurface.ctrl_pts_column_iter(1).eq(&column));
+    ///
+    /// let too_short = [Vector2::new(0.0, 0.0); 2];
+    /// assert_eq!(bspsurface.set_ctrl_pts_column(1, &too_short), Err(Error::DifferentLength));
+    /// assert!(bspsurface.ctrl_pts_column_iter(1).eq(&column));
+    /// ```
+    pub fn set_ctrl_pts_column(&mut self, row_idx: usize, pts: &[P]) -> Result<()>
+    where P: Clone {
+        let column = &mut self.control_points[row_idx];
+        if pts.len() != column.len() {
+            return Err(Error::DifferentLength);
+        }
+        column.clone_from_slice(pts);
+        Ok(())
+    }
+
     /// Returns the mutable reference of the control point corresponding to index `(idx0, idx1)`.
     #[inline(always)]
     pub fn control_point_mut(&mut self, idx0: usize, idx1: usize) -> &mut P {
@@ -276,6 +342,28 @@ impl<P> BSplineSurface<P> {
         self
     }
 
+    /// Returns the surface whose parameters are swapped, leaving `self` untouched.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let transposed = bspsurface.transposed();
+    /// assert_eq!(transposed.degrees(), (2, 1));
+    /// assert_near2!(transposed.subs(0.3, 0.6), bspsurface.subs(0.6, 0.3));
+    /// ```
+    #[inline(always)]
+    pub fn transposed(&self) -> Self
+    where P: Clone {
+        let mut surface = self.clone();
+        surface.swap_axes();
+        surface
+    }
+
     /// The range of the parameter of the surface.
     #[inline(always)]
     pub fn parameter_range(&self) -> ((f64, f64), (f64, f64)) {
@@ -349,6 +437,88 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64, f64) -> P + '_ { move |u, v| self.subs(u, v) }
 
+    /// Returns the isoparametric curve `v |-> self.subs(u, v)` with the fixed parameter `u`.
+    ///
+    /// The control points of the curve are the columns of the control points of `self`
+    /// collapsed by the B-spline basis functions at `u`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the unit sphere
+    /// let knot_vec0 = KnotVec::bezier_knot(3);
+    /// let knot_vec1 = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]
+    /// );
+    /// let mut v = vec![vec![Vector4::zero(); 7]; 4];
+    /// v[0][0] = Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// v[0][1] = &v[0][0] / 3.0;
+    /// v[0][2] = v[0][1].clone();
+    /// v[0][3] = v[0][0].clone();
+    /// v[0][4] = v[0][1].clone();
+    /// v[0][5] = v[0][1].clone();
+    /// v[0][6] = v[0][0].clone();
+    /// v[1][0] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[1][1] = Vector4::new(2.0, 4.0, 1.0, 1.0) / 9.0;
+    /// v[1][2] = Vector4::new(-2.0, 4.0, 1.0, 1.0) / 9.0;
+    /// v[1][3] = Vector4::new(-2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[1][4] = Vector4::new(-2.0, -4.0, 1.0, 1.0) / 9.0;
+    /// v[1][5] = Vector4::new(2.0, -4.0, 1.0, 1.0) / 9.0;
+    /// v[1][6] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[2][0] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[2][1] = Vector4::new(2.0, 4.0, -1.0, 1.0) / 9.0;
+    /// v[2][2] = Vector4::new(-2.0, 4.0, -1.0, 1.0) / 9.0;
+    /// v[2][3] = Vector4::new(-2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[2][4] = Vector4::new(-2.0, -4.0, -1.0, 1.0) / 9.0;
+    /// v[2][5] = Vector4::new(2.0, -4.0, -1.0, 1.0) / 9.0;
+    /// v[2][6] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[3][0] = Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// v[3][1] = &v[3][0] / 3.0;
+    /// v[3][2] = v[3][1].clone();
+    /// v[3][3] = v[3][0].clone();
+    /// v[3][4] = v[3][1].clone();
+    /// v[3][5] = v[3][1].clone();
+    /// v[3][6] = v[3][0].clone();
+    /// let sphere = BSplineSurface::new((knot_vec0, knot_vec1), v);
+    ///
+    /// // the equator
+    /// let curve = sphere.uparametric_curve(0.5);
+    /// assert_eq!(curve.knot_vec(), sphere.vknot_vec());
+    /// for v in [0.0, 0.25, 0.5, 0.75, 1.0] {
+    ///     assert_near2!(curve.subs(v), sphere.subs(0.5, v));
+    ///     let pt = curve.subs(v).to_point();
+    ///     assert_near!(pt.to_vec().magnitude(), 1.0);
+    ///     assert_near!(pt.z, 0.0);
+    /// }
+    /// ```
+    pub fn uparametric_curve(&self, u: f64) -> BSplineCurve<P> {
+        let basis = self.uknot_vec().bspline_basis_functions(self.udegree(), u);
+        let ctrl_pts = (0..self.control_points[0].len())
+            .map(|j| {
+                let closure = |sum: P, (vec, b): (&Vec<P>, &f64)| sum + vec[j].to_vec() * *b;
+                self.control_points.iter().zip(&basis).fold(P::origin(), closure)
+            })
+            .collect();
+        BSplineCurve::new_unchecked(self.vknot_vec().clone(), ctrl_pts)
+    }
+    /// Returns the isoparametric curve `u |-> self.subs(u, v)` with the fixed parameter `v`.
+    ///
+    /// The control points of the curve are the rows of the control points of `self`
+    /// collapsed by the B-spline basis functions at `v`.
+    /// See [`BSplineSurface::uparametric_curve`] for the example on the unit sphere.
+    pub fn vparametric_curve(&self, v: f64) -> BSplineCurve<P> {
+        let basis = self.vknot_vec().bspline_basis_functions(self.vdegree(), v);
+        let ctrl_pts = self
+            .control_points
+            .iter()
+            .map(|vec| {
+                let closure = |sum: P, (pt, b): (&P, &f64)| sum + pt.to_vec() * *b;
+                vec.iter().zip(&basis).fold(P::origin(), closure)
+            })
+            .collect();
+        BSplineCurve::new_unchecked(self.uknot_vec().clone(), ctrl_pts)
+    }
+
     #[inline(always)]
     fn udelta_control_points(&self, i: usize, j: usize) -> P::Diff {
         if i == 0 {
@@ -502,6 +672,77 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
         BSplineSurface::new_unchecked((uknot_vec, vknot_vec), new_points)
     }
 
+    #[inline(always)]
+    fn corner_parameters(&self) -> [(f64, f64); 4] {
+        let ((u0, u1), (v0, v1)) = self.parameter_range();
+        [(u0, v0), (u1, v0), (u1, v1), (u0, v1)]
+    }
+
+    /// Returns the four corner points of the surface.
+    ///
+    /// The corners are ordered as `(u0, v0)`, `(u1, v0)`, `(u1, v1)`, `(u0, v1)`,
+    /// where `((u0, u1), (v0, v1))` is the parameter range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::from(vec![0.0, 0.0, 1.0, 2.0, 2.0]));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), Point3::new(0.0, 2.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 2.0), Point3::new(1.0, 2.0, 1.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0), Point3::new(2.0, 2.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let corners = bspsurface.corners();
+    /// assert_near!(corners[0], bspsurface.subs(0.0, 0.0));
+    /// assert_near!(corners[1], bspsurface.subs(1.0, 0.0));
+    /// assert_near!(corners[2], bspsurface.subs(1.0, 2.0));
+    /// assert_near!(corners[3], bspsurface.subs(0.0, 2.0));
+    /// ```
+    #[inline(always)]
+    pub fn corners(&self) -> [P; 4] { self.corner_parameters().map(|(u, v)| self.subs(u, v)) }
+
+    /// Returns the derivatives at the four corners of the surface.
+    ///
+    /// Each element is the tuple of the derivation by `u`, that by `v`, and the twist vector,
+    /// i.e. the mixed derivation by `u` and `v`.
+    /// The corners are ordered as same as [`BSplineSurface::corners`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::from(vec![0.0, 0.0, 1.0, 2.0, 2.0]));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), Point3::new(0.0, 2.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.5, 2.0), Point3::new(1.0, 2.0, 1.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.5, 1.0, 1.0), Point3::new(2.0, 2.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let derivatives = bspsurface.corner_derivatives();
+    ///
+    /// // compare with the finite differences taken inside the domain
+    /// const EPS: f64 = 1.0e-4;
+    /// let corners = [
+    ///     (0.0, 0.0, 1.0, 1.0),
+    ///     (1.0, 0.0, -1.0, 1.0),
+    ///     (1.0, 2.0, -1.0, -1.0),
+    ///     (0.0, 2.0, 1.0, -1.0),
+    /// ];
+    /// for ((u, v, su, sv), (uder, vder, uvder)) in corners.into_iter().zip(derivatives) {
+    ///     let (du, dv) = (su * EPS, sv * EPS);
+    ///     let p = bspsurface.subs(u, v);
+    ///     let pu = bspsurface.subs(u + du, v);
+    ///     let pv = bspsurface.subs(u, v + dv);
+    ///     let puv = bspsurface.subs(u + du, v + dv);
+    ///     assert!((uder - (pu - p) / du).magnitude() < 1.0e-2);
+    ///     assert!((vder - (pv - p) / dv).magnitude() < 1.0e-2);
+    ///     assert!((uvder - ((puv - pu) - (pv - p)) / (du * dv)).magnitude() < 1.0e-2);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn corner_derivatives(&self) -> [(P::Diff, P::Diff, P::Diff); 4] {
+        self.corner_parameters()
+            .map(|(u, v)| (self.uder(u, v), self.vder(u, v), self.uvder(u, v)))
+    }
+
     pub(super) fn sub_near_as_surface<F: Fn(&P, &P) -> bool>(
         &self,
         other: &BSplineSurface<P>,
@@ -734,6 +975,7 @@ impl<P: ControlPoint<f64>> ParametricSurface for BSplineSurface<P> {
             .enumerate()
             .fold(P::Diff::zero(), closure)
             * degree0 as f64
+            * (degree0 - 1) as f64
     }
 
     /// Substitutes 2nd-ord derived B-spline surface by the second parameter `v`.
@@ -783,6 +1025,7 @@ impl<P: ControlPoint<f64>> ParametricSurface for BSplineSurface<P> {
             .enumerate()
             .fold(P::Diff::zero(), closure)
             * degree1 as f64
+            * (degree1 - 1) as f64
     }
 
     /// Substitutes 2nd-ord derived B-spline surface by the both parameters `u, v`.
@@ -1213,6 +1456,32 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     /// assert_eq!(bspsurface.vdegree(), org_surface.vdegree() + 1);
     /// assert!(bspsurface.near2_as_surface(&org_surface));
     /// ```
+    /// The surface values do not change at the sampled parameters, even if the surface has
+    /// several spans.
+    /// ```
+    /// use truck_geometry::*;
+    /// let uknot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let vknot_vec = KnotVec::from(vec![0.0, 0.0, 0.4, 0.7, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), Point3::new(0.0, 2.0, -1.0), Point3::new(0.0, 3.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 2.0), Point3::new(1.0, 1.0, -1.0), Point3::new(1.0, 2.0, 1.0), Point3::new(1.0, 3.0, 2.0)],
+    ///     vec![Point3::new(2.0, 0.0, -1.0), Point3::new(2.0, 1.0, 0.0), Point3::new(2.0, 2.0, 2.0), Point3::new(2.0, 3.0, 1.0)],
+    ///     vec![Point3::new(3.0, 0.0, 1.0), Point3::new(3.0, 1.0, 2.0), Point3::new(3.0, 2.0, 0.0), Point3::new(3.0, 3.0, -1.0)],
+    /// ];
+    /// let org_surface = BSplineSurface::new((uknot_vec, vknot_vec), ctrl_pts);
+    /// let mut bspsurface = org_surface.clone();
+    /// bspsurface.elevate_vdegree();
+    /// assert_eq!(bspsurface.vdegree(), org_surface.vdegree() + 1);
+    /// assert_eq!(bspsurface.udegree(), org_surface.udegree());
+    ///
+    /// const N: usize = 10; // sample size in test
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert_near2!(bspsurface.subs(u, v), org_surface.subs(u, v));
+    ///     }
+    /// }
+    /// ```
     pub fn elevate_vdegree(&mut self) -> &mut Self {
         let mut new_knot_vec = KnotVec::new();
         for (i, vec) in self.control_points.iter_mut().enumerate() {
@@ -1248,6 +1517,32 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     /// assert_eq!(bspsurface.vdegree(), org_surface.vdegree());
     /// assert!(bspsurface.near2_as_surface(&org_surface));
     /// ```
+    /// The surface values do not change at the sampled parameters, even if the surface has
+    /// several spans.
+    /// ```
+    /// use truck_geometry::*;
+    /// let uknot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let vknot_vec = KnotVec::from(vec![0.0, 0.0, 0.4, 0.7, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), Point3::new(0.0, 2.0, -1.0), Point3::new(0.0, 3.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 2.0), Point3::new(1.0, 1.0, -1.0), Point3::new(1.0, 2.0, 1.0), Point3::new(1.0, 3.0, 2.0)],
+    ///     vec![Point3::new(2.0, 0.0, -1.0), Point3::new(2.0, 1.0, 0.0), Point3::new(2.0, 2.0, 2.0), Point3::new(2.0, 3.0, 1.0)],
+    ///     vec![Point3::new(3.0, 0.0, 1.0), Point3::new(3.0, 1.0, 2.0), Point3::new(3.0, 2.0, 0.0), Point3::new(3.0, 3.0, -1.0)],
+    /// ];
+    /// let org_surface = BSplineSurface::new((uknot_vec, vknot_vec), ctrl_pts);
+    /// let mut bspsurface = org_surface.clone();
+    /// bspsurface.elevate_udegree();
+    /// assert_eq!(bspsurface.udegree(), org_surface.udegree() + 1);
+    /// assert_eq!(bspsurface.vdegree(), org_surface.vdegree());
+    ///
+    /// const N: usize = 10; // sample size in test
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert_near2!(bspsurface.subs(u, v), org_surface.subs(u, v));
+    ///     }
+    /// }
+    /// ```
     pub fn elevate_udegree(&mut self) -> &mut Self {
         self.swap_axes();
         self.elevate_vdegree();
@@ -1342,6 +1637,128 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Makes two surfaces having the same udegrees and the same vdegrees.
+    ///
+    /// Each degree is elevated to the larger one of the two surfaces.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    ///     vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.5, 1.0, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let mut bspsurface0 = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.5, 0.0, 0.5), Point3::new(0.5, 1.0, 0.5)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let mut bspsurface1 = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface0 = bspsurface0.clone();
+    /// let org_surface1 = bspsurface1.clone();
+    ///
+    /// bspsurface0.syncro_degrees(&mut bspsurface1);
+    /// assert_eq!(bspsurface0.degrees(), (2, 2));
+    /// assert_eq!(bspsurface1.degrees(), (2, 2));
+    /// assert!(bspsurface0.near2_as_surface(&org_surface0));
+    /// assert!(bspsurface1.near2_as_surface(&org_surface1));
+    /// ```
+    pub fn syncro_degrees(&mut self, other: &mut Self) -> &mut Self {
+        let ((udegree0, vdegree0), (udegree1, vdegree1)) = (self.degrees(), other.degrees());
+        for _ in udegree0..udegree1 {
+            self.elevate_udegree();
+        }
+        for _ in udegree1..udegree0 {
+            other.elevate_udegree();
+        }
+        for _ in vdegree0..vdegree1 {
+            self.elevate_vdegree();
+        }
+        for _ in vdegree1..vdegree0 {
+            other.elevate_vdegree();
+        }
+        self
+    }
+
+    /// Makes two surfaces having the same normalized uknot vectors and vknot vectors.
+    ///
+    /// The knots of each surface are inserted into the other one, so the shapes of the
+    /// surfaces are not changed except the normalization of the knot vectors.
+    /// The knots of the two surfaces which coincide within [`TOLERANCE`] are regarded as
+    /// the same knot and are unified to the one of `self`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(1, 2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    ///     vec![Point3::new(0.0, 0.5, 1.0), Point3::new(0.5, 0.5, 1.0), Point3::new(1.0, 0.5, 1.0)],
+    ///     vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.5, 1.0, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let mut bspsurface0 = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface1 = bspsurface0.clone();
+    /// bspsurface0.add_uknot(0.25).add_vknot(0.4);
+    /// // the normalized knot of `bspsurface1` is `2.4 - 2.0 == 0.3999999999999999`.
+    /// bspsurface1.add_vknot(0.4).knot_translate(1.0, 2.0);
+    /// let org_surface0 = bspsurface0.clone();
+    /// let mut org_surface1 = bspsurface1.clone();
+    ///
+    /// bspsurface0.syncro_knots(&mut bspsurface1);
+    /// assert_eq!(bspsurface0.knot_vecs(), bspsurface1.knot_vecs());
+    /// assert_eq!(bspsurface0.uknot_vec().as_slice(), &[0.0, 0.0, 0.25, 0.5, 1.0, 1.0]);
+    /// assert_eq!(bspsurface0.vknot_vec().as_slice(), &[0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0]);
+    /// assert!(bspsurface0.near2_as_surface(&org_surface0));
+    /// assert!(bspsurface1.near2_as_surface(org_surface1.knot_normalize()));
+    /// ```
+    pub fn syncro_knots(&mut self, other: &mut Self) -> &mut Self {
+        self.knot_normalize();
+        other.knot_normalize();
+        self.syncro_uknots(other);
+        self.swap_axes();
+        other.swap_axes();
+        self.syncro_uknots(other);
+        self.swap_axes();
+        other.swap_axes();
+        self
+    }
+
+    /// Makes two surfaces with the normalized knot vectors having the same uknot vectors.
+    fn syncro_uknots(&mut self, other: &mut Self) {
+        let mut i = 0;
+        let mut j = 0;
+        while !self.uknot(i).near2(&1.0) || !other.uknot(j).near2(&1.0) {
+            if self.uknot(i) - other.uknot(j) > TOLERANCE {
+                self.add_uknot(other.uknot(j));
+            } else if other.uknot(j) - self.uknot(i) > TOLERANCE {
+                other.add_uknot(self.uknot(i));
+            } else {
+                // unifies the rounding errors, otherwise the knot vectors are not the same.
+                other.knot_vecs.0 .0[j] = self.uknot(i);
+            }
+            i += 1;
+            j += 1;
+        }
+
+        let len0 = self.uknot_vec().len();
+        let len1 = other.uknot_vec().len();
+        use std::cmp::Ordering;
+        match usize::cmp(&len0, &len1) {
+            Ordering::Less => {
+                for _ in 0..len1 - len0 {
+                    self.add_uknot(1.0);
+                }
+            }
+            Ordering::Greater => {
+                for _ in 0..len0 - len1 {
+                    other.add_uknot(1.0);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Cuts the surface into two surfaces at the parameter `u`
     /// # Examples
     /// ```
@@ -1466,6 +1883,329 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         res
     }
 
+    /// Extracts the sub-patch on the parameter box `urange` x `vrange`.
+    ///
+    /// The knots of the four cut parameters are inserted up to full multiplicity,
+    /// and only the control points spanning the box are kept.
+    /// The returned surface coincides with `self` on the box.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the unit sphere
+    /// let knot_vec0 = KnotVec::bezier_knot(3);
+    /// let knot_vec1 = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]
+    /// );
+    /// let mut v = vec![vec![Vector4::zero(); 7]; 4];
+    /// v[0][0] = Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// v[0][1] = &v[0][0] / 3.0;
+    /// v[0][2] = v[0][1].clone();
+    /// v[0][3] = v[0][0].clone();
+    /// v[0][4] = v[0][1].clone();
+    /// v[0][5] = v[0][1].clone();
+    /// v[0][6] = v[0][0].clone();
+    /// v[1][0] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[1][1] = Vector4::new(2.0, 4.0, 1.0, 1.0) / 9.0;
+    /// v[1][2] = Vector4::new(-2.0, 4.0, 1.0, 1.0) / 9.0;
+    /// v[1][3] = Vector4::new(-2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[1][4] = Vector4::new(-2.0, -4.0, 1.0, 1.0) / 9.0;
+    /// v[1][5] = Vector4::new(2.0, -4.0, 1.0, 1.0) / 9.0;
+    /// v[1][6] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[2][0] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[2][1] = Vector4::new(2.0, 4.0, -1.0, 1.0) / 9.0;
+    /// v[2][2] = Vector4::new(-2.0, 4.0, -1.0, 1.0) / 9.0;
+    /// v[2][3] = Vector4::new(-2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[2][4] = Vector4::new(-2.0, -4.0, -1.0, 1.0) / 9.0;
+    /// v[2][5] = Vector4::new(2.0, -4.0, -1.0, 1.0) / 9.0;
+    /// v[2][6] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[3][0] = Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// v[3][1] = &v[3][0] / 3.0;
+    /// v[3][2] = v[3][1].clone();
+    /// v[3][3] = v[3][0].clone();
+    /// v[3][4] = v[3][1].clone();
+    /// v[3][5] = v[3][1].clone();
+    /// v[3][6] = v[3][0].clone();
+    /// let sphere = BSplineSurface::new((knot_vec0, knot_vec1), v);
+    ///
+    /// let patch = sphere.subdivide((0.25, 0.75), (0.25, 0.75));
+    /// assert_eq!(patch.parameter_range(), ((0.25, 0.75), (0.25, 0.75)));
+    /// const N: usize = 50;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let u = 0.25 + 0.5 * (i as f64) / (N as f64);
+    ///         let v = 0.25 + 0.5 * (j as f64) / (N as f64);
+    ///         assert_near2!(sphere.subs(u, v), patch.subs(u, v));
+    ///     }
+    /// }
+    /// ```
+    pub fn subdivide(&self, urange: (f64, f64), vrange: (f64, f64)) -> BSplineSurface<P> {
+        let ((u0, u1), (v0, v1)) = self.parameter_range();
+        let mut surface = self.clone();
+        if urange.0 > u0 && !urange.0.near(&u0) {
+            surface = surface.ucut(urange.0);
+        }
+        if urange.1 < u1 && !urange.1.near(&u1) {
+            surface.ucut(urange.1);
+        }
+        if vrange.0 > v0 && !vrange.0.near(&v0) {
+            surface = surface.vcut(vrange.0);
+        }
+        if vrange.1 < v1 && !vrange.1.near(&v1) {
+            surface.vcut(vrange.1);
+        }
+        surface
+    }
+
+    /// Creates the surface skinning `sections`.
+    ///
+    /// After the degrees and the knot vectors of the sections are synchronized,
+    /// the control points are interpolated in the `v`-direction.
+    /// The knot vector of the first parameter `u` is normalized one of the sections,
+    /// and the `k`th section is reproduced by the `u`-curve at `v = k / (sections.len() - 1)`.
+    /// The degree of `v` is `min(sections.len() - 1, 3)`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the circle whose radius is `r` at height `h`
+    /// let circle = |r: f64, h: f64| {
+    ///     let knot_vec = KnotVec::from(
+    ///         vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0]
+    ///     );
+    ///     let ctrl_pts = vec![
+    ///         Vector4::new(0.0, -2.0 * r, 2.0 * h, 2.0),
+    ///         Vector4::new(r, -r, h, 1.0),
+    ///         Vector4::new(r, 0.0, h, 1.0),
+    ///         Vector4::new(r, r, h, 1.0),
+    ///         Vector4::new(0.0, 2.0 * r, 2.0 * h, 2.0),
+    ///         Vector4::new(-r, r, h, 1.0),
+    ///         Vector4::new(-r, 0.0, h, 1.0),
+    ///         Vector4::new(-r, -r, h, 1.0),
+    ///         Vector4::new(0.0, -2.0 * r, 2.0 * h, 2.0),
+    ///     ];
+    ///     BSplineCurve::new(knot_vec, ctrl_pts)
+    /// };
+    /// let sections = vec![circle(1.0, 0.0), circle(2.0, 1.0), circle(1.0, 2.0)];
+    /// let surface = BSplineSurface::try_skin(&sections).unwrap();
+    ///
+    /// const N: usize = 100;
+    /// for (k, section) in sections.iter().enumerate() {
+    ///     let v = k as f64 / 2.0;
+    ///     for i in 0..=N {
+    ///         let u = i as f64 / N as f64;
+    ///         assert_near!(surface.subs(u, v), section.subs(u));
+    ///     }
+    /// }
+    /// ```
+    pub fn try_skin(sections: &[BSplineCurve<P>]) -> Result<BSplineSurface<P>> {
+        let mut curves = sections.to_vec();
+        let (first, others) = curves.split_first_mut().ok_or(Error::EmptyControlPoints)?;
+        first.knot_vec.try_normalize()?;
+        for _ in 0..2 {
+            others.iter_mut().for_each(|curve| first.syncro_degree(curve));
+        }
+        for _ in 0..2 {
+            others.iter_mut().for_each(|curve| first.syncro_knots(curve));
+        }
+
+        let n = curves.len();
+        let degree = usize::min(n - 1, 3);
+        let params: Vec<f64> = match n {
+            1 => vec![0.0],
+            _ => (0..n).map(|k| k as f64 / (n - 1) as f64).collect(),
+        };
+        let vknot_vec = averaging_knot_vec(&params, degree);
+        let matrix = params
+            .iter()
+            .map(|t| vknot_vec.try_bspline_basis_functions(degree, *t))
+            .collect::<Result<Vec<_>>>()?;
+        let rows = curves.iter().map(|curve| curve.control_points.clone()).collect();
+        let solution = solve_linear_system(matrix, rows).ok_or(Error::SingularSystem)?;
+
+        let uknot_vec = curves[0].knot_vec.clone();
+        let control_points = (0..curves[0].control_points.len())
+            .map(|i| solution.iter().map(|row| row[i]).collect())
+            .collect();
+        BSplineSurface::try_new((uknot_vec, vknot_vec), control_points)
+    }
+
+    /// Creates the surface skinning `sections`.
+    ///
+    /// # Panics
+    /// Panic occurs if `sections` is empty.
+    /// See [`BSplineSurface::try_skin`] for the details.
+    #[inline(always)]
+    pub fn skin(sections: &[BSplineCurve<P>]) -> BSplineSurface<P> {
+        BSplineSurface::try_skin(sections).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fits a surface to the scattered `points` by the least squares.
+    ///
+    /// The parameter of each point is given by `params`, and pairs beyond the shorter slice are
+    /// ignored. The surface has the clamped uniform knot vectors of the degree `degree` and the
+    /// grid of `ctrl.0 x ctrl.1` control points. The degree is lowered to `ctrl - 1` if the grid
+    /// is too coarse for it. `smoothing` is the weight of the Tikhonov term penalizing the second
+    /// differences of the control net, which stabilizes the regions with few samples.
+    /// If `smoothing == 0.0`, the points must determine all the control points.
+    ///
+    /// # Failures
+    /// - If the number of control points is zero, returns [`Error::EmptyControlPoints`].
+    /// - If the points are fewer than the control points, returns [`Error::TooFewPoints`].
+    /// - If the points do not determine the control points, e.g. some control points affect
+    ///   none of the points and `smoothing == 0.0`, or the points are not finite,
+    ///   returns [`Error::SingularSystem`].
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the known surface
+    /// let knot_vecs = (KnotVec::uniform_knot(3, 2), KnotVec::uniform_knot(3, 2));
+    /// let ctrl_pts = (0..5)
+    ///     .map(|i| {
+    ///         (0..5)
+    ///             .map(|j| {
+    ///                 let (x, y) = (i as f64 / 4.0, j as f64 / 4.0);
+    ///                 Point3::new(x, y, f64::sin(3.0 * x) * f64::cos(2.0 * y))
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// // noisy samples at scattered parameters
+    /// const NOISE: f64 = 1.0e-3;
+    /// let params: Vec<(f64, f64)> = (0..400)
+    ///     .map(|k| {
+    ///         let [u, v] = HashGen::hash2(k as f64);
+    ///         (u, v)
+    ///     })
+    ///     .collect();
+    /// let points: Vec<Point3> = params
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(k, (u, v))| {
+    ///         let [x, y, z] = HashGen::hash3(k as f64 + 0.5);
+    ///         let noise = Vector3::new(x - 0.5, y - 0.5, z - 0.5) * 2.0 * NOISE;
+    ///         surface.subs(*u, *v) + noise
+    ///     })
+    ///     .collect();
+    ///
+    /// let fitted = BSplineSurface::fit_scattered(&points, &params, (3, 3), (5, 5), 1.0e-6);
+    /// let sum = points.iter().zip(&params).fold(0.0, |sum, (pt, (u, v))| {
+    ///     sum + fitted.subs(*u, *v).distance2(*pt)
+    /// });
+    /// let rms = f64::sqrt(sum / points.len() as f64);
+    /// assert!(rms < 2.0 * NOISE, "rms: {rms}");
+    ///
+    /// // fewer points than control points
+    /// assert!(matches!(
+    ///     BSplineSurface::try_fit_scattered(&points[..10], &params, (3, 3), (5, 5), 0.0),
+    ///     Err(errors::Error::TooFewPoints(10, 25)),
+    /// ));
+    /// ```
+    pub fn try_fit_scattered(
+        points: &[P],
+        params: &[(f64, f64)],
+        degree: (usize, usize),
+        ctrl: (usize, usize),
+        smoothing: f64,
+    ) -> Result<BSplineSurface<P>>
+    where
+        P: Array<Element = f64>,
+    {
+        let (ulen, vlen) = ctrl;
+        if ulen == 0 || vlen == 0 {
+            return Err(Error::EmptyControlPoints);
+        }
+        let n = ulen * vlen;
+        let len = usize::min(points.len(), params.len());
+        if len < n {
+            return Err(Error::TooFewPoints(len, n));
+        }
+        let (udeg, vdeg) = (usize::min(degree.0, ulen - 1), usize::min(degree.1, vlen - 1));
+        let uknot_vec = KnotVec::uniform_knot(udeg, ulen - udeg);
+        let vknot_vec = KnotVec::uniform_knot(vdeg, vlen - vdeg);
+
+        // normal equation of the least squares
+        let mut matrix = vec![vec![0.0; n]; n];
+        let mut rhs = vec![P::Diff::zero(); n];
+        for (pt, (u, v)) in points.iter().zip(params) {
+            let ubasis = uknot_vec.try_bspline_basis_functions(udeg, *u)?;
+            let vbasis = vknot_vec.try_bspline_basis_functions(vdeg, *v)?;
+            let basis: Vec<(usize, f64)> = ubasis
+                .iter()
+                .enumerate()
+                .filter(|(_, a)| **a != 0.0)
+                .flat_map(|(i, a)| {
+                    vbasis
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, b)| **b != 0.0)
+                        .map(move |(j, b)| (i * vlen + j, a * b))
+                })
+                .collect();
+            for (k, a) in &basis {
+                basis.iter().for_each(|(l, b)| matrix[*k][*l] += a * b);
+                rhs[*k] += pt.to_vec() * *a;
+            }
+        }
+
+        // Tikhonov term: the second differences of the control net
+        let mut add_difference = |idx: [usize; 3]| {
+            const COEF: [f64; 3] = [1.0, -2.0, 1.0];
+            for (k, a) in idx.iter().zip(COEF) {
+                for (l, b) in idx.iter().zip(COEF) {
+                    matrix[*k][*l] += smoothing * a * b;
+                }
+            }
+        };
+        if smoothing != 0.0 {
+            for i in 0..ulen {
+                for j in 1..vlen.saturating_sub(1) {
+                    add_difference([0, 1, 2].map(|d| i * vlen + j + d - 1));
+                }
+            }
+            for i in 1..ulen.saturating_sub(1) {
+                for j in 0..vlen {
+                    add_difference([0, 1, 2].map(|d| (i + d - 1) * vlen + j));
+                }
+            }
+        }
+
+        let rows = rhs.into_iter().map(|v| vec![P::origin() + v]).collect();
+        let solution = solve_linear_system(matrix, rows).ok_or(Error::SingularSystem)?;
+        if solution.iter().any(|row| !row[0].is_finite()) {
+            return Err(Error::SingularSystem);
+        }
+        let control_points = solution
+            .chunks(vlen)
+            .map(|chunk| chunk.iter().map(|row| row[0]).collect())
+            .collect();
+        BSplineSurface::try_new((uknot_vec, vknot_vec), control_points)
+    }
+
+    /// Fits a surface to the scattered `points` by the least squares.
+    ///
+    /// # Panics
+    /// Panic occurs if the points are fewer than the control points.
+    /// See [`BSplineSurface::try_fit_scattered`] for the details.
+    #[inline(always)]
+    pub fn fit_scattered(
+        points: &[P],
+        params: &[(f64, f64)],
+        degree: (usize, usize),
+        ctrl: (usize, usize),
+        smoothing: f64,
+    ) -> BSplineSurface<P>
+    where
+        P: Array<Element = f64>,
+    {
+        BSplineSurface::try_fit_scattered(points, params, degree, ctrl, smoothing)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
     /// Creates a sectional curve with normalized knot vector from the parameter `p` to the parameter `q`.
     /// # Examples
     /// ```
@@ -1841,10 +2581,79 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     }
 }
 
+impl<P: ControlPoint<f64, Diff = Vector3>> BSplineSurface<P> {
+    /// Returns the Gaussian curvature at the parameter `(u, v)`.
+    ///
+    /// The curvature is computed by the first and second fundamental forms.
+    /// Returns `0.0` at the degenerate points, where `uder` and `vder` are parallel.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the paraboloid z = x^2 + y^2 on [-1, 1]^2
+    /// let ctrl_pts = (0..3)
+    ///     .map(|i| {
+    ///         (0..3)
+    ///             .map(|j| {
+    ///                 let (x, y) = (i as f64 - 1.0, j as f64 - 1.0);
+    ///                 // the control points of x^2 is [1, -1, 1]
+    ///                 Point3::new(x, y, 2.0 * x * x + 2.0 * y * y - 2.0)
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// assert_near!(bspsurface.subs(0.5, 0.5), Point3::origin());
+    /// assert_near!(bspsurface.gaussian_curvature(0.5, 0.5), 4.0);
+    /// assert_near!(bspsurface.mean_curvature(0.5, 0.5), 2.0);
+    /// ```
+    #[inline(always)]
+    pub fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+        surface_curvatures(self, u, v).map_or(0.0, |(gaussian, _)| gaussian)
+    }
+    /// Returns the mean curvature at the parameter `(u, v)`.
+    ///
+    /// The curvature is computed by the first and second fundamental forms, and is positive
+    /// if the surface bends toward the normal `uder.cross(vder)`.
+    /// Returns `0.0` at the degenerate points, where `uder` and `vder` are parallel.
+    /// # Examples
+    /// See the example of [`gaussian_curvature`](BSplineSurface::gaussian_curvature).
+    #[inline(always)]
+    pub fn mean_curvature(&self, u: f64, v: f64) -> f64 {
+        surface_curvatures(self, u, v).map_or(0.0, |(_, mean)| mean)
+    }
+}
+
 impl<V> BSplineSurface<V>
 where V: MetricSpace<Metric = f64> + Index<usize, Output = f64> + Bounded<f64> + Copy
 {
     /// Returns the bounding box including all control points.
+    ///
+    /// Since a B-spline surface lies in the convex hull of its control points,
+    /// the returned box includes the whole surface.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), Point3::new(0.0, 2.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, -2.0), Point3::new(1.0, 1.0, 3.0), Point3::new(1.0, 2.0, 0.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0), Point3::new(2.0, 2.0, 0.0)],
+    /// ];
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let bdb = bspsurface.roughly_bounding_box();
+    /// assert_eq!(bdb.min(), &Point3::new(0.0, 0.0, -2.0));
+    /// assert_eq!(bdb.max(), &Point3::new(2.0, 2.0, 3.0));
+    ///
+    /// const N: usize = 20;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let mut bdb0 = bdb.clone();
+    ///         bdb0.push(&bspsurface.subs(i as f64 / N as f64, j as f64 / N as f64));
+    ///         assert_eq!(bdb0, bdb);
+    ///     }
+    /// }
+    /// ```
     #[inline(always)]
     pub fn roughly_bounding_box(&self) -> BoundingBox<V> {
         self.control_points.iter().flatten().collect()
@@ -1901,6 +2710,129 @@ impl SearchParameter<D2> for BSplineSurface<Point2> {
     }
 }
 
+impl BSplineSurface<Point3> {
+    /// Returns the geometric continuity across the edge shared with `other`.
+    ///
+    /// # Shared edge
+    /// The four boundaries of `self`, i.e. the curves on `u = u0, u1` and `v = v0, v1`, are
+    /// sampled in this order, and each sample point is projected to `other`.
+    /// The first boundary whose all sample points are within the distance `tol` from
+    /// the boundary of `other` is regarded as the shared edge.
+    /// If there is no such boundary, returns [`Continuity::None`].
+    ///
+    /// # Continuity
+    /// At each sample point on the shared edge,
+    /// - the tangent planes coincide if the cross product of the unit normals of the surfaces
+    ///   is shorter than `tol`, and
+    /// - the curvatures coincide if the differences of the Gaussian curvatures and of
+    ///   the mean curvatures are less than `tol`.
+    ///
+    /// The orientations of the surfaces may be different.
+    /// The sample points at which either of the surfaces is degenerate are skipped.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let plane = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    ///     ],
+    /// );
+    ///
+    /// // folded at the edge x = 1
+    /// let kinked = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    ///         vec![Point3::new(2.0, 0.0, 1.0), Point3::new(2.0, 1.0, 1.0)],
+    ///     ],
+    /// );
+    /// let continuity = plane.continuity_with(&kinked, 1.0e-6);
+    /// assert_eq!(continuity, Continuity::G0);
+    /// assert!(continuity < Continuity::G1);
+    ///
+    /// // tangent to the plane at the edge, but curved
+    /// let curved = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Point3::new(1.0, 1.0, 0.0), Point3::new(1.0, 0.0, 0.0)],
+    ///         vec![Point3::new(1.5, 1.0, 0.0), Point3::new(1.5, 0.0, 0.0)],
+    ///         vec![Point3::new(2.0, 1.0, 1.0), Point3::new(2.0, 0.0, 1.0)],
+    ///     ],
+    /// );
+    /// assert_eq!(plane.continuity_with(&curved, 1.0e-6), Continuity::G1);
+    ///
+    /// // two pieces of a surface
+    /// let mut part0 = curved.clone();
+    /// let part1 = part0.ucut(0.5);
+    /// assert_eq!(part0.continuity_with(&part1, 1.0e-6), Continuity::G2);
+    ///
+    /// // separated surfaces
+    /// let mut far = plane.clone();
+    /// far.transform_control_points(|p| *p += Vector3::new(5.0, 0.0, 0.0));
+    /// assert_eq!(plane.continuity_with(&far, 1.0e-6), Continuity::None);
+    /// ```
+    pub fn continuity_with(&self, other: &BSplineSurface<Point3>, tol: f64) -> Continuity {
+        const N: usize = 16;
+        let ((u0, u1), (v0, v1)) = self.parameter_range();
+        let ((s0, s1), (t0, t1)) = other.parameter_range();
+        let on_boundary = |(s, t): (f64, f64)| {
+            let inside = s0 - TOLERANCE <= s
+                && s <= s1 + TOLERANCE
+                && t0 - TOLERANCE <= t
+                && t <= t1 + TOLERANCE;
+            inside && (s.near(&s0) || s.near(&s1) || t.near(&t0) || t.near(&t1))
+        };
+        let edges = [
+            (Point2::new(u0, v0), Point2::new(u0, v1)),
+            (Point2::new(u1, v0), Point2::new(u1, v1)),
+            (Point2::new(u0, v0), Point2::new(u1, v0)),
+            (Point2::new(u0, v1), Point2::new(u1, v1)),
+        ];
+        let samples = edges.iter().find_map(|(p, q)| {
+            (0..=N)
+                .map(|i| {
+                    let uv = p + (q - p) * (i as f64 / N as f64);
+                    let pt = self.subs(uv.x, uv.y);
+                    let st = other.search_nearest_parameter(pt, None, 100)?;
+                    let is_near = other.subs(st.0, st.1).distance(pt) <= tol && on_boundary(st);
+                    match is_near {
+                        true => Some(((uv.x, uv.y), st)),
+                        false => None,
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+        let samples = match samples {
+            Some(samples) => samples,
+            None => return Continuity::None,
+        };
+        samples
+            .into_iter()
+            .filter_map(|((u, v), (s, t))| {
+                let n0 = self.uder(u, v).cross(self.vder(u, v));
+                let n1 = other.uder(s, t).cross(other.vder(s, t));
+                if n0.so_small() || n1.so_small() {
+                    return None;
+                }
+                let (n0, n1) = (n0.normalize(), n1.normalize());
+                if n0.cross(n1).magnitude() >= tol {
+                    return Some(Continuity::G0);
+                }
+                let sign = n0.dot(n1).signum();
+                let gaussian = self.gaussian_curvature(u, v) - other.gaussian_curvature(s, t);
+                let mean = self.mean_curvature(u, v) - sign * other.mean_curvature(s, t);
+                match gaussian.abs() < tol && mean.abs() < tol {
+                    true => Some(Continuity::G2),
+                    false => Some(Continuity::G1),
+                }
+            })
+            .min()
+            .unwrap_or(Continuity::G2)
+    }
+}
+
 impl SearchParameter<D2> for BSplineSurface<Point3> {
     type Point = Point3;
     fn search_parameter<H: Into<SPHint2D>>(
@@ -1930,6 +2862,28 @@ where
     <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
 {
     type Point = P;
+    /// Searches the parameter `(u, v)` which minimize `|self(u, v) - point|` by Newton's method
+    /// on the two orthogonality equations. If the repeated trial does not converge, then returns `None`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    ///     vec![Point3::new(0.0, 0.5, 1.0), Point3::new(0.5, 0.5, 2.0), Point3::new(1.0, 0.5, 1.0)],
+    ///     vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.5, 1.0, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let pt = Point3::new(0.3, 0.6, 2.0);
+    /// let (u, v) = surface.search_nearest_parameter(pt, None, 100).unwrap();
+    ///
+    /// // the foot of the perpendicular
+    /// let diff = surface.subs(u, v) - pt;
+    /// assert!(surface.uder(u, v).dot(diff).abs() < TOLERANCE);
+    /// assert!(surface.vder(u, v).dot(diff).abs() < TOLERANCE);
+    /// ```
+    /// # Remarks
+    /// It may converge to a local solution depending on the hint.
     fn search_nearest_parameter<H: Into<SPHint2D>>(
         &self,
         point: P,
@@ -2121,6 +3075,80 @@ fn combinatorial(n: usize) -> Vec<usize> {
     res
 }
 
+/// the clamped knot vector whose inner knots are the averages of `degree` consecutive parameters
+fn averaging_knot_vec(params: &[f64], degree: usize) -> KnotVec {
+    let n = params.len();
+    let front = std::iter::repeat_n(params[0], degree + 1);
+    let inner = (1..n - degree).map(|j| params[j..j + degree].iter().sum::<f64>() / degree as f64);
+    let back = std::iter::repeat_n(params[n - 1] + if n == 1 { 1.0 } else { 0.0 }, degree + 1);
+    KnotVec::from(front.chain(inner).chain(back).collect::<Vec<_>>())
+}
+
+/// Solves `matrix * x = rows` by the Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if `matrix` is numerically singular, i.e. a pivot is negligible compared with
+/// the largest entry of `matrix`, or is not finite.
+pub(super) fn solve_linear_system<P: ControlPoint<f64>>(
+    mut matrix: Vec<Vec<f64>>,
+    mut rows: Vec<Vec<P>>,
+) -> Option<Vec<Vec<P>>> {
+    let n = matrix.len();
+    let max_abs = matrix.iter().flatten().fold(0.0, |max, a| f64::max(max, a.abs()));
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|i, j| matrix[*i][k].abs().total_cmp(&matrix[*j][k].abs()))
+            .unwrap();
+        let pivot_abs = matrix[pivot][k].abs();
+        if pivot_abs.is_nan() || pivot_abs <= f64::EPSILON * max_abs {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        rows.swap(k, pivot);
+        for i in k + 1..n {
+            let coef = matrix[i][k] / matrix[k][k];
+            if coef == 0.0 {
+                continue;
+            }
+            let (upper, lower) = matrix.split_at_mut(i);
+            lower[0][k..n]
+                .iter_mut()
+                .zip(&upper[k][k..n])
+                .for_each(|(a, b)| *a -= b * coef);
+            let (upper, lower) = rows.split_at_mut(i);
+            lower[0]
+                .iter_mut()
+                .zip(&upper[k])
+                .for_each(|(p, q)| *p -= (*q * coef).to_vec());
+        }
+    }
+    for k in (0..n).rev() {
+        let (upper, lower) = rows.split_at_mut(k + 1);
+        let row = &mut upper[k];
+        lower.iter().enumerate().for_each(|(i, solved)| {
+            let coef = matrix[k][k + 1 + i];
+            row.iter_mut()
+                .zip(solved)
+                .for_each(|(p, q)| *p -= (*q * coef).to_vec());
+        });
+        row.iter_mut().for_each(|p| *p /= matrix[k][k]);
+    }
+    Some(rows)
+}
+
+#[test]
+fn solve_linear_system_test() {
+    let matrix = vec![vec![2.0, 1.0], vec![1.0, 3.0]];
+    let rows = vec![vec![Point1::new(3.0)], vec![Point1::new(4.0)]];
+    let solution = solve_linear_system(matrix, rows).unwrap();
+    assert_near!(solution[0][0], Point1::new(1.0));
+    assert_near!(solution[1][0], Point1::new(1.0));
+
+    // The last pivot does not vanish, but is negligible compared with the other entries.
+    let matrix = vec![vec![1.0, 1.0], vec![1.0, 1.0 + f64::EPSILON]];
+    let rows = vec![vec![Point1::new(1.0)], vec![Point1::new(2.0)]];
+    assert!(solve_linear_system(matrix, rows).is_none());
+}
+
 #[test]
 fn test_include_bspcurve2() {
     let knot_vec = KnotVec::uniform_knot(2, 3);
@@ -2218,3 +3246,169 @@ fn test_include_bspcurve3() {
     *curve.control_point_mut(2) += Vector3::new(0.0, 0.0, 0.001);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn test_second_derivatives() {
+    let knot_vec = KnotVec::uniform_knot(3, 2);
+    let ctrl_pts = (0..5)
+        .map(|i| {
+            (0..5)
+                .map(|j| {
+                    let (x, y) = (i as f64, j as f64);
+                    Point3::new(x, y, f64::sin(x * y))
+                })
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+    const EPS: f64 = 1.0e-4;
+    // the sample points avoid the inner knot, where the third derivatives jump.
+    for i in 0..10 {
+        for j in 0..10 {
+            let (u, v) = ((i as f64 + 0.5) / 10.0, (j as f64 + 0.5) / 10.0);
+            let uuder = (surface.uder(u + EPS, v) - surface.uder(u - EPS, v)) / (2.0 * EPS);
+            let vvder = (surface.vder(u, v + EPS) - surface.vder(u, v - EPS)) / (2.0 * EPS);
+            let uvder = (surface.uder(u, v + EPS) - surface.uder(u, v - EPS)) / (2.0 * EPS);
+            assert!((surface.uuder(u, v) - uuder).magnitude() < 1.0e-4);
+            assert!((surface.vvder(u, v) - vvder).magnitude() < 1.0e-4);
+            assert!((surface.uvder(u, v) - uvder).magnitude() < 1.0e-4);
+        }
+    }
+}
+
+#[test]
+fn test_transposed() {
+    let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::uniform_knot(2, 2));
+    let ctrl_pts = vec![
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.3, 0.0, 1.0),
+            Point3::new(0.6, 0.0, -1.0),
+            Point3::new(1.0, 0.0, 0.5),
+        ],
+        vec![
+            Point3::new(0.0, 1.0, 0.2),
+            Point3::new(0.3, 1.0, 0.4),
+            Point3::new(0.6, 1.0, 0.1),
+            Point3::new(1.0, 1.0, 0.0),
+        ],
+    ];
+    let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let org = surface.clone();
+    let transposed = surface.transposed();
+    assert_eq!(surface, org);
+    assert_eq!(transposed.knot_vecs().0, *surface.vknot_vec());
+    assert_eq!(transposed.knot_vecs().1, *surface.uknot_vec());
+
+    let mut swapped = surface.clone();
+    swapped.swap_axes();
+    assert_eq!(swapped, transposed);
+    assert_eq!(transposed.transposed(), surface);
+}
+
+#[test]
+fn test_syncro_degrees_and_knots() {
+    let knot_vec = KnotVec::uniform_knot(2, 3);
+    let ctrl_pts = (0..5)
+        .map(|i| {
+            (0..5)
+                .map(|j| {
+                    let (x, y) = (i as f64 / 4.0, j as f64 / 4.0);
+                    Point3::new(x, y, f64::sin(3.0 * x) * f64::cos(2.0 * y))
+                })
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+
+    // two refinements of the same geometry
+    let mut surface0 = surface.clone();
+    surface0.add_uknot(0.3).elevate_vdegree();
+    let mut surface1 = surface.clone();
+    surface1.add_vknot(0.6).add_uknot(0.7).elevate_udegree();
+    assert_ne!(surface0.degrees(), surface1.degrees());
+    assert_ne!(surface0.knot_vecs(), surface1.knot_vecs());
+
+    surface0.syncro_degrees(&mut surface1);
+    assert_eq!(surface0.degrees(), (3, 3));
+    assert_eq!(surface1.degrees(), (3, 3));
+    surface0.syncro_knots(&mut surface1);
+    assert_eq!(surface0.knot_vecs(), surface1.knot_vecs());
+    assert!(surface0.near2_as_surface(&surface));
+    assert!(surface1.near2_as_surface(&surface));
+    // the same geometry with the same knots has the same control points.
+    surface0
+        .control_points()
+        .iter()
+        .flatten()
+        .zip(surface1.control_points().iter().flatten())
+        .for_each(|(p, q)| assert_near!(*p, *q));
+}
+
+#[test]
+fn test_fit_scattered_singular() {
+    let params: Vec<(f64, f64)> = (0..100)
+        .map(|k| ((k % 10) as f64 / 9.0, (k / 10) as f64 / 9.0))
+        .collect();
+    let mut points: Vec<Point3> = params
+        .iter()
+        .map(|(u, v)| Point3::new(*u, *v, u * v))
+        .collect();
+    let surface = BSplineSurface::try_fit_scattered(&points, &params, (2, 2), (4, 4), 0.0);
+    assert!(surface.is_ok());
+
+    // a non-finite point
+    points[42].z = f64::NAN;
+    let res = BSplineSurface::try_fit_scattered(&points, &params, (2, 2), (4, 4), 0.0);
+    assert!(matches!(res, Err(Error::SingularSystem)));
+    points[42].z = f64::INFINITY;
+    let res = BSplineSurface::try_fit_scattered(&points, &params, (2, 2), (4, 4), 1.0e-3);
+    assert!(matches!(res, Err(Error::SingularSystem)));
+
+    // all the points at the same parameter
+    let params = vec![(0.5, 0.5); 100];
+    let res = BSplineSurface::try_fit_scattered(&points, &params, (2, 2), (4, 4), 0.0);
+    assert!(matches!(res, Err(Error::SingularSystem)));
+}
+
+#[test]
+fn test_isoparametric_curves() {
+    // the unit sphere
+    let knot_vec0 = KnotVec::bezier_knot(3);
+    let knot_vec1 = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    let pole = |z: f64| {
+        let (pt, mid) = (Vector4::new(0.0, 0.0, z, 1.0), Vector4::new(0.0, 0.0, z, 1.0) / 3.0);
+        vec![pt, mid, mid, pt, mid, mid, pt]
+    };
+    let row = |z: f64| {
+        vec![
+            Vector4::new(2.0, 0.0, z, 1.0) / 3.0,
+            Vector4::new(2.0, 4.0, z, 1.0) / 9.0,
+            Vector4::new(-2.0, 4.0, z, 1.0) / 9.0,
+            Vector4::new(-2.0, 0.0, z, 1.0) / 3.0,
+            Vector4::new(-2.0, -4.0, z, 1.0) / 9.0,
+            Vector4::new(2.0, -4.0, z, 1.0) / 9.0,
+            Vector4::new(2.0, 0.0, z, 1.0) / 3.0,
+        ]
+    };
+    let ctrl_pts = vec![pole(1.0), row(1.0), row(-1.0), pole(-1.0)];
+    let sphere = BSplineSurface::new((knot_vec0, knot_vec1), ctrl_pts);
+
+    const N: usize = 50;
+    for i in 0..=N {
+        let s = (i as f64) / (N as f64);
+        let ucurve = sphere.uparametric_curve(s);
+        let vcurve = sphere.vparametric_curve(s);
+        assert_eq!(ucurve.knot_vec(), sphere.vknot_vec());
+        assert_eq!(vcurve.knot_vec(), sphere.uknot_vec());
+        for j in 0..=N {
+            let t = (j as f64) / (N as f64);
+            assert_near2!(ucurve.subs(t), sphere.subs(s, t));
+            assert_near2!(vcurve.subs(t), sphere.subs(t, s));
+            // the curves are on the sphere
+            let radius = |pt: Vector4| (pt.truncate() / pt.w).magnitude();
+            assert_near!(radius(ucurve.subs(t)), 1.0);
+            assert_near!(radius(vcurve.subs(t)), 1.0);
+        }
+    }
+}
@@ -88,18 +88,59 @@ impl KnotVec {
     /// ```
     #[inline(always)]
     pub fn add_knot(&mut self, knot: f64) -> usize {
-        match self.floor(knot) {
-            Some(idx) => {
-                self.0.insert(idx + 1, knot);
-                idx + 1
-            }
-            None => {
-                self.0.insert(0, knot);
-                0
-            }
+        let idx = self.insertion_index(knot);
+        self.0.insert(idx, knot);
+        idx
+    }
+
+    /// Returns the index at which `add_knot(x)` would insert `x`, without mutating `self`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::KnotVec;
+    /// // clamped cubic knot vector
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    /// // a value in the middle span [0.5, 1.0)
+    /// assert_eq!(knot_vec.insertion_index(0.75), 5);
+    /// // the same index as the one returned by `add_knot`
+    /// let mut clone = knot_vec.clone();
+    /// assert_eq!(clone.add_knot(0.75), 5);
+    /// // a value before the front knot
+    /// assert_eq!(knot_vec.insertion_index(-1.0), 0);
+    /// ```
+    #[inline(always)]
+    pub fn insertion_index(&self, x: f64) -> usize {
+        match self.floor(x) {
+            Some(idx) => idx + 1,
+            None => 0,
         }
     }
 
+    /// Returns the index of the nearest knot to `x` and its distance from `x`.
+    /// If several knots have the same distance, returns the minimum index.
+    /// # Panics
+    /// Panic occurs if `self` is empty.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::KnotVec;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    /// let (idx, dist) = knot_vec.nearest_knot(0.6);
+    /// assert_eq!(idx, 4);
+    /// assert!(f64::abs(dist - 0.1) < 1.0e-10);
+    /// let (idx, dist) = knot_vec.nearest_knot(-0.5);
+    /// assert_eq!(idx, 0);
+    /// assert_eq!(dist, 0.5);
+    /// ```
+    pub fn nearest_knot(&self, x: f64) -> (usize, f64) {
+        self.iter()
+            .map(|t| f64::abs(t - x))
+            .enumerate()
+            .fold(None, |res: Option<(usize, f64)>, (i, dist)| match res {
+                Some((_, min)) if min <= dist => res,
+                _ => Some((i, dist)),
+            })
+            .expect("the knot vector is empty.")
+    }
+
     /// Calculate B-spline basis functions at `t` with degree `degree`.
     /// # Panics
     /// If the length of `self` is not more than `degree`, panic occurs.
@@ -314,6 +355,56 @@ impl KnotVec {
             .unwrap_or_else(|error| panic!("{}", error))
     }
 
+    /// Maps the range of the knot vector onto `range` by an affine map, and returns the applied
+    /// map as `(scale, offset)`, i.e. each knot `t` is replaced by `t * scale + offset`.
+    /// # Failures
+    /// - Returns [`Error::ZeroRange`] if the range of the knot vector is so small.
+    /// - Returns [`Error::InvalidRange`] if `range` is inverted or degenerate.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::from(vec![1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 1.0),
+    /// ];
+    /// let curve = BSplineCurve::new(knot_vec.clone(), ctrl_pts.clone());
+    ///
+    /// let mut knot_vec = knot_vec;
+    /// let (scale, offset) = knot_vec.normalize_to((2.0, 5.0)).unwrap();
+    /// assert_near!(scale, 1.5);
+    /// assert_near!(offset, 0.5);
+    /// assert_near!(knot_vec[0], 2.0);
+    /// assert_near!(knot_vec[knot_vec.len() - 1], 5.0);
+    ///
+    /// // the curve is preserved under the reparameterization
+    /// let normalized = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// for i in 0..=10 {
+    ///     let t = 1.0 + 2.0 * i as f64 / 10.0;
+    ///     assert_near!(normalized.subs(t * scale + offset), curve.subs(t));
+    /// }
+    ///
+    /// let mut knot_vec = KnotVec::from(vec![1.0, 1.0]);
+    /// assert_eq!(knot_vec.normalize_to((2.0, 5.0)), Err(errors::Error::ZeroRange));
+    /// let mut knot_vec = KnotVec::from(vec![0.0, 1.0]);
+    /// assert_eq!(knot_vec.normalize_to((5.0, 2.0)), Err(errors::Error::InvalidRange(5.0, 2.0)));
+    /// ```
+    pub fn normalize_to(&mut self, range: (f64, f64)) -> Result<(f64, f64)> {
+        let length = self.range_length();
+        if length.so_small() {
+            return Err(Error::ZeroRange);
+        }
+        if range.1 - range.0 <= 0.0 || (range.1 - range.0).so_small() {
+            return Err(Error::InvalidRange(range.0, range.1));
+        }
+        let scale = (range.1 - range.0) / length;
+        let offset = range.0 - self[0] * scale;
+        self.transform(scale, offset);
+        Ok((scale, offset))
+    }
+
     /// translate the knot vector
     /// # Example
     /// ```
@@ -363,6 +454,94 @@ impl KnotVec {
         self.multiplicity(0) > degree && self.multiplicity(self.len() - 1) > degree
     }
 
+    /// Clamps the knot vector for the given degree.
+    ///
+    /// The end knots of the domain `[self[degree], self[self.len() - degree - 1]]` are inserted
+    /// until their multiplicities are `degree + 1`, and the knots outside the domain are removed.
+    /// This is the knot vector of the curve clamped by the knot insertion and cut at the ends
+    /// of the domain, so the control points can be given by [`BSplineCurve::add_knot`] without
+    /// changing the geometry on the domain.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::KnotVec;
+    /// let mut knot_vec = KnotVec::from((0..10).map(|i| i as f64).collect::<Vec<_>>());
+    /// assert!(!knot_vec.is_clamped(3));
+    /// knot_vec.to_clamped(3);
+    /// assert!(knot_vec.is_clamped(3));
+    /// assert_eq!(
+    ///     knot_vec.as_slice(),
+    ///     &[3.0, 3.0, 3.0, 3.0, 4.0, 5.0, 6.0, 6.0, 6.0, 6.0],
+    /// );
+    ///
+    /// // the multiple knot at the end of the domain is not duplicated.
+    /// let mut knot_vec = KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    /// knot_vec.to_clamped(3);
+    /// assert_eq!(knot_vec.as_slice(), &[3.0, 3.0, 3.0, 3.0, 4.0, 4.0, 4.0, 4.0]);
+    /// ```
+    /// # Remarks
+    /// If the length of the knot vector is less than `2 * (degree + 1)`, does nothing.
+    ///
+    /// [`BSplineCurve::add_knot`]: ./struct.BSplineCurve.html#method.add_knot
+    pub fn to_clamped(&mut self, degree: usize) -> &mut Self {
+        let n = self.len();
+        if n < 2 * (degree + 1) {
+            return self;
+        }
+        let (front, back) = (self[degree], self[n - degree - 1]);
+        let inner = self.0[degree + 1..n - degree - 1]
+            .iter()
+            .filter(|t| front < **t && **t < back);
+        let knots = std::iter::repeat_n(&front, degree + 1)
+            .chain(inner)
+            .chain(std::iter::repeat_n(&back, degree + 1))
+            .copied()
+            .collect();
+        self.0 = knots;
+        self
+    }
+
+    /// Unclamps the knot vector for the given degree.
+    ///
+    /// The domain `[self[degree], self[self.len() - degree - 1]]` is kept,
+    /// and the `degree` knots at each end are replaced with the knots whose intervals are
+    /// the same as the first or last non-degenerate span of the domain.
+    /// The end knots of the result are simple. The length of the knot vector is not changed.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::KnotVec;
+    /// let mut knot_vec = KnotVec::uniform_knot(2, 5);
+    /// let org_knot_vec = knot_vec.clone();
+    /// assert!(knot_vec.is_clamped(2));
+    ///
+    /// knot_vec.to_unclamped(2);
+    /// assert!(!knot_vec.is_clamped(2));
+    /// assert_eq!(knot_vec.multiplicity(0), 1);
+    /// let res: Vec<f64> = knot_vec.iter().map(|t| (t * 10.0).round() / 10.0).collect();
+    /// assert_eq!(res, vec![-0.4, -0.2, 0.0, 0.2, 0.4, 0.6, 0.8, 1.0, 1.2, 1.4]);
+    ///
+    /// // the inverse operation of clamping
+    /// knot_vec.to_clamped(2);
+    /// assert_eq!(knot_vec, org_knot_vec);
+    /// ```
+    /// # Remarks
+    /// If the length of the knot vector is less than `2 * (degree + 1)`,
+    /// or the domain has zero range, does nothing.
+    pub fn to_unclamped(&mut self, degree: usize) -> &mut Self {
+        let n = self.len();
+        if n < 2 * (degree + 1) {
+            return self;
+        }
+        let (front, back) = (self[degree], self[n - degree - 1]);
+        let domain = &self.0[degree..n - degree];
+        let span0 = domain.windows(2).map(|x| x[1] - x[0]).find(|d| !d.so_small());
+        let span1 = domain.windows(2).rev().map(|x| x[1] - x[0]).find(|d| !d.so_small());
+        if let (Some(span0), Some(span1)) = (span0, span1) {
+            (0..degree).for_each(|i| self.0[i] = front - (degree - i) as f64 * span0);
+            (0..degree).for_each(|i| self.0[n - degree + i] = back + (i + 1) as f64 * span1);
+        }
+        self
+    }
+
     /// Concats two knot vectors.
     /// # Examples
     /// ```
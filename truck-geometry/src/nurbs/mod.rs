@@ -118,6 +118,21 @@ pub struct NURBSCurve<V>(BSplineCurve<V>);
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct NURBSSurface<V>(BSplineSurface<V>);
 
+/// Geometric continuity across the shared edge of two surfaces.
+///
+/// The variants are ordered by the strength, i.e. `None < G0 < G1 < G2`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Continuity {
+    /// The surfaces do not share an edge.
+    None,
+    /// The positions coincide along the shared edge.
+    G0,
+    /// The tangent planes also coincide along the shared edge.
+    G1,
+    /// The curvatures also coincide along the shared edge.
+    G2,
+}
+
 mod bspcurve;
 mod bspsurface;
 mod knot_vec;
@@ -133,3 +148,253 @@ pub fn inv_or_zero(delta: f64) -> f64 {
         1.0 / delta
     }
 }
+
+/// signed curvature of a planar curve from its first and second derivations.
+/// Returns `0.0` if the speed is so small.
+#[inline(always)]
+fn planar_curvature(der: Vector2, der2: Vector2) -> f64 {
+    let speed2 = der.magnitude2();
+    match speed2.so_small() {
+        true => 0.0,
+        false => (der.x * der2.y - der.y * der2.x) / (speed2 * f64::sqrt(speed2)),
+    }
+}
+
+/// Gaussian and mean curvatures of a surface by the first and second fundamental forms.
+/// The mean curvature is signed with respect to the normal `uder.cross(vder)`.
+/// Returns `None` at the degenerate points.
+fn surface_curvatures<S>(surface: &S, u: f64, v: f64) -> Option<(f64, f64)>
+where S: ParametricSurface<Vector = Vector3> {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    let normal = uder.cross(vder);
+    if normal.so_small() {
+        return None;
+    }
+    let (e, f, g) = (uder.dot(uder), uder.dot(vder), vder.dot(vder));
+    let det = normal.magnitude2();
+    let normal = normal / f64::sqrt(det);
+    let l = surface.uuder(u, v).dot(normal);
+    let m = surface.uvder(u, v).dot(normal);
+    let n = surface.vvder(u, v).dot(normal);
+    let gaussian = (l * n - m * m) / det;
+    let mean = (l * g - 2.0 * m * f + n * e) / (2.0 * det);
+    Some((gaussian, mean))
+}
+
+type ParameterBox = ((f64, f64), (f64, f64));
+
+/// Intersection parameters of two planar curves.
+///
+/// The pairs of pieces are subdivided while their bounding boxes `bdd` overlap,
+/// the leaves touching each other are put together,
+/// and the representative of each cluster is refined by Newton's method.
+/// If the curves overlap, the subdivision is stopped when the number of the pairs exceeds
+/// `MAX_PAIRS`, and each overlapping part is represented by the parameters of its both ends.
+fn planar_curve_intersection<C, F>(curve0: &C, curve1: &C, tol: f64, bdd: F) -> Vec<(f64, f64)>
+where
+    C: ParametricCurve<Point = Point2, Vector = Vector2> + BoundedCurve + Cut,
+    F: Fn(&C) -> BoundingBox<Point2>,
+{
+    let (leaves, overlapping) = subdivide_planar_curves(curve0, curve1, tol, &bdd);
+    let distance = |(s, t): (f64, f64)| curve0.subs(s).distance(curve1.subs(t));
+    let mut res = Vec::<(f64, f64)>::new();
+    let mut push = |param: (f64, f64)| {
+        let pt = curve0.subs(param.0);
+        if res.iter().all(|(s, _)| curve0.subs(*s).distance(pt) >= tol) {
+            res.push(param);
+        }
+    };
+    cluster_by(leaves, |leaf| leaf.0)
+        .into_iter()
+        .flat_map(|cluster| cluster_by(cluster, |leaf| leaf.1))
+        .for_each(|cluster| {
+            if overlapping && cluster.len() >= MIN_OVERLAP_LEAVES {
+                return overlap_ends(curve0, curve1, &cluster).into_iter().for_each(&mut push);
+            }
+            let mid = |((s0, s1), (t0, t1)): ParameterBox| ((s0 + s1) / 2.0, (t0 + t1) / 2.0);
+            let hull = cluster.iter().fold(cluster[0], |(s, t), leaf| {
+                let s = (f64::min(s.0, leaf.0 .0), f64::max(s.1, leaf.0 .1));
+                let t = (f64::min(t.0, leaf.1 .0), f64::max(t.1, leaf.1 .1));
+                (s, t)
+            });
+            let hint = cluster
+                .into_iter()
+                .map(mid)
+                .min_by(|x, y| distance(*x).partial_cmp(&distance(*y)).unwrap())
+                .unwrap();
+            let inside = |(s, t): (f64, f64)| {
+                let ((s0, s1), (t0, t1)) = hull;
+                s0 - tol <= s && s <= s1 + tol && t0 - tol <= t && t <= t1 + tol
+            };
+            let param = newton_planar_curve_intersection(curve0, curve1, hint)
+                .filter(|param| inside(*param) && distance(*param) <= distance(hint))
+                .unwrap_or(hint);
+            push(param);
+        });
+    res.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    res
+}
+
+/// The maximum number of the pairs of pieces at each level of the subdivision.
+/// The curves are regarded as overlapping if more pairs remain.
+const MAX_PAIRS: usize = 1 << 10;
+/// The minimum number of the leaves of a cluster regarded as an overlapping part.
+/// The clusters of the transversal intersections consist of a few leaves.
+const MIN_OVERLAP_LEAVES: usize = 8;
+
+/// Subdivides the pairs of pieces level by level, and returns the leaves and whether the
+/// subdivision has been stopped by `MAX_PAIRS`.
+fn subdivide_planar_curves<C, F>(
+    curve0: &C,
+    curve1: &C,
+    tol: f64,
+    bdd: &F,
+) -> (Vec<ParameterBox>, bool)
+where
+    C: ParametricCurve<Point = Point2, Vector = Vector2> + BoundedCurve + Cut,
+    F: Fn(&C) -> BoundingBox<Point2>,
+{
+    const MAX_DEPTH: usize = 100;
+    let range = |(curve0, curve1): &(C, C)| (curve0.parameter_range(), curve1.parameter_range());
+    let mut leaves = Vec::new();
+    let mut pairs = vec![(curve0.clone(), curve1.clone())];
+    for _ in 0..MAX_DEPTH {
+        if pairs.len() > MAX_PAIRS {
+            leaves.extend(pairs.iter().map(range));
+            return (leaves, true);
+        }
+        pairs = pairs
+            .into_iter()
+            .flat_map(|(curve0, curve1)| {
+                let (bdd0, bdd1) = (bdd(&curve0), bdd(&curve1));
+                let separated = (0..2).any(|i| {
+                    bdd0.max()[i] + tol < bdd1.min()[i] || bdd1.max()[i] + tol < bdd0.min()[i]
+                });
+                let (diam0, diam1) = (bdd0.diameter(), bdd1.diameter());
+                if separated {
+                    Vec::new()
+                } else if diam0 < tol && diam1 < tol {
+                    leaves.push(range(&(curve0, curve1)));
+                    Vec::new()
+                } else if diam0 >= diam1 {
+                    let (t0, t1) = curve0.parameter_range();
+                    let mut curve00 = curve0;
+                    let curve01 = curve00.cut((t0 + t1) / 2.0);
+                    vec![(curve00, curve1.clone()), (curve01, curve1)]
+                } else {
+                    let (t0, t1) = curve1.parameter_range();
+                    let mut curve10 = curve1;
+                    let curve11 = curve10.cut((t0 + t1) / 2.0);
+                    vec![(curve0.clone(), curve10), (curve0, curve11)]
+                }
+            })
+            .collect();
+    }
+    leaves.extend(pairs.iter().map(range));
+    (leaves, false)
+}
+
+/// The parameters of the both ends of the overlapping part covered by `cluster`.
+fn overlap_ends<C>(curve0: &C, curve1: &C, cluster: &[ParameterBox]) -> [(f64, f64); 2]
+where C: ParametricCurve<Point = Point2, Vector = Vector2> + BoundedCurve {
+    let end = |((s0, s1), (t0, t1)): ParameterBox, start: bool| {
+        // the outer end of the leaf, from which the end of the overlap is searched inward.
+        let s = if start { s0 } else { s1 };
+        let pt = curve0.subs(s);
+        let t = match curve1.subs(t0).distance2(pt) < curve1.subs(t1).distance2(pt) {
+            true => t0,
+            false => t1,
+        };
+        refine_overlap_end(curve0, curve1, (s, t))
+    };
+    let first = cluster.iter().min_by(|x, y| x.0 .0.total_cmp(&y.0 .0)).unwrap();
+    let last = cluster.iter().max_by(|x, y| x.0 .1.total_cmp(&y.0 .1)).unwrap();
+    [end(*first, true), end(*last, false)]
+}
+
+/// Refines the end of an overlapping part by the alternating projections onto the curves.
+fn refine_overlap_end<C>(curve0: &C, curve1: &C, (mut s, mut t): (f64, f64)) -> (f64, f64)
+where C: ParametricCurve<Point = Point2, Vector = Vector2> + BoundedCurve {
+    const TRIALS: usize = 100;
+    for _ in 0..TRIALS {
+        let t_next = project_to_planar_curve(curve1, curve0.subs(s), t);
+        let s_next = project_to_planar_curve(curve0, curve1.subs(t_next), s);
+        let converged = f64::abs(s_next - s) + f64::abs(t_next - t) < TOLERANCE2;
+        (s, t) = (s_next, t_next);
+        if converged {
+            break;
+        }
+    }
+    (s, t)
+}
+
+/// The parameter of the nearest point on `curve` to `pt` by Newton's method from `hint`,
+/// clamped into the parameter range.
+fn project_to_planar_curve<C>(curve: &C, pt: Point2, hint: f64) -> f64
+where C: ParametricCurve<Point = Point2, Vector = Vector2> + BoundedCurve {
+    const TRIALS: usize = 100;
+    let (t0, t1) = curve.parameter_range();
+    let mut t = hint;
+    for _ in 0..TRIALS {
+        let (diff, der) = (curve.subs(t) - pt, curve.der(t));
+        let dder = der.magnitude2() + diff.dot(curve.der2(t));
+        if dder.so_small2() {
+            break;
+        }
+        let next = f64::clamp(t - diff.dot(der) / dder, t0, t1);
+        let converged = f64::abs(next - t) < TOLERANCE2;
+        t = next;
+        if converged {
+            break;
+        }
+    }
+    t
+}
+
+/// Divides `leaves` into the clusters whose ranges given by `range` are connected.
+fn cluster_by(
+    mut leaves: Vec<ParameterBox>,
+    range: impl Fn(&ParameterBox) -> (f64, f64),
+) -> Vec<Vec<ParameterBox>> {
+    leaves.sort_by(|x, y| range(x).partial_cmp(&range(y)).unwrap());
+    let mut clusters = Vec::<Vec<ParameterBox>>::new();
+    let mut end = f64::NEG_INFINITY;
+    leaves.into_iter().for_each(|leaf| {
+        let (t0, t1) = range(&leaf);
+        match clusters.last_mut() {
+            Some(cluster) if t0 <= end => cluster.push(leaf),
+            _ => clusters.push(vec![leaf]),
+        }
+        end = f64::max(end, t1);
+    });
+    clusters
+}
+
+fn newton_planar_curve_intersection<C>(
+    curve0: &C,
+    curve1: &C,
+    hint: (f64, f64),
+) -> Option<(f64, f64)>
+where
+    C: ParametricCurve<Point = Point2, Vector = Vector2>,
+{
+    const TRIALS: usize = 100;
+    let (mut s, mut t) = hint;
+    for _ in 0..TRIALS {
+        let diff = curve0.subs(s) - curve1.subs(t);
+        if diff.magnitude2() < TOLERANCE2 * TOLERANCE2 {
+            return Some((s, t));
+        }
+        let jacobian = Matrix2::from_cols(curve0.der(s), -curve1.der(t));
+        if jacobian.determinant().so_small2() {
+            return Some((s, t));
+        }
+        let delta = jacobian.invert()? * diff;
+        s -= delta.x;
+        t -= delta.y;
+        if delta.magnitude2() < TOLERANCE2 * TOLERANCE2 {
+            return Some((s, t));
+        }
+    }
+    None
+}
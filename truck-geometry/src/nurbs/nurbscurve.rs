@@ -221,6 +221,45 @@ where V::Point: Tolerance
         self.0
             .sub_near_as_curve(&other.0, 2, move |x, y| x.to_point().near2(&y.to_point()))
     }
+
+    /// Returns whether the front point and the back point of the projected curve are near
+    /// or not.
+    ///
+    /// Unlike [`BSplineCurve::is_closed`] of the non-rationalized curve, the weights of
+    /// the end control points may be different.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Vector3::new(0.0, 0.0, 2.0),
+    /// ];
+    /// let curve = NURBSCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// assert!(!curve.non_rationalized().is_closed());
+    /// assert!(curve.is_closed());
+    /// ```
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool { self.front().near(&self.back()) }
+
+    /// Returns whether the projected curve is closed and its derivation is continuous
+    /// at the seam.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use std::f64::consts::PI;
+    /// let circle = CircleArc::new(Point3::origin(), 1.0, Vector3::unit_z(), (0.0, 2.0 * PI));
+    /// assert!(circle.to_nurbs().is_periodic());
+    /// let arc = CircleArc::new(Point3::origin(), 1.0, Vector3::unit_z(), (0.0, PI));
+    /// assert!(!arc.to_nurbs().is_periodic());
+    /// ```
+    pub fn is_periodic(&self) -> bool {
+        let (t0, t1) = self.parameter_range();
+        let (der0, der1) = (self.der(t0), self.der(t1));
+        let origin = V::Point::origin();
+        self.is_closed() && (origin + der0).near(&(origin + der1))
+    }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NURBSCurve<V> {
@@ -377,6 +416,8 @@ where V::Point: Tolerance
     /// assert!(flag);
     ///
     /// curve.make_locally_injective().knot_normalize();
+    /// // the end point is kept.
+    /// assert_near!(curve.subs(1.0), Point3::new(0.0, 0.0, 1.0));
     /// let mut flag = false;
     /// for i in 0..N {
     ///     let t = 1.0 * (i as f64) / (N as f64);
@@ -423,7 +464,7 @@ where V::Point: Tolerance
                     .control_points
                     .iter_mut()
                     .for_each(move |vec| *vec *= s0 / s1);
-                self.concat(bezier.knot_translate(-x));
+                *self = self.concat(bezier.knot_translate(-x));
             }
         }
         self
@@ -467,6 +508,35 @@ where
     /// let ans = Point2::from_vec(pt.to_vec().normalize());
     /// assert_near!(res, ans);
     /// ```
+    /// If `hint` is `None`, the initial guess is taken by sampling the curve.
+    /// ```
+    /// use truck_geometry::*;
+    ///
+    /// // the unit circle
+    /// let knot_vec = KnotVec::from(vec![
+    ///     0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+    /// ]);
+    /// let w = 0.5 * f64::sqrt(2.0);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(w, w, w),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    ///     Vector3::new(-w, w, w),
+    ///     Vector3::new(-1.0, 0.0, 1.0),
+    ///     Vector3::new(-w, -w, w),
+    ///     Vector3::new(0.0, -1.0, 1.0),
+    ///     Vector3::new(w, -w, w),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    /// ];
+    /// let curve = NURBSCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    ///
+    /// let pt = Point2::new(-1.5, -2.0);
+    /// let t = curve.search_nearest_parameter(pt, None, 100).unwrap();
+    /// let foot = curve.subs(t);
+    /// assert_near!(foot, Point2::new(-0.6, -0.8));
+    /// // the derivation at the foot point is orthogonal to the perpendicular.
+    /// assert!(curve.der(t).dot(foot - pt).so_small());
+    /// ```
     /// # Remarks
     /// It may converge to a local solution depending on the hint.
     /// ```
@@ -530,17 +600,141 @@ where
     }
 }
 
+impl NURBSCurve<Vector3> {
+    /// Returns the signed curvature of the planar rational curve at the parameter `t`.
+    ///
+    /// The curvature is `(x'y'' - y'x'') / (x'^2 + y'^2)^{3/2}`, positive if the curve turns
+    /// counterclockwise. The unsigned curvature is its absolute value.
+    /// Returns `0.0` at the parameter where the derivation vanishes.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the quarter circle with radius 2
+    /// let w = f64::sqrt(2.0) / 2.0;
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(2.0, 0.0, 1.0),
+    ///     Vector3::new(2.0 * w, 2.0 * w, w),
+    ///     Vector3::new(0.0, 2.0, 1.0),
+    /// ];
+    /// let curve = NURBSCurve::new(BSplineCurve::new(KnotVec::bezier_knot(2), ctrl_pts));
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert!(f64::abs(curve.curvature(t) - 0.5) < TOLERANCE);
+    ///     // clockwise
+    ///     assert!(f64::abs(curve.inverse().curvature(t) + 0.5) < TOLERANCE);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn curvature(&self, t: f64) -> f64 { planar_curvature(self.der(t), self.der2(t)) }
+}
+
 impl<V: Homogeneous<f64>> NURBSCurve<V>
 where V::Point:
         MetricSpace<Metric = f64> + std::ops::Index<usize, Output = f64> + Bounded<f64> + Copy
 {
     /// Returns the bounding box including all control points.
+    ///
+    /// If all weights are positive, the NURBS curve lies in the convex hull of its control points,
+    /// and the returned box includes the whole curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// // the unit circle
+    /// let knot_vec = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0]
+    /// );
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(0.0, -2.0, 2.0),
+    ///     Vector3::new(1.0, -1.0, 1.0),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Vector3::new(0.0, 2.0, 2.0),
+    ///     Vector3::new(-1.0, 1.0, 1.0),
+    ///     Vector3::new(-1.0, 0.0, 1.0),
+    ///     Vector3::new(-1.0, -1.0, 1.0),
+    ///     Vector3::new(0.0, -2.0, 2.0),
+    /// ];
+    /// let circle = NURBSCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let bdb = circle.roughly_bounding_box();
+    /// assert_eq!(bdb.min(), &Point2::new(-1.0, -1.0));
+    /// assert_eq!(bdb.max(), &Point2::new(1.0, 1.0));
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let pt = circle.subs(i as f64 / N as f64);
+    ///     assert!(bdb.min()[0] - TOLERANCE <= pt[0] && pt[0] <= bdb.max()[0] + TOLERANCE);
+    ///     assert!(bdb.min()[1] - TOLERANCE <= pt[1] && pt[1] <= bdb.max()[1] + TOLERANCE);
+    /// }
+    /// ```
     #[inline(always)]
     pub fn roughly_bounding_box(&self) -> BoundingBox<V::Point> {
         self.0.control_points.iter().map(|p| p.to_point()).collect()
     }
 }
 
+impl NURBSCurve<Vector3> {
+    /// Returns the pairs of parameters `(s, t)` such that `self.subs(s)` and `other.subs(t)` meet.
+    ///
+    /// The roots whose points are closer than `tol` are regarded as the same one.
+    /// If the curves overlap, each overlapping part is represented by the pairs of its both ends.
+    /// The returned vector is sorted.
+    /// cf. [`BSplineCurve::intersection`](struct.BSplineCurve.html#method.intersection)
+    /// # Remarks
+    /// The weights of control points are assumed to be positive.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0]
+    /// );
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(0.0, -2.0, 2.0),
+    ///     Vector3::new(1.0, -1.0, 1.0),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Vector3::new(0.0, 2.0, 2.0),
+    ///     Vector3::new(-1.0, 1.0, 1.0),
+    ///     Vector3::new(-1.0, 0.0, 1.0),
+    ///     Vector3::new(-1.0, -1.0, 1.0),
+    ///     Vector3::new(0.0, -2.0, 2.0),
+    /// ];
+    /// // the unit circle
+    /// let circle0 = NURBSCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    ///
+    /// // the circles with radius 1 centered at (1, 0) and (2, 0)
+    /// let mut circle1 = circle0.clone();
+    /// circle1.transform_by(Matrix3::from_translation(Vector2::new(1.0, 0.0)));
+    /// let mut circle2 = circle0.clone();
+    /// circle2.transform_by(Matrix3::from_translation(Vector2::new(2.0, 0.0)));
+    ///
+    /// // two intersection points
+    /// let res = circle0.intersection(&circle1, TOLERANCE);
+    /// assert_eq!(res.len(), 2);
+    /// let mut pts: Vec<Point2> = res.iter().map(|(s, _)| circle0.subs(*s)).collect();
+    /// pts.sort_by(|p, q| p.y.partial_cmp(&q.y).unwrap());
+    /// assert_near!(pts[0], Point2::new(0.5, -f64::sqrt(3.0) / 2.0));
+    /// assert_near!(pts[1], Point2::new(0.5, f64::sqrt(3.0) / 2.0));
+    /// for (s, t) in res {
+    ///     assert_near!(circle0.subs(s), circle1.subs(t));
+    /// }
+    ///
+    /// // the tangent point
+    /// let res = circle0.intersection(&circle2, TOLERANCE);
+    /// assert_eq!(res.len(), 1);
+    /// let (s, t) = res[0];
+    /// assert!(circle0.subs(s).distance(Point2::new(1.0, 0.0)) < 1.0e-3);
+    /// assert!(circle2.subs(t).distance(Point2::new(1.0, 0.0)) < 1.0e-3);
+    ///
+    /// // no intersection
+    /// let mut circle3 = circle0.clone();
+    /// circle3.transform_by(Matrix3::from_translation(Vector2::new(3.0, 0.0)));
+    /// assert!(circle0.intersection(&circle3, TOLERANCE).is_empty());
+    /// ```
+    pub fn intersection(&self, other: &Self, tol: f64) -> Vec<(f64, f64)> {
+        planar_curve_intersection(self, other, tol, Self::roughly_bounding_box)
+    }
+}
+
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricCurve for NURBSCurve<V> {
     type Point = V::Point;
     type Vector = <V::Point as EuclideanSpace>::Diff;
@@ -186,6 +186,16 @@ impl<V> NURBSSurface<V> {
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NURBSSurface<V> {
+    /// Returns the isoparametric curve `v |-> self.subs(u, v)` with the fixed parameter `u`.
+    #[inline(always)]
+    pub fn uparametric_curve(&self, u: f64) -> NURBSCurve<V> {
+        NURBSCurve(self.0.uparametric_curve(u))
+    }
+    /// Returns the isoparametric curve `u |-> self.subs(u, v)` with the fixed parameter `v`.
+    #[inline(always)]
+    pub fn vparametric_curve(&self, v: f64) -> NURBSCurve<V> {
+        NURBSCurve(self.0.vparametric_curve(v))
+    }
     /// Substitutes to a NURBS surface.
     #[inline(always)]
     pub fn subs(&self, u: f64, v: f64) -> V::Point { self.0.subs(u, v).to_point() }
@@ -511,6 +521,60 @@ where
     /// let (u, v) = surface.search_nearest_parameter(pt, Some((0.5, 0.5)), 100).unwrap();
     /// assert!(u.near(&0.3) && v.near(&0.7));
     /// ```
+    /// Projects points onto the unit sphere.
+    /// ```
+    /// use truck_geometry::*;
+    /// let knot_vec0 = KnotVec::bezier_knot(3);
+    /// let knot_vec1 = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]
+    /// );
+    /// let mut v = vec![vec![Vector4::zero(); 7]; 4];
+    /// v[0][0] = Vector4::new(0.0, 0.0, 1.0, 1.0);
+    /// v[0][1] = &v[0][0] / 3.0;
+    /// v[0][2] = v[0][1].clone();
+    /// v[0][3] = v[0][0].clone();
+    /// v[0][4] = v[0][1].clone();
+    /// v[0][5] = v[0][1].clone();
+    /// v[0][6] = v[0][0].clone();
+    /// v[1][0] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[1][1] = Vector4::new(2.0, 4.0, 1.0, 1.0) / 9.0;
+    /// v[1][2] = Vector4::new(-2.0, 4.0, 1.0, 1.0) / 9.0;
+    /// v[1][3] = Vector4::new(-2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[1][4] = Vector4::new(-2.0, -4.0, 1.0, 1.0) / 9.0;
+    /// v[1][5] = Vector4::new(2.0, -4.0, 1.0, 1.0) / 9.0;
+    /// v[1][6] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    /// v[2][0] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[2][1] = Vector4::new(2.0, 4.0, -1.0, 1.0) / 9.0;
+    /// v[2][2] = Vector4::new(-2.0, 4.0, -1.0, 1.0) / 9.0;
+    /// v[2][3] = Vector4::new(-2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[2][4] = Vector4::new(-2.0, -4.0, -1.0, 1.0) / 9.0;
+    /// v[2][5] = Vector4::new(2.0, -4.0, -1.0, 1.0) / 9.0;
+    /// v[2][6] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    /// v[3][0] = Vector4::new(0.0, 0.0, -1.0, 1.0);
+    /// v[3][1] = &v[3][0] / 3.0;
+    /// v[3][2] = v[3][1].clone();
+    /// v[3][3] = v[3][0].clone();
+    /// v[3][4] = v[3][1].clone();
+    /// v[3][5] = v[3][1].clone();
+    /// v[3][6] = v[3][0].clone();
+    /// let sphere = NURBSSurface::new(BSplineSurface::new((knot_vec0, knot_vec1), v));
+    ///
+    /// const N: usize = 10;
+    /// for i in 1..N {
+    ///     for j in 0..N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         let pt = sphere.subs(u, v);
+    ///         for scale in [0.5, 2.0] {
+    ///             let pt = Point3::from_vec(pt.to_vec() * scale);
+    ///             let (u, v) = sphere.search_nearest_parameter(pt, None, 100).unwrap();
+    ///             let diff = sphere.subs(u, v) - pt;
+    ///             assert!(sphere.uder(u, v).dot(diff).abs() < TOLERANCE);
+    ///             assert!(sphere.vder(u, v).dot(diff).abs() < TOLERANCE);
+    ///             assert_near!(sphere.subs(u, v), Point3::from_vec(pt.to_vec().normalize()));
+    ///         }
+    ///     }
+    /// }
+    /// ```
     /// # Remarks
     /// It may converge to a local solution depending on the hint.
     /// cf. [`BSplineCurve::search_rational_nearest_parameter`](struct.BSplineCurve.html#method.search_rational_nearest_parameter)
@@ -621,6 +685,68 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricSurface for NU
     fn vvder(&self, u: f64, v: f64) -> Self::Vector { self.vvder(u, v) }
 }
 
+impl NURBSSurface<Vector4> {
+    /// Returns the Gaussian curvature at the parameter `(u, v)`.
+    ///
+    /// The curvature is computed by the first and second fundamental forms.
+    /// Returns `0.0` at the degenerate points, where `uder` and `vder` are parallel.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// const R: f64 = 2.0;
+    /// // the sphere with radius `R`
+    /// let knot_vec0 = KnotVec::bezier_knot(3);
+    /// let knot_vec1 = KnotVec::from(
+    ///     vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]
+    /// );
+    /// let mut v = vec![vec![Vector4::zero(); 7]; 4];
+    /// v[0][0] = Vector4::new(0.0, 0.0, R, 1.0);
+    /// v[0][1] = v[0][0] / 3.0;
+    /// v[0][2] = v[0][1];
+    /// v[0][3] = v[0][0];
+    /// v[0][4] = v[0][1];
+    /// v[0][5] = v[0][1];
+    /// v[0][6] = v[0][0];
+    /// v[1][0] = Vector4::new(2.0 * R, 0.0, R, 1.0) / 3.0;
+    /// v[1][1] = Vector4::new(2.0 * R, 4.0 * R, R, 1.0) / 9.0;
+    /// v[1][2] = Vector4::new(-2.0 * R, 4.0 * R, R, 1.0) / 9.0;
+    /// v[1][3] = Vector4::new(-2.0 * R, 0.0, R, 1.0) / 3.0;
+    /// v[1][4] = Vector4::new(-2.0 * R, -4.0 * R, R, 1.0) / 9.0;
+    /// v[1][5] = Vector4::new(2.0 * R, -4.0 * R, R, 1.0) / 9.0;
+    /// v[1][6] = Vector4::new(2.0 * R, 0.0, R, 1.0) / 3.0;
+    /// v[2] = v[1].iter().map(|p| Vector4::new(p.x, p.y, -p.z, p.w)).collect();
+    /// v[3] = v[0].iter().map(|p| Vector4::new(p.x, p.y, -p.z, p.w)).collect();
+    /// let sphere = NURBSSurface::new(BSplineSurface::new((knot_vec0, knot_vec1), v));
+    ///
+    /// const N: usize = 10;
+    /// for i in 1..N {
+    ///     for j in 0..=N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert_near!(sphere.subs(u, v).to_vec().magnitude(), R);
+    ///         assert_near!(sphere.gaussian_curvature(u, v), 1.0 / (R * R));
+    ///         assert_near!(sphere.mean_curvature(u, v).abs(), 1.0 / R);
+    ///     }
+    /// }
+    /// // the poles are degenerate points.
+    /// assert_eq!(sphere.gaussian_curvature(0.0, 0.5), 0.0);
+    /// ```
+    #[inline(always)]
+    pub fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+        surface_curvatures(self, u, v).map_or(0.0, |(gaussian, _)| gaussian)
+    }
+    /// Returns the mean curvature at the parameter `(u, v)`.
+    ///
+    /// The curvature is computed by the first and second fundamental forms, and is positive
+    /// if the surface bends toward the normal `uder.cross(vder)`.
+    /// Returns `0.0` at the degenerate points, where `uder` and `vder` are parallel.
+    /// # Examples
+    /// See the example of [`gaussian_curvature`](NURBSSurface::gaussian_curvature).
+    #[inline(always)]
+    pub fn mean_curvature(&self, u: f64, v: f64) -> f64 {
+        surface_curvatures(self, u, v).map_or(0.0, |(_, mean)| mean)
+    }
+}
+
 impl ParametricSurface3D for NURBSSurface<Vector4> {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 {
@@ -0,0 +1,467 @@
+use super::*;
+use std::f64::consts::PI;
+
+impl CircleArc {
+    /// Creates an arc of the circle of `radius` around `axis` through `center`.
+    ///
+    /// The reference direction, the direction of the angle zero, is the normalized projection
+    /// of the x-axis onto the plane of the circle, or the one of the y-axis if `axis` is
+    /// nearly parallel to the x-axis.
+    #[inline(always)]
+    pub fn new(center: Point3, radius: f64, axis: Vector3, angle_range: (f64, f64)) -> CircleArc {
+        let (x_axis, _) = axis.orthonormal_basis();
+        CircleArc {
+            center,
+            radius,
+            axis: axis.normalize(),
+            x_axis,
+            angle_range,
+            parameter_range: angle_range,
+        }
+    }
+    /// Creates an arc around `axis` through `center` from the point `start` by `angle`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use std::f64::consts::PI;
+    /// let center = Point3::new(0.0, 0.0, 1.0);
+    /// let start = Point3::new(0.0, 2.0, 1.0);
+    /// let arc = CircleArc::from_start(center, start, Vector3::unit_z(), PI);
+    /// assert_near!(arc.radius(), 2.0);
+    /// assert_near!(arc.front(), start);
+    /// assert_near!(arc.subs(PI / 2.0), Point3::new(-2.0, 0.0, 1.0));
+    /// assert_near!(arc.back(), Point3::new(0.0, -2.0, 1.0));
+    /// ```
+    #[inline(always)]
+    pub fn from_start(center: Point3, start: Point3, axis: Vector3, angle: f64) -> CircleArc {
+        let axis = axis.normalize();
+        let vec = start - center;
+        let radial = vec - axis * axis.dot(vec);
+        CircleArc {
+            center,
+            radius: radial.magnitude(),
+            axis,
+            x_axis: radial.normalize(),
+            angle_range: (0.0, angle),
+            parameter_range: (0.0, angle),
+        }
+    }
+    /// Returns the center
+    #[inline(always)]
+    pub fn center(&self) -> Point3 { self.center }
+    /// Returns the radius
+    #[inline(always)]
+    pub fn radius(&self) -> f64 { self.radius }
+    /// Returns the normalized axis
+    #[inline(always)]
+    pub fn axis(&self) -> Vector3 { self.axis }
+    /// Returns the reference direction, the direction of the angle zero.
+    #[inline(always)]
+    pub fn x_axis(&self) -> Vector3 { self.x_axis }
+    /// Returns the direction of the angle `PI / 2`.
+    #[inline(always)]
+    pub fn y_axis(&self) -> Vector3 { self.axis.cross(self.x_axis) }
+    /// Returns the range of the angle
+    #[inline(always)]
+    pub fn angle_range(&self) -> (f64, f64) { self.angle_range }
+    /// Returns the angle at the parameter `t`.
+    ///
+    /// The angle is the parameter itself unless the arc is reparametrized by
+    /// [`ParameterTransform`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use std::f64::consts::PI;
+    /// let mut arc = CircleArc::new(Point3::origin(), 1.0, Vector3::unit_z(), (0.0, PI));
+    /// assert_near!(arc.angle_at(PI / 2.0), PI / 2.0);
+    /// arc.parameter_transform(1.0 / PI, 1.0);
+    /// assert_eq!(arc.parameter_range(), (1.0, 2.0));
+    /// assert_near!(arc.angle_at(1.5), PI / 2.0);
+    /// assert_near!(arc.subs(1.5), Point3::new(0.0, 1.0, 0.0));
+    /// ```
+    #[inline(always)]
+    pub fn angle_at(&self, t: f64) -> f64 {
+        self.angle_range.0 + (t - self.parameter_range.0) * self.angular_speed()
+    }
+    /// Returns the parameter at `angle`, the inverse of [`CircleArc::angle_at`].
+    #[inline(always)]
+    fn parameter_at(&self, angle: f64) -> f64 {
+        self.parameter_range.0 + (angle - self.angle_range.0) / self.angular_speed()
+    }
+    /// Returns the derivation of the angle by the parameter.
+    #[inline(always)]
+    fn angular_speed(&self) -> f64 {
+        let ((t0, t1), (a0, a1)) = (self.parameter_range, self.angle_range);
+        match t0 == t1 {
+            true => 1.0,
+            false => (a1 - a0) / (t1 - t0),
+        }
+    }
+    /// Returns the point of the circle at `angle`.
+    #[inline(always)]
+    fn point_at_angle(&self, angle: f64) -> Point3 {
+        let (x, y) = (f64::cos(angle), f64::sin(angle));
+        self.center + self.radius * (x * self.x_axis + y * self.y_axis())
+    }
+    /// Returns the arc transformed by `trans`, or `None` if the image of the circle is not
+    /// a circle, i.e. `trans` is not a similarity on the plane of the circle.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use std::f64::consts::PI;
+    /// let arc = CircleArc::new(Point3::origin(), 1.0, Vector3::unit_z(), (0.0, PI));
+    ///
+    /// let trans = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+    ///     * Matrix4::from_scale(2.0);
+    /// let moved = arc.try_transformed(trans).unwrap();
+    /// assert_near!(moved.radius(), 2.0);
+    /// assert_near!(moved.subs(PI / 2.0), Point3::new(1.0, 4.0, 3.0));
+    ///
+    /// // the circle is stretched to an ellipse
+    /// let trans = Matrix4::from_nonuniform_scale(2.0, 1.0, 1.0);
+    /// assert!(arc.try_transformed(trans).is_none());
+    /// // the stretch along the axis keeps the circle
+    /// let trans = Matrix4::from_nonuniform_scale(1.0, 1.0, 2.0);
+    /// assert!(arc.try_transformed(trans).is_some());
+    /// ```
+    pub fn try_transformed(&self, trans: Matrix4) -> Option<CircleArc> {
+        // the projective part
+        if !Vector3::new(trans.x.w, trans.y.w, trans.z.w).so_small() || trans.w.w.so_small() {
+            return None;
+        }
+        let linear = |vec: Vector3| trans.transform_vector(vec) / trans.w.w;
+        let (x_axis, y_axis) = (linear(self.x_axis), linear(self.y_axis()));
+        let scale = x_axis.magnitude();
+        let similar = !scale.so_small()
+            && (y_axis.magnitude() / scale).near(&1.0)
+            && (x_axis.dot(y_axis) / (scale * scale)).so_small();
+        if !similar {
+            return None;
+        }
+        Some(CircleArc {
+            center: trans.transform_point(self.center),
+            radius: self.radius * scale,
+            axis: x_axis.cross(y_axis).normalize(),
+            x_axis: x_axis / scale,
+            ..*self
+        })
+    }
+    /// Returns the NURBS curve of the arc.
+    ///
+    /// The curve consists of the rational quadratic Bezier curves of the angle less than
+    /// or equal to `PI / 2`, and has the same parameter range as the arc.
+    /// The parameter is not proportional to the angle inside each segment.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// use std::f64::consts::PI;
+    /// let arc = CircleArc::new(Point3::new(1.0, 2.0, 3.0), 2.0, Vector3::unit_z(), (0.0, PI));
+    /// let curve = arc.to_nurbs();
+    /// assert_eq!(curve.parameter_range(), arc.parameter_range());
+    /// assert_near!(curve.front(), arc.front());
+    /// assert_near!(curve.subs(PI / 2.0), arc.subs(PI / 2.0));
+    /// assert_near!(curve.back(), arc.back());
+    /// for i in 0..=10 {
+    ///     let t = PI * i as f64 / 10.0;
+    ///     assert_near!(curve.subs(t).distance(arc.center()), 2.0);
+    /// }
+    /// ```
+    pub fn to_nurbs(&self) -> NURBSCurve<Vector4> {
+        let ((t0, t1), (a0, a1)) = (self.parameter_range, self.angle_range);
+        let n = usize::max(f64::ceil((a1 - a0).abs() / (PI / 2.0)) as usize, 1);
+        let half = (a1 - a0) / (2 * n) as f64;
+        let weight = f64::cos(half);
+        let mut knots = vec![t0; 3];
+        let mut control_points = vec![Vector4::from_point(self.point_at_angle(a0))];
+        (0..n).for_each(|i| {
+            let angle = a0 + (2 * i + 1) as f64 * half;
+            let vec = self.point_at_angle(angle) - self.center;
+            let pt = self.center + vec / weight;
+            control_points.push(Vector4::from_point_weight(pt, weight));
+            control_points.push(Vector4::from_point(self.point_at_angle(angle + half)));
+            let r = (i + 1) as f64 / n as f64;
+            let t = t0 * (1.0 - r) + t1 * r;
+            knots.extend([t, t]);
+        });
+        knots.push(t1);
+        NURBSCurve::new(BSplineCurve::new(KnotVec::from(knots), control_points))
+    }
+    /// Returns the angle of the projection of `pt` onto the plane of the circle,
+    /// or `None` if `pt` is on the axis.
+    #[inline(always)]
+    fn angle(&self, pt: Point3) -> Option<f64> {
+        let vec = pt - self.center;
+        let (x, y) = (vec.dot(self.x_axis), vec.dot(self.y_axis()));
+        if Vector2::new(x, y).so_small() {
+            return None;
+        }
+        // the angle in the range [a0, a0 + 2PI)
+        let a0 = self.angle_range.0;
+        let theta = f64::atan2(y, x) - a0;
+        Some(a0 + theta - 2.0 * PI * f64::floor(theta / (2.0 * PI)))
+    }
+}
+
+impl ParametricCurve for CircleArc {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Point3 { self.point_at_angle(self.angle_at(t)) }
+    #[inline(always)]
+    fn der(&self, t: f64) -> Vector3 {
+        let theta = self.angle_at(t);
+        let speed = self.angular_speed();
+        speed * self.radius * (-f64::sin(theta) * self.x_axis + f64::cos(theta) * self.y_axis())
+    }
+    #[inline(always)]
+    fn der2(&self, t: f64) -> Vector3 {
+        let speed = self.angular_speed();
+        speed * speed * (self.center - self.subs(t))
+    }
+}
+
+impl BoundedCurve for CircleArc {
+    #[inline(always)]
+    fn parameter_range(&self) -> (f64, f64) { self.parameter_range }
+}
+
+impl Cut for CircleArc {
+    #[inline(always)]
+    fn cut(&mut self, t: f64) -> Self {
+        let theta = self.angle_at(t);
+        let res = CircleArc {
+            angle_range: (theta, self.angle_range.1),
+            parameter_range: (t, self.parameter_range.1),
+            ..*self
+        };
+        self.angle_range.1 = theta;
+        self.parameter_range.1 = t;
+        res
+    }
+}
+
+impl ParameterTransform for CircleArc {
+    /// # Panics
+    /// Panics if `scalar` is not positive.
+    #[inline(always)]
+    fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
+        assert!(scalar > 0.0, "the scalar must be positive: {scalar}");
+        let (t0, t1) = self.parameter_range;
+        self.parameter_range = (t0 * scalar + r#move, t1 * scalar + r#move);
+        self
+    }
+}
+
+impl Invertible for CircleArc {
+    /// The arc around the opposite axis, whose angle range and parameter range are negated.
+    #[inline(always)]
+    fn invert(&mut self) {
+        self.axis = -self.axis;
+        self.angle_range = (-self.angle_range.1, -self.angle_range.0);
+        self.parameter_range = (-self.parameter_range.1, -self.parameter_range.0);
+    }
+}
+
+impl Transformed<Matrix4> for CircleArc {
+    /// # Panics
+    /// Panics if the image of the circle is not a circle.
+    /// Use [`CircleArc::try_transformed`] for the general transformations.
+    #[inline(always)]
+    fn transform_by(&mut self, trans: Matrix4) {
+        *self = self
+            .try_transformed(trans)
+            .expect("the transformation does not map the circle to a circle");
+    }
+}
+
+impl ParameterDivision1D for CircleArc {
+    type Point = Point3;
+    fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<Point3>) {
+        nonpositive_tolerance!(tol);
+        let tol = f64::min(tol / self.radius, 0.8);
+        let delta = 2.0 * f64::acos(1.0 - tol);
+        let n = 1 + ((range.1 - range.0) * self.angular_speed() / delta) as usize;
+        let params = (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                range.0 * (1.0 - t) + range.1 * t
+            })
+            .collect::<Vec<_>>();
+        let pts = params.iter().map(|t| self.subs(*t)).collect();
+        (params, pts)
+    }
+}
+
+impl SearchNearestParameter<D1> for CircleArc {
+    type Point = Point3;
+    /// Returns the parameter of the nearest point on the arc, `None` if `pt` is on the axis.
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: Point3,
+        _: H,
+        _: usize,
+    ) -> Option<f64> {
+        let theta = self.angle(pt)?;
+        let (t0, t1) = self.parameter_range;
+        if theta <= self.angle_range.1 {
+            Some(self.parameter_at(theta))
+        } else {
+            // the nearest point is one of the ends.
+            match self.subs(t0).distance2(pt) < self.subs(t1).distance2(pt) {
+                true => Some(t0),
+                false => Some(t1),
+            }
+        }
+    }
+}
+
+impl SearchParameter<D1> for CircleArc {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint1D>>(&self, pt: Point3, _: H, _: usize) -> Option<f64> {
+        let theta = self.angle(pt)?;
+        let (t0, _) = self.parameter_range;
+        let t = match theta <= self.angle_range.1 {
+            true => self.parameter_at(theta),
+            // the front end within the tolerance
+            false if self.subs(t0).near(&pt) => t0,
+            false => return None,
+        };
+        match self.subs(t).near(&pt) {
+            true => Some(t),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+fn random_arc() -> CircleArc {
+    let center = Point3::new(
+        20.0 * rand::random::<f64>() - 10.0,
+        20.0 * rand::random::<f64>() - 10.0,
+        20.0 * rand::random::<f64>() - 10.0,
+    );
+    let axis = Vector3::new(
+        2.0 * rand::random::<f64>() - 1.0,
+        2.0 * rand::random::<f64>() - 1.0,
+        2.0 * rand::random::<f64>() - 1.0,
+    ) + Vector3::new(0.0, 0.0, 0.1);
+    let radius = 10.0 * rand::random::<f64>() + 0.1;
+    let t0 = 4.0 * PI * rand::random::<f64>() - 2.0 * PI;
+    let t1 = t0 + (2.0 * PI - 0.2) * rand::random::<f64>() + 0.1;
+    CircleArc::new(center, radius, axis, (t0, t1))
+}
+
+#[test]
+fn arc_on_circle_test() {
+    (0..10).for_each(|_| {
+        let arc = random_arc();
+        let (t0, t1) = arc.parameter_range();
+        (0..=100).for_each(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / 100.0;
+            let radius = arc.subs(t) - arc.center();
+            // on the plane of the circle
+            assert!(radius.dot(arc.axis()).so_small());
+            assert_near!(radius.magnitude(), arc.radius());
+            // the derivation is the tangent
+            let der = arc.der(t);
+            assert!(der.dot(radius).so_small());
+            assert!(der.dot(arc.axis()).so_small());
+            assert_near!(der.magnitude(), arc.radius());
+            assert_near!(arc.der2(t), -radius);
+            // compare with the finite differences
+            const EPS: f64 = 1.0e-4;
+            let diff = (arc.subs(t + EPS) - arc.subs(t - EPS)) / (2.0 * EPS);
+            assert_near!(der, diff);
+        });
+    });
+}
+
+#[test]
+fn arc_search_parameter_test() {
+    (0..10).for_each(|_| {
+        let arc = random_arc();
+        let (t0, t1) = arc.parameter_range();
+        let t = t0 + (t1 - t0) * rand::random::<f64>();
+        let pt = arc.subs(t);
+        assert_near!(arc.search_parameter(pt, None, 0).unwrap(), t);
+        let moved = pt + (pt - arc.center()) * 0.5 + arc.axis() * 1.5;
+        assert!(arc.search_parameter(moved, None, 0).is_none());
+        assert_near!(arc.search_nearest_parameter(moved, None, 0).unwrap(), t);
+
+        let mut inverse = arc;
+        inverse.invert();
+        let (s0, s1) = inverse.parameter_range();
+        assert_near!(inverse.subs(s0), arc.back());
+        assert_near!(inverse.subs(s1), arc.front());
+        assert_near!(inverse.subs(-t), pt);
+    });
+}
+
+#[test]
+fn arc_cut_transform_test() {
+    (0..10).for_each(|_| {
+        let arc = random_arc();
+        let (t0, t1) = arc.parameter_range();
+        let t = t0 + (t1 - t0) * rand::random::<f64>();
+
+        let (mut part0, mut part1) = (arc, arc);
+        let part2 = part1.cut(t);
+        part0.parameter_transform(2.0, 1.0);
+        assert_eq!(part0.parameter_range(), (2.0 * t0 + 1.0, 2.0 * t1 + 1.0));
+        assert_eq!(part1.parameter_range(), (t0, t));
+        assert_eq!(part2.parameter_range(), (t, t1));
+        (0..=10).for_each(|i| {
+            let s = t0 + (t - t0) * i as f64 / 10.0;
+            assert_near!(part1.subs(s), arc.subs(s));
+            assert_near!(part0.subs(2.0 * s + 1.0), arc.subs(s));
+            assert_near!(part0.der(2.0 * s + 1.0) * 2.0, arc.der(s));
+            assert_near!(part0.der2(2.0 * s + 1.0) * 4.0, arc.der2(s));
+            let s = t + (t1 - t) * i as f64 / 10.0;
+            assert_near!(part2.subs(s), arc.subs(s));
+        });
+        let pt = part0.subs(2.0 * t + 1.0);
+        assert_near!(part0.search_parameter(pt, None, 0).unwrap(), 2.0 * t + 1.0);
+
+        let nurbs = part0.to_nurbs();
+        assert_eq!(nurbs.parameter_range(), part0.parameter_range());
+        (0..=10).for_each(|i| {
+            let s = 2.0 * (t0 + (t1 - t0) * i as f64 / 10.0) + 1.0;
+            let pt = nurbs.subs(s);
+            assert_near!(pt.distance(arc.center()), arc.radius());
+            assert!((pt - arc.center()).dot(arc.axis()).so_small());
+        });
+        assert_near!(nurbs.front(), arc.front());
+        assert_near!(nurbs.back(), arc.back());
+
+        let trans = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+            * Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Rad(1.0))
+            * Matrix4::from_scale(3.0);
+        let moved = arc.transformed(trans);
+        assert_near!(moved.radius(), arc.radius() * 3.0);
+        assert_near!(moved.subs(t), trans.transform_point(arc.subs(t)));
+        let mirror = Matrix4::from_nonuniform_scale(-1.0, 1.0, 1.0);
+        let mirrored = arc.transformed(mirror);
+        assert_near!(mirrored.subs(t), mirror.transform_point(arc.subs(t)));
+    });
+}
+
+#[test]
+fn arc_include_test() {
+    let arc = CircleArc::new(Point3::new(1.0, 2.0, 3.0), 4.0, Vector3::unit_z(), (0.0, PI));
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 3.0),
+        Point3::new(1.0, 0.0, 3.0),
+        Point3::new(0.0, 1.0, 3.0),
+    );
+    assert!(plane.include(&arc));
+    let tilted = Plane::new(
+        Point3::new(0.0, 0.0, 3.0),
+        Point3::new(1.0, 0.0, 4.0),
+        Point3::new(0.0, 1.0, 3.0),
+    );
+    assert!(!tilted.include(&arc));
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 0.0), 5.0);
+    assert!(IncludeCurve::include(&sphere, &arc));
+    let sphere = Sphere::new(Point3::new(1.0, 0.0, 0.0), 5.0);
+    assert!(!IncludeCurve::include(&sphere, &arc));
+}
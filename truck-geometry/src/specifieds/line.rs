@@ -8,6 +8,25 @@ impl<P: Copy> Line<P> {
     where P: std::ops::Add<V, Output = P> {
         Self(origin, origin + direction)
     }
+    /// Returns the origin, the point at the parameter `0`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let origin = Point3::new(1.0, 2.0, 3.0);
+    /// let direction = Vector3::new(0.0, 1.0, 1.0);
+    /// let line = Line::from_origin_direction(origin, direction);
+    /// assert_eq!(line.origin(), origin);
+    /// assert_eq!(line.direction(), direction);
+    /// assert_eq!(line.subs(2.0), origin + 2.0 * direction);
+    /// ```
+    #[inline]
+    pub fn origin(&self) -> P { self.0 }
+    /// Returns the direction, the derivation of the line.
+    #[inline]
+    pub fn direction<V>(&self) -> V
+    where P: std::ops::Sub<P, Output = V> {
+        self.1 - self.0
+    }
     /// to a bspline curve
     #[inline]
     pub fn to_bspline(&self) -> BSplineCurve<P> {
@@ -1,6 +1,9 @@
 use crate::*;
 
 /// line
+///
+/// The segment from `self.0` to `self.1`, parametrized by `[0, 1]`: the line through
+/// the [origin](Line::origin) in the [direction](Line::direction).
 /// # Example
 /// ```
 /// use truck_geometry::*;
@@ -14,6 +17,33 @@ pub struct Line<P>(pub P, pub P);
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct UnitCircle<P>(std::marker::PhantomData<P>);
 
+/// circular arc in 3D space
+///
+/// The arc is on the circle of `radius` around `axis` through `center`, and its parameter is the
+/// angle from the reference direction perpendicular to `axis`. The parameter may be mapped
+/// linearly from the angle by [`ParameterTransform`], see [`CircleArc::angle_at`].
+/// # Examples
+/// ```
+/// use truck_geometry::*;
+/// use std::f64::consts::PI;
+///
+/// // the quarter arc on the xy-plane from (3, 2, 0) to (1, 4, 0).
+/// let center = Point3::new(1.0, 2.0, 0.0);
+/// let arc = CircleArc::new(center, 2.0, Vector3::unit_z(), (0.0, PI / 2.0));
+/// assert_near!(arc.front(), Point3::new(3.0, 2.0, 0.0));
+/// assert_near!(arc.back(), Point3::new(1.0, 4.0, 0.0));
+/// assert_near!(arc.subs(PI / 4.0).distance(arc.center()), 2.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CircleArc {
+    center: Point3,
+    radius: f64,
+    axis: Vector3,
+    x_axis: Vector3,
+    angle_range: (f64, f64),
+    parameter_range: (f64, f64),
+}
+
 /// unit hyperbola
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct UnitHyperbola<P>(std::marker::PhantomData<P>);
@@ -85,12 +115,45 @@ pub struct Sphere {
     radius: f64,
 }
 
+/// torus whose axis is the z-axis
+/// # Examples
+/// ```
+/// use truck_geometry::*;
+/// use std::f64::consts::PI;
+///
+/// let center = Point3::new(1.0, 2.0, 3.0);
+/// let torus = Torus::new(center, 3.0, 1.0);
+/// const N: usize = 100;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         // the parameter u is the angle around the z-axis
+///         let u = 2.0 * PI * i as f64 / N as f64;
+///         // the parameter v is the angle around the tube
+///         let v = 2.0 * PI * j as f64 / N as f64;
+///
+///         // the point is apart from the center circle of the tube by the minor radius.
+///         let pt = torus.subs(u, v);
+///         let n = torus.normal(u, v);
+///         let tube_center = center + 3.0 * Vector3::new(f64::cos(u), f64::sin(u), 0.0);
+///         assert_near!(pt - tube_center, n);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Torus {
+    center: Point3,
+    major_radius: f64,
+    minor_radius: f64,
+}
+
 mod circle;
+mod circle_arc;
 mod hyperbola;
 mod line;
 mod parabola;
 mod plane;
 mod sphere;
+mod torus;
 
 macro_rules! always_true {
     ($ty: tt) => {
@@ -58,6 +58,30 @@ impl Plane {
         let mat = Matrix3::from_cols(a, b, c).invert().unwrap();
         mat * (pt - self.o)
     }
+    /// Returns the parameters of the orthogonal projection of `pt` onto the plane.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::*;
+    /// let plane = Plane::new(
+    ///     Point3::new(1.0, 2.0, 3.0),
+    ///     Point3::new(2.0, 1.0, 3.0),
+    ///     Point3::new(3.0, 4.0, -1.0),
+    /// );
+    ///
+    /// // the point on the plane
+    /// let pt = plane.subs(0.3, -1.2);
+    /// let (u, v) = plane.project(pt);
+    /// assert_near!(plane.subs(u, v), pt);
+    ///
+    /// // the point apart from the plane
+    /// let (u0, v0) = plane.project(pt + 2.5 * plane.normal());
+    /// assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+    /// ```
+    #[inline(always)]
+    pub fn project(&self, pt: Point3) -> (f64, f64) {
+        let v = self.get_parameter(pt);
+        (v[0], v[1])
+    }
     /// into B-spline surface
     /// # Examples
     /// ```
@@ -197,6 +221,15 @@ impl IncludeCurve<NURBSCurve<Vector4>> for Plane {
     }
 }
 
+impl IncludeCurve<CircleArc> for Plane {
+    #[inline(always)]
+    fn include(&self, curve: &CircleArc) -> bool {
+        let normal = self.normal();
+        (curve.center() - self.origin()).dot(normal).so_small()
+            && (curve.radius().so_small() || curve.axis().cross(normal).so_small())
+    }
+}
+
 impl ParameterDivision2D for Plane {
     #[inline(always)]
     fn parameter_division(&self, range: ((f64, f64), (f64, f64)), _: f64) -> (Vec<f64>, Vec<f64>) {
@@ -247,7 +280,51 @@ impl SearchNearestParameter<D2> for Plane {
         _: H,
         _: usize,
     ) -> Option<(f64, f64)> {
-        let v = self.get_parameter(point);
-        Some((v[0], v[1]))
+        Some(self.project(point))
+    }
+}
+
+#[cfg(test)]
+fn random_plane() -> Plane {
+    let random_point = || {
+        Point3::new(
+            20.0 * rand::random::<f64>() - 10.0,
+            20.0 * rand::random::<f64>() - 10.0,
+            20.0 * rand::random::<f64>() - 10.0,
+        )
+    };
+    loop {
+        let plane = Plane::new(random_point(), random_point(), random_point());
+        let (a, b) = (plane.u_axis(), plane.v_axis());
+        // avoid the degenerate planes
+        if a.cross(b).magnitude() > 0.1 * a.magnitude() * b.magnitude() {
+            return plane;
+        }
     }
 }
+
+#[test]
+fn project_test() {
+    (0..10).for_each(|_| {
+        let plane = random_plane();
+        let (u, v) = (
+            4.0 * rand::random::<f64>() - 2.0,
+            4.0 * rand::random::<f64>() - 2.0,
+        );
+        let pt = plane.subs(u, v);
+        let (u0, v0) = plane.project(pt);
+        assert_near!(Vector2::new(u0, v0), Vector2::new(u, v));
+        assert_near!(plane.subs(u0, v0), pt);
+    })
+}
+
+#[test]
+fn normal_test() {
+    (0..10).for_each(|_| {
+        let plane = random_plane();
+        let normal = plane.u_axis().cross(plane.v_axis()).normalize();
+        assert_near!(plane.normal(), normal);
+        assert_near!(ParametricSurface3D::normal(&plane, 0.2, 0.7), normal);
+        assert_near!(plane.normal().magnitude(), 1.0);
+    })
+}
@@ -74,6 +74,25 @@ fn sphere_derivation_test() {
     }
 }
 
+#[test]
+fn sphere_finite_difference_test() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 4.56);
+    const N: usize = 50;
+    const EPS: f64 = 1.0e-4;
+    // the poles u = 0 and u = PI are omitted since the normal is not given by derivations there.
+    for i in 1..N {
+        for j in 0..N {
+            let u = PI * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            let uder = (sphere.subs(u + EPS, v) - sphere.subs(u - EPS, v)) / (2.0 * EPS);
+            let vder = (sphere.subs(u, v + EPS) - sphere.subs(u, v - EPS)) / (2.0 * EPS);
+            assert_near!(sphere.uder(u, v), uder);
+            assert_near!(sphere.vder(u, v), vder);
+            assert_near!(sphere.normal(u, v), uder.cross(vder).normalize());
+        }
+    }
+}
+
 impl BoundedSurface for Sphere {
     #[inline(always)]
     fn parameter_range(&self) -> ((f64, f64), (f64, f64)) { ((0.0, PI), (0.0, 2.0 * PI)) }
@@ -101,6 +120,15 @@ impl IncludeCurve<NURBSCurve<Vector4>> for Sphere {
     }
 }
 
+impl IncludeCurve<CircleArc> for Sphere {
+    #[inline(always)]
+    fn include(&self, curve: &CircleArc) -> bool {
+        // The circle is on the sphere iff its center is on the axis through the sphere center.
+        (curve.center() - self.center).cross(curve.axis()).so_small()
+            && self.include(curve.front())
+    }
+}
+
 impl ParameterDivision2D for Sphere {
     #[inline(always)]
     fn parameter_division(
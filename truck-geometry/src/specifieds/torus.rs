@@ -0,0 +1,252 @@
+use super::*;
+use std::f64::consts::PI;
+
+impl Torus {
+    /// Creates a torus whose axis is the z-axis through `center`.
+    #[inline(always)]
+    pub fn new(center: Point3, major_radius: f64, minor_radius: f64) -> Torus {
+        Torus {
+            center,
+            major_radius,
+            minor_radius,
+        }
+    }
+    /// Returns the center
+    #[inline(always)]
+    pub fn center(&self) -> Point3 { self.center }
+    /// Returns the major radius, the radius of the center circle of the tube.
+    #[inline(always)]
+    pub fn major_radius(&self) -> f64 { self.major_radius }
+    /// Returns the minor radius, the radius of the tube.
+    #[inline(always)]
+    pub fn minor_radius(&self) -> f64 { self.minor_radius }
+    /// Returns whether the point `pt` is on torus
+    #[inline(always)]
+    pub fn include(&self, pt: Point3) -> bool {
+        let vec = pt - self.center;
+        let rho = f64::hypot(vec.x, vec.y) - self.major_radius;
+        f64::hypot(rho, vec.z).near(&self.minor_radius)
+    }
+    /// the parameters `(u, v)` of the nearest point, `None` if `v` is not determined.
+    #[inline(always)]
+    fn angles(&self, pt: Point3) -> (f64, Option<f64>) {
+        let vec = pt - self.center;
+        let u = positive_angle(f64::atan2(vec.y, vec.x));
+        let rho = f64::hypot(vec.x, vec.y) - self.major_radius;
+        match Vector2::new(rho, vec.z).so_small() {
+            true => (u, None),
+            false => (u, Some(positive_angle(f64::atan2(vec.z, rho)))),
+        }
+    }
+}
+
+#[inline(always)]
+fn positive_angle(angle: f64) -> f64 {
+    match angle < 0.0 {
+        true => angle + 2.0 * PI,
+        false => angle,
+    }
+}
+
+impl ParametricSurface for Torus {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> Point3 {
+        let radial = Vector3::new(f64::cos(u), f64::sin(u), 0.0);
+        self.center + self.major_radius * radial + self.minor_radius * self.normal(u, v)
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> Vector3 {
+        let rho = self.major_radius + self.minor_radius * f64::cos(v);
+        rho * Vector3::new(-f64::sin(u), f64::cos(u), 0.0)
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> Vector3 {
+        self.minor_radius
+            * Vector3::new(
+                -f64::sin(v) * f64::cos(u),
+                -f64::sin(v) * f64::sin(u),
+                f64::cos(v),
+            )
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> Vector3 {
+        let rho = self.major_radius + self.minor_radius * f64::cos(v);
+        -rho * Vector3::new(f64::cos(u), f64::sin(u), 0.0)
+    }
+    #[inline(always)]
+    fn uvder(&self, u: f64, v: f64) -> Vector3 {
+        -self.minor_radius * f64::sin(v) * Vector3::new(-f64::sin(u), f64::cos(u), 0.0)
+    }
+    #[inline(always)]
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { -self.minor_radius * self.normal(u, v) }
+}
+
+impl ParametricSurface3D for Torus {
+    #[inline(always)]
+    fn normal(&self, u: f64, v: f64) -> Vector3 {
+        Vector3::new(
+            f64::cos(v) * f64::cos(u),
+            f64::cos(v) * f64::sin(u),
+            f64::sin(v),
+        )
+    }
+}
+
+#[test]
+fn torus_derivation_test() {
+    let torus = Torus::new(Point3::new(1.0, 2.0, 3.0), 4.56, 1.23);
+    const N: usize = 100;
+    for i in 0..N {
+        for j in 0..N {
+            let u = 2.0 * PI * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            let normal = torus.normal(u, v);
+            assert!(normal.dot(torus.uder(u, v)).so_small());
+            assert!(normal.dot(torus.vder(u, v)).so_small());
+        }
+    }
+}
+
+#[test]
+fn torus_finite_difference_test() {
+    let torus = Torus::new(Point3::new(1.0, 2.0, 3.0), 4.56, 1.23);
+    const N: usize = 50;
+    const EPS: f64 = 1.0e-4;
+    for i in 0..N {
+        for j in 0..N {
+            let u = 2.0 * PI * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            let uder = (torus.subs(u + EPS, v) - torus.subs(u - EPS, v)) / (2.0 * EPS);
+            let vder = (torus.subs(u, v + EPS) - torus.subs(u, v - EPS)) / (2.0 * EPS);
+            assert_near!(torus.uder(u, v), uder);
+            assert_near!(torus.vder(u, v), vder);
+            assert_near!(torus.normal(u, v), uder.cross(vder).normalize());
+            let uuder = (torus.uder(u + EPS, v) - torus.uder(u - EPS, v)) / (2.0 * EPS);
+            let uvder = (torus.uder(u, v + EPS) - torus.uder(u, v - EPS)) / (2.0 * EPS);
+            let vvder = (torus.vder(u, v + EPS) - torus.vder(u, v - EPS)) / (2.0 * EPS);
+            assert_near!(torus.uuder(u, v), uuder);
+            assert_near!(torus.uvder(u, v), uvder);
+            assert_near!(torus.vvder(u, v), vvder);
+        }
+    }
+}
+
+impl BoundedSurface for Torus {
+    #[inline(always)]
+    fn parameter_range(&self) -> ((f64, f64), (f64, f64)) { ((0.0, 2.0 * PI), (0.0, 2.0 * PI)) }
+}
+
+impl IncludeCurve<BSplineCurve<Point3>> for Torus {
+    #[inline(always)]
+    fn include(&self, curve: &BSplineCurve<Point3>) -> bool {
+        curve.is_const() && self.include(curve.front())
+    }
+}
+
+impl IncludeCurve<NURBSCurve<Vector4>> for Torus {
+    fn include(&self, curve: &NURBSCurve<Vector4>) -> bool {
+        let (knots, _) = curve.knot_vec().to_single_multi();
+        let degree = curve.degree() * 2;
+        knots
+            .windows(2)
+            .flat_map(move |window| (1..degree).map(move |i| (window, i)))
+            .all(move |(window, i)| {
+                let t = i as f64 / degree as f64;
+                let t = window[0] * (1.0 - t) + window[1] * t;
+                self.include(curve.subs(t))
+            })
+    }
+}
+
+impl ParameterDivision2D for Torus {
+    #[inline(always)]
+    fn parameter_division(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        nonpositive_tolerance!(tol);
+        assert!(
+            tol < self.minor_radius,
+            "Tolerance is larger than the minor radius of torus."
+        );
+        let outer_radius = self.major_radius + self.minor_radius;
+        let u_acos = f64::acos(1.0 - tol / outer_radius);
+        let v_acos = f64::acos(1.0 - tol / self.minor_radius);
+        let u_div: usize = 1 + ((urange.1 - urange.0) / u_acos).floor() as usize;
+        let v_div: usize = 1 + ((vrange.1 - vrange.0) / v_acos).floor() as usize;
+        (
+            (0..=u_div)
+                .map(|i| urange.0 + (urange.1 - urange.0) * i as f64 / u_div as f64)
+                .collect(),
+            (0..=v_div)
+                .map(|j| vrange.0 + (vrange.1 - vrange.0) * j as f64 / v_div as f64)
+                .collect(),
+        )
+    }
+}
+
+impl SearchParameter<D2> for Torus {
+    type Point = Point3;
+    #[inline(always)]
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        _: usize,
+    ) -> Option<(f64, f64)> {
+        if !self.include(point) {
+            return None;
+        }
+        match self.angles(point) {
+            (u, Some(v)) => Some((u, v)),
+            (u, None) => match hint.into() {
+                SPHint2D::Parameter(_, hint) => Some((u, hint)),
+                _ => Some((u, 0.0)),
+            },
+        }
+    }
+}
+
+impl SearchNearestParameter<D2> for Torus {
+    type Point = Point3;
+    #[inline(always)]
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        _: H,
+        _: usize,
+    ) -> Option<(f64, f64)> {
+        match self.angles(point) {
+            (u, Some(v)) => Some((u, v)),
+            (_, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+fn exec_search_parameter_test() {
+    let center = Point3::new(
+        100.0 * rand::random::<f64>() - 50.0,
+        100.0 * rand::random::<f64>() - 50.0,
+        100.0 * rand::random::<f64>() - 50.0,
+    );
+    let minor_radius = 10.0 * rand::random::<f64>() + 1.0;
+    let major_radius = minor_radius + 10.0 * rand::random::<f64>() + 0.1;
+    let torus = Torus::new(center, major_radius, minor_radius);
+    let u = 2.0 * PI * rand::random::<f64>();
+    let v = 2.0 * PI * rand::random::<f64>();
+    let pt = torus.subs(u, v);
+    let (u0, v0) = torus.search_parameter(pt, None, 100).unwrap();
+    assert_near!(torus.subs(u0, v0), pt);
+    let offset = (0.5 * rand::random::<f64>() + 0.01) * f64::signum(rand::random::<f64>() - 0.5);
+    let pt = pt + offset * torus.normal(u, v);
+    assert!(torus.search_parameter(pt, None, 100).is_none());
+    let (u1, v1) = torus.search_nearest_parameter(pt, None, 100).unwrap();
+    assert_near!(torus.subs(u1, v1), torus.subs(u0, v0));
+}
+
+#[test]
+fn search_parameter_test() { (0..10).for_each(|_| exec_search_parameter_test()) }
@@ -32,6 +32,8 @@ where
 }
 
 /// Searches the nearest parameter by Newton's method.
+///
+/// If the Jacobian is singular, the Newton step is damped in the manner of Levenberg-Marquardt.
 pub fn search_nearest_parameter<S>(
     surface: &S,
     point: S::Point,
@@ -62,8 +64,16 @@ where
         let fprime = Matrix2::new(a, c, c, b);
         let dermag2 = f64::min(1.0, ud.magnitude2());
         let dermag2 = f64::min(dermag2, vd.magnitude2());
-        if f.magnitude2() < TOLERANCE2 * dermag2 || fprime.determinant().so_small() {
+        if f.magnitude2() < TOLERANCE2 * dermag2 {
             return Some(hint);
+        } else if fprime.determinant().so_small() {
+            // Levenberg-Marquardt damping for the singular Jacobian
+            let mu = f.magnitude() + TOLERANCE;
+            let delta = (fprime + Matrix2::identity() * mu).invert()? * f;
+            if delta.magnitude2() < TOLERANCE2 {
+                return Some(hint);
+            }
+            hint = (Vector2::from(hint) - delta).into();
         } else {
             hint = (Vector2::from(hint) - fprime.invert()? * f).into();
         }
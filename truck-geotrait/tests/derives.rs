@@ -15,6 +15,25 @@ enum DerivedCurve {
     CurveB { polycurve: PolyCurve<Point2> },
 }
 
+// The variants wrap different types with the same associated types.
+#[allow(dead_code)]
+#[derive(Clone, Debug, ParametricCurve, BoundedCurve, ParameterDivision1D)]
+enum MixedCurve {
+    Poly(PolyCurve<Point2>),
+    Tupled(TupledCurve),
+}
+
+// The type of a variant depends on the generic parameter.
+#[allow(dead_code)]
+#[derive(Clone, Debug, ParametricCurve, BoundedCurve, ParameterDivision1D)]
+enum GenericCurve<C>
+where C: ParametricCurve<Point = Point2, Vector = Vector2>
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point2> {
+    Generic(C),
+    Poly(PolyCurve<Point2>),
+}
+
 #[allow(dead_code)]
 #[derive(Clone, Debug, ParametricSurface, BoundedSurface, ParameterDivision2D)]
 enum DeriveSurface {
@@ -213,3 +213,30 @@ fn polysurface_division() {
     let count = (0..10).filter(|_| exec_polysurface_division()).count();
     assert!(count > 8, "wrong answer: {:?}", 10 - count);
 }
+
+#[derive(Clone, Debug)]
+struct UnitCylinder;
+
+impl ParametricSurface for UnitCylinder {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u.cos(), u.sin(), v) }
+    fn uder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.sin(), u.cos(), 0.0) }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_z() }
+    fn uuder(&self, u: f64, _: f64) -> Vector3 { Vector3::new(-u.cos(), -u.sin(), 0.0) }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+}
+
+#[test]
+fn snp_singular_jacobian() {
+    // Every point of the circle at height 0.5 is nearest to the point on the axis,
+    // so the Jacobian of the orthogonality equations is singular.
+    let pt = Point3::new(0.0, 0.0, 0.5);
+    let (u, v) = algo::surface::search_nearest_parameter(&UnitCylinder, pt, (1.0, 0.0), 100)
+        .expect("not converge");
+    assert!(v.near(&0.5));
+    let diff = UnitCylinder.subs(u, v) - pt;
+    assert!(UnitCylinder.uder(u, v).dot(diff).abs() < TOLERANCE);
+    assert!(UnitCylinder.vder(u, v).dot(diff).abs() < TOLERANCE);
+}
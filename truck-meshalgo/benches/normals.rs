@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+// a wavy grid divided into 2 * N * N triangles
+fn wavy_grid(n: usize) -> PolygonMesh {
+    let positions = (0..=n)
+        .map(|i| {
+            (0..=n)
+                .map(|j| {
+                    let (x, y) = (i as f64 / n as f64, j as f64 / n as f64);
+                    Point3::new(x, y, 0.1 * f64::sin(10.0 * x) * f64::cos(10.0 * y))
+                })
+                .collect()
+        })
+        .collect();
+    let mut mesh = StructuredMesh::from_positions(positions).destruct();
+    mesh.triangulate();
+    mesh
+}
+
+fn normals(c: &mut Criterion) {
+    let mesh = wavy_grid(500);
+    c.bench_function("add_smooth_normals", |b| {
+        b.iter(|| mesh.clone().add_smooth_normals(std::f64::consts::PI / 3.0, true).normals().len())
+    });
+    c.bench_function("quadrangulate", |b| {
+        b.iter(|| mesh.clone().quadrangulate(0.01, 1.0).faces().len())
+    });
+}
+
+criterion_group!(benches, normals);
+criterion_main!(benches);
@@ -0,0 +1,19 @@
+//! Tessellate a cone modeled by `truck-modeling` and output an obj file.
+//!
+//! - Output: cone.obj
+
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+fn main() {
+    let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let v1 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let v2 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let wire: Wire = vec![builder::line(&v0, &v1), builder::line(&v1, &v2)].into();
+    let cone = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+
+    let mesh = cone.triangulation(0.01).to_polygon();
+    println!("polygon shell condition: {:?}", mesh.shell_condition());
+    let file = std::fs::File::create("cone.obj").unwrap();
+    obj::write(&mesh, file).unwrap();
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-/// Find collisions between two polygon meshes and extract interference lines.
+/// Find collisions between two polygon meshes and extract interference lines,
+/// or find self-intersections of a polygon mesh.
 ///
 /// # Details
 ///
@@ -21,6 +22,37 @@ pub trait Collision {
     /// # Remarks
     /// The results is not arranged so that included lines make continuous maximal polyline curve.
     fn extract_interference(&self, other: &PolygonMesh) -> Vec<(Point3, Point3)>;
+    /// Returns all pairs of the indices of intersecting faces.
+    ///
+    /// The indices are those of `self.faces()`, and each pair `(i, j)` satisfies `i < j`.
+    /// The candidates of the pairs are narrowed down by [`TriangleGrid`].
+    /// Adjacent faces are not regarded as intersecting, where two faces are adjacent if
+    /// they have vertices whose positions are within the distance `tol`, so the positions
+    /// do not have to be put together in advance. Pass [`TOLERANCE`] to regard only the faces
+    /// sharing a vertex or an edge as adjacent.
+    /// The faces are triangulated internally, and each pair of intersecting triangles is
+    /// reported by the indices of the faces including them.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the triangle pierces the other one
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///             Point3::new(0.5, 0.5, -1.0),
+    ///             Point3::new(0.5, 0.5, 1.0),
+    ///             Point3::new(5.0, 5.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [3, 4, 5]]),
+    /// );
+    /// assert_eq!(mesh.find_self_intersections(TOLERANCE), vec![(0, 1)]);
+    /// ```
+    fn find_self_intersections(&self, tol: f64) -> Vec<(usize, usize)>;
 }
 
 impl Collision for PolygonMesh {
@@ -32,6 +64,10 @@ impl Collision for PolygonMesh {
     fn extract_interference(&self, other: &PolygonMesh) -> Vec<(Point3, Point3)> {
         collision(self, other)
     }
+    #[inline(always)]
+    fn find_self_intersections(&self, tol: f64) -> Vec<(usize, usize)> {
+        find_self_intersections(self, tol)
+    }
 }
 
 #[repr(u8)]
@@ -289,6 +325,65 @@ fn are_colliding(poly0: &PolygonMesh, poly1: &PolygonMesh) -> Option<(Point3, Po
     })
 }
 
+fn find_self_intersections(poly: &PolygonMesh, tol: f64) -> Vec<(usize, usize)> {
+    let (positions, faces) = (poly.positions(), poly.faces());
+    // the triangles of the fans of the faces, with the indices of the faces
+    let (face_indices, tris): (Vec<usize>, Vec<[Point3; 3]>) = faces
+        .face_iter()
+        .enumerate()
+        .flat_map(|(i, face)| {
+            (2..face.len())
+                .map(move |j| (i, [face[0], face[j - 1], face[j]].map(|v| positions[v.pos])))
+        })
+        .unzip();
+    let bdb = poly.bounding_box();
+    if tris.is_empty() || bdb.is_empty() {
+        return Vec::new();
+    }
+    let cell_size = match bdb.size() / f64::cbrt(tris.len() as f64) {
+        size if size > TOLERANCE => size,
+        _ => 1.0,
+    };
+    let grid = TriangleGrid::new(tris.clone(), cell_size);
+    let (tris, face_indices) = (&tris, &face_indices);
+    let adjacent = |i: usize, j: usize| {
+        faces[i].iter().any(|v| {
+            let p = positions[v.pos];
+            faces[j]
+                .iter()
+                .any(|w| p.distance2(positions[w.pos]) <= tol * tol)
+        })
+    };
+    let margin = Vector3::new(tol, tol, tol);
+    let degenerate = |tri: &[Point3; 3]| (tri[1] - tri[0]).cross(tri[2] - tri[0]).so_small();
+    let mut res: Vec<(usize, usize)> = tris
+        .iter()
+        .enumerate()
+        .filter(|(_, tri0)| !degenerate(tri0))
+        .flat_map(|(i, tri0)| {
+            let mut bdb0: BoundingBox<Point3> = tri0.iter().collect();
+            bdb0.push(&(*bdb0.min() - margin));
+            bdb0.push(&(*bdb0.max() + margin));
+            grid.candidates(&bdb0)
+                .into_iter()
+                .filter(move |j| i < *j)
+                .filter_map(move |j| {
+                    let (tri1, fi, fj) = (tris[j], face_indices[i], face_indices[j]);
+                    let skip = fi == fj || degenerate(&tri1) || disjoint_bdbs(*tri0, tri1);
+                    if skip || adjacent(fi, fj) {
+                        None
+                    } else {
+                        collide_triangles(*tri0, tri1)
+                            .map(|_| (usize::min(fi, fj), usize::max(fi, fj)))
+                    }
+                })
+        })
+        .collect();
+    res.sort();
+    res.dedup();
+    res
+}
+
 #[test]
 fn collide_triangles_test() {
     let tri0 = [
@@ -3,12 +3,15 @@ use crate::*;
 mod collision;
 mod in_out_judge;
 mod point_cloud;
+mod silhouette;
 mod splitting;
 mod topology;
 
 pub use collision::Collision;
 pub use in_out_judge::IncludingPointInDomain;
 pub use point_cloud::WithPointCloud;
+pub use silhouette::Silhouette;
 pub use splitting::ExperimentalSplitters;
 pub use splitting::Splitting;
+pub use splitting::SurfaceKind;
 pub use topology::Topology;
@@ -0,0 +1,70 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Extracts silhouette edges for non-photorealistic rendering.
+pub trait Silhouette {
+    /// Returns the silhouette edges of the mesh seen from the direction `view_dir`.
+    ///
+    /// Each edge is a pair of the indices of positions, the smaller index first,
+    /// and the returned vector is sorted. An edge is a silhouette edge if it is
+    /// shared by a front-facing face and a back-facing face, i.e. the sign of
+    /// the inner product of the face normal and `view_dir` changes across the edge.
+    /// All boundary edges are also silhouette edges.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // two triangles folded at the edge (0, 2)
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 1.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(-1.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    /// );
+    /// // Both faces are front-facing: only the boundary edges remain.
+    /// let edges = mesh.silhouette_edges(Vector3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(edges, vec![(0, 1), (0, 3), (1, 2), (2, 3)]);
+    /// // The faces are seen from the side: the folded edge is a silhouette.
+    /// let edges = mesh.silhouette_edges(Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(edges, vec![(0, 1), (0, 2), (0, 3), (1, 2), (2, 3)]);
+    /// ```
+    fn silhouette_edges(&self, view_dir: Vector3) -> Vec<(usize, usize)>;
+}
+
+impl Silhouette for PolygonMesh {
+    fn silhouette_edges(&self, view_dir: Vector3) -> Vec<(usize, usize)> {
+        let positions = self.positions();
+        // edge -> (the number of adjacent front faces, that of back faces)
+        let mut edge_map = HashMap::<(usize, usize), (usize, usize)>::default();
+        for (i, face) in self.face_iter().enumerate() {
+            let normal = FaceNormal::new(positions, face, i).normal;
+            let front = normal.dot(view_dir) < 0.0;
+            face.windows(2)
+                .chain(std::iter::once([face[face.len() - 1], face[0]].as_ref()))
+                .filter(|v| v[0].pos != v[1].pos)
+                .for_each(|v| {
+                    let edge = match v[0].pos < v[1].pos {
+                        true => (v[0].pos, v[1].pos),
+                        false => (v[1].pos, v[0].pos),
+                    };
+                    let entry = edge_map.entry(edge).or_insert((0, 0));
+                    match front {
+                        true => entry.0 += 1,
+                        false => entry.1 += 1,
+                    }
+                })
+        }
+        let mut res: Vec<_> = edge_map
+            .into_iter()
+            .filter(|(_, (front, back))| *front + *back == 1 || (*front > 0 && *back > 0))
+            .map(|(edge, _)| edge)
+            .collect();
+        res.sort_unstable();
+        res
+    }
+}
@@ -1,9 +1,59 @@
 use super::*;
+use crate::filters::OptimizingFilter;
 use std::f64::consts::PI;
 
+/// The maximum angle between the normals of two adjacent faces in the same smooth region.
+const SMOOTH_ANGLE: f64 = PI / 6.0;
+
+/// The surface fitted to a region of a mesh by [`Splitting::classify_regions`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SurfaceKind {
+    /// plane
+    Plane {
+        /// the centroid of the vertices in the region
+        origin: Point3,
+        /// the unit normal, oriented along the face normals
+        normal: Vector3,
+    },
+    /// sphere
+    Sphere {
+        /// the center of the sphere
+        center: Point3,
+        /// the radius of the sphere
+        radius: f64,
+    },
+    /// cylinder
+    Cylinder {
+        /// a point on the axis
+        origin: Point3,
+        /// the unit direction of the axis
+        axis: Vector3,
+        /// the radius of the cylinder
+        radius: f64,
+    },
+    /// cone
+    Cone {
+        /// the apex of the cone
+        apex: Point3,
+        /// the unit direction of the axis, from the apex toward the region
+        axis: Vector3,
+        /// the angle between the axis and the generating lines
+        half_angle: f64,
+    },
+    /// none of the above primitives fits the region
+    Freeform,
+}
+
 /// Splitting the faces into several clusters.
 pub trait Splitting {
     /// Creates a sub mesh by the face indices.
+    /// # Details
+    /// `indices` are the global face indices, i.e. the indices of [`Faces::enumerate`],
+    /// which run over the triangles, the quadrangles, and the others in this order.
+    /// The indices do not depend on the kinds of the faces, so the indices returned by
+    /// the other methods of this trait can be passed directly.
+    /// # Panics
+    /// Panics if an index is not less than the number of faces.
     /// # Examples
     /// ```
     /// use truck_polymesh::*;
@@ -44,6 +94,45 @@ pub trait Splitting {
     /// assert_eq!(submesh.faces()[2], faces0[2]);
     /// ```
     fn create_mesh_by_face_indices(&self, indices: &[usize]) -> PolygonMesh;
+    /// Splits into the meshes of the connected components.
+    /// # Details
+    /// Two polygons are in the same component if they are connected by the polygons sharing
+    /// the vertices with the same position indices. Each returned mesh has only the attributes
+    /// used by its faces, and the components are sorted by their first faces.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::analyzers::*;
+    ///
+    /// // two triangles sharing a vertex, and a separated triangle
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(5.0, 0.0, 0.0),
+    ///             Point3::new(6.0, 0.0, 0.0),
+    ///             Point3::new(5.0, 1.0, 0.0),
+    ///             Point3::new(-1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, -1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2], &[3, 4, 5], &[0, 6, 7]]),
+    /// );
+    ///
+    /// let components = mesh.split_connected_components();
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[0].faces().len(), 2);
+    /// assert_eq!(components[0].positions().len(), 5);
+    /// assert_eq!(components[1].faces().len(), 1);
+    /// assert_eq!(
+    ///     components[1].positions(),
+    ///     &[Point3::new(5.0, 0.0, 0.0), Point3::new(6.0, 0.0, 0.0), Point3::new(5.0, 1.0, 0.0)],
+    /// );
+    /// ```
+    fn split_connected_components(&self) -> Vec<PolygonMesh>;
     /// Extracts polygons such that there exists its normal is the same as its face normal.
     /// # Arguments
     /// - `tol`: tolerance to be regarded as the same normal as the face normal
@@ -128,6 +217,55 @@ pub trait Splitting {
     /// assert_eq!(components.len(), 1);
     /// ```
     fn components(&self, use_normal: bool) -> Vec<Vec<usize>>;
+    /// Splits the faces into smooth regions and classifies each region into a primitive surface.
+    /// # Details
+    /// Two faces sharing an edge are in the same region if the angle between their normals
+    /// is less than 30 degrees. The vertices of each region are fitted to a plane, a sphere,
+    /// a cylinder and a cone by least squares, in this order. The first primitive is adopted
+    /// if all the vertices of the region are within the distance `tol` from the fitted surface,
+    /// and [`SurfaceKind::Freeform`] is returned if no primitive satisfies the threshold.
+    /// # Returns
+    /// The pairs of the fitted surface and the indices of faces in the region.
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::analyzers::*;
+    ///
+    /// // cube
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 1.0, 1.0),
+    ///             Point3::new(0.0, 1.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[3, 2, 1, 0], &[0, 1, 5, 4], &[1, 2, 6, 5],
+    ///         &[2, 3, 7, 6], &[3, 0, 4, 7], &[4, 5, 6, 7],
+    ///     ]),
+    /// );
+    ///
+    /// let regions = mesh.classify_regions(1.0e-3);
+    /// assert_eq!(regions.len(), 6);
+    /// match regions[0] {
+    ///     (SurfaceKind::Plane { origin, normal }, ref faces) => {
+    ///         assert!(origin.near(&Point3::new(0.5, 0.5, 0.0)));
+    ///         assert!(normal.near(&-Vector3::unit_z()));
+    ///         assert_eq!(faces, &vec![0]);
+    ///     }
+    ///     _ => panic!("the bottom is not a plane"),
+    /// }
+    /// ```
+    fn classify_regions(&self, tol: f64) -> Vec<(SurfaceKind, Vec<usize>)>;
 }
 
 impl Splitting for PolygonMesh {
@@ -146,6 +284,31 @@ impl Splitting for PolygonMesh {
         )
     }
 
+    fn split_connected_components(&self) -> Vec<PolygonMesh> {
+        let mut parents: Vec<usize> = (0..self.positions().len()).collect();
+        self.face_iter().for_each(|face| {
+            face.windows(2).for_each(|edge| union(&mut parents, edge[0].pos, edge[1].pos))
+        });
+        let mut component_indices = vec![None; parents.len()];
+        let mut components = Vec::<Vec<usize>>::new();
+        self.face_iter().enumerate().for_each(|(i, face)| {
+            let root = find(&mut parents, face[0].pos);
+            let idx = *component_indices[root].get_or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[idx].push(i);
+        });
+        components
+            .iter()
+            .map(|faces| {
+                let mut mesh = self.create_mesh_by_face_indices(faces);
+                mesh.remove_unused_attrs();
+                mesh
+            })
+            .collect()
+    }
+
     fn extract_planes(&self, tol: f64) -> (Vec<usize>, Vec<usize>) {
         nonpositive_tolerance!(tol, 0.0);
         self.faces_into_two_clusters(|face: &[Vertex]| {
@@ -157,6 +320,37 @@ impl Splitting for PolygonMesh {
         let face_adjacency = self.faces().face_adjacency(use_normal);
         get_components(&face_adjacency)
     }
+
+    fn classify_regions(&self, tol: f64) -> Vec<(SurfaceKind, Vec<usize>)> {
+        nonpositive_tolerance!(tol);
+        let positions = self.positions();
+        let faces: Vec<&[Vertex]> = self.face_iter().collect();
+        let normals: Vec<Vector3> = faces
+            .iter()
+            .enumerate()
+            .map(|(i, face)| FaceNormal::new(positions, face, i).normal)
+            .collect();
+        let adjacency: Vec<Vec<usize>> = self
+            .faces()
+            .face_adjacency(false)
+            .into_iter()
+            .enumerate()
+            .map(|(i, adjacent)| {
+                adjacent
+                    .into_iter()
+                    .filter(|j| normals[i].angle(normals[*j]).0 < SMOOTH_ANGLE)
+                    .collect()
+            })
+            .collect();
+        get_components(&adjacency)
+            .into_iter()
+            .map(|mut region| {
+                region.sort_unstable();
+                let samples = RegionSamples::new(positions, &faces, &region);
+                (samples.classify(tol), region)
+            })
+            .collect()
+    }
 }
 
 #[doc(hidden)]
@@ -269,6 +463,217 @@ fn get_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
     }
 }
 
+/// the root of `i` in the union-find forest `parents`, with the path halving
+fn find(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// merges the trees including `i` and `j` in the union-find forest `parents`
+fn union(parents: &mut [usize], i: usize, j: usize) {
+    let (root0, root1) = (find(parents, i), find(parents, j));
+    parents[usize::max(root0, root1)] = usize::min(root0, root1);
+}
+
+/// vertices and face normals of a region, the input of the least-squares fittings
+struct RegionSamples {
+    points: Vec<Point3>,
+    centroid: Point3,
+    // pairs of the center and the normal of faces, the length of normal is the area.
+    faces: Vec<(Point3, Vector3)>,
+}
+
+impl RegionSamples {
+    fn new(positions: &[Point3], faces: &[&[Vertex]], region: &[usize]) -> Self {
+        let mut indices: Vec<usize> = region
+            .iter()
+            .flat_map(|i| faces[*i].iter().map(|v| v.pos))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let points: Vec<Point3> = indices.into_iter().map(|i| positions[i]).collect();
+        let centroid = Point3::centroid(&points);
+        let faces = region
+            .iter()
+            .map(|i| {
+                let face = faces[*i];
+                let pts: Vec<Point3> = face.iter().map(|v| positions[v.pos]).collect();
+                let center = Point3::centroid(&pts);
+                let normal = pts
+                    .iter()
+                    .zip(pts.iter().cycle().skip(1))
+                    .fold(Vector3::zero(), |sum, (p, q)| sum + (p - center).cross(q - center));
+                (center, normal / 2.0)
+            })
+            .collect();
+        Self {
+            points,
+            centroid,
+            faces,
+        }
+    }
+
+    fn classify(&self, tol: f64) -> SurfaceKind {
+        let axis = self.axis();
+        let candidates = [
+            self.fit_plane(),
+            self.fit_sphere(),
+            axis.and_then(|axis| self.fit_cylinder(axis)),
+            axis.and_then(|axis| self.fit_cone(axis)),
+        ];
+        candidates
+            .into_iter()
+            .flatten()
+            .find(|(_, residual)| *residual <= tol)
+            .map(|(kind, _)| kind)
+            .unwrap_or(SurfaceKind::Freeform)
+    }
+
+    fn fit_plane(&self) -> Option<(SurfaceKind, f64)> {
+        let c = self.centroid;
+        let covariance = self.points.iter().fold(Matrix3::zero(), |sum, p| {
+            let v = p - c;
+            sum + outer_product3(v)
+        });
+        let mut normal = smallest_eigenvector(covariance);
+        let orientation = self.faces.iter().fold(Vector3::zero(), |sum, (_, n)| sum + n);
+        if normal.dot(orientation) < 0.0 {
+            normal = -normal;
+        }
+        let residual = self.max_residual(|p| normal.dot(p - c).abs());
+        Some((SurfaceKind::Plane { origin: c, normal }, residual))
+    }
+
+    fn fit_sphere(&self) -> Option<(SurfaceKind, f64)> {
+        // |p - c|^2 = r^2 <=> 2 p.c + (r^2 - |c|^2) = |p|^2, linear in c and r^2 - |c|^2.
+        let (mat, vec) = self.points.iter().fold(
+            (Matrix4::zero(), Vector4::zero()),
+            |(mat, vec), p| {
+                let v = p - self.centroid;
+                let row = Vector4::new(2.0 * v.x, 2.0 * v.y, 2.0 * v.z, 1.0);
+                (mat + outer_product4(row), vec + row * v.magnitude2())
+            },
+        );
+        let sol = mat.invert()? * vec;
+        let center = self.centroid + sol.truncate();
+        let radius = f64::sqrt(sol.w + sol.truncate().magnitude2());
+        let residual = self.max_residual(|p| (p.distance(center) - radius).abs());
+        Some((SurfaceKind::Sphere { center, radius }, residual))
+    }
+
+    fn fit_cylinder(&self, axis: Vector3) -> Option<(SurfaceKind, f64)> {
+        let (u, w) = axis.orthonormal_basis();
+        // circle fitting in the plane perpendicular to the axis
+        let (mat, vec) = self.points.iter().fold(
+            (Matrix3::zero(), Vector3::zero()),
+            |(mat, vec), p| {
+                let v = p - self.centroid;
+                let (x, y) = (v.dot(u), v.dot(w));
+                let row = Vector3::new(2.0 * x, 2.0 * y, 1.0);
+                (mat + outer_product3(row), vec + row * (x * x + y * y))
+            },
+        );
+        let sol = mat.invert()? * vec;
+        let origin = self.centroid + u * sol.x + w * sol.y;
+        let radius = f64::sqrt(sol.z + sol.x * sol.x + sol.y * sol.y);
+        let residual = self.max_residual(|p| {
+            let v = p - origin;
+            ((v - axis * v.dot(axis)).magnitude() - radius).abs()
+        });
+        Some((
+            SurfaceKind::Cylinder {
+                origin,
+                axis,
+                radius,
+            },
+            residual,
+        ))
+    }
+
+    fn fit_cone(&self, axis: Vector3) -> Option<(SurfaceKind, f64)> {
+        // The apex is the nearest point to the tangent planes of all faces.
+        let (mat, vec) = self.faces.iter().fold(
+            (Matrix3::zero(), Vector3::zero()),
+            |(mat, vec), (center, normal)| {
+                let n = normal.normalize();
+                let v = center - self.centroid;
+                (mat + outer_product3(n), vec + n * n.dot(v))
+            },
+        );
+        let apex = self.centroid + mat.invert()? * vec;
+        let axis = match self.points.iter().map(|p| (p - apex).dot(axis)).sum::<f64>() < 0.0 {
+            true => -axis,
+            false => axis,
+        };
+        let angles: Vec<f64> = self
+            .points
+            .iter()
+            .map(|p| p - apex)
+            .filter(|v| !v.so_small())
+            .map(|v| v.angle(axis).0)
+            .collect();
+        if angles.is_empty() {
+            return None;
+        }
+        let half_angle = angles.iter().sum::<f64>() / angles.len() as f64;
+        let (sin, cos) = half_angle.sin_cos();
+        let residual = self.max_residual(|p| {
+            let v = p - apex;
+            let h = v.dot(axis);
+            ((v - axis * h).magnitude() * cos - h * sin).abs()
+        });
+        Some((
+            SurfaceKind::Cone {
+                apex,
+                axis,
+                half_angle,
+            },
+            residual,
+        ))
+    }
+
+    /// The direction of axis of a surface of revolution: the normals of a cylinder are
+    /// perpendicular to the axis, and those of a cone have the constant angle with the axis.
+    fn axis(&self) -> Option<Vector3> {
+        let area = self.faces.iter().map(|(_, n)| n.magnitude()).sum::<f64>();
+        if area.so_small() {
+            return None;
+        }
+        let mean = self.faces.iter().fold(Vector3::zero(), |sum, (_, n)| sum + n) / area;
+        let covariance = self.faces.iter().fold(Matrix3::zero(), |sum, (_, n)| {
+            let a = n.magnitude();
+            let v = n / a - mean;
+            sum + outer_product3(v) * a
+        });
+        Some(smallest_eigenvector(covariance))
+    }
+
+    fn max_residual(&self, distance: impl Fn(Point3) -> f64) -> f64 {
+        self.points.iter().fold(0.0, |max, p| f64::max(max, distance(*p)))
+    }
+}
+
+#[inline(always)]
+fn outer_product3(v: Vector3) -> Matrix3 { Matrix3::from_cols(v * v.x, v * v.y, v * v.z) }
+
+#[inline(always)]
+fn outer_product4(v: Vector4) -> Matrix4 {
+    Matrix4::from_cols(v * v.x, v * v.y, v * v.z, v * v.w)
+}
+
+/// Returns the unit eigenvector of the smallest eigenvalue of the symmetric matrix.
+fn smallest_eigenvector(mat: Matrix3) -> Vector3 {
+    let eigenvectors = symmetric_eigenvectors(mat.into());
+    let eigenvalue = |v: &Vector3| v.dot(mat * v);
+    (0..3)
+        .map(|i| eigenvectors[i])
+        .min_by(|u, v| eigenvalue(u).total_cmp(&eigenvalue(v)))
+        .unwrap()
+}
+
 fn is_in_the_plane(positions: &[Point3], normals: &[Vector3], face: &[Vertex], tol2: f64) -> bool {
     let n = FaceNormal::new(positions, face, 0).normal;
     for v in face {
@@ -0,0 +1,302 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// the weight of the quadrics keeping the boundary edges
+const BOUNDARY_PENALTY: f64 = 1.0e3;
+
+/// mesh simplification algorithms
+pub trait Decimation {
+    /// Simplifies the mesh by the edge collapses of Garland–Heckbert quadric error metrics.
+    ///
+    /// The faces are triangulated first, and the edges are collapsed in ascending order of the
+    /// errors until the number of triangles reaches `target_faces` or no valid collapse remains.
+    /// A collapse is invalid if it makes a non-manifold edge or flips a triangle. The boundary
+    /// edges are kept by the quadrics with high penalty.
+    ///
+    /// # Remarks
+    /// The vertices which are merged into another keep their texture coordinates and normals,
+    /// so it is recommended to recompute normals by [`NormalFilters`] after decimation.
+    /// The unused attributes are removed.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a flat square divided into 4 x 4 quadrangles
+    /// let positions = (0..25)
+    ///     .map(|i| Point3::new((i / 5) as f64, (i % 5) as f64, 0.0))
+    ///     .collect();
+    /// let faces: Vec<[usize; 4]> = (0..16)
+    ///     .map(|i| {
+    ///         let k = i / 4 * 5 + i % 4;
+    ///         [k, k + 5, k + 6, k + 1]
+    ///     })
+    ///     .collect();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&faces),
+    /// );
+    /// mesh.decimate(8);
+    /// assert!(mesh.faces().len() <= 8);
+    /// // the boundary is kept.
+    /// let bdb = mesh.bounding_box();
+    /// assert!(bdb.min().near(&Point3::new(0.0, 0.0, 0.0)));
+    /// assert!(bdb.max().near(&Point3::new(4.0, 4.0, 0.0)));
+    /// ```
+    fn decimate(&mut self, target_faces: usize) -> &mut Self;
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    cost: f64,
+    edge: [usize; 2],
+    versions: [usize; 2],
+    position: Point3,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Collapse {
+    // reversed order for the min-heap
+    fn cmp(&self, other: &Self) -> Ordering { other.cost.total_cmp(&self.cost) }
+}
+
+#[derive(Debug)]
+struct Decimator {
+    positions: Vec<Point3>,
+    quadrics: Vec<Matrix4>,
+    versions: Vec<usize>,
+    triangles: Vec<[Vertex; 3]>,
+    alive: Vec<bool>,
+    vertex_faces: Vec<Vec<usize>>,
+    num_alive: usize,
+}
+
+fn outer_product4(v: Vector4) -> Matrix4 { Matrix4::from_cols(v * v.x, v * v.y, v * v.z, v * v.w) }
+
+fn quadric_error(quadric: Matrix4, p: Point3) -> f64 {
+    let v = p.to_homogeneous();
+    v.dot(quadric * v)
+}
+
+fn triangle_normal(p: [Point3; 3]) -> Vector3 { (p[1] - p[0]).cross(p[2] - p[0]) }
+
+impl Decimator {
+    fn new(positions: Vec<Point3>, triangles: Vec<[Vertex; 3]>) -> Self {
+        let mut quadrics = vec![Matrix4::zero(); positions.len()];
+        let mut vertex_faces = vec![Vec::new(); positions.len()];
+        let mut edge_faces = HashMap::<[usize; 2], Vec<usize>>::default();
+        triangles.iter().enumerate().for_each(|(i, tri)| {
+            let p = tri.map(|v| positions[v.pos]);
+            let normal = triangle_normal(p);
+            if normal.so_small() {
+                return;
+            }
+            let normal = normal.normalize();
+            let plane = outer_product4(normal.extend(-normal.dot(p[0].to_vec())));
+            tri.iter().for_each(|v| {
+                quadrics[v.pos] += plane;
+                vertex_faces[v.pos].push(i);
+            });
+            (0..3).for_each(|j| {
+                let (a, b) = (tri[j].pos, tri[(j + 1) % 3].pos);
+                let edge = [usize::min(a, b), usize::max(a, b)];
+                edge_faces.entry(edge).or_default().push(i);
+            });
+        });
+        edge_faces
+            .into_iter()
+            .filter(|(_, faces)| faces.len() == 1)
+            .for_each(|([a, b], faces)| {
+                let tri = triangles[faces[0]];
+                let normal = triangle_normal(tri.map(|v| positions[v.pos])).normalize();
+                let side = (positions[b] - positions[a]).cross(normal);
+                if side.so_small() {
+                    return;
+                }
+                let side = side.normalize();
+                let plane = side.extend(-side.dot(positions[a].to_vec()));
+                let penalty = outer_product4(plane) * BOUNDARY_PENALTY;
+                quadrics[a] += penalty;
+                quadrics[b] += penalty;
+            });
+        let alive: Vec<bool> = vertex_faces
+            .iter()
+            .fold(vec![false; triangles.len()], |mut alive, faces| {
+                faces.iter().for_each(|i| alive[*i] = true);
+                alive
+            });
+        Self {
+            versions: vec![0; positions.len()],
+            num_alive: alive.iter().filter(|a| **a).count(),
+            positions,
+            quadrics,
+            triangles,
+            alive,
+            vertex_faces,
+        }
+    }
+
+    fn alive_faces(&self, pos: usize) -> impl Iterator<Item = usize> + '_ {
+        self.vertex_faces[pos]
+            .iter()
+            .copied()
+            .filter(move |i| self.alive[*i])
+    }
+
+    fn neighbors(&self, pos: usize) -> Vec<usize> {
+        let mut neighbors: Vec<usize> = self
+            .alive_faces(pos)
+            .flat_map(|i| self.triangles[i])
+            .map(|v| v.pos)
+            .filter(|p| *p != pos)
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    fn collapse(&self, a: usize, b: usize) -> Collapse {
+        let quadric = self.quadrics[a] + self.quadrics[b];
+        let (pa, pb) = (self.positions[a], self.positions[b]);
+        let mut candidates = vec![pa, pb, pa.midpoint(pb)];
+        let mat = Matrix3::from_cols(
+            quadric.x.truncate(),
+            quadric.y.truncate(),
+            quadric.z.truncate(),
+        );
+        if let Some(inv) = mat.invert() {
+            let p = Point3::from_vec(inv * -quadric.w.truncate());
+            if p.distance2(pa.midpoint(pb)) <= pa.distance2(pb) {
+                candidates.push(p);
+            }
+        }
+        let (cost, position) = candidates
+            .into_iter()
+            .map(|p| (quadric_error(quadric, p), p))
+            .min_by(|x, y| x.0.total_cmp(&y.0))
+            .unwrap();
+        Collapse {
+            cost,
+            edge: [a, b],
+            versions: [self.versions[a], self.versions[b]],
+            position,
+        }
+    }
+
+    fn is_valid(&self, collapse: &Collapse) -> bool {
+        let [a, b] = collapse.edge;
+        let shared = self
+            .alive_faces(a)
+            .filter(|i| self.triangles[*i].iter().any(|v| v.pos == b))
+            .count();
+        let neighbors_a = self.neighbors(a);
+        let common = self
+            .neighbors(b)
+            .into_iter()
+            .filter(|p| neighbors_a.binary_search(p).is_ok())
+            .count();
+        if shared == 0 || common != shared {
+            return false;
+        }
+        self.alive_faces(a).chain(self.alive_faces(b)).all(|i| {
+            let tri = self.triangles[i];
+            if tri.iter().any(|v| v.pos == a) && tri.iter().any(|v| v.pos == b) {
+                return true;
+            }
+            let before = tri.map(|v| self.positions[v.pos]);
+            let after = tri.map(|v| match v.pos == a || v.pos == b {
+                true => collapse.position,
+                false => self.positions[v.pos],
+            });
+            triangle_normal(before).dot(triangle_normal(after)) > 0.0
+        })
+    }
+
+    fn apply(&mut self, collapse: &Collapse) {
+        let [a, b] = collapse.edge;
+        self.positions[a] = collapse.position;
+        self.quadrics[a] = self.quadrics[a] + self.quadrics[b];
+        self.versions[a] += 1;
+        self.versions[b] += 1;
+        let faces_b = std::mem::take(&mut self.vertex_faces[b]);
+        for i in faces_b {
+            if !self.alive[i] {
+                continue;
+            }
+            let tri = &mut self.triangles[i];
+            if tri.iter().any(|v| v.pos == a) {
+                self.alive[i] = false;
+                self.num_alive -= 1;
+            } else {
+                tri.iter_mut()
+                    .filter(|v| v.pos == b)
+                    .for_each(|v| v.pos = a);
+                self.vertex_faces[a].push(i);
+            }
+        }
+    }
+
+    fn decimate(&mut self, target_faces: usize) {
+        let mut heap = BinaryHeap::new();
+        (0..self.positions.len()).for_each(|a| {
+            self.neighbors(a)
+                .into_iter()
+                .filter(|b| a < *b)
+                .for_each(|b| heap.push(self.collapse(a, b)));
+        });
+        while self.num_alive > target_faces {
+            let collapse = match heap.pop() {
+                Some(collapse) => collapse,
+                None => break,
+            };
+            let [a, b] = collapse.edge;
+            if collapse.versions != [self.versions[a], self.versions[b]] {
+                continue;
+            }
+            if !self.is_valid(&collapse) {
+                continue;
+            }
+            self.apply(&collapse);
+            self.neighbors(a)
+                .into_iter()
+                .for_each(|c| heap.push(self.collapse(a, c)));
+        }
+    }
+}
+
+impl Decimation for PolygonMesh {
+    fn decimate(&mut self, target_faces: usize) -> &mut Self {
+        let triangles: Vec<[Vertex; 3]> = self.faces().triangle_iter().collect();
+        let mut decimator = Decimator::new(self.positions().clone(), triangles);
+        decimator.decimate(target_faces);
+        let Decimator {
+            positions,
+            triangles,
+            alive,
+            ..
+        } = decimator;
+        let tri_faces = triangles
+            .into_iter()
+            .zip(alive)
+            .filter_map(|(tri, alive)| alive.then_some(tri))
+            .collect();
+        {
+            let editor = self.debug_editor();
+            editor.attributes.positions = positions;
+            *editor.faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
+        }
+        self.remove_unused_attrs()
+    }
+}
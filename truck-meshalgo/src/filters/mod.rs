@@ -1,11 +1,19 @@
 use crate::*;
 
+mod decimation;
 mod normal_filters;
 mod optimizing;
+mod orientation;
+mod smoothing;
 mod structuring;
 mod subdivision;
+mod uv_filters;
 
-pub use normal_filters::NormalFilters;
+pub use decimation::Decimation;
+pub use normal_filters::{NormalFilters, NormalWeighting};
 pub use optimizing::OptimizingFilter;
+pub use orientation::OrientingFilter;
+pub use smoothing::Smoothing;
 pub use structuring::StructuringFilter;
 pub use subdivision::Subdivision;
+pub use uv_filters::UVFilters;
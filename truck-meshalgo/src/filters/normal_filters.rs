@@ -1,5 +1,18 @@
 use super::*;
-use rustc_hash::FxHashMap as HashMap;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The weights of the face normals averaged into a vertex normal,
+/// cf: [`NormalFilters::recompute_normals`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormalWeighting {
+    /// All faces around the vertex have the same weight.
+    Uniform,
+    /// Each face is weighted by its area.
+    ByArea,
+    /// Each face is weighted by its interior angle at the vertex.
+    ByAngle,
+}
 
 /// Filters for adding normals
 pub trait NormalFilters {
@@ -85,6 +98,8 @@ pub trait NormalFilters {
     /// # Arguments
     /// - If `overwrite == true`, clear all normals and update all normals in vertices.
     /// - If `overwrite == false`, add normals only for `nor` is `None`.
+    /// # Remarks
+    /// With the feature `rayon`, the face normals and the clusters are computed in parallel.
     /// # Examples
     /// Compare with the examples of [`add_smooth_normals`](./trait.NormalFilters.html#tymethod.add_smooth_normals).
     /// ```
@@ -184,6 +199,61 @@ pub trait NormalFilters {
     /// assert_eq!(polymesh.normals()[nor], Vector3::new(0.0, 0.0, 1.0));
     /// ```
     fn make_normal_compatible_to_face(&mut self) -> &mut Self;
+    /// Negates all normals and reverses the orientations of all faces.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut polymesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         normals: vec![Vector3::new(0.0, 0.0, 1.0)],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+    /// );
+    /// polymesh.flip_normals();
+    /// assert_eq!(polymesh.normals()[0], Vector3::new(0.0, 0.0, -1.0));
+    /// let face: Vec<usize> = polymesh.faces()[0].iter().map(|v| v.pos).collect();
+    /// assert_eq!(face, vec![2, 1, 0]);
+    /// ```
+    fn flip_normals(&mut self) -> &mut Self;
+    /// Overwrites all normals by the averages of the face normals around the positions.
+    /// # Details
+    /// One normal is created for each position used by the faces. The normals of the faces
+    /// containing the position are weighted by `weighting`, summed up, and normalized.
+    /// Unlike [`add_smooth_normals`](./trait.NormalFilters.html#tymethod.add_smooth_normals),
+    /// the faces are not clustered, so the sharp edges are also smoothed.
+    /// The `nor` of the vertex is `None` if all faces around the position are degenerate.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(-5.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 2.0, -2.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 2.0),
+    ///             Point3::new(5.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[0, 2, 1], &[0, 3, 2], &[1, 2, 4], &[2, 3, 4],
+    ///     ]),
+    /// );
+    ///
+    /// mesh.recompute_normals(NormalWeighting::ByArea);
+    /// assert_eq!(mesh.normals().len(), 5);
+    /// let v: StandardVertex = mesh.faces()[0][1];
+    /// assert!(mesh.normals()[v.nor.unwrap()].near(&Vector3::new(0.0, 1.0, 0.0)));
+    /// ```
+    fn recompute_normals(&mut self, weighting: NormalWeighting) -> &mut Self;
 }
 
 impl NormalFilters for PolygonMesh {
@@ -286,32 +356,121 @@ impl NormalFilters for PolygonMesh {
         self.reflect_normal_clusters(vnmap, overwrite);
         self
     }
+    fn flip_normals(&mut self) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { normals, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        normals.iter_mut().for_each(|normal| *normal = -*normal);
+        faces.face_iter_mut().for_each(|face| face.reverse());
+        drop(mesh);
+        self
+    }
+    fn recompute_normals(&mut self, weighting: NormalWeighting) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes {
+                positions, normals, ..
+            },
+            faces,
+            ..
+        } = &mut mesh;
+        let mut sums = vec![None; positions.len()];
+        faces.face_iter().for_each(|face| {
+            let area_normal = area_normal(positions, face);
+            face.iter().enumerate().for_each(|(i, v)| {
+                let weight = match weighting {
+                    NormalWeighting::Uniform => 1.0,
+                    NormalWeighting::ByArea => area_normal.magnitude(),
+                    NormalWeighting::ByAngle => interior_angle(positions, face, i),
+                };
+                let normal = area_normal.normalize() * weight;
+                let sum = sums[v.pos].get_or_insert_with(Vector3::zero);
+                if normal.x.is_finite() && normal.y.is_finite() && normal.z.is_finite() {
+                    *sum += normal;
+                }
+            });
+        });
+        normals.clear();
+        let indices = sums
+            .into_iter()
+            .map(|sum| {
+                sum.filter(|sum| !sum.so_small()).map(|sum| {
+                    normals.push(sum.normalize());
+                    normals.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+        faces
+            .face_iter_mut()
+            .flatten()
+            .for_each(|v| v.nor = indices[v.pos]);
+        drop(mesh);
+        self
+    }
+}
+
+/// the normal of `face` whose magnitude is twice the area of `face`
+fn area_normal(positions: &[Point3], face: &[Vertex]) -> Vector3 {
+    let p0 = positions[face[0].pos];
+    face.windows(2).skip(1).fold(Vector3::zero(), |sum, v| {
+        sum + (positions[v[0].pos] - p0).cross(positions[v[1].pos] - p0)
+    })
+}
+
+/// the interior angle of `face` at the `i`-th vertex
+fn interior_angle(positions: &[Point3], face: &[Vertex], i: usize) -> f64 {
+    let len = face.len();
+    let p = positions[face[i].pos];
+    let prev = positions[face[(i + len - 1) % len].pos] - p;
+    let next = positions[face[(i + 1) % len].pos] - p;
+    match prev.so_small() || next.so_small() {
+        true => 0.0,
+        false => next.angle(prev).0,
+    }
 }
 
 trait SubNormalFilter {
-    fn clustering_noraml_faces(&self, inf: f64) -> HashMap<usize, Vec<Vec<FaceNormal>>>;
-    fn reflect_normal_clusters(
-        &mut self,
-        vnmap: HashMap<usize, Vec<Vec<FaceNormal>>>,
-        overwrite: bool,
-    );
+    fn clustering_noraml_faces(&self, inf: f64) -> Vec<Vec<NormalCluster>>;
+    fn reflect_normal_clusters(&mut self, vnmap: Vec<Vec<NormalCluster>>, overwrite: bool);
+}
+
+/// the averaged normal and the ids of faces of a cluster of face normals around a vertex
+struct NormalCluster {
+    normal: Vector3,
+    face_ids: Vec<usize>,
 }
 
 impl SubNormalFilter for PolygonMesh {
-    fn clustering_noraml_faces(&self, inf: f64) -> HashMap<usize, Vec<Vec<FaceNormal>>> {
+    fn clustering_noraml_faces(&self, inf: f64) -> Vec<Vec<NormalCluster>> {
         let positions = self.positions();
-        let mut vnmap = HashMap::default();
-        self.face_iter()
+        let faces: Vec<&[Vertex]> = self.face_iter().collect();
+        let mut vertex_faces = vec![Vec::new(); positions.len()];
+        faces.iter().enumerate().for_each(|(i, face)| {
+            face.iter().for_each(|v| vertex_faces[v.pos].push(i));
+        });
+
+        #[cfg(not(feature = "rayon"))]
+        let faces_iter = faces.iter();
+        #[cfg(feature = "rayon")]
+        let faces_iter = faces.par_iter();
+        let face_normals: Vec<FaceNormal> = faces_iter
             .enumerate()
-            .for_each(|(i, face)| add_face_normal(positions, i, face, &mut vnmap, inf));
-        vnmap
+            .map(|(i, face)| FaceNormal::new(positions, face, i))
+            .collect();
+
+        #[cfg(not(feature = "rayon"))]
+        let vertex_faces_iter = vertex_faces.iter();
+        #[cfg(feature = "rayon")]
+        let vertex_faces_iter = vertex_faces.par_iter();
+        vertex_faces_iter
+            .map(|face_ids| clustering_face_normals(face_ids, &face_normals, inf))
+            .collect()
     }
 
-    fn reflect_normal_clusters(
-        &mut self,
-        vnmap: HashMap<usize, Vec<Vec<FaceNormal>>>,
-        overwrite: bool,
-    ) {
+    fn reflect_normal_clusters(&mut self, vnmap: Vec<Vec<NormalCluster>>, overwrite: bool) {
         let mut mesh = self.debug_editor();
         let PolygonMeshEditor {
             attributes: StandardAttributes { normals, .. },
@@ -321,13 +480,9 @@ impl SubNormalFilter for PolygonMesh {
         if overwrite {
             normals.clear();
         }
-        for (pos_id, vecs) in vnmap.into_iter() {
-            for vec in vecs {
-                let normal = vec
-                    .iter()
-                    .fold(Vector3::zero(), |sum, x| sum + x.normal)
-                    .normalize();
-                for FaceNormal { face_id, .. } in vec {
+        for (pos_id, clusters) in vnmap.into_iter().enumerate() {
+            for NormalCluster { normal, face_ids } in clusters {
+                for face_id in face_ids {
                     signup_vertex_normal(pos_id, face_id, normals, normal, faces, overwrite);
                 }
             }
@@ -335,44 +490,36 @@ impl SubNormalFilter for PolygonMesh {
     }
 }
 
-fn add_face_normal(
-    positions: &[Point3],
-    face_id: usize,
-    face: &[Vertex],
-    vnmap: &mut HashMap<usize, Vec<Vec<FaceNormal>>>,
+fn clustering_face_normals(
+    face_ids: &[usize],
+    face_normals: &[FaceNormal],
     inf: f64,
-) {
-    let face_normal = FaceNormal::new(positions, face, face_id);
-    face.iter().for_each(|v| {
-        add_to_vnmap(v.pos, face_normal, vnmap, inf);
-    })
-}
-
-fn add_to_vnmap(
-    pos_id: usize,
-    face_normal: FaceNormal,
-    vnmap: &mut HashMap<usize, Vec<Vec<FaceNormal>>>,
-    inf: f64,
-) {
-    match vnmap.get_mut(&pos_id) {
-        Some(vecs) => {
-            for vec in vecs.iter_mut() {
-                let normal = vec
-                    .iter()
-                    .fold(Vector3::zero(), |sum, x| sum + x.normal)
-                    .normalize();
-                if face_normal.normal.dot(normal) > inf {
-                    vec.push(face_normal);
-                    return;
-                }
-            }
-            vecs.push(vec![face_normal]);
-        }
-        None => {
-            let vecs = vec![vec![face_normal]];
-            vnmap.insert(pos_id, vecs);
+) -> Vec<NormalCluster> {
+    let mut clusters = Vec::<Vec<FaceNormal>>::new();
+    face_ids.iter().for_each(|i| {
+        let face_normal = face_normals[*i];
+        let cluster = clusters.iter_mut().find(|vec| {
+            let normal = vec
+                .iter()
+                .fold(Vector3::zero(), |sum, x| sum + x.normal)
+                .normalize();
+            face_normal.normal.dot(normal) > inf
+        });
+        match cluster {
+            Some(vec) => vec.push(face_normal),
+            None => clusters.push(vec![face_normal]),
         }
-    }
+    });
+    clusters
+        .into_iter()
+        .map(|vec| NormalCluster {
+            normal: vec
+                .iter()
+                .fold(Vector3::zero(), |sum, x| sum + x.normal)
+                .normalize(),
+            face_ids: vec.into_iter().map(|x| x.face_id).collect(),
+        })
+        .collect()
 }
 
 fn signup_vertex_normal(
@@ -94,6 +94,79 @@ pub trait OptimizingFilter {
     /// assert_eq!(mesh.positions().len(), 4);
     /// ```
     fn put_together_same_attrs(&mut self) -> &mut Self;
+    /// Merges the positions within `tol` into one, and removes the no longer used positions.
+    ///
+    /// The positions are clustered greedily in the order of indices: each position which is not
+    /// clustered yet becomes the representative of the unclustered positions within `tol` from it.
+    /// The indices of texture coordinates and normals are preserved.
+    /// # Panics
+    /// Panic occurs if `tol` is not positive.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.001),
+    ///             Point3::new(1.0, 0.0, -0.001),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[0, 1, 2],
+    ///         &[3, 4, 5],
+    ///     ]),
+    /// );
+    ///
+    /// mesh.merge_close_vertices(0.01);
+    /// assert_eq!(mesh.positions().len(), 4);
+    /// assert_eq!(mesh.faces()[1][1], StandardVertex { pos: 2, uv: None, nor: None });
+    /// ```
+    fn merge_close_vertices(&mut self, tol: f64) -> &mut Self;
+    /// Removes the faces buried inside the solid, and returns the number of removed faces.
+    ///
+    /// For each face, `samples` rays are cast from its centroid to random directions
+    /// in the hemisphere of its normal. The face is regarded as internal
+    /// if all the rays are blocked by other faces. The directions are determined by `seed`.
+    ///
+    /// # Remarks
+    /// This is a heuristic. A face in a deep concave region may be removed
+    /// if `samples` is too small, and a face whose centroid is buried is removed
+    /// even if the other part is exposed. The orientation of faces is assumed to be consistent
+    /// outward. If `samples == 0`, no face is removed.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .chain(vec![
+    ///         Point3::new(0.25, 0.25, 0.5),
+    ///         Point3::new(0.75, 0.25, 0.5),
+    ///         Point3::new(0.75, 0.75, 0.5),
+    ///         Point3::new(0.25, 0.75, 0.5),
+    ///     ])
+    ///     .collect::<Vec<_>>();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 4, 6, 2], [1, 3, 7, 5], [0, 1, 5, 4],
+    ///         [2, 6, 7, 3], [0, 2, 3, 1], [4, 5, 7, 6],
+    ///         // a square in the cube
+    ///         [8, 9, 10, 11],
+    ///     ]),
+    /// );
+    ///
+    /// assert_eq!(mesh.remove_internal_faces(32, 0), 1);
+    /// assert_eq!(mesh.faces().len(), 6);
+    /// ```
+    fn remove_internal_faces(&mut self, samples: usize, seed: u64) -> usize;
 }
 
 fn all_pos_mut(faces: &mut Faces) -> impl Iterator<Item = &mut usize> {
@@ -191,6 +264,73 @@ impl OptimizingFilter for PolygonMesh {
         drop(mesh);
         self
     }
+
+    fn merge_close_vertices(&mut self, tol: f64) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { positions, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        let grid = PointGrid::new(positions.clone(), tol);
+        let mut pos_map = vec![None; positions.len()];
+        for (i, position) in positions.iter().enumerate() {
+            if pos_map[i].is_some() {
+                continue;
+            }
+            for j in grid.within(*position, tol) {
+                pos_map[j] = pos_map[j].or(Some(i));
+            }
+        }
+        all_pos_mut(faces).for_each(|idx| *idx = pos_map[*idx].unwrap());
+        let idcs = sub_remove_unused_attrs(all_pos_mut(faces), positions.len());
+        *positions = idcs.iter().map(|i| positions[*i]).collect();
+        drop(mesh);
+        self
+    }
+
+    fn remove_internal_faces(&mut self, samples: usize, seed: u64) -> usize {
+        let len = self.faces().triangle_iter().count();
+        if samples == 0 || len == 0 {
+            return 0;
+        }
+        let cell_size = match self.bounding_box().size() / f64::cbrt(len as f64) {
+            size if size > TOLERANCE => size,
+            _ => 1.0,
+        };
+        let grid = self.triangle_grid(cell_size);
+        let positions = self.positions();
+        let internal: Vec<bool> = self
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| {
+                let normal = FaceNormal::new(positions, face, i).normal;
+                let center = face
+                    .iter()
+                    .fold(Vector3::zero(), |sum, v| sum + positions[v.pos].to_vec())
+                    / face.len() as f64;
+                // the origin is lifted so that the rays do not hit the face itself.
+                let origin = Point3::from_vec(center) + normal * TOLERANCE;
+                (0..samples).all(|k| {
+                    let gen = [seed as f64, (i * samples + k) as f64];
+                    let direction = hash::take_one_unit(gen);
+                    let direction = match direction.dot(normal) < 0.0 {
+                        true => -direction,
+                        false => direction,
+                    };
+                    grid.ray_cast(origin, direction).is_some()
+                })
+            })
+            .collect();
+        let mut faces = Faces::default();
+        self.face_iter()
+            .zip(&internal)
+            .filter(|(_, internal)| !**internal)
+            .for_each(|(face, _)| faces.push(face));
+        let count = internal.iter().filter(|internal| **internal).count();
+        *self.debug_editor().faces = faces;
+        count
+    }
 }
 
 fn sub_remove_unused_attrs<'a, I: Iterator<Item = &'a mut usize>>(
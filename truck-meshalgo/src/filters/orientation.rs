@@ -0,0 +1,136 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::collections::VecDeque;
+
+/// repairing the orientation of faces
+pub trait OrientingFilter {
+    /// Makes the windings of the faces consistent in each connected component.
+    ///
+    /// The faces are visited by the breadth first search over the edges shared by exactly two
+    /// faces, and the faces whose windings disagree with their already visited neighbors are
+    /// flipped. The first visited face of each component keeps its winding. If `outward` is
+    /// `true`, each closed component is flipped as a whole so that its enclosed volume is
+    /// positive.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_topology::shell::ShellCondition;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    /// ];
+    /// // the first face is inverted
+    /// let mut tetrahedron = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 1, 3], [1, 2, 3], [2, 0, 3]]),
+    /// );
+    /// assert_ne!(tetrahedron.shell_condition(), ShellCondition::Closed);
+    ///
+    /// tetrahedron.orient_faces(true);
+    /// assert_eq!(tetrahedron.shell_condition(), ShellCondition::Closed);
+    /// assert!(tetrahedron.volume() > 0.0);
+    /// ```
+    fn orient_faces(&mut self, outward: bool) -> &mut Self;
+}
+
+impl OrientingFilter for PolygonMesh {
+    fn orient_faces(&mut self, outward: bool) -> &mut Self {
+        let positions = self.positions();
+        let faces: Vec<Vec<usize>> = self
+            .face_iter()
+            .map(|face| face.iter().map(|v| v.pos).collect())
+            .collect();
+        // the faces adjacent to each edge, with `true` if the face runs the edge forward
+        let mut edge_faces = HashMap::<[usize; 2], Vec<(usize, bool)>>::default();
+        faces.iter().enumerate().for_each(|(i, face)| {
+            face.iter().zip(face.iter().cycle().skip(1)).for_each(|(a, b)| {
+                if a != b {
+                    let edge = [usize::min(*a, *b), usize::max(*a, *b)];
+                    edge_faces.entry(edge).or_default().push((i, a < b));
+                }
+            })
+        });
+
+        let mut flipped = vec![false; faces.len()];
+        let mut visited = vec![false; faces.len()];
+        for first in 0..faces.len() {
+            if visited[first] {
+                continue;
+            }
+            visited[first] = true;
+            let mut component = vec![first];
+            let mut closed = true;
+            let mut queue = VecDeque::from([first]);
+            while let Some(i) = queue.pop_front() {
+                let face = &faces[i];
+                face.iter().zip(face.iter().cycle().skip(1)).for_each(|(a, b)| {
+                    if a == b {
+                        return;
+                    }
+                    let edge = [usize::min(*a, *b), usize::max(*a, *b)];
+                    let (dir0, dir1, j) = match edge_faces[&edge].as_slice() {
+                        [(i0, dir0), (i1, dir1)] if *i0 == i => (*dir0, *dir1, *i1),
+                        [(i0, dir0), (i1, dir1)] if *i1 == i => (*dir1, *dir0, *i0),
+                        _ => {
+                            closed = false;
+                            return;
+                        }
+                    };
+                    if !visited[j] {
+                        visited[j] = true;
+                        // the shared edge has to be run oppositely
+                        flipped[j] = flipped[i] ^ (dir0 == dir1);
+                        component.push(j);
+                        queue.push_back(j);
+                    }
+                })
+            }
+            if outward && closed {
+                let component_faces = component.iter().map(|i| match flipped[*i] {
+                    true => faces[*i].iter().rev().copied().collect(),
+                    false => faces[*i].clone(),
+                });
+                let volume = component_mesh(positions, component_faces).volume();
+                if volume < 0.0 {
+                    component.into_iter().for_each(|i| flipped[i] = !flipped[i]);
+                }
+            }
+        }
+
+        self.face_iter_mut()
+            .zip(flipped)
+            .filter(|(_, flipped)| *flipped)
+            .for_each(|(face, _)| face.reverse());
+        self
+    }
+}
+
+/// the mesh consisting of `faces` and the positions used by them
+fn component_mesh(positions: &[Point3], faces: impl Iterator<Item = Vec<usize>>) -> PolygonMesh {
+    let mut indices = HashMap::<usize, usize>::default();
+    let mut component_positions = Vec::new();
+    let faces: Faces = faces
+        .map(|face| {
+            face.into_iter()
+                .map(|i| {
+                    *indices.entry(i).or_insert_with(|| {
+                        component_positions.push(positions[i]);
+                        component_positions.len() - 1
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: component_positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
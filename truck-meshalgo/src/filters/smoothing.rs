@@ -0,0 +1,90 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// mesh smoothing algorithms
+pub trait Smoothing {
+    /// Laplacian smoothing
+    ///
+    /// In each of `iterations` steps, every position is moved toward the centroid of the
+    /// adjacent positions by the ratio `lambda`. The positions on the boundary, i.e. the ones on
+    /// the edges which are not shared by exactly two faces, are fixed.
+    /// If the mesh has normals, they are recomputed by
+    /// [`add_smooth_normals`](./trait.NormalFilters.html#tymethod.add_smooth_normals)
+    /// with the tolerance angle `PI / 3.0`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a square with a spike at the center
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.5, 0.5, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]]),
+    /// );
+    /// mesh.smooth_laplacian(1, 0.5);
+    /// assert!(mesh.positions()[4].near(&Point3::new(0.5, 0.5, 0.5)));
+    /// // the boundary is fixed.
+    /// assert_eq!(mesh.positions()[0], Point3::new(0.0, 0.0, 0.0));
+    /// ```
+    fn smooth_laplacian(&mut self, iterations: usize, lambda: f64) -> &mut Self;
+}
+
+impl Smoothing for PolygonMesh {
+    fn smooth_laplacian(&mut self, iterations: usize, lambda: f64) -> &mut Self {
+        let mut adjacency = vec![Vec::<usize>::new(); self.positions().len()];
+        let mut edge_count = HashMap::<[usize; 2], usize>::default();
+        self.face_iter().for_each(|face| {
+            face.iter().zip(face.iter().cycle().skip(1)).for_each(|(v, w)| {
+                if v.pos == w.pos {
+                    return;
+                }
+                let edge = [usize::min(v.pos, w.pos), usize::max(v.pos, w.pos)];
+                *edge_count.entry(edge).or_insert(0) += 1;
+                if !adjacency[v.pos].contains(&w.pos) {
+                    adjacency[v.pos].push(w.pos);
+                    adjacency[w.pos].push(v.pos);
+                }
+            })
+        });
+        let mut fixed = vec![false; self.positions().len()];
+        edge_count
+            .into_iter()
+            .filter(|(_, count)| *count != 2)
+            .for_each(|([i, j], _)| {
+                fixed[i] = true;
+                fixed[j] = true;
+            });
+
+        let mesh = self.debug_editor();
+        let positions = &mut mesh.attributes.positions;
+        for _ in 0..iterations {
+            *positions = positions
+                .iter()
+                .zip(&adjacency)
+                .zip(&fixed)
+                .map(|((p, adjacency), fixed)| {
+                    if *fixed || adjacency.is_empty() {
+                        return *p;
+                    }
+                    let sum = adjacency
+                        .iter()
+                        .fold(Vector3::zero(), |sum, i| sum + positions[*i].to_vec());
+                    let centroid = Point3::from_vec(sum / adjacency.len() as f64);
+                    p + (centroid - p) * lambda
+                })
+                .collect();
+        }
+        drop(mesh);
+        if !self.normals().is_empty() {
+            self.add_smooth_normals(std::f64::consts::PI / 3.0, true);
+        }
+        self
+    }
+}
@@ -1,8 +1,15 @@
 use super::*;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// triangulation, quadrangulation, give a structure
 pub trait StructuringFilter {
     /// triangulate all n-gons
+    ///
+    /// The quadrangles are divided by the diagonals from their first vertices, and the other
+    /// polygons are divided by the ear clipping in their best-fit planes, so that non-convex
+    /// polygons are also divided into non-overlapping triangles. After this method, all faces
+    /// are stored as triangles.
     /// # Examples
     /// ```
     /// use truck_polymesh::*;
@@ -49,6 +56,8 @@ pub trait StructuringFilter {
     /// 1. sort the list of the pairs of triangles by the score
     /// 1. take a pair of triangles in order from the top of the list and register a new one
     /// if it doesn't conflict with the one has been already registered.
+    ///
+    /// With the feature `rayon`, the scores of the pairs are computed in parallel.
     /// # Panics
     /// `plane_tol` and `score_tol` must be more than `TOLERANCE`.
     /// # Examples
@@ -88,7 +97,17 @@ pub trait StructuringFilter {
 
 impl StructuringFilter for PolygonMesh {
     fn triangulate(&mut self) -> &mut Self {
-        let tri_faces = self.faces().triangle_iter().collect::<Vec<_>>();
+        let positions = self.positions();
+        let faces = self.faces();
+        let mut tri_faces = faces.tri_faces().clone();
+        faces.quad_faces().iter().for_each(|face| {
+            tri_faces.push([face[0], face[1], face[2]]);
+            tri_faces.push([face[0], face[2], face[3]]);
+        });
+        faces
+            .other_faces()
+            .iter()
+            .for_each(|face| tri_faces.extend(ear_clipping(positions, face)));
         *self.debug_editor().faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
         self
     }
@@ -116,17 +135,20 @@ trait SubStructureFilter {
 impl SubStructureFilter for PolygonMesh {
     fn create_face_edge_list(&self, plane_tol: f64, score_tol: f64) -> Vec<FaceEdge> {
         let face_adjacency = self.faces().face_adjacency(true);
-        let mut passed = Vec::new();
         let tri_len = self.faces().tri_faces().len();
-        for (i, face) in face_adjacency.iter().enumerate().take(tri_len) {
-            for j in face {
-                if i > *j {
-                    continue;
-                } else if let Some(face_edge) = self.get_face_edge(i, *j, plane_tol, score_tol) {
-                    passed.push(face_edge);
-                }
-            }
-        }
+        #[cfg(not(feature = "rayon"))]
+        let adjacency_iter = face_adjacency[..tri_len].iter();
+        #[cfg(feature = "rayon")]
+        let adjacency_iter = face_adjacency[..tri_len].par_iter();
+        let mut passed: Vec<FaceEdge> = adjacency_iter
+            .enumerate()
+            .flat_map(|(i, face)| {
+                face.iter()
+                    .filter(|j| i < **j)
+                    .filter_map(|j| self.get_face_edge(i, *j, plane_tol, score_tol))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
         passed.sort_by(|x, y| x.score.partial_cmp(&y.score).unwrap());
         passed
     }
@@ -217,6 +239,60 @@ impl SubStructureFilter for PolygonMesh {
     }
 }
 
+/// Divides the polygon into triangles by the ear clipping on the plane by the Newell's method.
+fn ear_clipping(positions: &[Point3], face: &[Vertex]) -> Vec<[Vertex; 3]> {
+    let normal = face
+        .iter()
+        .zip(face.iter().cycle().skip(1))
+        .fold(Vector3::zero(), |sum, (v, w)| {
+            sum + positions[v.pos].to_vec().cross(positions[w.pos].to_vec())
+        });
+    let fan = || (2..face.len()).map(|i| [face[0], face[i - 1], face[i]]).collect();
+    if normal.so_small() {
+        return fan();
+    }
+    let normal = normal.normalize();
+    let axis0 = match normal.x.abs() < 0.5 {
+        true => Vector3::unit_x().cross(normal).normalize(),
+        false => Vector3::unit_y().cross(normal).normalize(),
+    };
+    let axis1 = normal.cross(axis0);
+    let points: Vec<Point2> = face
+        .iter()
+        .map(|v| {
+            let p = positions[v.pos].to_vec();
+            Point2::new(p.dot(axis0), p.dot(axis1))
+        })
+        .collect();
+    let cross = |a: usize, b: usize, c: usize| {
+        (points[b] - points[a]).perp_dot(points[c] - points[b])
+    };
+
+    let mut remained: Vec<usize> = (0..face.len()).collect();
+    let mut res = Vec::with_capacity(face.len() - 2);
+    while remained.len() > 3 {
+        let len = remained.len();
+        let is_ear = |i: usize| {
+            let (a, b, c) = (remained[(i + len - 1) % len], remained[i], remained[(i + 1) % len]);
+            cross(a, b, c) > 0.0
+                && remained.iter().all(|d| {
+                    [a, b, c].contains(d)
+                        || [a, b, c].iter().any(|e| points[*e] == points[*d])
+                        || cross(a, b, *d) < 0.0
+                        || cross(b, c, *d) < 0.0
+                        || cross(c, a, *d) < 0.0
+                })
+        };
+        // If there are no ears by the degeneracy, the first vertex is clipped.
+        let i = (0..len).find(|i| is_ear(*i)).unwrap_or(0);
+        let (a, b, c) = (remained[(i + len - 1) % len], remained[i], remained[(i + 1) % len]);
+        res.push([face[a], face[b], face[c]]);
+        remained.remove(i);
+    }
+    res.push([face[remained[0]], face[remained[1]], face[remained[2]]]);
+    res
+}
+
 struct FaceEdge {
     faces: (usize, usize),
     positions: [Vertex; 4],
@@ -1,4 +1,5 @@
 use super::*;
+use crate::analyzers::Splitting;
 use rustc_hash::FxHashMap as HashMap;
 use std::f64::consts::PI;
 
@@ -12,6 +13,47 @@ pub trait Subdivision {
     /// - `self.shell_condition()` is `Oriented` or `Closed` before use.
     /// This method does NOT check these conditions.
     fn loop_subdivision(&mut self) -> &mut Self;
+    /// Subdivides the curved triangles and leaves the flat regions.
+    /// # Details
+    /// An edge of triangles is split if the angle between the normals of its end vertices is
+    /// larger than `curvature_tol`. The face normal is used for the vertices without normals.
+    /// The new vertex is placed on the cubic curve determined by the positions and normals of
+    /// the end vertices, so the refined mesh approaches the smooth surface.
+    /// Each triangle is divided into two, three or four triangles by the number of split edges.
+    ///
+    /// The edges of the planar faces detected by [`Splitting::extract_planes`] with the
+    /// tolerance `curvature_tol` and of the non-triangle faces are not split,
+    /// so these faces are left untouched. Each call refines the mesh once.
+    /// # Panics
+    /// `curvature_tol` must be non-negative.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         normals: vec![Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[(0, None, Some(0)), (1, None, Some(1)), (2, None, Some(2))]]),
+    /// );
+    ///
+    /// // an octant of the unit sphere
+    /// mesh.adaptive_refine(0.1);
+    /// assert_eq!(mesh.faces().len(), 4);
+    /// assert_eq!(mesh.positions().len(), 6);
+    /// // the new vertices are nearer to the sphere than the midpoints of the edges.
+    /// mesh.positions()[3..].iter().for_each(|p| {
+    ///     let radius = p.to_vec().magnitude();
+    ///     assert!(f64::abs(radius - 1.0) < 1.0 - f64::sqrt(0.5));
+    /// });
+    /// ```
+    fn adaptive_refine(&mut self, curvature_tol: f64) -> &mut Self;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -82,6 +124,129 @@ impl Subdivision for PolygonMesh {
         }
         self
     }
+    fn adaptive_refine(&mut self, curvature_tol: f64) -> &mut Self {
+        nonpositive_tolerance!(curvature_tol, 0.0);
+        let (planes, _) = self.extract_planes(curvature_tol);
+        let mut is_plane = vec![false; self.faces().len()];
+        planes.into_iter().for_each(|i| is_plane[i] = true);
+
+        // the pairs of flags whether the edge is curved and whether the edge is fixed
+        let mut edge_flags = HashMap::<Edge, (bool, bool)>::default();
+        self.face_iter().zip(is_plane).for_each(|(face, is_plane)| {
+            let is_curved_face = face.len() == 3 && !is_plane;
+            let normals = face_vertex_normals(self, face);
+            (0..face.len()).for_each(|i| {
+                let j = (i + 1) % face.len();
+                let flags = edge_flags
+                    .entry(Edge::new(face[i].pos, face[j].pos))
+                    .or_insert((false, false));
+                flags.0 |= is_curved_face && normals[i].clamped_angle(normals[j]).0 > curvature_tol;
+                flags.1 |= !is_curved_face;
+            })
+        });
+
+        let (positions, normals, uv_coords) = (self.positions(), self.normals(), self.uv_coords());
+        let (mut new_positions, mut new_normals, mut new_uv_coords) = (vec![], vec![], vec![]);
+        let mut position_indices = HashMap::<Edge, usize>::default();
+        let mut midpoints = HashMap::<(StandardVertex, StandardVertex), StandardVertex>::default();
+        let mut midpoint = |v0: StandardVertex, v1: StandardVertex, n0: Vector3, n1: Vector3| {
+            let key = match v0.pos < v1.pos {
+                true => (v0, v1),
+                false => (v1, v0),
+            };
+            *midpoints.entry(key).or_insert_with(|| {
+                let edge = Edge::new(v0.pos, v1.pos);
+                let pos = *position_indices.entry(edge).or_insert_with(|| {
+                    let (p0, p1) = (positions[v0.pos], positions[v1.pos]);
+                    new_positions.push(cubic_midpoint(p0, p1, n0, n1));
+                    positions.len() + new_positions.len() - 1
+                });
+                let uv = v0.uv.zip(v1.uv).map(|(uv0, uv1)| {
+                    new_uv_coords.push(uv_coords[uv0].lerp(uv_coords[uv1], 0.5));
+                    uv_coords.len() + new_uv_coords.len() - 1
+                });
+                let nor = v0.nor.zip(v1.nor).map(|_| {
+                    new_normals.push((n0 + n1).normalize());
+                    normals.len() + new_normals.len() - 1
+                });
+                StandardVertex { pos, uv, nor }
+            })
+        };
+        let tri_faces = self
+            .tri_faces()
+            .iter()
+            .flat_map(|face| {
+                let normals = face_vertex_normals(self, face);
+                let mids: [Option<StandardVertex>; 3] = std::array::from_fn(|i| {
+                    let j = (i + 1) % 3;
+                    match edge_flags[&Edge::new(face[i].pos, face[j].pos)] {
+                        (true, false) => Some(midpoint(face[i], face[j], normals[i], normals[j])),
+                        _ => None,
+                    }
+                });
+                split_triangle(*face, mids)
+            })
+            .collect::<Vec<_>>();
+
+        let mut faces = Faces::from_tri_and_quad_faces(tri_faces, self.quad_faces().clone());
+        faces.extend(self.other_faces());
+        {
+            let editor = self.debug_editor();
+            editor.attributes.positions.extend(new_positions);
+            editor.attributes.uv_coords.extend(new_uv_coords);
+            editor.attributes.normals.extend(new_normals);
+            *editor.faces = faces;
+        }
+        self
+    }
+}
+
+/// the normals of the vertices of `face`, the face normal for the vertex without normal
+fn face_vertex_normals(mesh: &PolygonMesh, face: &[StandardVertex]) -> Vec<Vector3> {
+    let face_normal = FaceNormal::new(mesh.positions(), face, 0).normal;
+    face.iter()
+        .map(|v| v.nor.map_or(face_normal, |idx| mesh.normals()[idx]))
+        .collect()
+}
+
+/// the point at the parameter `1/2` of the cubic Bezier curve from `p0` to `p1`,
+/// whose inner control points are projected onto the tangent planes at the ends.
+fn cubic_midpoint(p0: Point3, p1: Point3, n0: Vector3, n1: Vector3) -> Point3 {
+    let vec = p1 - p0;
+    p0.midpoint(p1) - (n0 * vec.dot(n0) - n1 * vec.dot(n1)) / 8.0
+}
+
+/// divides the triangle by the midpoints `mids[i]` on the edges from `face[i]` to `face[i + 1]`.
+fn split_triangle(
+    face: [StandardVertex; 3],
+    mids: [Option<StandardVertex>; 3],
+) -> Vec<[StandardVertex; 3]> {
+    let v = |i: usize| face[i % 3];
+    match mids {
+        [None, None, None] => vec![face],
+        [Some(m0), Some(m1), Some(m2)] => vec![
+            [face[0], m0, m2],
+            [m0, face[1], m1],
+            [m2, m1, face[2]],
+            [m0, m1, m2],
+        ],
+        _ => {
+            let count = mids.iter().filter(|m| m.is_some()).count();
+            // `i` is the first index of the split edges in the cyclic order.
+            let i = (0..3)
+                .find(|i| mids[*i].is_some() && (count == 1 || mids[(i + 1) % 3].is_some()))
+                .unwrap();
+            match (mids[i], mids[(i + 1) % 3]) {
+                (Some(m0), None) => vec![[v(i), m0, v(i + 2)], [m0, v(i + 1), v(i + 2)]],
+                (Some(m0), Some(m1)) => vec![
+                    [v(i), m0, m1],
+                    [m0, v(i + 1), m1],
+                    [v(i), m1, v(i + 2)],
+                ],
+                _ => unreachable!(),
+            }
+        }
+    }
 }
 
 fn add_vertex_edge(
@@ -0,0 +1,158 @@
+use super::*;
+
+/// Filters for generating texture coordinates
+pub trait UVFilters {
+    /// Generates the texture coordinates by the projection onto the plane orthogonal to `axis`.
+    /// # Details
+    /// The projected positions are normalized into `[0, 1]^2` by their bounding box, and the
+    /// texture coordinates are overwritten. The `uv` index of each vertex is the same as its
+    /// `pos` index.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 1.0),
+    ///             Point3::new(2.0, 4.0, 2.0),
+    ///             Point3::new(0.0, 4.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2, 3]]),
+    /// );
+    ///
+    /// mesh.generate_planar_uv(Vector3::unit_z());
+    /// assert_eq!(mesh.uv_coords().len(), 4);
+    /// assert!(mesh.uv_coords().iter().all(|uv| (0.0..=1.0).contains(&uv.x)));
+    /// assert!(mesh.uv_coords().iter().all(|uv| (0.0..=1.0).contains(&uv.y)));
+    /// assert_eq!(mesh.faces()[0][2].uv, Some(2));
+    /// ```
+    fn generate_planar_uv(&mut self, axis: Vector3) -> &mut Self;
+    /// Generates the texture coordinates by the local frame of each face.
+    /// # Details
+    /// The vertices of each face are projected onto the plane of the face, whose first axis
+    /// is the first edge of the face, and scaled into `[0, 1]^2` keeping the aspect ratio.
+    /// Each vertex of each face has its own texture coordinate, e.g. for texture atlases.
+    /// The texture coordinates are overwritten.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 2.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2], &[0, 3, 1]]),
+    /// );
+    ///
+    /// mesh.generate_per_face_uv();
+    /// assert_eq!(mesh.uv_coords().len(), 6);
+    /// let face = &mesh.faces()[0];
+    /// assert_eq!(mesh.uv_coords()[face[0].uv.unwrap()], Vector2::new(0.0, 0.0));
+    /// assert_eq!(mesh.uv_coords()[face[1].uv.unwrap()], Vector2::new(1.0, 0.0));
+    /// assert_eq!(mesh.uv_coords()[face[2].uv.unwrap()], Vector2::new(0.0, 0.5));
+    /// ```
+    fn generate_per_face_uv(&mut self) -> &mut Self;
+}
+
+impl UVFilters for PolygonMesh {
+    fn generate_planar_uv(&mut self, axis: Vector3) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes:
+                StandardAttributes {
+                    positions,
+                    uv_coords,
+                    ..
+                },
+            faces,
+            ..
+        } = &mut mesh;
+        let (u_axis, v_axis) = orthonormal_basis(axis.normalize());
+        let projected = positions
+            .iter()
+            .map(|p| Vector2::new(p.to_vec().dot(u_axis), p.to_vec().dot(v_axis)));
+        *uv_coords = normalize_into_unit_square(projected, false);
+        faces.face_iter_mut().flatten().for_each(|v| v.uv = Some(v.pos));
+        drop(mesh);
+        self
+    }
+    fn generate_per_face_uv(&mut self) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes:
+                StandardAttributes {
+                    positions,
+                    uv_coords,
+                    ..
+                },
+            faces,
+            ..
+        } = &mut mesh;
+        uv_coords.clear();
+        faces.face_iter_mut().for_each(|face| {
+            let normal = FaceNormal::new(positions, face, 0).normal;
+            let origin = positions[face[0].pos];
+            let first_edge = positions[face[1].pos] - origin;
+            let (u_axis, v_axis) = match first_edge.so_small() || normal.x.is_nan() {
+                true => orthonormal_basis(normal),
+                false => {
+                    let u_axis = first_edge.normalize();
+                    (u_axis, normal.cross(u_axis))
+                }
+            };
+            let projected = face.iter().map(|v| {
+                let vec = positions[v.pos] - origin;
+                Vector2::new(vec.dot(u_axis), vec.dot(v_axis))
+            });
+            let len = uv_coords.len();
+            uv_coords.extend(normalize_into_unit_square(projected, true));
+            face.iter_mut().enumerate().for_each(|(i, v)| v.uv = Some(len + i));
+        });
+        drop(mesh);
+        self
+    }
+}
+
+/// two unit vectors orthogonal to `axis` and each other.
+/// If `axis` is degenerate, the basis of the xy-plane is returned.
+fn orthonormal_basis(axis: Vector3) -> (Vector3, Vector3) {
+    if axis.x.is_nan() || axis.so_small() {
+        return (Vector3::unit_x(), Vector3::unit_y());
+    }
+    axis.orthonormal_basis()
+}
+
+/// maps the points into `[0, 1]^2` by their bounding box.
+/// If `keep_aspect`, both coordinates are scaled by the same ratio.
+fn normalize_into_unit_square<I>(points: I, keep_aspect: bool) -> Vec<Vector2>
+where I: Iterator<Item = Vector2> + Clone {
+    let bdd_box: BoundingBox<Vector2> = points.clone().collect();
+    if bdd_box.is_empty() {
+        return Vec::new();
+    }
+    let (min, diag) = (*bdd_box.min(), bdd_box.diagonal());
+    let scale = |length: f64| match length.so_small() {
+        true => 1.0,
+        false => 1.0 / length,
+    };
+    let (su, sv) = match keep_aspect {
+        true => {
+            let s = scale(f64::max(diag.x, diag.y));
+            (s, s)
+        }
+        false => (scale(diag.x), scale(diag.y)),
+    };
+    points
+        .map(|p| Vector2::new((p.x - min.x) * su, (p.y - min.y) * sv))
+        .collect()
+}
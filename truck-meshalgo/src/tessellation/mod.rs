@@ -1,3 +1,4 @@
+use crate::filters::OptimizingFilter;
 use crate::*;
 use spade::delaunay::*;
 use spade::kernels::*;
@@ -19,31 +20,40 @@ type PolylineCurve = truck_polymesh::PolylineCurve<Point3>;
 /// Trait for converting tessellated shape into polygon.
 pub trait MeshedShape {
     /// Converts tessellated shape into polygon.
+    ///
+    /// The vertices on the edges shared by the faces are put together, so the polygon of
+    /// a tessellated closed shell is closed.
     fn to_polygon(&self) -> PolygonMesh;
 }
 
+/// Merges the meshes of the faces and puts together the vertices on the shared edges.
+fn merge_faces(faces: impl IntoIterator<Item = PolygonMesh>) -> PolygonMesh {
+    let mut polygon = PolygonMesh::default();
+    faces.into_iter().for_each(|face| {
+        polygon.merge(face);
+    });
+    polygon
+        .put_together_same_attrs()
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    polygon
+}
+
 impl MeshedShape for Shell<Point3, PolylineCurve, PolygonMesh> {
     fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.face_iter().for_each(|face| {
-            polygon.merge(face.oriented_surface());
-        });
-        polygon
+        merge_faces(self.face_iter().map(|face| face.oriented_surface()))
     }
 }
 
 impl MeshedShape for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
     fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.face_iter().for_each(|face| {
-            if let Some(mut poly) = face.get_surface() {
-                if !face.orientation() {
-                    poly.invert();
-                }
-                polygon.merge(poly);
+        merge_faces(self.face_iter().filter_map(|face| {
+            let mut poly = face.get_surface()?;
+            if !face.orientation() {
+                poly.invert();
             }
-        });
-        polygon
+            Some(poly)
+        }))
     }
 }
 
@@ -61,27 +71,22 @@ where Shell<P, C, S>: MeshedShape
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
     fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.faces.iter().for_each(|face| match face.orientation {
-            true => polygon.merge(face.surface.clone()),
-            false => polygon.merge(face.surface.inverse()),
-        });
-        polygon
+        merge_faces(self.faces.iter().map(|face| match face.orientation {
+            true => face.surface.clone(),
+            false => face.surface.inverse(),
+        }))
     }
 }
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
     fn to_polygon(&self) -> PolygonMesh {
-        let mut polygon = PolygonMesh::default();
-        self.faces.iter().for_each(|face| {
-            if let Some(surface) = &face.surface {
-                match face.orientation {
-                    true => polygon.merge(surface.clone()),
-                    false => polygon.merge(surface.inverse()),
-                }
+        merge_faces(self.faces.iter().filter_map(|face| {
+            let surface = face.surface.as_ref()?;
+            match face.orientation {
+                true => Some(surface.clone()),
+                false => Some(surface.inverse()),
             }
-        });
-        polygon
+        }))
     }
 }
 
@@ -108,10 +113,7 @@ pub trait MeshableShape {
     ///
     /// `tol` must be more than `TOLERANCE`.
     ///
-    /// # Remarks
-    ///
-    /// The tessellated mesh is not necessarily closed even if `self` is `Solid`.
-    /// If you want to get closed mesh, use `OptimizationFilter::put_together_same_attrs`.
+    /// # Examples
     /// ```
     /// use truck_meshalgo::prelude::*;
     /// use truck_modeling::builder;
@@ -123,12 +125,8 @@ pub trait MeshableShape {
     /// let f = builder::tsweep(&e, Vector3::unit_y());
     /// let cube = builder::tsweep(&f, Vector3::unit_z());
     ///
-    /// // cube is Solid, however, the tessellated mesh is not closed.
-    /// let mut mesh = cube.triangulation(0.01).to_polygon();
-    /// assert!(mesh.shell_condition() != ShellCondition::Closed);
-    ///
-    /// // use optimization filters!
-    /// mesh.put_together_same_attrs();
+    /// // the vertices on the shared edges are put together.
+    /// let mesh = cube.triangulation(0.01).to_polygon();
     /// assert!(mesh.shell_condition() == ShellCondition::Closed);
     /// ```
     fn triangulation(&self, tol: f64) -> Self::MeshedShape;
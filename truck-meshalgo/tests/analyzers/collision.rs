@@ -81,3 +81,73 @@ fn collision_sphere() {
         instant.elapsed().as_secs_f64()
     );
 }
+
+fn tetrahedron(origin: Point3) -> PolygonMesh {
+    let positions = vec![
+        origin,
+        origin + Vector3::unit_x(),
+        origin + Vector3::unit_y(),
+        origin + Vector3::unit_z(),
+    ];
+    let faces = Faces::from_iter([[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn cube_self_intersections() {
+    let mut cube = common::shapes::cube();
+    assert!(cube.find_self_intersections(TOLERANCE).is_empty());
+
+    // the triangle piercing the bottom and the top. The indices are the ones of `faces()`,
+    // where the triangle comes first and the quads are the faces from 1 to 6.
+    cube.merge(PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.5, 0.5, -1.0),
+                Point3::new(0.6, 0.5, 2.0),
+                Point3::new(0.5, 0.6, 2.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter([[0, 1, 2]]),
+    ));
+    assert_eq!(cube.find_self_intersections(TOLERANCE), vec![(0, 1), (0, 6)]);
+}
+
+#[test]
+fn find_self_intersections_cube() {
+    // each face of the cube has its own positions
+    let corners = common::shapes::cube_positions();
+    let positions = common::shapes::CUBE_FACES.iter().flatten().map(|i| corners[*i]).collect();
+    let faces = Faces::from_iter((0..6).map(|i| [4 * i, 4 * i + 1, 4 * i + 2, 4 * i + 3]));
+    let cube = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    assert!(cube.find_self_intersections(TOLERANCE).is_empty());
+}
+
+#[test]
+fn find_self_intersections_tetrahedra() {
+    let mut mesh = tetrahedron(Point3::origin());
+    assert!(mesh.find_self_intersections(TOLERANCE).is_empty());
+    mesh.merge(tetrahedron(Point3::new(0.2, 0.2, 0.2)));
+    // the slanted face of the first tetrahedron crosses the axial faces of the second one.
+    assert_eq!(
+        mesh.find_self_intersections(TOLERANCE),
+        vec![(3, 4), (3, 5), (3, 6)],
+    );
+
+    let mut mesh = tetrahedron(Point3::origin());
+    mesh.merge(tetrahedron(Point3::new(2.0, 0.0, 0.0)));
+    assert!(mesh.find_self_intersections(TOLERANCE).is_empty());
+}
@@ -2,5 +2,6 @@ use truck_meshalgo::prelude::*;
 
 mod collision;
 mod point_cloud;
+mod silhouette;
 mod splitting;
 mod topology;
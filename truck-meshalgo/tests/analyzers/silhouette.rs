@@ -0,0 +1,39 @@
+use super::*;
+#[path = "../common/mod.rs"]
+mod common;
+
+#[test]
+fn sphere_silhouette() {
+    const UDIV: usize = 32;
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, UDIV, 17);
+    sphere
+        .put_together_same_attrs()
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    let positions = sphere.positions();
+
+    let edges = sphere.silhouette_edges(Vector3::unit_z());
+    assert_eq!(edges.len(), UDIV);
+    let mut degrees = std::collections::HashMap::<usize, usize>::new();
+    for (i, j) in edges {
+        // the edges are on the great circle perpendicular to the view direction.
+        assert!(positions[i][2].so_small(), "{:?}", positions[i]);
+        assert!(positions[j][2].so_small(), "{:?}", positions[j]);
+        *degrees.entry(i).or_insert(0) += 1;
+        *degrees.entry(j).or_insert(0) += 1;
+    }
+    // the edges form a ring.
+    assert_eq!(degrees.len(), UDIV);
+    assert!(degrees.values().all(|degree| *degree == 2));
+
+    // a slanted view direction
+    let view_dir = Vector3::new(1.0, 0.0, 1.0).normalize();
+    let edges = sphere.silhouette_edges(view_dir);
+    assert!(!edges.is_empty());
+    for (i, j) in edges {
+        let dist = positions[i].to_vec().dot(view_dir);
+        assert!(dist.abs() < 0.2, "{:?}", positions[i]);
+        let dist = positions[j].to_vec().dot(view_dir);
+        assert!(dist.abs() < 0.2, "{:?}", positions[j]);
+    }
+}
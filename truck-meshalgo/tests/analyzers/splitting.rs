@@ -1,4 +1,7 @@
 use super::*;
+use truck_topology::shell::ShellCondition;
+#[path = "../common/mod.rs"]
+mod common;
 
 #[test]
 fn extract_planes_test() {
@@ -90,3 +93,141 @@ fn into_component_test() {
     let components = mesh.components(false);
     assert_eq!(components.len(), 1);
 }
+
+#[test]
+fn classify_regions_test() {
+    const N: usize = 16;
+    let ys = [0.0, 1.0, 2.0];
+    // half cylinder with radius 1 around the y-axis
+    let mut positions: Vec<Point3> = (0..=N)
+        .flat_map(|i| {
+            let theta = std::f64::consts::PI * i as f64 / N as f64;
+            let (x, z) = (f64::cos(theta), f64::sin(theta));
+            ys.map(|y| Point3::new(x, y, z))
+        })
+        .collect();
+    // plane z = 0 attached to the cylinder at x = 1
+    positions.extend((1..=4).flat_map(|k| ys.map(|y| Point3::new(1.0 + 0.5 * k as f64, y, 0.0))));
+    // the index of the position at y = 0 in the `k`-th column of the plane
+    let column = |k: usize| match k {
+        0 => 0,
+        _ => 3 * (N + k),
+    };
+    let strip = |c0: usize, c1: usize| {
+        (0..2).map(move |j| [c0 + j, c0 + j + 1, c1 + j + 1, c1 + j])
+    };
+    let faces: Faces = (0..N)
+        .flat_map(|i| strip(3 * i, 3 * i + 3))
+        .chain((0..4).flat_map(|k| strip(column(k), column(k + 1))))
+        .collect();
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+
+    let regions = mesh.classify_regions(1.0e-6);
+    assert_eq!(regions.len(), 2);
+    match regions[0] {
+        (
+            SurfaceKind::Cylinder {
+                origin,
+                axis,
+                radius,
+            },
+            ref faces,
+        ) => {
+            assert!(axis.cross(Vector3::unit_y()).near(&Vector3::zero()));
+            assert!(origin.x.near(&0.0));
+            assert!(origin.z.near(&0.0));
+            assert!(radius.near(&1.0));
+            assert_eq!(faces, &(0..2 * N).collect::<Vec<_>>());
+        }
+        _ => panic!("the half cylinder is classified as {:?}", regions[0].0),
+    }
+    match regions[1] {
+        (SurfaceKind::Plane { origin, normal }, ref faces) => {
+            assert!(origin.z.near(&0.0));
+            assert!(normal.near(&-Vector3::unit_z()));
+            assert_eq!(faces, &(2 * N..2 * N + 8).collect::<Vec<_>>());
+        }
+        _ => panic!("the plane is classified as {:?}", regions[1].0),
+    }
+}
+
+#[test]
+fn split_connected_components_test() {
+    // two separated cubes
+    let corners = common::shapes::cube_positions();
+    let shifted = corners.iter().map(|p| p + Vector3::new(3.0, 0.0, 0.0));
+    let positions: Vec<Point3> = corners.iter().copied().chain(shifted).collect();
+    // the faces of the two cubes are mixed.
+    let faces: Faces = common::shapes::CUBE_FACES
+        .iter()
+        .flat_map(|face| [*face, face.map(|i| i + 8)])
+        .collect();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    mesh.add_naive_normals(true);
+
+    let components = mesh.split_connected_components();
+    assert_eq!(components.len(), 2);
+    components.iter().enumerate().for_each(|(i, component)| {
+        assert_eq!(component.faces().len(), 6);
+        assert_eq!(component.positions().len(), 8);
+        assert_eq!(component.normals().len(), 6);
+        let x = 3.0 * i as f64;
+        let in_cube = |p: &Point3| p.x.near(&x) || p.x.near(&(x + 1.0));
+        assert!(component.positions().iter().all(in_cube));
+        // the attribute indices are re-indexed into the compacted arrays.
+        component.face_iter().flatten().for_each(|v| {
+            assert!(v.pos < 8);
+            assert!(v.nor.unwrap() < 6);
+        });
+        assert_eq!(component.shell_condition(), ShellCondition::Closed);
+    });
+}
+
+#[test]
+fn create_mesh_by_global_face_indices() {
+    let positions = (0..8)
+        .map(|i| Point3::new(i as f64, (i * i) as f64, 0.0))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        &[0, 1, 2, 3, 4][..],
+        &[0, 1, 2, 3],
+        &[5, 6, 7],
+        &[2, 3, 4, 5],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    // global indices: the triangle, the quadrangles, and the pentagon
+    let indices = mesh
+        .faces()
+        .enumerate()
+        .filter(|(_, face)| face.len() != 4)
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    assert_eq!(indices, vec![0, 3]);
+    let submesh = mesh.create_mesh_by_face_indices(&indices);
+    assert_eq!(submesh.faces().len(), 2);
+    assert_eq!(submesh.tri_faces(), &vec![[5.into(), 6.into(), 7.into()]]);
+    assert_eq!(submesh.other_faces().len(), 1);
+    assert_eq!(submesh.faces()[1], mesh.faces()[3]);
+
+    let submesh = mesh.create_mesh_by_face_indices(&[2]);
+    assert_eq!(submesh.quad_faces().len(), 1);
+    assert_eq!(submesh.faces()[0], mesh.faces()[2]);
+}
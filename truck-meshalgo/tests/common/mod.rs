@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use truck_meshalgo::prelude::*;
 
 pub mod shapes;
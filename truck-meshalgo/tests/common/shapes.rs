@@ -1,6 +1,34 @@
 use super::*;
 use std::f64::consts::PI;
 
+/// the faces of the unit cube, oriented outward
+pub const CUBE_FACES: [[usize; 4]; 6] = [
+    [0, 2, 3, 1],
+    [0, 1, 5, 4],
+    [1, 3, 7, 5],
+    [3, 2, 6, 7],
+    [2, 0, 4, 6],
+    [4, 5, 7, 6],
+];
+
+/// the vertices of the unit cube; the `k`-th bit of the index is the `k`-th coordinate.
+pub fn cube_positions() -> Vec<Point3> {
+    (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect()
+}
+
+/// the unit cube `[0, 1]^3` with only positions
+pub fn cube() -> PolygonMesh {
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: cube_positions(),
+            ..Default::default()
+        },
+        Faces::from_iter(&CUBE_FACES),
+    )
+}
+
 pub fn sphere(center: Point3, radius: f64, udiv: usize, vdiv: usize) -> PolygonMesh {
     let positions = (0..udiv)
         .flat_map(move |i| {
@@ -0,0 +1,56 @@
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+const N: usize = 8;
+
+// the unit cube whose faces are divided into N x N quadrangles
+fn subdivided_cube() -> PolygonMesh {
+    let mut positions = Vec::new();
+    let mut faces = Vec::<[usize; 4]>::new();
+    for (axis, side) in (0..3).flat_map(|axis| [(axis, 0.0), (axis, 1.0)]) {
+        let offset = positions.len();
+        positions.extend((0..=N).flat_map(|i| (0..=N).map(move |j| (i, j))).map(|(i, j)| {
+            let mut p = Point3::origin();
+            p[axis] = side;
+            p[(axis + 1) % 3] = i as f64 / N as f64;
+            p[(axis + 2) % 3] = j as f64 / N as f64;
+            p
+        }));
+        faces.extend((0..N).flat_map(|i| (0..N).map(move |j| (i, j))).map(|(i, j)| {
+            let k = offset + i * (N + 1) + j;
+            match side == 0.0 {
+                true => [k, k + 1, k + N + 2, k + N + 1],
+                false => [k, k + N + 1, k + N + 2, k + 1],
+            }
+        }));
+    }
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    mesh.merge_close_vertices(TOLERANCE);
+    mesh
+}
+
+#[test]
+fn decimate_subdivided_cube() {
+    const TARGET: usize = 100;
+    let mut mesh = subdivided_cube();
+    assert_eq!(mesh.positions().len(), 6 * N * N + 2);
+    mesh.decimate(TARGET);
+    let len = mesh.faces().len();
+    assert!(len <= TARGET && len + 4 >= TARGET, "{len} faces remain");
+    assert_eq!(mesh.faces().tri_faces().len(), len);
+    // the result is still a closed surface of genus 0
+    assert_eq!(mesh.positions().len(), len / 2 + 2);
+
+    let bdb = mesh.bounding_box();
+    let (min, max): ([f64; 3], [f64; 3]) = ((*bdb.min()).into(), (*bdb.max()).into());
+    (0..3).for_each(|i| {
+        assert!(f64::abs(min[i]) < 0.05, "{bdb:?}");
+        assert!(f64::abs(max[i] - 1.0) < 0.05, "{bdb:?}");
+    });
+}
@@ -1,4 +1,8 @@
+mod decimation;
 mod normal_filter;
 mod optimizing;
+mod orientation;
+mod smoothing;
 mod structuring;
 mod subdivision;
+mod uv_filters;
@@ -135,3 +135,110 @@ fn add_smooth_normals() {
         assert!(p0.distance(n0) > p1.distance(n1));
     }
 }
+
+#[test]
+fn add_smooth_normals_reference() {
+    const N: usize = 30;
+    let positions = (0..=N)
+        .map(|i| {
+            (0..=N)
+                .map(|j| {
+                    let (x, y) = (i as f64 / N as f64, j as f64 / N as f64);
+                    Point3::new(x, y, 0.1 * f64::sin(10.0 * x) * f64::cos(10.0 * y))
+                })
+                .collect()
+        })
+        .collect();
+    let mut mesh = StructuredMesh::from_positions(positions).destruct();
+    mesh.triangulate();
+
+    // all faces around a vertex are in one cluster
+    let mut sums = vec![Vector3::zero(); mesh.positions().len()];
+    mesh.faces().triangle_iter().for_each(|tri| {
+        let p = tri.map(|v| mesh.positions()[v.pos]);
+        let normal = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
+        tri.iter().for_each(|v| sums[v.pos] += normal);
+    });
+    mesh.add_smooth_normals(std::f64::consts::PI, true);
+    assert_eq!(mesh.normals().len(), mesh.positions().len());
+    mesh.face_iter().flatten().for_each(|v| {
+        let normal = mesh.normals()[v.nor.unwrap()];
+        assert!(normal.near(&sums[v.pos].normalize()));
+    });
+}
+
+/// one large face and three small faces meeting at the origin
+fn large_and_small_faces() -> PolygonMesh {
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(10.0, 0.0, 0.0),
+                Point3::new(0.0, 10.0, 0.0),
+                Point3::new(0.0, 0.1, 0.0),
+                Point3::new(0.0, 0.0, 0.1),
+                Point3::new(0.0, -0.1, 0.0),
+                Point3::new(0.0, 0.0, -0.1),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 3, 4], [0, 4, 5], [0, 5, 6]]),
+    )
+}
+
+#[test]
+fn recompute_normals_weighting() {
+    let mut mesh = large_and_small_faces();
+    let normal_at_origin = |mesh: &PolygonMesh| {
+        let v = mesh.faces()[0][0];
+        assert_eq!(v.pos, 0);
+        mesh.normals()[v.nor.unwrap()]
+    };
+
+    // the large face is dominant.
+    mesh.recompute_normals(NormalWeighting::ByArea);
+    assert_eq!(mesh.normals().len(), 7);
+    let by_area = normal_at_origin(&mesh);
+    assert!(by_area.dot(Vector3::unit_z()) > 0.999);
+
+    // the sum of the angles of the small faces is three times the one of the large face.
+    mesh.recompute_normals(NormalWeighting::ByAngle);
+    assert_eq!(mesh.normals().len(), 7);
+    let by_angle = normal_at_origin(&mesh);
+    assert!(by_angle.near(&Vector3::new(3.0, 0.0, 1.0).normalize()));
+
+    mesh.recompute_normals(NormalWeighting::Uniform);
+    let uniform = normal_at_origin(&mesh);
+    assert!(uniform.near(&Vector3::new(3.0, 0.0, 1.0).normalize()));
+
+    // the normals at the other positions are the face normals.
+    let v = mesh.faces()[0][1];
+    assert!(mesh.normals()[v.nor.unwrap()].near(&Vector3::unit_z()));
+    let v = mesh.faces()[2][1];
+    assert!(mesh.normals()[v.nor.unwrap()].near(&Vector3::unit_x()));
+}
+
+#[test]
+fn flip_normals() {
+    let mut mesh = large_and_small_faces();
+    mesh.recompute_normals(NormalWeighting::ByAngle);
+    let normals = mesh.normals().clone();
+    let faces = mesh.faces().clone();
+
+    mesh.flip_normals();
+    mesh.normals()
+        .iter()
+        .zip(&normals)
+        .for_each(|(n0, n1)| assert_eq!(*n0, -*n1));
+    mesh.faces()
+        .face_iter()
+        .zip(faces.face_iter())
+        .for_each(|(f0, f1)| assert!(f0.iter().eq(f1.iter().rev())));
+    // the face normals are compatible to the normals.
+    mesh.face_iter().for_each(|face| {
+        let p: Vec<Point3> = face.iter().map(|v| mesh.positions()[v.pos]).collect();
+        let face_normal = (p[1] - p[0]).cross(p[2] - p[0]);
+        face.iter()
+            .for_each(|v| assert!(mesh.normals()[v.nor.unwrap()].dot(face_normal) > 0.0));
+    });
+}
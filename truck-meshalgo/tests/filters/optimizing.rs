@@ -1,5 +1,8 @@
 use truck_meshalgo::filters::*;
 use truck_polymesh::*;
+#[path = "../common/mod.rs"]
+mod common;
+use common::shapes::{cube_positions, CUBE_FACES};
 
 #[test]
 fn remove_unused_attrs_test() {
@@ -190,3 +193,123 @@ fn put_together_same_attrs_test() {
     assert_eq!(mesh.uv_coords().len(), 18);
     assert_eq!(mesh.normals().len(), 17);
 }
+
+#[test]
+fn remove_internal_faces_test() {
+    // box [0, 2]^3 whose +x face is divided into 3x3 squares
+    let mut positions: Vec<_> = cube_positions().into_iter().map(|p| p * 2.0).collect();
+    positions.extend((0..16).map(|i| Point3::new(2.0, (i / 4) as f64 / 1.5, (i % 4) as f64 / 1.5)));
+    // `[1, 3, 7, 5]` is the +x face.
+    let mut faces: Vec<[usize; 4]> = CUBE_FACES.iter().copied().filter(|f| f[0] != 1).collect();
+    for (a, b) in (0..3).flat_map(|a| (0..3).map(move |b| (a, b))) {
+        let g = |a: usize, b: usize| 8 + a * 4 + b;
+        faces.push([g(a, b), g(a + 1, b), g(a + 1, b + 1), g(a, b + 1)]);
+    }
+    // box [1, 4] x [0.5, 1.5]^2 piercing the center square
+    positions.extend(
+        cube_positions()
+            .into_iter()
+            .map(|p| Point3::new(1.0 + 3.0 * p.x, 0.5 + p.y, 0.5 + p.z)),
+    );
+    faces.extend(CUBE_FACES.iter().map(|f| f.map(|i| i + 24)));
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    assert_eq!(mesh.faces().len(), 20);
+
+    assert_eq!(mesh.remove_internal_faces(64, 1), 2);
+    assert_eq!(mesh.faces().len(), 18);
+    let centers: Vec<Point3> = mesh
+        .face_iter()
+        .map(|face| {
+            let sum = face
+                .iter()
+                .fold(Vector3::zero(), |sum, v| sum + mesh.positions()[v.pos].to_vec());
+            Point3::from_vec(sum / face.len() as f64)
+        })
+        .collect();
+    // the center square of the +x face and the -x face of the second box are removed.
+    assert!(!centers.iter().any(|c| c.near(&Point3::new(2.0, 1.0, 1.0))));
+    assert!(!centers.iter().any(|c| c.near(&Point3::new(1.0, 1.0, 1.0))));
+    // the outer shell remains.
+    assert!(centers.iter().any(|c| c.near(&Point3::new(0.0, 1.0, 1.0))));
+    assert!(centers.iter().any(|c| c.near(&Point3::new(2.0, 1.0 / 3.0, 1.0))));
+    assert!(centers.iter().any(|c| c.near(&Point3::new(4.0, 1.0, 1.0))));
+    assert!(centers.iter().any(|c| c.near(&Point3::new(2.5, 0.5, 1.0))));
+
+    assert_eq!(mesh.remove_internal_faces(64, 1), 0);
+}
+
+#[test]
+fn merge_close_vertices_test() {
+    let corners = cube_positions();
+    let quads = CUBE_FACES;
+    // every face has its own positions, i.e. all edges are split seams.
+    let mut positions = Vec::new();
+    let mut faces = Faces::default();
+    for (i, quad) in quads.iter().enumerate() {
+        let face: Vec<StandardVertex> = quad
+            .iter()
+            .enumerate()
+            .map(|(j, idx)| {
+                let noise = hash::take_one_unit([i as f64, j as f64]) * 1.0e-4;
+                positions.push(corners[*idx] + noise);
+                (positions.len() - 1, Some(j), Some(i)).into()
+            })
+            .collect();
+        faces.push(face);
+    }
+    let uv_coords = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let normals = vec![
+        -Vector3::unit_z(),
+        -Vector3::unit_y(),
+        Vector3::unit_x(),
+        Vector3::unit_y(),
+        -Vector3::unit_x(),
+        Vector3::unit_z(),
+    ];
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords: uv_coords.clone(),
+            normals: normals.clone(),
+        },
+        faces.clone(),
+    );
+    assert_eq!(mesh.positions().len(), 24);
+
+    mesh.merge_close_vertices(1.0e-3);
+    assert_eq!(mesh.positions().len(), 8);
+    assert_eq!(mesh.uv_coords(), &uv_coords);
+    assert_eq!(mesh.normals(), &normals);
+    mesh.face_iter().zip(faces.face_iter()).for_each(|(face, org)| {
+        face.iter().zip(org).for_each(|(v, w)| {
+            assert_eq!((v.uv, v.nor), (w.uv, w.nor));
+        });
+    });
+    mesh.face_iter().zip(&quads).for_each(|(face, quad)| {
+        face.iter().zip(quad).for_each(|(v, idx)| {
+            assert!(mesh.positions()[v.pos].distance(corners[*idx]) < 1.0e-3);
+        });
+    });
+    // the shared corners refer to the same position.
+    let mut pos_of_corner = [None; 8];
+    mesh.face_iter().zip(&quads).for_each(|(face, quad)| {
+        face.iter().zip(quad).for_each(|(v, idx)| {
+            assert_eq!(*pos_of_corner[*idx].get_or_insert(v.pos), v.pos);
+        });
+    });
+
+    // the positions farther than `tol` are not merged.
+    mesh.merge_close_vertices(0.5);
+    assert_eq!(mesh.positions().len(), 8);
+}
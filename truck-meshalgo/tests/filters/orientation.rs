@@ -0,0 +1,55 @@
+use truck_meshalgo::prelude::*;
+use truck_topology::shell::ShellCondition;
+#[path = "../common/mod.rs"]
+mod common;
+use common::shapes::{cube, cube_positions};
+
+#[test]
+fn orient_cube_with_reversed_faces() {
+    let mut mesh = cube();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    mesh.face_iter_mut()
+        .enumerate()
+        .filter(|(i, _)| *i == 1 || *i == 4)
+        .for_each(|(_, face)| face.reverse());
+    assert_eq!(mesh.shell_condition(), ShellCondition::Regular);
+
+    mesh.orient_faces(true);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert!(mesh.volume().near(&1.0));
+    assert_eq!(mesh.faces(), cube().faces());
+}
+
+#[test]
+fn orient_from_reversed_first_face() {
+    let mut mesh = cube();
+    mesh.face_iter_mut().take(1).for_each(|face| face.reverse());
+
+    let mut oriented = mesh.clone();
+    oriented.orient_faces(false);
+    assert_eq!(oriented.shell_condition(), ShellCondition::Closed);
+    // the first face keeps its winding, so the cube is inverted.
+    assert!(oriented.volume().near(&-1.0));
+
+    mesh.orient_faces(true);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert!(mesh.volume().near(&1.0));
+}
+
+#[test]
+fn orient_open_components() {
+    // two separated squares, each of which is divided into two triangles
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: cube_positions(),
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 3], [0, 2, 3], [4, 5, 7], [4, 7, 6]]),
+    );
+    mesh.orient_faces(true);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    let faces = mesh.faces();
+    assert_eq!(faces[0].iter().map(|v| v.pos).collect::<Vec<_>>(), [0, 1, 3]);
+    assert_eq!(faces[1].iter().map(|v| v.pos).collect::<Vec<_>>(), [3, 2, 0]);
+    assert_eq!(faces[3].iter().map(|v| v.pos).collect::<Vec<_>>(), [4, 7, 6]);
+}
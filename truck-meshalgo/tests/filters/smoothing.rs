@@ -0,0 +1,63 @@
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+const N: usize = 20;
+
+fn roughness(positions: &[Point3]) -> f64 {
+    (1..N - 1)
+        .flat_map(|i| (1..N - 1).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let sum = [(i - 1, j), (i + 1, j), (i, j - 1), (i, j + 1)]
+                .iter()
+                .fold(Vector3::zero(), |sum, (k, l)| {
+                    sum + positions[k * N + l].to_vec()
+                });
+            positions[i * N + j].distance2(Point3::from_vec(sum / 4.0))
+        })
+        .sum()
+}
+
+#[test]
+fn smooth_perturbed_plane() {
+    let positions: Vec<Point3> = (0..N * N)
+        .map(|k| {
+            let (i, j) = (k / N, k % N);
+            let z = 0.05 * hash::take_one_unit([i as f64, j as f64]).x;
+            Point3::new(i as f64 / N as f64, j as f64 / N as f64, z)
+        })
+        .collect();
+    let faces: Vec<[StandardVertex; 4]> = (1..N)
+        .flat_map(|i| (1..N).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            [
+                ((i - 1) * N + j - 1, None, Some(0)).into(),
+                (i * N + j - 1, None, Some(0)).into(),
+                (i * N + j, None, Some(0)).into(),
+                ((i - 1) * N + j, None, Some(0)).into(),
+            ]
+        })
+        .collect();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: positions.clone(),
+            normals: vec![Vector3::unit_z()],
+            ..Default::default()
+        },
+        Faces::from_tri_and_quad_faces(Vec::new(), faces),
+    );
+    let org_roughness = roughness(mesh.positions());
+    mesh.smooth_laplacian(10, 0.5);
+    assert!(roughness(mesh.positions()) < 0.2 * org_roughness);
+
+    // the boundary is fixed.
+    (0..N * N)
+        .filter(|k| k / N == 0 || k / N == N - 1 || k % N == 0 || k % N == N - 1)
+        .for_each(|k| assert_eq!(mesh.positions()[k], positions[k]));
+    // the normals are recomputed.
+    assert!(!mesh.normals().is_empty());
+    mesh.face_iter().flatten().for_each(|v| {
+        let normal = mesh.normals()[v.nor.unwrap()];
+        assert!(normal.magnitude().near(&1.0));
+        assert!(normal.z > 0.5);
+    });
+}
@@ -106,3 +106,53 @@ fn quadrangulate_test() {
     tri_mesh.quadrangulate(TOLERANCE, TOLERANCE);
     assert_eq!(tri_mesh.faces(), quad_mesh.faces());
 }
+
+#[test]
+fn triangulate_concave_pentagon() {
+    // the pentagon notched at the vertex 3, in a tilted plane
+    let polygon = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [2.0, 1.0], [0.0, 4.0]];
+    let axis0 = Vector3::new(1.0, 0.0, 1.0).normalize();
+    let axis1 = Vector3::unit_y();
+    let mut positions: Vec<Point3> = polygon
+        .iter()
+        .map(|[x, y]| Point3::origin() + axis0 * *x + axis1 * *y)
+        .collect();
+    positions.extend([
+        Point3::new(10.0, 0.0, 0.0),
+        Point3::new(11.0, 0.0, 0.0),
+        Point3::new(11.0, 1.0, 0.0),
+        Point3::new(10.0, 1.0, 0.0),
+    ]);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[&[0, 1, 2, 3, 4][..], &[5, 6, 7, 8]]),
+    );
+    mesh.triangulate();
+    let faces = mesh.faces();
+    assert!(faces.quad_faces().is_empty());
+    assert!(faces.other_faces().is_empty());
+    assert_eq!(faces.tri_faces().len(), 5);
+
+    // the quadrangle is divided by the diagonal from the first vertex
+    let pos = |tri: &[StandardVertex; 3]| tri.map(|v| v.pos);
+    assert_eq!(pos(&faces.tri_faces()[0]), [5, 6, 7]);
+    assert_eq!(pos(&faces.tri_faces()[1]), [5, 7, 8]);
+
+    // the triangles of the pentagon tile it without overlap:
+    // all of them have the same orientation as the pentagon, and the sum of their areas is
+    // equal to the area of the pentagon.
+    let normal = axis0.cross(axis1);
+    let area: f64 = faces.tri_faces()[2..]
+        .iter()
+        .map(|tri| {
+            let [p0, p1, p2] = tri.map(|v| mesh.positions()[v.pos]);
+            let signed_area = (p1 - p0).cross(p2 - p0).dot(normal) / 2.0;
+            assert!(signed_area > 0.0, "{tri:?}");
+            signed_area
+        })
+        .sum();
+    assert!(area.near(&10.0));
+}
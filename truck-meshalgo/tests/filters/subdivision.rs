@@ -1,5 +1,7 @@
-use truck_meshalgo::filters::*;
+use std::f64::consts::PI;
+use truck_meshalgo::{analyzers::*, filters::*};
 use truck_polymesh::*;
+use truck_topology::shell::ShellCondition;
 
 #[test]
 fn loop_subdivision() {
@@ -38,3 +40,79 @@ fn loop_subdivision() {
         ],
     );
 }
+
+/// the side of the prism over the square `[-1, 1]^2` filleted by the radius `0.5`
+fn filleted_square_tube() -> PolygonMesh {
+    const N: usize = 4;
+    let profile = (0..4)
+        .flat_map(|k| {
+            let center = match k {
+                0 => Point2::new(0.5, 0.5),
+                1 => Point2::new(-0.5, 0.5),
+                2 => Point2::new(-0.5, -0.5),
+                _ => Point2::new(0.5, -0.5),
+            };
+            (0..=N).map(move |j| {
+                let angle = PI / 2.0 * (k as f64 + j as f64 / N as f64);
+                let normal = Vector2::new(f64::cos(angle), f64::sin(angle));
+                (center + 0.5 * normal, normal)
+            })
+        })
+        .collect::<Vec<_>>();
+    let len = profile.len();
+    let positions = [0.0, 1.0]
+        .iter()
+        .flat_map(|z| profile.iter().map(move |(p, _)| Point3::new(p.x, p.y, *z)))
+        .collect();
+    let normals = profile
+        .iter()
+        .map(|(_, n)| Vector3::new(n.x, n.y, 0.0))
+        .collect();
+    let faces = Faces::from_iter((0..len).flat_map(|i| {
+        let j = (i + 1) % len;
+        let v = |pos: usize| (pos, None, Some(pos % len));
+        [
+            [v(i), v(j), v(len + j)],
+            [v(i), v(len + j), v(len + i)],
+        ]
+    }));
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn adaptive_refine() {
+    let mut mesh = filleted_square_tube();
+    assert_eq!(mesh.faces().len(), 40);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    let (planes, _) = mesh.extract_planes(0.1);
+    assert_eq!(planes.len(), 8);
+    let flat_faces = planes
+        .iter()
+        .map(|i| mesh.tri_faces()[*i])
+        .collect::<Vec<_>>();
+    let old_len = mesh.positions().len();
+
+    mesh.adaptive_refine(0.1);
+    // each triangle on the fillets is divided into three triangles.
+    assert_eq!(mesh.faces().len(), 8 + 32 * 3);
+    // no cracks
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    // the flat faces are left untouched.
+    flat_faces
+        .iter()
+        .for_each(|face| assert!(mesh.tri_faces().contains(face)));
+    // the new vertices are on the fillets.
+    assert!(mesh.positions().len() > old_len);
+    mesh.positions()[old_len..].iter().for_each(|p| {
+        let center = Point2::new(0.5 * f64::signum(p.x), 0.5 * f64::signum(p.y));
+        let radius = Point2::new(p.x, p.y).distance(center);
+        assert!(f64::abs(radius - 0.5) < 1.0e-3, "{p:?}");
+    });
+}
@@ -0,0 +1,62 @@
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+#[path = "../common/mod.rs"]
+mod common;
+
+fn in_unit_square(uv: &Vector2) -> bool {
+    (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y)
+}
+
+#[test]
+fn generate_planar_uv_test() {
+    let mut mesh = common::shapes::sphere(Point3::new(1.0, 2.0, 3.0), 2.0, 16, 16);
+    mesh.generate_planar_uv(Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(mesh.uv_coords().len(), mesh.positions().len());
+    assert!(mesh.uv_coords().iter().all(in_unit_square));
+    // the bounding box of the texture coordinates is the unit square.
+    let bdd_box: BoundingBox<Vector2> = mesh.uv_coords().iter().collect();
+    assert!(bdd_box.min().near(&Vector2::new(0.0, 0.0)));
+    assert!(bdd_box.max().near(&Vector2::new(1.0, 1.0)));
+    mesh.face_iter().flatten().for_each(|v| {
+        assert_eq!(v.uv, Some(v.pos));
+    });
+
+    // the points on the same line parallel to the axis have the same coordinate.
+    let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+    mesh.face_iter().flatten().for_each(|v| {
+        mesh.face_iter().flatten().for_each(|w| {
+            let vec = mesh.positions()[w.pos] - mesh.positions()[v.pos];
+            if vec.cross(axis).so_small() {
+                assert!(mesh.uv_coords()[v.pos].near(&mesh.uv_coords()[w.pos]));
+            }
+        })
+    });
+}
+
+#[test]
+fn generate_per_face_uv_test() {
+    let mut mesh = common::shapes::sphere(Point3::new(1.0, 2.0, 3.0), 2.0, 16, 16);
+    mesh.generate_planar_uv(Vector3::unit_z());
+    mesh.generate_per_face_uv();
+    let num_vertices = mesh.face_iter().map(|face| face.len()).sum::<usize>();
+    // the old coordinates are removed.
+    assert_eq!(mesh.uv_coords().len(), num_vertices);
+    assert!(mesh.uv_coords().iter().all(in_unit_square));
+    let mut used = vec![false; num_vertices];
+    mesh.face_iter().for_each(|face| {
+        face.iter().for_each(|v| {
+            let idx = v.uv.unwrap();
+            assert!(idx < num_vertices);
+            // each vertex of each face has its own coordinate.
+            assert!(!used[idx]);
+            used[idx] = true;
+        });
+        // the first vertex is the origin of the local frame.
+        assert!(mesh.uv_coords()[face[0].uv.unwrap()].y.so_small());
+        // the length of the edges are kept up to the scale.
+        let uv = |i: usize| mesh.uv_coords()[face[i].uv.unwrap()];
+        let pos = |i: usize| mesh.positions()[face[i].pos];
+        let ratio = uv(0).distance(uv(1)) / pos(0).distance(pos(1));
+        assert!(uv(1).distance(uv(2)).near(&(pos(1).distance(pos(2)) * ratio)));
+    });
+}
@@ -1,4 +1,5 @@
 use truck_meshalgo::prelude::*;
 use truck_modeling::*;
 
+mod structured_mesh;
 mod triangulation;
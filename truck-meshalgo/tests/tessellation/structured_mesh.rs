@@ -0,0 +1,72 @@
+use super::*;
+
+fn unit_sphere() -> NURBSSurface<Vector4> {
+    let knot_vec0 = KnotVec::bezier_knot(3);
+    let knot_vec1 = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    let mut v = vec![vec![Vector4::zero(); 7]; 4];
+    v[0][0] = Vector4::new(0.0, 0.0, 1.0, 1.0);
+    v[0][1] = v[0][0] / 3.0;
+    v[0][2] = v[0][1];
+    v[0][3] = v[0][0];
+    v[0][4] = v[0][1];
+    v[0][5] = v[0][1];
+    v[0][6] = v[0][0];
+    v[1][0] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    v[1][1] = Vector4::new(2.0, 4.0, 1.0, 1.0) / 9.0;
+    v[1][2] = Vector4::new(-2.0, 4.0, 1.0, 1.0) / 9.0;
+    v[1][3] = Vector4::new(-2.0, 0.0, 1.0, 1.0) / 3.0;
+    v[1][4] = Vector4::new(-2.0, -4.0, 1.0, 1.0) / 9.0;
+    v[1][5] = Vector4::new(2.0, -4.0, 1.0, 1.0) / 9.0;
+    v[1][6] = Vector4::new(2.0, 0.0, 1.0, 1.0) / 3.0;
+    v[2][0] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    v[2][1] = Vector4::new(2.0, 4.0, -1.0, 1.0) / 9.0;
+    v[2][2] = Vector4::new(-2.0, 4.0, -1.0, 1.0) / 9.0;
+    v[2][3] = Vector4::new(-2.0, 0.0, -1.0, 1.0) / 3.0;
+    v[2][4] = Vector4::new(-2.0, -4.0, -1.0, 1.0) / 9.0;
+    v[2][5] = Vector4::new(2.0, -4.0, -1.0, 1.0) / 9.0;
+    v[2][6] = Vector4::new(2.0, 0.0, -1.0, 1.0) / 3.0;
+    v[3][0] = Vector4::new(0.0, 0.0, -1.0, 1.0);
+    v[3][1] = v[3][0] / 3.0;
+    v[3][2] = v[3][1];
+    v[3][3] = v[3][0];
+    v[3][4] = v[3][1];
+    v[3][5] = v[3][1];
+    v[3][6] = v[3][0];
+    NURBSSurface::new(BSplineSurface::new((knot_vec0, knot_vec1), v))
+}
+
+#[test]
+fn structured_mesh_from_sphere() {
+    const TOL: f64 = 0.01;
+    let sphere = unit_sphere();
+    let mesh = StructuredMesh::from_surface(&sphere, sphere.parameter_range(), TOL);
+    let (udiv, vdiv) = mesh.uv_division().unwrap();
+    assert_eq!((udiv[0], udiv[udiv.len() - 1]), (0.0, 1.0));
+    assert_eq!((vdiv[0], vdiv[vdiv.len() - 1]), (0.0, 1.0));
+    assert!(udiv.windows(2).all(|a| a[0] < a[1]));
+    assert!(vdiv.windows(2).all(|a| a[0] < a[1]));
+
+    let positions = mesh.positions();
+    assert_eq!(positions.len(), udiv.len());
+    positions.iter().zip(udiv).for_each(|(row, u)| {
+        assert_eq!(row.len(), vdiv.len());
+        row.iter().zip(vdiv).for_each(|(pt, v)| {
+            assert!((pt.to_vec().magnitude() - 1.0).abs() < TOL);
+            assert_near!(*pt, sphere.subs(*u, *v));
+        });
+    });
+
+    // the normals are perpendicular to the sphere except at the poles
+    let normals = mesh.normals().unwrap();
+    let last = positions.len() - 1;
+    positions[1..last]
+        .iter()
+        .zip(&normals[1..last])
+        .flat_map(|(prow, nrow)| prow.iter().zip(nrow))
+        .for_each(|(pt, normal)| assert_near!(normal.dot(pt.to_vec()).abs(), 1.0));
+
+    // the finer tolerance gives the finer division
+    let finer = StructuredMesh::from_surface(&sphere, sphere.parameter_range(), TOL / 10.0);
+    let (finer_udiv, finer_vdiv) = finer.uv_division().unwrap();
+    assert!(finer_udiv.len() * finer_vdiv.len() > udiv.len() * vdiv.len());
+}
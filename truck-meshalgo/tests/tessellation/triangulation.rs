@@ -48,3 +48,20 @@ fn large_number_meshing() {
     let torus: Solid = serde_json::from_slice(json.as_slice()).unwrap();
     let _ = torus.triangulation(1.0).to_polygon();
 }
+
+#[test]
+fn cone_is_closed() {
+    let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let v1 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let v2 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let wire: Wire = vec![builder::line(&v0, &v1), builder::line(&v1, &v2)].into();
+    let cone = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+
+    let poly = cone.triangulation(0.01).to_polygon();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+    poly.positions().iter().for_each(|pt| {
+        assert!(-TOLERANCE < pt.y && pt.y < 1.0 + TOLERANCE);
+        let radius = f64::hypot(pt.x, pt.z);
+        assert!(radius < 1.0 - pt.y + TOLERANCE, "{:?}", pt);
+    });
+}
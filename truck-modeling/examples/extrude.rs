@@ -0,0 +1,21 @@
+//! Modeling a box by extruding a square.
+
+use truck_modeling::*;
+
+fn main() {
+    let v0 = builder::vertex(Point3::new(-0.5, -0.5, -0.25));
+    let v1 = builder::vertex(Point3::new(0.5, -0.5, -0.25));
+    let v2 = builder::vertex(Point3::new(0.5, 0.5, -0.25));
+    let v3 = builder::vertex(Point3::new(-0.5, 0.5, -0.25));
+    let wire: Wire = vec![
+        builder::line(&v0, &v1),
+        builder::line(&v1, &v2),
+        builder::line(&v2, &v3),
+        builder::line(&v3, &v0),
+    ]
+    .into();
+    let cuboid = builder::extrude(&wire, Vector3::new(0.0, 0.0, 0.5));
+    assert!(cuboid.is_geometric_consistent());
+    let json = serde_json::to_vec_pretty(&cuboid).unwrap();
+    std::fs::write("extrude.json", &json).unwrap();
+}
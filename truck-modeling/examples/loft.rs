@@ -0,0 +1,53 @@
+//! Modeling a solid lofted from a circle to a square.
+//!
+//! Generated json file can be visualized by `simple-shape-viewer`, an example of `truck-rendimpl`.
+
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+/// the point at the angle `angle` around the y-axis
+fn point(radius: f64, y: f64, angle: f64) -> Point3 {
+    Point3::new(radius * f64::cos(angle), y, -radius * f64::sin(angle))
+}
+
+/// the circle divided into four arcs
+fn circle(radius: f64, y: f64) -> Wire {
+    let v: Vec<Vertex> = (0..4)
+        .map(|i| builder::vertex(point(radius, y, PI / 2.0 * i as f64)))
+        .collect();
+    (0..4)
+        .map(|i| {
+            let transit = point(radius, y, PI / 2.0 * i as f64 + PI / 4.0);
+            builder::circle_arc(&v[i], &v[(i + 1) % 4], transit)
+        })
+        .collect()
+}
+
+/// the square whose corners are on the circle of `radius`
+fn square(radius: f64, y: f64) -> Wire {
+    let v: Vec<Vertex> = (0..4)
+        .map(|i| builder::vertex(point(radius, y, PI / 2.0 * i as f64)))
+        .collect();
+    (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect()
+}
+
+fn main() {
+    // each section has four edges, and goes around the y-axis counterclockwise from +y.
+    let sections = [circle(1.0, 0.0), square(1.0, 1.0), square(0.6, 2.0)];
+    let mut shell = builder::loft(&sections);
+    assert!(shell.is_geometric_consistent());
+    // closes the both ends by the planes.
+    let caps: Vec<Face> = shell
+        .extract_boundaries()
+        .iter()
+        .map(|wire| builder::try_attach_plane(&[wire.inverse()]).unwrap())
+        .collect();
+    shell.extend(caps);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    let solid = Solid::new(vec![shell]);
+    assert!(solid.is_geometric_consistent());
+    let json = serde_json::to_vec_pretty(&solid).unwrap();
+    std::fs::write("loft.json", &json).unwrap();
+}
@@ -0,0 +1,25 @@
+//! Modeling a sphere, a cylinder and a torus by the primitive builders.
+//!
+//! Generated json files can be visualized by `simple-shape-viewer`, an example of `truck-rendimpl`.
+
+use truck_modeling::*;
+
+fn main() {
+    let sphere = builder::sphere(Point3::origin(), 0.5);
+    let cylinder = builder::cylinder(
+        Point3::new(0.0, -0.5, 0.0),
+        0.5,
+        Vector3::unit_y(),
+        1.0,
+    );
+    let torus = builder::torus(Point3::origin(), 0.75, 0.25, Vector3::unit_z());
+    for (solid, filename) in [
+        (sphere, "sphere.json"),
+        (cylinder, "cylinder.json"),
+        (torus, "torus.json"),
+    ] {
+        assert!(solid.is_geometric_consistent());
+        let json = serde_json::to_vec_pretty(&solid).unwrap();
+        std::fs::write(filename, &json).unwrap();
+    }
+}
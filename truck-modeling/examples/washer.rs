@@ -0,0 +1,27 @@
+//! Modeling a flanged washer by revolving an L-shaped profile.
+//!
+//! Generated json file can be visualized by `simple-shape-viewer`, an example of `truck-rendimpl`.
+
+use truck_modeling::*;
+
+fn main() {
+    // the L-shaped profile on the xy-plane, clockwise as seen from +z.
+    let points = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(1.2, 1.0, 0.0),
+        Point3::new(1.2, 0.2, 0.0),
+        Point3::new(2.0, 0.2, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+    ];
+    let vertices: Vec<Vertex> = points.iter().map(|pt| builder::vertex(*pt)).collect();
+    let wire: Wire = (0..vertices.len())
+        .map(|i| builder::line(&vertices[i], &vertices[(i + 1) % vertices.len()]))
+        .collect();
+    let shell = builder::revolve(&wire, Point3::origin(), Vector3::unit_y(), Rad(7.0));
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    let washer = Solid::new(vec![shell]);
+    assert!(washer.is_geometric_consistent());
+    let json = serde_json::to_vec_pretty(&washer).unwrap();
+    std::fs::write("washer.json", &json).unwrap();
+}
@@ -255,6 +255,64 @@ pub fn try_wire_homotopy(wire0: &Wire, wire1: &Wire) -> Result<Shell> {
     Ok(shell)
 }
 
+/// Returns a lofted shell through `sections`.
+///
+/// The corresponding edges of each pair of consecutive sections are connected by
+/// the homotopic surfaces as in [`try_wire_homotopy`], and the sections are shared by
+/// the adjacent faces. Returns an empty shell if there are less than two sections.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // three squares of the different sizes
+/// let square = |size: f64, y: f64| -> Wire {
+///     let v: Vec<Vertex> = [(1.0, 1.0), (1.0, -1.0), (-1.0, -1.0), (-1.0, 1.0)]
+///         .iter()
+///         .map(|(x, z)| builder::vertex(Point3::new(x * size, y, z * size)))
+///         .collect();
+///     (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect()
+/// };
+/// let sections = [square(1.0, 0.0), square(0.5, 1.0), square(1.5, 2.0)];
+/// let shell = builder::try_loft(&sections).unwrap();
+/// assert_eq!(shell.len(), 8);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// assert!(shell.is_geometric_consistent());
+/// let boundaries = shell.extract_boundaries();
+/// assert_eq!(boundaries.len(), 2);
+/// ```
+/// # Failures
+/// If the sections have different numbers of edges, then return `Error::NotSameNumberOfEdges`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+/// let v3 = builder::vertex(Point3::new(1.0, 0.0, 1.0));
+/// let v4 = builder::vertex(Point3::new(2.0, 0.0, 1.0));
+/// let wire0: Wire = vec![builder::line(&v0, &v1)].into();
+/// let wire1: Wire = vec![builder::line(&v2, &v3), builder::line(&v3, &v4)].into();
+/// assert!(matches!(
+///     builder::try_loft(&[wire0.clone(), wire0, wire1]),
+///     Err(Error::NotSameNumberOfEdges),
+/// ));
+/// ```
+pub fn try_loft(sections: &[Wire]) -> Result<Shell> {
+    if sections.iter().any(|wire| wire.len() != sections[0].len()) {
+        return Err(Error::NotSameNumberOfEdges);
+    }
+    sections.windows(2).try_fold(Shell::new(), |mut shell, pair| {
+        shell.append(&mut try_wire_homotopy(&pair[0], &pair[1])?);
+        Ok(shell)
+    })
+}
+
+/// Returns a lofted shell through `sections`.
+/// # Panics
+/// Panic occurs if the sections have different numbers of edges. See [`try_loft`] for the details.
+#[inline(always)]
+pub fn loft(sections: &[Wire]) -> Shell { try_loft(sections).unwrap_or_else(|e| panic!("{}", e)) }
+
 /// Creates a cone by R-sweeping.
 /// # Examples
 /// ```
@@ -316,7 +374,7 @@ pub fn cone<R: Into<Rad<f64>>>(wire: &Wire, axis: Vector3, angle: R) -> Shell {
         let mut new_wire = Wire::new();
         new_wire.push_back(edge.clone());
         new_wire.push_back(old_wire[1].clone());
-        let new_edge = if closed && i + 1 == shell.len() / new_wire.len() {
+        let new_edge = if closed && i + 1 == shell.len() / wire.len() {
             shell[0].boundaries()[0][0].inverse()
         } else {
             let curve = old_wire[2].oriented_curve();
@@ -350,6 +408,67 @@ pub fn cone<R: Into<Rad<f64>>>(wire: &Wire, axis: Vector3, angle: R) -> Shell {
     shell
 }
 
+/// Revolves the profile `wire` around the axis through `origin` by `angle`.
+/// # Details
+/// If the absolute value of `angle` is 2π rad or more, the seam edges are merged and the
+/// result is closed in the direction of the rotation. Otherwise, the result is open.
+/// If the front or the back vertex of `wire` is on the axis, the degenerate edges are
+/// removed as in [`cone`].
+/// # Remarks
+/// `axis` is normalized in this function, so it must not be zero.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // the rectangle profile
+/// let v0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 1.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(2.0, 1.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v3),
+///     builder::line(&v3, &v0),
+/// ].into();
+///
+/// // the partial revolution is open.
+/// let origin = Point3::origin();
+/// let shell = builder::revolve(&wire, origin, Vector3::unit_y(), Rad(PI / 2.0));
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// assert_eq!(shell.extract_boundaries().len(), 2);
+///
+/// // the full revolution is watertight.
+/// let shell = builder::revolve(&wire, origin, Vector3::unit_y(), Rad(2.0 * PI));
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// let ring = Solid::new(vec![shell]);
+/// assert!(ring.is_geometric_consistent());
+///
+/// // the profile whose back vertex is on the axis
+/// let v4 = builder::vertex(Point3::new(0.0, 2.0, 0.0));
+/// let wire: Wire = vec![builder::line(&v1, &v4)].into();
+/// let shell = builder::revolve(&wire, origin, Vector3::unit_y(), Rad(2.0 * PI));
+/// assert!(shell.face_iter().all(|face| face.boundaries()[0].len() == 3));
+/// assert_eq!(shell.extract_boundaries().len(), 1);
+/// ```
+pub fn revolve<R: Into<Rad<f64>>>(wire: &Wire, origin: Point3, axis: Vector3, angle: R) -> Shell {
+    let axis = axis.normalize();
+    let on_axis = |vertex: &Vertex| (vertex.get_point() - origin).cross(axis).so_small();
+    match (wire.front_vertex(), wire.back_vertex()) {
+        (Some(front), _) if on_axis(front) => cone(wire, axis, angle),
+        (_, Some(back)) if on_axis(back) => {
+            // `cone` revolves the wire starting from the axis.
+            let mut shell = cone(&wire.inverse(), axis, angle);
+            shell.face_iter_mut().for_each(|face| {
+                face.invert();
+            });
+            shell
+        }
+        _ => rsweep(wire, origin, axis, angle),
+    }
+}
+
 /// Try attatiching a plane whose boundary is `wire`.
 /// # Examples
 /// ```
@@ -419,6 +538,201 @@ pub fn try_attach_plane(wires: &[Wire]) -> Result<Face> {
     Ok(Face::try_new(wires.to_owned(), surface)?)
 }
 
+/// Extrudes the planar closed `wire` by the vector `dir` and creates a prism.
+///
+/// The bottom face is attached to `wire` by [`try_attach_plane`], and the side faces and
+/// the top face are created by [`tsweep`]. The orientation of the bottom face is chosen so
+/// that the boundary of the prism faces outward, regardless of the direction of `wire`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v0),
+/// ].into();
+/// let prism = builder::try_extrude(&wire, Vector3::new(0.0, 0.0, 2.0)).unwrap();
+/// assert!(prism.is_geometric_consistent());
+/// let shell = &prism.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// assert_eq!(shell.len(), 5);
+///
+/// // the bottom face faces downward.
+/// let normal = shell[0].oriented_surface().normal(0.0, 0.0);
+/// assert!(normal.near(&-Vector3::unit_z()));
+///
+/// // the same prism by the inverse wire
+/// let prism = builder::try_extrude(&wire.inverse(), Vector3::new(0.0, 0.0, 2.0)).unwrap();
+/// let normal = prism.boundaries()[0][0].oriented_surface().normal(0.0, 0.0);
+/// assert!(normal.near(&-Vector3::unit_z()));
+/// ```
+/// # Failures
+/// - If `wire` is not closed or not in one plane, then return `Error::WireNotInOnePlane`.
+/// - If `dir` is zero or parallel to the plane of `wire`, then return `Error::DegenerateExtrusion`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v0),
+/// ].into();
+/// assert_eq!(
+///     builder::try_extrude(&wire, Vector3::zero()).unwrap_err(),
+///     Error::DegenerateExtrusion,
+/// );
+/// assert_eq!(
+///     builder::try_extrude(&wire, Vector3::unit_x()).unwrap_err(),
+///     Error::DegenerateExtrusion,
+/// );
+/// ```
+pub fn try_extrude(wire: &Wire, dir: Vector3) -> Result<Solid> {
+    let mut face = try_attach_plane(std::slice::from_ref(wire))?;
+    let normal = match face.oriented_surface() {
+        Surface::Plane(plane) => plane.normal(),
+        _ => unreachable!(),
+    };
+    let height = normal.dot(dir);
+    if height.so_small() {
+        return Err(Error::DegenerateExtrusion);
+    } else if height < 0.0 {
+        face.invert();
+    }
+    Ok(tsweep(&face, dir))
+}
+
+/// Extrudes the planar closed `wire` by the vector `dir` and creates a prism.
+/// # Panics
+/// Panic occurs if the extrusion fails. See [`try_extrude`] for the details.
+#[inline(always)]
+pub fn extrude(wire: &Wire, dir: Vector3) -> Solid {
+    try_extrude(wire, dir).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Returns a unit vector perpendicular to `axis`.
+fn perpendicular(axis: Vector3) -> Vector3 {
+    let other = match axis.x.abs() < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    axis.cross(other).normalize()
+}
+
+/// Creates a sphere by rotating a semicircle with [`cone`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let center = Point3::new(1.0, 2.0, 3.0);
+/// let sphere = builder::sphere(center, 2.0);
+/// assert!(sphere.is_geometric_consistent());
+/// let shell = &sphere.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # for face in shell.face_iter() {
+/// #     let surface = face.get_surface();
+/// #     for i in 0..=10 {
+/// #         for j in 0..=10 {
+/// #             let pt = surface.subs(i as f64 / 10.0, j as f64 / 10.0);
+/// #             assert_near!(pt.distance(center), 2.0);
+/// #         }
+/// #     }
+/// # }
+///
+/// // serialization round trip
+/// let json = serde_json::to_vec(&sphere).unwrap();
+/// let solid: Solid = serde_json::from_slice(&json).unwrap();
+/// assert!(solid.is_geometric_consistent());
+/// assert_eq!(serde_json::to_vec(&solid).unwrap(), json);
+/// ```
+pub fn sphere(center: Point3, radius: f64) -> Solid {
+    let v0 = vertex(center + radius * Vector3::unit_y());
+    let v1 = vertex(center - radius * Vector3::unit_y());
+    let wire: Wire = vec![circle_arc(&v0, &v1, center + radius * Vector3::unit_z())].into();
+    let shell = cone(&wire, Vector3::unit_y(), Rad(7.0));
+    Solid::new(vec![shell])
+}
+
+/// Creates a cylinder by sweeping a disk whose center is `base_center`
+/// by the vector `height * axis`.
+/// # Remarks
+/// `axis` is normalized in this function, so it must not be zero. `height` must be positive.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let base_center = Point3::new(0.0, 1.0, 0.0);
+/// let axis = Vector3::new(1.0, 1.0, 0.0);
+/// let cylinder = builder::cylinder(base_center, 0.5, axis, 2.0);
+/// assert!(cylinder.is_geometric_consistent());
+/// let shell = &cylinder.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// // the side, the bottom and the top
+/// assert_eq!(shell.len(), 4);
+/// # let axis = axis.normalize();
+/// # for vertex in shell.vertex_iter() {
+/// #     let vec = vertex.get_point() - base_center;
+/// #     assert_near!((vec - axis * vec.dot(axis)).magnitude(), 0.5);
+/// # }
+///
+/// // serialization round trip
+/// let json = serde_json::to_vec(&cylinder).unwrap();
+/// let solid: Solid = serde_json::from_slice(&json).unwrap();
+/// assert!(solid.is_geometric_consistent());
+/// assert_eq!(serde_json::to_vec(&solid).unwrap(), json);
+/// ```
+pub fn cylinder(base_center: Point3, radius: f64, axis: Vector3, height: f64) -> Solid {
+    let axis = axis.normalize();
+    let v = vertex(base_center + radius * perpendicular(axis));
+    let circle = rsweep(&v, base_center, axis, Rad(7.0));
+    let disk = try_attach_plane(&[circle]).unwrap();
+    tsweep(&disk, height * axis)
+}
+
+/// Creates a torus by rotating a circle of the radius `minor_r` around `axis` through `center`.
+///
+/// The distance between `center` and the center of the rotated circle is `major_r`.
+/// # Remarks
+/// `axis` is normalized in this function, so it must not be zero.
+/// `minor_r` must be less than `major_r`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let center = Point3::new(0.0, 0.0, 1.0);
+/// let axis = Vector3::unit_z();
+/// let torus = builder::torus(center, 2.0, 0.5, axis);
+/// assert!(torus.is_geometric_consistent());
+/// let shell = &torus.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// # for face in shell.face_iter() {
+/// #     let surface = face.get_surface();
+/// #     for i in 0..=10 {
+/// #         for j in 0..=10 {
+/// #             let vec = surface.subs(i as f64 / 10.0, j as f64 / 10.0) - center;
+/// #             let tmp = f64::sqrt(vec.x * vec.x + vec.y * vec.y) - 2.0;
+/// #             assert_near!(tmp * tmp + vec.z * vec.z, 0.25);
+/// #         }
+/// #     }
+/// # }
+///
+/// // serialization round trip
+/// let json = serde_json::to_vec(&torus).unwrap();
+/// let solid: Solid = serde_json::from_slice(&json).unwrap();
+/// assert!(solid.is_geometric_consistent());
+/// assert_eq!(serde_json::to_vec(&solid).unwrap(), json);
+/// ```
+pub fn torus(center: Point3, major_r: f64, minor_r: f64, axis: Vector3) -> Solid {
+    let axis = axis.normalize();
+    let dir = perpendicular(axis);
+    let circle_center = center + major_r * dir;
+    let v = vertex(circle_center + minor_r * axis);
+    let circle = rsweep(&v, circle_center, axis.cross(dir), Rad(7.0));
+    let shell = rsweep(&circle, center, axis, Rad(7.0));
+    Solid::new(vec![shell])
+}
+
 /// Returns another topology whose points, curves, and surfaces are cloned.
 #[inline(always)]
 pub fn clone<T: Mapped<Point3, Curve, Surface>>(elem: &T) -> T { elem.topological_clone() }
@@ -434,12 +748,66 @@ pub fn transformed<T: Mapped<Point3, Curve, Surface>>(elem: &T, mat: Matrix4) ->
 }
 
 /// Returns a translated vertex, edge, wire, face, shell or solid.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::new(1.0, 0.0, 0.0), Vector3::unit_z(), Rad(7.0));
+/// let disk = builder::try_attach_plane(&[circle]).unwrap();
+///
+/// // the translations compose additively.
+/// let (a, b) = (Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 0.5, 2.0));
+/// let disk0 = builder::translated(&builder::translated(&disk, a), b);
+/// let disk1 = builder::translated(&disk, a + b);
+/// assert!(disk.is_geometric_consistent());
+/// assert!(disk0.is_geometric_consistent());
+/// for (edge0, edge1) in disk0.edge_iter().zip(disk1.edge_iter()) {
+///     assert!(edge0.front().get_point().near(&edge1.front().get_point()));
+///     let (curve0, curve1) = (edge0.get_curve(), edge1.get_curve());
+///     for i in 0..=10 {
+///         let t = i as f64 / 10.0;
+///         assert!(curve0.subs(t).near(&curve1.subs(t)));
+///     }
+/// }
+/// let surface = disk0.get_surface();
+/// assert!(surface.subs(0.5, 0.5).near(&(disk.get_surface().subs(0.5, 0.5) + a + b)));
+/// ```
 #[inline(always)]
 pub fn translated<T: Mapped<Point3, Curve, Surface>>(elem: &T, vector: Vector3) -> T {
     transformed(elem, Matrix4::from_translation(vector))
 }
 
 /// Returns a rotated vertex, edge, wire, face, shell or solid.
+/// # Remarks
+/// `axis` must be normalized.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the rotation by 2π is the identity.
+/// let origin = Point3::new(0.5, -1.0, 2.0);
+/// let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+/// let rotated = builder::rotated(&cube, origin, axis, Rad(2.0 * PI));
+/// assert!(rotated.is_geometric_consistent());
+/// let shell0 = &cube.boundaries()[0];
+/// let shell1 = &rotated.boundaries()[0];
+/// for (v0, v1) in shell0.vertex_iter().zip(shell1.vertex_iter()) {
+///     assert!(v0.get_point().near(&v1.get_point()));
+/// }
+/// for (face0, face1) in shell0.face_iter().zip(shell1.face_iter()) {
+///     let (surface0, surface1) = (face0.get_surface(), face1.get_surface());
+///     assert!(surface0.subs(0.3, 0.7).near(&surface1.subs(0.3, 0.7)));
+/// }
+///
+/// // the rotation by π / 2 around the z-axis
+/// let v = builder::rotated(&v, Point3::new(1.0, 0.0, 0.0), Vector3::unit_z(), Rad(PI / 2.0));
+/// assert!(v.get_point().near(&Point3::new(1.0, -1.0, 0.0)));
+/// ```
 #[inline(always)]
 pub fn rotated<T: Mapped<Point3, Curve, Surface>>(
     elem: &T,
@@ -454,6 +822,22 @@ pub fn rotated<T: Mapped<Point3, Curve, Surface>>(
 }
 
 /// Returns a scaled vertex, edge, wire, face, shell or solid.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 1.0, 1.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the cuboid [1, 3] x [1, 2] x [1, 4]
+/// let cuboid = builder::scaled(&cube, Point3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 1.0, 3.0));
+/// assert!(cuboid.is_geometric_consistent());
+/// let bdb: BoundingBox<Point3> =
+///     cuboid.boundaries()[0].vertex_iter().map(|v| v.get_point()).collect();
+/// assert_near!(*bdb.min(), Point3::new(1.0, 1.0, 1.0));
+/// assert_near!(*bdb.max(), Point3::new(3.0, 2.0, 4.0));
+/// ```
 #[inline(always)]
 pub fn scaled<T: Mapped<Point3, Curve, Surface>>(elem: &T, origin: Point3, scalars: Vector3) -> T {
     let mat0 = Matrix4::from_translation(-origin.to_vec());
@@ -462,6 +846,63 @@ pub fn scaled<T: Mapped<Point3, Curve, Surface>>(elem: &T, origin: Point3, scala
     transformed(elem, mat2 * mat1 * mat0)
 }
 
+/// Returns a vertex, edge, wire, face, shell or solid mirrored across the plane
+/// through `plane_origin` with the normal `plane_normal`.
+///
+/// Since the reflection reverses the handedness, the faces are inverted so that
+/// the normals of the mirrored faces keep pointing outward.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let wire: Wire = vec![builder::line(&v0, &v1), builder::line(&v1, &v2)].into();
+/// let cone = Solid::new(vec![builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI))]);
+///
+/// // mirror across the xz-plane
+/// let mirrored = builder::mirrored(&cone, Point3::origin(), Vector3::unit_y());
+/// assert!(mirrored.is_geometric_consistent());
+/// let shell = &mirrored.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// assert!(shell.vertex_iter().all(|v| v.get_point().y < TOLERANCE));
+/// assert!(shell.vertex_iter().any(|v| v.get_point().near(&Point3::new(0.0, -1.0, 0.0))));
+///
+/// // the normals point outward from the center of the mirrored cone.
+/// let center = Point3::new(0.0, -0.25, 0.0);
+/// let outward = |face: &Face| {
+///     let surface = face.oriented_surface();
+///     let (pt, normal) = (surface.subs(0.5, 0.5), surface.normal(0.5, 0.5));
+///     (pt - center).dot(normal) > 0.0
+/// };
+/// assert!(shell.face_iter().all(|face| outward(face)));
+///
+/// // the mere transformation by the reflection turns the cone inside out.
+/// let mat = Matrix4::from_nonuniform_scale(1.0, -1.0, 1.0);
+/// let inside_out = builder::transformed(&cone, mat);
+/// assert!(inside_out.boundaries()[0].face_iter().all(|face| !outward(face)));
+/// ```
+pub fn mirrored<T: Mirrored<Point3, Curve, Surface>>(
+    elem: &T,
+    plane_origin: Point3,
+    plane_normal: Vector3,
+) -> T {
+    let n = plane_normal.normalize();
+    let reflection = Matrix3::from_cols(
+        Vector3::unit_x() - 2.0 * n.x * n,
+        Vector3::unit_y() - 2.0 * n.y * n,
+        Vector3::unit_z() - 2.0 * n.z * n,
+    );
+    let translation = 2.0 * plane_origin.to_vec().dot(n) * n;
+    let mat = Matrix4::from_translation(translation) * Matrix4::from(reflection);
+    elem.mirrored(
+        &move |pt: &Point3| mat.transform_point(*pt),
+        &move |curve: &Curve| curve.transformed(mat),
+        &move |surface: &Surface| surface.transformed(mat),
+    )
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by a vector.
 /// # Examples
 /// ```
@@ -519,6 +960,12 @@ pub fn tsweep<T: Sweep<Point3, Curve, Surface>>(elem: &T, vector: Vector3) -> T:
                     curve1.non_rationalized().clone(),
                 )))
             }
+            (Curve::CircleArc(curve0), Curve::CircleArc(curve1)) => {
+                Surface::NURBSSurface(NURBSSurface::new(BSplineSurface::homotopy(
+                    curve0.to_nurbs().into_non_rationalized(),
+                    curve1.to_nurbs().into_non_rationalized(),
+                )))
+            }
             (Curve::IntersectionCurve(_), Curve::IntersectionCurve(_)) => unimplemented!(),
             _ => unreachable!(),
         },
@@ -717,3 +1164,45 @@ fn partial_torus() {
     let torus = rsweep(&face, Point3::origin(), Vector3::unit_z(), Rad(-5.0));
     assert!(torus.is_geometric_consistent());
 }
+
+#[test]
+fn transformed_revolution() {
+    let cylinder = cylinder(Point3::origin(), 1.0, Vector3::unit_z(), 2.0);
+    let cylinder = translated(&cylinder, Vector3::new(1.0, 2.0, 3.0));
+    assert!(cylinder.is_geometric_consistent());
+    let sphere = sphere(Point3::new(1.0, 0.0, 0.0), 2.0);
+    let sphere = rotated(&sphere, Point3::origin(), Vector3::unit_y(), Rad(1.0));
+    assert!(sphere.is_geometric_consistent());
+}
+
+#[test]
+fn cone_with_three_edges() {
+    // The seam of the first faces was not merged if the wire does not consist of two edges.
+    let v: Vec<Vertex> = [(0.0, 2.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)]
+        .iter()
+        .map(|(x, y)| vertex(Point3::new(*x, *y, 0.0)))
+        .collect();
+    let wire: Wire = (0..3).map(|i| line(&v[i], &v[i + 1])).collect();
+    let shell = cone(&wire, Vector3::unit_y(), Rad(2.0 * PI.0));
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    let solid = Solid::new(vec![shell]);
+    assert!(solid.is_geometric_consistent());
+}
+
+#[test]
+fn circle_arc_edge() {
+    let arc = CircleArc::new(Point3::origin(), 1.0, Vector3::unit_z(), (0.0, 2.0));
+    let v0 = vertex(arc.front());
+    let v1 = vertex(arc.back());
+    let edge = Edge::new(&v0, &v1, Curve::CircleArc(arc));
+    assert!(edge.is_geometric_consistent());
+    let face = tsweep(&edge, Vector3::unit_z());
+    assert!(face.is_geometric_consistent());
+    let moved = translated(&edge, Vector3::new(1.0, 2.0, 3.0));
+    assert!(matches!(moved.get_curve(), Curve::CircleArc(_)));
+    assert!(moved.is_geometric_consistent());
+    // the image of the circle is an ellipse
+    let scaled = scaled(&edge, Point3::origin(), Vector3::new(2.0, 1.0, 1.0));
+    assert!(matches!(scaled.get_curve(), Curve::NURBSCurve(_)));
+    assert!(scaled.is_geometric_consistent());
+}
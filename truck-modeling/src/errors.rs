@@ -11,9 +11,31 @@ pub enum Error {
     #[error("cannot attach a plane to a wire that is not on one plane.")]
     WireNotInOnePlane,
     /// tried to create homotopy for two wires with different numbers of edges.
-    /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
+    /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html),
+    /// [`builder::try_loft`](../builder/fn.try_loft.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to extrude a wire by a vector which is zero or parallel to the plane of the wire.
+    /// cf. [`builder::try_extrude`](../builder/fn.try_extrude.html)
+    #[error("The extrusion vector must not be zero or parallel to the plane of the wire.")]
+    DegenerateExtrusion,
+    /// tried to section a solid whose faces are not planar or whose edges are not lines.
+    /// cf. [`Section::section`](../trait.Section.html#tymethod.section)
+    #[error("The faces and edges of the sectioned solid must be planes and lines.")]
+    UnsupportedSectionGeometry,
+    /// a boundary of a face crosses the section plane more than twice,
+    /// or a hole of the section is not surrounded by its outer boundary.
+    /// cf. [`Section::section`](../trait.Section.html#tymethod.section)
+    #[error("The section of the solid is too complex.")]
+    TooComplexSection,
+    /// the json is broken or does not represent the shape.
+    /// cf. [`json::from_json`](../json/fn.from_json.html)
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
+    /// the `format_version` of the json is not supported.
+    /// cf. [`json::from_json`](../json/fn.from_json.html)
+    #[error("The format version {0} is not supported.")]
+    UnsupportedFormatVersion(String),
 }
 
 #[test]
@@ -31,6 +53,21 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::DegenerateExtrusion).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::UnsupportedSectionGeometry).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::TooComplexSection).unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::InvalidJson("EOF while parsing an object at line 1 column 1".to_string())
+    )
+    .unwrap();
+    writeln!(
+        &mut std::io::stderr(),
+        "{}\n",
+        Error::UnsupportedFormatVersion("0.1-beta".to_string())
+    )
+    .unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
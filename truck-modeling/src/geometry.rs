@@ -48,6 +48,8 @@ pub enum Leader {
 pub enum Curve {
     /// line
     Line(Line<Point3>),
+    /// circular arc
+    CircleArc(CircleArc),
     /// 3-dimensional B-spline curve
     BSplineCurve(BSplineCurve<Point3>),
     /// 3-dimensional NURBS curve
@@ -60,6 +62,7 @@ macro_rules! derive_curve_method {
     ($curve: expr, $method: expr, $($ver: ident),*) => {
         match $curve {
             Curve::Line(got) => $method(got, $($ver), *),
+            Curve::CircleArc(got) => $method(got, $($ver), *),
             Curve::BSplineCurve(got) => $method(got, $($ver), *),
             Curve::NURBSCurve(got) => $method(got, $($ver), *),
             Curve::IntersectionCurve(got) => $method(got, $($ver), *),
@@ -71,6 +74,7 @@ macro_rules! derive_curve_self_method {
     ($curve: expr, $method: expr, $($ver: ident),*) => {
         match $curve {
             Curve::Line(got) => Curve::Line($method(got, $($ver), *)),
+            Curve::CircleArc(got) => Curve::CircleArc($method(got, $($ver), *)),
             Curve::BSplineCurve(got) => Curve::BSplineCurve($method(got, $($ver), *)),
             Curve::NURBSCurve(got) => Curve::NURBSCurve($method(got, $($ver), *)),
             Curve::IntersectionCurve(got) => Curve::IntersectionCurve($method(got, $($ver), *)),
@@ -94,11 +98,19 @@ impl Transformed<Matrix4> for Leader {
 }
 
 impl Transformed<Matrix4> for Curve {
+    /// The circular arc becomes a NURBS curve if its image is not a circle.
     fn transform_by(&mut self, trans: Matrix4) {
-        derive_curve_method!(self, Transformed::transform_by, trans);
+        match self {
+            Curve::CircleArc(arc) => *self = Curve::transformed_arc(arc, trans),
+            _ => derive_curve_method!(self, Transformed::transform_by, trans),
+        }
     }
+    /// The circular arc becomes a NURBS curve if its image is not a circle.
     fn transformed(&self, trans: Matrix4) -> Self {
-        derive_curve_self_method!(self, Transformed::transformed, trans)
+        match self {
+            Curve::CircleArc(arc) => Curve::transformed_arc(arc, trans),
+            _ => derive_curve_self_method!(self, Transformed::transformed, trans),
+        }
     }
 }
 
@@ -109,10 +121,17 @@ impl From<IntersectionCurve<PolylineCurve<Point3>, Surface>> for Curve {
 }
 
 impl Curve {
+    fn transformed_arc(arc: &CircleArc, trans: Matrix4) -> Curve {
+        match arc.try_transformed(trans) {
+            Some(arc) => Curve::CircleArc(arc),
+            None => Curve::NURBSCurve(arc.to_nurbs().transformed(trans)),
+        }
+    }
     /// Into non-ratinalized 4-dimensinal B-spline curve
     pub fn lift_up(self) -> BSplineCurve<Vector4> {
         match self {
             Curve::Line(curve) => Curve::BSplineCurve(curve.to_bspline()).lift_up(),
+            Curve::CircleArc(curve) => curve.to_nurbs().into_non_rationalized(),
             Curve::BSplineCurve(curve) => BSplineCurve::new(
                 curve.knot_vec().clone(),
                 curve
@@ -217,52 +236,50 @@ impl IncludeCurve<Curve> for Surface {
         match self {
             Surface::BSplineSurface(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
+                Curve::CircleArc(curve) => surface.include(&curve.to_nurbs()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NURBSCurve(curve) => surface.include(curve),
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
             Surface::NURBSSurface(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
+                Curve::CircleArc(curve) => surface.include(&curve.to_nurbs()),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NURBSCurve(curve) => surface.include(curve),
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
             Surface::Plane(surface) => match curve {
                 Curve::Line(curve) => surface.include(&curve.to_bspline()),
+                Curve::CircleArc(curve) => surface.include(curve),
                 Curve::BSplineCurve(curve) => surface.include(curve),
                 Curve::NURBSCurve(curve) => surface.include(curve),
                 Curve::IntersectionCurve(_) => unimplemented!(),
             },
-            Surface::RevolutedCurve(surface) => match surface.entity_curve() {
-                Curve::Line(curve) => self.include(&Curve::BSplineCurve(curve.to_bspline())),
-                Curve::BSplineCurve(entity_curve) => {
-                    let surface = RevolutedCurve::by_revolution(
-                        entity_curve,
-                        surface.origin(),
-                        surface.axis(),
-                    );
-                    match curve {
-                        Curve::Line(curve) => surface.include(&curve.to_bspline()),
-                        Curve::BSplineCurve(curve) => surface.include(curve),
-                        Curve::NURBSCurve(curve) => surface.include(curve),
-                        Curve::IntersectionCurve(_) => unimplemented!(),
-                    }
-                }
-                Curve::NURBSCurve(entity_curve) => {
-                    let surface = RevolutedCurve::by_revolution(
-                        entity_curve,
-                        surface.origin(),
-                        surface.axis(),
-                    );
-                    match curve {
-                        Curve::Line(curve) => surface.include(&curve.to_bspline()),
-                        Curve::BSplineCurve(curve) => surface.include(curve),
-                        Curve::NURBSCurve(curve) => surface.include(curve),
-                        Curve::IntersectionCurve(_) => unimplemented!(),
-                    }
+            Surface::RevolutedCurve(surface) => {
+                // the curve is pulled back to the space of the entity surface.
+                let inv = match surface.transform().invert() {
+                    Some(inv) => inv,
+                    None => return false,
+                };
+                let curve = curve.transformed(inv);
+                let surface = surface.entity();
+                let entity_curve: NURBSCurve<Vector4> = match surface.entity_curve() {
+                    Curve::Line(entity_curve) => entity_curve.to_bspline().into(),
+                    Curve::CircleArc(entity_curve) => entity_curve.to_nurbs(),
+                    Curve::BSplineCurve(entity_curve) => entity_curve.clone().into(),
+                    Curve::NURBSCurve(entity_curve) => entity_curve.clone(),
+                    Curve::IntersectionCurve(_) => unimplemented!(),
+                };
+                let surface =
+                    RevolutedCurve::by_revolution(entity_curve, surface.origin(), surface.axis());
+                match &curve {
+                    Curve::Line(curve) => surface.include(&curve.to_bspline()),
+                    Curve::CircleArc(curve) => surface.include(&curve.to_nurbs()),
+                    Curve::BSplineCurve(curve) => surface.include(curve),
+                    Curve::NURBSCurve(curve) => surface.include(curve),
+                    Curve::IntersectionCurve(_) => unimplemented!(),
                 }
-                Curve::IntersectionCurve(_) => unimplemented!(),
-            },
+            }
         }
     }
 }
@@ -0,0 +1,89 @@
+use crate::{errors::Error, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// The version of the JSON format of the shapes written by [`to_json`].
+pub const FORMAT_VERSION: &str = "1";
+
+const VERSION_KEY: &str = "format_version";
+
+/// Serializes a shape, e.g. a solid or a shell, into JSON with the top-level field
+/// `format_version` set to [`FORMAT_VERSION`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let json = json::to_json(&cube).unwrap();
+/// let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+/// assert_eq!(value["format_version"], json::FORMAT_VERSION);
+///
+/// // round trip
+/// let solid: Solid = json::from_json(&json).unwrap();
+/// assert!(solid.is_geometric_consistent());
+/// assert_eq!(json::to_json(&solid).unwrap(), json);
+/// ```
+pub fn to_json<T: Serialize>(shape: &T) -> Result<Vec<u8>> {
+    let mut value = serde_json::to_value(shape).map_err(invalid_json)?;
+    match &mut value {
+        Value::Object(map) => {
+            let version = Value::String(FORMAT_VERSION.to_string());
+            map.insert(VERSION_KEY.to_string(), version);
+        }
+        _ => return Err(Error::InvalidJson("the shape is not serialized into an object".into())),
+    }
+    serde_json::to_vec_pretty(&value).map_err(invalid_json)
+}
+
+/// Deserializes a shape, e.g. a solid or a shell, from JSON.
+///
+/// The JSON without `format_version`, written by `serde_json` directly, is read as the
+/// current format.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use errors::Error;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the json without the version
+/// let json = serde_json::to_vec(&cube).unwrap();
+/// let solid: Solid = json::from_json(&json).unwrap();
+/// assert!(solid.is_geometric_consistent());
+///
+/// // the hand-edited version
+/// let mut value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+/// value["format_version"] = "0.1-beta".into();
+/// let json = serde_json::to_vec(&value).unwrap();
+/// let res = json::from_json::<Solid>(&json);
+/// assert_eq!(res.unwrap_err(), Error::UnsupportedFormatVersion("0.1-beta".to_string()));
+///
+/// // the version must be a string
+/// value["format_version"] = 1.into();
+/// let json = serde_json::to_vec(&value).unwrap();
+/// let res = json::from_json::<Solid>(&json);
+/// assert_eq!(res.unwrap_err(), Error::UnsupportedFormatVersion("1".to_string()));
+///
+/// // broken json
+/// assert!(matches!(json::from_json::<Solid>(b"{"), Err(Error::InvalidJson(_))));
+/// ```
+pub fn from_json<T: DeserializeOwned>(json: &[u8]) -> Result<T> {
+    let mut value: Value = serde_json::from_slice(json).map_err(invalid_json)?;
+    if let Value::Object(map) = &mut value {
+        match map.remove(VERSION_KEY) {
+            None => {}
+            Some(Value::String(version)) if version == FORMAT_VERSION => {}
+            Some(Value::String(version)) => return Err(Error::UnsupportedFormatVersion(version)),
+            Some(version) => return Err(Error::UnsupportedFormatVersion(version.to_string())),
+        }
+    }
+    serde_json::from_value(value).map_err(invalid_json)
+}
+
+#[inline(always)]
+fn invalid_json(error: serde_json::Error) -> Error { Error::InvalidJson(error.to_string()) }
@@ -53,9 +53,10 @@ pub mod topology {
 }
 pub use topology::*;
 
-/// topological utility: [`Mapped`], [`Sweep`], and [`ClosedSweep`].
+/// topological utility: [`Mapped`], [`Mirrored`], [`Sweep`], and [`ClosedSweep`].
 ///
 /// [`Mapped`]: ./topo_traits/trait.Mapped.html
+/// [`Mirrored`]: ./topo_traits/trait.Mirrored.html
 /// [`Sweep`]: ./topo_traits/trait.Sweep.html
 /// [`ClosedSweep`]: ./topo_traits/trait.ClosedSweep.html
 pub mod topo_traits {
@@ -82,6 +83,19 @@ pub mod topo_traits {
         }
     }
 
+    /// Mapping by an orientation-reversing map, e.g. a reflection.
+    pub trait Mirrored<P, C, S>: Mapped<P, C, S> {
+        /// Returns a new topology mapped by the closures as [`Mapped::mapped`],
+        /// whose faces are inverted so that the normals keep pointing outward.
+        #[doc(hidden)]
+        fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+            &self,
+            point_mapping: &FP,
+            curve_mapping: &FC,
+            surface_mapping: &FS,
+        ) -> Self;
+    }
+
     /// Abstract sweeping, builds a circle-arc, a prism, a half torus, and so on.
     pub trait Sweep<P, C, S> {
         /// The struct of sweeped topology.
@@ -156,7 +170,14 @@ mod closed_sweep;
 /// declare errors
 pub mod errors;
 mod geom_impls;
+/// Reads and writes shapes in JSON with the format version.
+pub mod json;
 mod mapped;
+/// Measures distances and angles between topological entities.
+pub mod measure;
+pub use measure::Measure;
 mod multi_sweep;
+mod section;
+pub use section::Section;
 mod sweep;
 mod topo_impls;
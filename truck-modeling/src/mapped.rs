@@ -81,3 +81,86 @@ impl<P: Clone, C: Clone, S: Clone> Mapped<P, C, S> for Solid<P, C, S> {
         )
     }
 }
+
+impl<P: Clone, C: Clone, S: Clone> Mirrored<P, C, S> for Vertex<P> {
+    #[inline(always)]
+    fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        Mapped::mapped(self, point_mapping, curve_mapping, surface_mapping)
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Mirrored<P, C, S> for Edge<P, C> {
+    #[inline(always)]
+    fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        Mapped::mapped(self, point_mapping, curve_mapping, surface_mapping)
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Mirrored<P, C, S> for Wire<P, C> {
+    #[inline(always)]
+    fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        Mapped::mapped(self, point_mapping, curve_mapping, surface_mapping)
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Mirrored<P, C, S> for Face<P, C, S> {
+    #[inline(always)]
+    fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        let mut face = self.mapped(point_mapping, curve_mapping, surface_mapping);
+        face.invert();
+        face
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Mirrored<P, C, S> for Shell<P, C, S> {
+    #[inline(always)]
+    fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        let mut shell = self.mapped(point_mapping, curve_mapping, surface_mapping);
+        shell.face_iter_mut().for_each(|face| {
+            face.invert();
+        });
+        shell
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Mirrored<P, C, S> for Solid<P, C, S> {
+    #[inline(always)]
+    fn mirrored<FP: Fn(&P) -> P, FC: Fn(&C) -> C, FS: Fn(&S) -> S>(
+        &self,
+        point_mapping: &FP,
+        curve_mapping: &FC,
+        surface_mapping: &FS,
+    ) -> Self {
+        Solid::debug_new(
+            self.boundaries()
+                .iter()
+                .map(|shell| shell.mirrored(point_mapping, curve_mapping, surface_mapping))
+                .collect(),
+        )
+    }
+}
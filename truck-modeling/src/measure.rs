@@ -0,0 +1,409 @@
+use crate::*;
+use derive_more::From;
+
+/// The tolerance of the parameter division for sampling entities.
+const SAMPLING_TOLERANCE: f64 = 0.01;
+/// The number of trials for searching the nearest parameters.
+const SEARCH_TRIALS: usize = 100;
+/// The maximum number of alternating projections in refining distances.
+const REFINEMENT_TRIALS: usize = 20;
+
+/// The reference to the topological entity measured by [`distance_between`] and [`angle_between`],
+/// or the methods of [`Measure`].
+#[derive(Clone, Copy, Debug, From)]
+pub enum EntityRef<'a> {
+    /// vertex
+    Vertex(&'a Vertex),
+    /// edge
+    Edge(&'a Edge),
+    /// face
+    Face(&'a Face),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Hint {
+    None,
+    Curve(f64),
+    Surface(f64, f64),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    point: Point3,
+    hint: Hint,
+}
+
+/// The region of the face in the parameter space of its surface.
+#[derive(Clone, Debug)]
+struct FaceDomain {
+    surface: Surface,
+    polygons: Vec<Vec<Point2>>,
+}
+
+impl FaceDomain {
+    fn new(face: &Face) -> Option<Self> {
+        let surface = face.oriented_surface();
+        let polygons = face
+            .boundaries()
+            .iter()
+            .map(|wire| {
+                wire.edge_iter()
+                    .flat_map(|edge| {
+                        let (_, mut pts) = sample_curve(&edge.oriented_curve());
+                        pts.pop();
+                        pts
+                    })
+                    .map(|pt| {
+                        surface
+                            .search_parameter(pt, None, SEARCH_TRIALS)
+                            .map(|(u, v)| Point2::new(u, v))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { surface, polygons })
+    }
+
+    fn range(&self) -> ((f64, f64), (f64, f64)) {
+        let bdd: BoundingBox<Point2> = self.polygons.iter().flatten().collect();
+        let (min, max) = (bdd.min(), bdd.max());
+        ((min[0], max[0]), (min[1], max[1]))
+    }
+
+    /// even-odd rule
+    fn include(&self, uv: Point2) -> bool {
+        let crossing = |polygon: &Vec<Point2>| {
+            let len = polygon.len();
+            (0..len)
+                .filter(|i| {
+                    let (p, q) = (polygon[*i], polygon[(*i + 1) % len]);
+                    if (p[1] > uv[1]) == (q[1] > uv[1]) {
+                        return false;
+                    }
+                    let x = p[0] + (uv[1] - p[1]) * (q[0] - p[0]) / (q[1] - p[1]);
+                    uv[0] < x
+                })
+                .count()
+        };
+        self.polygons.iter().map(crossing).sum::<usize>() % 2 == 1
+    }
+}
+
+fn sample_curve(curve: &Curve) -> (Vec<f64>, Vec<Point3>) {
+    curve.parameter_division(curve.parameter_range(), SAMPLING_TOLERANCE)
+}
+
+fn samples(entity: EntityRef<'_>, domain: Option<&FaceDomain>) -> Vec<Sample> {
+    match entity {
+        EntityRef::Vertex(vertex) => vec![Sample {
+            point: vertex.get_point(),
+            hint: Hint::None,
+        }],
+        EntityRef::Edge(edge) => {
+            let (params, points) = sample_curve(&edge.get_curve());
+            params
+                .into_iter()
+                .zip(points)
+                .map(|(t, point)| Sample {
+                    point,
+                    hint: Hint::Curve(t),
+                })
+                .collect()
+        }
+        EntityRef::Face(face) => {
+            let mut res: Vec<Sample> = face
+                .edge_iter()
+                .flat_map(|edge| samples(EntityRef::Edge(&edge), None))
+                .map(|sample| Sample {
+                    hint: Hint::None,
+                    ..sample
+                })
+                .collect();
+            if let Some(domain) = domain {
+                let (udiv, vdiv) = domain
+                    .surface
+                    .parameter_division(domain.range(), SAMPLING_TOLERANCE);
+                udiv.iter()
+                    .flat_map(|u| vdiv.iter().map(move |v| Point2::new(*u, *v)))
+                    .filter(|uv| domain.include(*uv))
+                    .for_each(|uv| {
+                        res.push(Sample {
+                            point: domain.surface.subs(uv[0], uv[1]),
+                            hint: Hint::Surface(uv[0], uv[1]),
+                        })
+                    });
+            }
+            res
+        }
+    }
+}
+
+/// Projects `point` to the entity. Returns `None` if the foot is out of the entity.
+fn project(
+    entity: EntityRef<'_>,
+    domain: Option<&FaceDomain>,
+    point: Point3,
+    hint: Hint,
+) -> Option<Sample> {
+    match (entity, hint) {
+        (EntityRef::Edge(edge), Hint::Curve(t)) => {
+            let curve = edge.get_curve();
+            let (t0, t1) = curve.parameter_range();
+            let t = curve.search_nearest_parameter(point, Some(t), SEARCH_TRIALS)?;
+            let t = f64::clamp(t, t0, t1);
+            Some(Sample {
+                point: curve.subs(t),
+                hint: Hint::Curve(t),
+            })
+        }
+        (EntityRef::Face(_), _) => {
+            let domain = domain?;
+            let hint = match hint {
+                Hint::Surface(u, v) => SPHint2D::Parameter(u, v),
+                _ => SPHint2D::Range(domain.range().0, domain.range().1),
+            };
+            let (u, v) = domain
+                .surface
+                .search_nearest_parameter(point, hint, SEARCH_TRIALS)?;
+            match domain.include(Point2::new(u, v)) {
+                true => Some(Sample {
+                    point: domain.surface.subs(u, v),
+                    hint: Hint::Surface(u, v),
+                }),
+                false => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the minimum distance between two entities.
+///
+/// # Accuracy
+/// The distance is exact up to the numerical error for the pairs of vertices and
+/// the pairs of linear edges and planar faces.
+/// For curved entities, the entities are sampled by the parameter divisions with
+/// the tolerance `0.01`, and the nearest pair of samples is refined by the alternating
+/// projections to the curves and surfaces. Hence, the result is an upper bound of
+/// the true distance and is accurate as long as the nearest points are not too close
+/// to the boundaries of the entities. For faces on periodic surfaces whose boundaries
+/// cross the seam, the interior samples may be lacked and the accuracy drops to
+/// the one of the sampling of the boundaries.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_modeling::measure::*;
+///
+/// // cuboid [0, 1] x [0, 1] x [0, 2]
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cuboid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 2.0));
+/// let shell = &cuboid.boundaries()[0];
+///
+/// // the bottom face and the top face
+/// let (bottom, top) = (&shell[0], &shell[shell.len() - 1]);
+/// assert_near!(distance_between(bottom.into(), top.into()), 2.0);
+/// // adjacent faces
+/// assert_near!(distance_between(bottom.into(), (&shell[1]).into()), 0.0);
+///
+/// // a point and a face
+/// let v = builder::vertex(Point3::new(0.5, 0.5, 3.0));
+/// assert_near!(distance_between((&v).into(), bottom.into()), 3.0);
+/// assert_near!(distance_between(top.into(), (&v).into()), 1.0);
+///
+/// // a point and an edge
+/// let v = builder::vertex(Point3::new(2.0, 0.0, 1.0));
+/// assert_near!(distance_between((&v).into(), (&e).into()), f64::sqrt(2.0));
+///
+/// // a point and a curved edge
+/// let arc = builder::circle_arc(
+///     &builder::vertex(Point3::new(1.0, 0.0, 0.0)),
+///     &builder::vertex(Point3::new(0.0, 1.0, 0.0)),
+///     Point3::new(f64::sqrt(0.5), f64::sqrt(0.5), 0.0),
+/// );
+/// let v = builder::vertex(Point3::new(2.0, 2.0, 0.0));
+/// assert_near!(distance_between((&v).into(), (&arc).into()), 2.0 * f64::sqrt(2.0) - 1.0);
+/// ```
+pub fn distance_between(a: EntityRef<'_>, b: EntityRef<'_>) -> f64 {
+    let domain = |entity: EntityRef<'_>| match entity {
+        EntityRef::Face(face) => FaceDomain::new(face),
+        _ => None,
+    };
+    let (domain_a, domain_b) = (domain(a), domain(b));
+    let samples_a = samples(a, domain_a.as_ref());
+    let samples_b = samples(b, domain_b.as_ref());
+    let nearest = samples_a
+        .iter()
+        .flat_map(|p| samples_b.iter().map(move |q| (*p, *q)))
+        .min_by(|(p0, q0), (p1, q1)| {
+            let dist0 = p0.point.distance2(q0.point);
+            let dist1 = p1.point.distance2(q1.point);
+            dist0.partial_cmp(&dist1).unwrap()
+        });
+    let (mut p, mut q) = match nearest {
+        Some(nearest) => nearest,
+        None => return f64::INFINITY,
+    };
+    let mut dist = p.point.distance(q.point);
+    for _ in 0..REFINEMENT_TRIALS {
+        let new_q = project(b, domain_b.as_ref(), p.point, q.hint).unwrap_or(q);
+        let new_p = project(a, domain_a.as_ref(), new_q.point, p.hint).unwrap_or(p);
+        let new_dist = new_p.point.distance(new_q.point);
+        if new_dist > dist - TOLERANCE2 {
+            dist = f64::min(dist, new_dist);
+            break;
+        }
+        (p, q, dist) = (new_p, new_q, new_dist);
+    }
+    dist
+}
+
+/// Returns the normal of the planar face, `None` if the face is not planar.
+fn planar_normal(face: &Face) -> Option<Vector3> {
+    let domain = FaceDomain::new(face)?;
+    let normals = samples(EntityRef::Face(face), Some(&domain))
+        .into_iter()
+        .filter_map(|sample| match sample.hint {
+            Hint::Surface(u, v) => Some(domain.surface.normal(u, v)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let normal = *normals.first()?;
+    match normals.iter().all(|n| n.near(&normal)) {
+        true => Some(normal),
+        false => None,
+    }
+}
+
+/// Returns the direction of the linear edge, `None` if the edge is not linear.
+fn linear_direction(edge: &Edge) -> Option<Vector3> {
+    let (front, back) = (edge.front().get_point(), edge.back().get_point());
+    let dir = (back - front).normalize();
+    let curve = edge.oriented_curve();
+    let (_, points) = sample_curve(&curve);
+    let linear = points.iter().all(|pt| {
+        let vec = pt - front;
+        (vec - dir * vec.dot(dir)).so_small()
+    });
+    match linear && !front.near(&back) {
+        true => Some(dir),
+        false => None,
+    }
+}
+
+/// Returns the angle between two planar faces, two linear edges, or a linear edge and a planar face.
+///
+/// - For two faces, returns the angle between the normals of oriented faces.
+/// - For two edges, returns the angle between the oriented directions of edges.
+/// - For an edge and a face, returns the angle between the edge and the plane, in `[0, π/2]`.
+///
+/// Returns `None` if the entities are not planar faces or linear edges. The planarity and
+/// the linearity are judged by the samples of the entities with the tolerance `TOLERANCE`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_modeling::measure::*;
+/// use std::f64::consts::PI;
+///
+/// // cuboid [0, 1] x [0, 1] x [0, 2]
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cuboid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 2.0));
+/// let shell = &cuboid.boundaries()[0];
+///
+/// // adjacent faces
+/// let angle = angle_between((&shell[0]).into(), (&shell[1]).into()).unwrap();
+/// assert_near!(angle.0, PI / 2.0);
+/// // opposite faces
+/// let angle = angle_between((&shell[0]).into(), (&shell[shell.len() - 1]).into()).unwrap();
+/// assert_near!(angle.0, PI);
+///
+/// // edge and face
+/// let edge = builder::line(&builder::vertex(Point3::origin()), &builder::vertex(Point3::new(1.0, 0.0, 1.0)));
+/// let angle = angle_between((&edge).into(), (&shell[0]).into()).unwrap();
+/// assert_near!(angle.0, PI / 4.0);
+///
+/// // the angle of a curved edge is not defined.
+/// let arc = builder::circle_arc(
+///     &builder::vertex(Point3::new(1.0, 0.0, 0.0)),
+///     &builder::vertex(Point3::new(0.0, 1.0, 0.0)),
+///     Point3::new(f64::sqrt(0.5), f64::sqrt(0.5), 0.0),
+/// );
+/// assert!(angle_between((&arc).into(), (&edge).into()).is_none());
+/// ```
+pub fn angle_between(a: EntityRef<'_>, b: EntityRef<'_>) -> Option<Rad<f64>> {
+    match (a, b) {
+        (EntityRef::Face(face0), EntityRef::Face(face1)) => {
+            Some(planar_normal(face0)?.angle(planar_normal(face1)?))
+        }
+        (EntityRef::Edge(edge0), EntityRef::Edge(edge1)) => {
+            Some(linear_direction(edge0)?.angle(linear_direction(edge1)?))
+        }
+        (EntityRef::Edge(edge), EntityRef::Face(face))
+        | (EntityRef::Face(face), EntityRef::Edge(edge)) => {
+            let cos = linear_direction(edge)?.dot(planar_normal(face)?);
+            Some(Rad(f64::asin(f64::clamp(cos.abs(), 0.0, 1.0))))
+        }
+        _ => None,
+    }
+}
+
+/// The measurement of the entities of a solid, as the "measure" tool of CAD viewers.
+pub trait Measure {
+    /// Returns the minimum distance between two entities of `self`.
+    ///
+    /// The same as [`distance_between`], see it for the accuracy for curved entities.
+    fn distance_between(&self, a: EntityRef<'_>, b: EntityRef<'_>) -> f64;
+    /// Returns the angle between two planar faces, two linear edges, or a linear edge and
+    /// a planar face of `self`.
+    ///
+    /// The same as [`angle_between`], returns `None` if the entities are curved.
+    fn angle_between(&self, a: EntityRef<'_>, b: EntityRef<'_>) -> Option<Rad<f64>>;
+}
+
+impl Measure for Solid {
+    /// Returns the minimum distance between two entities of `self`.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    ///
+    /// // cuboid [0, 1] x [0, 1] x [0, 2]
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cuboid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 2.0));
+    /// let shell = &cuboid.boundaries()[0];
+    ///
+    /// // the bottom face and the top face are parallel.
+    /// let (bottom, top) = (&shell[0], &shell[shell.len() - 1]);
+    /// assert_near!(cuboid.distance_between(bottom.into(), top.into()), 2.0);
+    /// ```
+    #[inline(always)]
+    fn distance_between(&self, a: EntityRef<'_>, b: EntityRef<'_>) -> f64 {
+        distance_between(a, b)
+    }
+    /// Returns the angle between two planar faces, two linear edges, or a linear edge and
+    /// a planar face of `self`.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // cuboid [0, 1] x [0, 1] x [0, 2]
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cuboid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 2.0));
+    /// let shell = &cuboid.boundaries()[0];
+    ///
+    /// // adjacent faces
+    /// let angle = cuboid.angle_between((&shell[0]).into(), (&shell[1]).into()).unwrap();
+    /// assert_near!(angle.0, PI / 2.0);
+    /// ```
+    #[inline(always)]
+    fn angle_between(&self, a: EntityRef<'_>, b: EntityRef<'_>) -> Option<Rad<f64>> {
+        angle_between(a, b)
+    }
+}
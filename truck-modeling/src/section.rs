@@ -0,0 +1,273 @@
+use crate::errors::Error;
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Side {
+    Inside,
+    On,
+    Outside,
+}
+
+#[derive(Debug)]
+struct Sectioner<'a> {
+    plane: &'a Plane,
+    /// the pieces of the crossing edges, from the inside vertex to the crossing point
+    pieces: HashMap<EdgeID, Edge>,
+}
+
+impl<'a> Sectioner<'a> {
+    fn new(plane: &'a Plane) -> Self {
+        Self {
+            plane,
+            pieces: HashMap::default(),
+        }
+    }
+
+    fn distance(&self, vertex: &Vertex) -> f64 {
+        (vertex.get_point() - self.plane.origin()).dot(self.plane.normal())
+    }
+
+    fn side(&self, vertex: &Vertex) -> Side {
+        match self.distance(vertex) {
+            d if d < -TOLERANCE => Side::Inside,
+            d if d > TOLERANCE => Side::Outside,
+            _ => Side::On,
+        }
+    }
+
+    /// Returns the piece of the crossing `edge` from the inside vertex to the crossing point,
+    /// oriented as `edge`.
+    fn piece(&mut self, edge: &Edge) -> Edge {
+        let (front, back) = (edge.absolute_front(), edge.absolute_back());
+        let (d0, d1) = (self.distance(front), self.distance(back));
+        let piece = self.pieces.entry(edge.id()).or_insert_with(|| {
+            let (p, q) = (front.get_point(), back.get_point());
+            let crossing = builder::vertex(p + (q - p) * (d0 / (d0 - d1)));
+            match d0 < 0.0 {
+                true => builder::line(front, &crossing),
+                false => builder::line(&crossing, back),
+            }
+        });
+        match edge.orientation() {
+            true => piece.clone(),
+            false => piece.inverse(),
+        }
+    }
+
+    fn section_wire(&mut self, wire: &Wire) -> Result<Option<Wire>> {
+        let sides: Vec<(Side, Side)> = wire
+            .edge_iter()
+            .map(|edge| (self.side(edge.front()), self.side(edge.back())))
+            .collect();
+        if sides.iter().all(|(side, _)| *side != Side::Outside) {
+            return Ok(Some(wire.clone()));
+        } else if sides.iter().all(|(side, _)| *side != Side::Inside) {
+            return Ok(None);
+        }
+        let mut entries = sides.iter().enumerate().filter(|(_, (side0, side1))| {
+            *side0 == Side::Outside && *side1 != Side::Outside
+        });
+        let start = match (entries.next(), entries.next()) {
+            (Some((idx, _)), None) => idx,
+            _ => return Err(Error::TooComplexSection),
+        };
+        let len = wire.len();
+        let (mut new_wire, mut entry, mut exit) = (Wire::new(), None, None);
+        for idx in (start..start + len).map(|i| i % len) {
+            let edge = &wire[idx];
+            match sides[idx] {
+                (Side::Outside, Side::Outside) => {}
+                (Side::Outside, Side::On) => entry = Some(edge.back().clone()),
+                (Side::Outside, Side::Inside) => {
+                    let piece = self.piece(edge);
+                    entry = Some(piece.front().clone());
+                    new_wire.push_back(piece);
+                }
+                (Side::On, Side::Outside) => exit = Some(edge.front().clone()),
+                (Side::Inside, Side::Outside) => {
+                    let piece = self.piece(edge);
+                    exit = Some(piece.back().clone());
+                    new_wire.push_back(piece);
+                }
+                _ => new_wire.push_back(edge.clone()),
+            }
+        }
+        match (exit, entry) {
+            (Some(exit), Some(entry)) if exit != entry => {
+                new_wire.push_back(builder::line(&exit, &entry))
+            }
+            _ => {}
+        }
+        Ok(Some(new_wire))
+    }
+
+    fn section_face(&mut self, face: &Face) -> Result<Option<Face>> {
+        let sides: Vec<Side> = face.vertex_iter().map(|v| self.side(&v)).collect();
+        if sides.iter().all(|side| *side != Side::Outside) {
+            return Ok(Some(face.clone()));
+        } else if sides.iter().all(|side| *side != Side::Inside) {
+            return Ok(None);
+        }
+        let mut wires = Vec::new();
+        for wire in face.boundaries() {
+            if let Some(wire) = self.section_wire(&wire)? {
+                wires.push(wire);
+            }
+        }
+        match wires.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(Face::try_new(wires, face.oriented_surface())?)),
+        }
+    }
+
+    /// Returns the faces closing the boundaries of the sectioned shell.
+    fn caps(&self, shell: &Shell) -> Result<Vec<Face>> {
+        let plane = self.plane;
+        let (origin, u_axis, v_axis) = (plane.origin(), plane.u_axis(), plane.v_axis());
+        let project = |pt: Point3| {
+            let vec = pt - origin;
+            Point2::new(vec.dot(u_axis), vec.dot(v_axis))
+        };
+        let loops: Vec<(Wire, Vec<Point2>, f64)> = shell
+            .extract_boundaries()
+            .into_iter()
+            .map(|wire| {
+                let wire = wire.inverse();
+                let polygon: Vec<Point2> =
+                    wire.vertex_iter().map(|v| project(v.get_point())).collect();
+                let area = signed_area(&polygon);
+                (wire, polygon, area)
+            })
+            .collect();
+        let (outers, holes): (Vec<_>, Vec<_>) =
+            loops.into_iter().partition(|(_, _, area)| *area > 0.0);
+        let mut boundaries: Vec<(Vec<Wire>, Vec<Point2>, f64)> = outers
+            .into_iter()
+            .map(|(wire, polygon, area)| (vec![wire], polygon, area))
+            .collect();
+        for (wire, polygon, _) in holes {
+            let outer = boundaries
+                .iter_mut()
+                .filter(|(_, outer, _)| inside_polygon(outer, polygon[0]))
+                .min_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap())
+                .ok_or(Error::TooComplexSection)?;
+            outer.0.push(wire);
+        }
+        boundaries
+            .into_iter()
+            .map(|(wires, _, _)| Ok(Face::try_new(wires, Surface::Plane(*plane))?))
+            .collect()
+    }
+}
+
+fn signed_area(polygon: &[Point2]) -> f64 {
+    let n = polygon.len();
+    (0..n).fold(0.0, |sum, i| {
+        let (p, q) = (polygon[i], polygon[(i + 1) % n]);
+        sum + (p.x * q.y - p.y * q.x) / 2.0
+    })
+}
+
+fn inside_polygon(polygon: &[Point2], pt: Point2) -> bool {
+    let n = polygon.len();
+    (0..n).fold(false, |inside, i| {
+        let (p, q) = (polygon[i], polygon[(i + 1) % n]);
+        let crossing = (p.y > pt.y) != (q.y > pt.y)
+            && pt.x < p.x + (q.x - p.x) * (pt.y - p.y) / (q.y - p.y);
+        inside != crossing
+    })
+}
+
+fn section(solid: &Solid, plane: &Plane) -> Result<Solid> {
+    // The vertices decide the sides of the faces and edges, which is valid only if they are flat.
+    if solid.face_iter().any(|face| !matches!(face.get_surface(), Surface::Plane(_)))
+        || solid.edge_iter().any(|edge| !matches!(edge.get_curve(), Curve::Line(_)))
+    {
+        return Err(Error::UnsupportedSectionGeometry);
+    }
+    let mut sectioner = Sectioner::new(plane);
+    // The shells are sectioned together so that cavities make holes of the caps.
+    let mut shell = Shell::new();
+    for face in solid.face_iter() {
+        if let Some(face) = sectioner.section_face(face)? {
+            shell.push(face);
+        }
+    }
+    let caps = sectioner.caps(&shell)?;
+    shell.extend(caps);
+    Ok(Solid::try_new(shell.connected_components())?)
+}
+
+/// The section of a solid by a plane, for section drawings.
+pub trait Section: Sized {
+    /// Cuts `self` by `plane` and closes the opening by planar faces.
+    fn section(&self, plane: &Plane) -> Result<Self>;
+}
+
+impl Section for Solid {
+    /// Cuts `self` by `plane` and closes the opening by planar faces.
+    ///
+    /// The part of `self` on the side to which the normal of `plane` points is removed,
+    /// and the returned solid is the part on the other side.
+    /// The faces crossing the plane are trimmed, and new faces on `plane` cap the cut.
+    /// If the whole solid is on the removed side, the returned solid has no boundaries.
+    ///
+    /// # Failures
+    /// - [`Error::UnsupportedSectionGeometry`] if a face of `self` is not planar or
+    ///   an edge of `self` is not a line.
+    /// - [`Error::TooComplexSection`] if a boundary of a face crosses the plane more than twice,
+    ///   or a hole of the section is not surrounded by any outer boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    ///
+    /// // the cuboid [0, 2] x [0, 3] x [0, 4]
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, 2.0 * Vector3::unit_x());
+    /// let f = builder::tsweep(&e, 3.0 * Vector3::unit_y());
+    /// let cuboid = builder::tsweep(&f, 4.0 * Vector3::unit_z());
+    ///
+    /// // cuts off the upper half
+    /// let plane = Plane::new(
+    ///     Point3::new(0.0, 0.0, 2.0),
+    ///     Point3::new(1.0, 0.0, 2.0),
+    ///     Point3::new(0.0, 1.0, 2.0),
+    /// );
+    /// let half = cuboid.section(&plane).unwrap();
+    /// let shell = &half.boundaries()[0];
+    /// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    /// assert_eq!(shell.len(), 6);
+    ///
+    /// // one new cap face on the plane
+    /// let caps: Vec<&Face> = shell
+    ///     .face_iter()
+    ///     .filter(|face| matches!(face.get_surface(), Surface::Plane(p) if p == plane))
+    ///     .collect();
+    /// assert_eq!(caps.len(), 1);
+    /// assert!(caps[0].vertex_iter().all(|v| v.get_point().z.near(&2.0)));
+    ///
+    /// // the volume by the divergence theorem
+    /// let volume = shell.face_iter().fold(0.0, |sum, face| {
+    ///     let pts: Vec<Point3> = face.boundaries()[0].vertex_iter().map(|v| v.get_point()).collect();
+    ///     sum + (1..pts.len() - 1).fold(0.0, |sum, i| {
+    ///         sum + pts[0].to_vec().dot(pts[i].to_vec().cross(pts[i + 1].to_vec())) / 6.0
+    ///     })
+    /// });
+    /// assert_near!(volume, 12.0);
+    /// assert!(shell.vertex_iter().all(|v| v.get_point().z < 2.0 + TOLERANCE));
+    ///
+    /// // a cylinder is not supported, even if the curved face does not cross the plane.
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    /// let disk = builder::try_attach_plane(&[circle]).unwrap();
+    /// let cylinder = builder::tsweep(&disk, Vector3::unit_z());
+    /// assert!(matches!(
+    ///     cylinder.section(&plane),
+    ///     Err(errors::Error::UnsupportedSectionGeometry),
+    /// ));
+    /// ```
+    #[inline(always)]
+    fn section(&self, plane: &Plane) -> Result<Self> { section(self, plane) }
+}
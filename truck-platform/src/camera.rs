@@ -1,4 +1,9 @@
 use crate::*;
+use std::f64::consts::FRAC_PI_2;
+use truck_base::tolerance::Origin;
+
+/// the maximum elevation angle of [`Camera::orbit`], which avoids the flip at the poles
+const ORBIT_PHI_MAX: f64 = FRAC_PI_2 - 1.0e-3;
 
 impl Ray {
     /// Returns the origin of the ray
@@ -368,6 +373,159 @@ impl Camera {
             }
         }
     }
+
+    /// Returns the view frustum of camera with the aspect ratio `as_rat`.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{bounding_box::*, cgmath64::*};
+    /// use truck_platform::*;
+    /// let camera = Camera::perspective_camera(Matrix4::identity(), Rad(PI / 2.0), 0.1, 10.0);
+    /// let frustum = camera.frustum(1.0);
+    ///
+    /// let cube = |x: f64, y: f64, z: f64| {
+    ///     OrientedBoundingBox::new(Point3::new(x, y, z), Matrix3::from_scale(0.5))
+    /// };
+    /// // in front of camera
+    /// assert!(frustum.intersects(&cube(0.0, 0.0, -2.0)));
+    /// // behind camera
+    /// assert!(!frustum.intersects(&cube(0.0, 0.0, 2.0)));
+    /// // beyond the far plane
+    /// assert!(!frustum.intersects(&cube(0.0, 0.0, -12.0)));
+    /// // out of the side plane
+    /// assert!(!frustum.intersects(&cube(4.0, 0.0, -2.0)));
+    /// // across the side plane
+    /// assert!(frustum.intersects(&cube(2.3, 0.0, -2.0)));
+    /// ```
+    pub fn frustum(&self, as_rat: f64) -> Frustum {
+        let matrix = self.projection(as_rat);
+        let rows = [0, 1, 2, 3].map(|i| matrix.row(i));
+        let planes = [
+            rows[3] + rows[0],
+            rows[3] - rows[0],
+            rows[3] + rows[1],
+            rows[3] - rows[1],
+            // the depth in the normalized view volume of wgpu is in [0, 1].
+            rows[2],
+            rows[3] - rows[2],
+        ]
+        .map(|plane| plane / plane.truncate().magnitude());
+        Frustum { planes }
+    }
+
+    /// Orbits the camera around `target`.
+    ///
+    /// The camera rotates by the azimuth `dtheta` around the y-axis through `target` and by the
+    /// elevation `dphi`, positive upward. The elevation of the camera seen from `target` is
+    /// clamped to a little less than `PI / 2` in absolute value, so the camera never flips
+    /// over the poles. If the camera looks at `target`, it keeps looking at `target`.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let matrix = Matrix4::look_at_rh(
+    ///     Point3::new(0.0, 0.0, 2.0),
+    ///     Point3::origin(),
+    ///     Vector3::unit_y(),
+    /// );
+    /// let mut camera =
+    ///     Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 10.0);
+    /// camera.orbit(Point3::origin(), PI / 2.0, 0.0);
+    /// assert!(camera.position().near(&Point3::new(2.0, 0.0, 0.0)));
+    /// assert!(camera.eye_direction().near(&-Vector3::unit_x()));
+    ///
+    /// // the elevation is clamped at the north pole.
+    /// camera.orbit(Point3::origin(), 0.0, PI);
+    /// assert!(camera.position().y < 2.0);
+    /// assert!(camera.head_direction().dot(Vector3::unit_x()) < 0.0);
+    /// ```
+    pub fn orbit(&mut self, target: Point3, dtheta: f64, dphi: f64) {
+        let offset = self.position() - target;
+        let radius = offset.magnitude();
+        if radius.so_small() {
+            return;
+        }
+        let phi = f64::asin(f64::clamp(offset.y / radius, -1.0, 1.0));
+        let dphi = f64::clamp(phi + dphi, -ORBIT_PHI_MAX, ORBIT_PHI_MAX) - phi;
+        // the horizontal axis, along which the camera goes upward with positive angle
+        let axis = offset.cross(Vector3::unit_y());
+        let axis = match axis.so_small() {
+            true => -self.matrix[0].truncate(),
+            false => axis.normalize(),
+        };
+        let rotation = Matrix4::from_translation(target.to_vec())
+            * Matrix4::from_angle_y(Rad(dtheta))
+            * Matrix4::from_axis_angle(axis, Rad(dphi))
+            * Matrix4::from_translation(-target.to_vec());
+        self.matrix = rotation * self.matrix;
+    }
+
+    /// Translates the camera by `dx` along the x-axis and `dy` along the y-axis of the camera.
+    ///
+    /// The point looked at by the camera is translated together,
+    /// so the target of [`Camera::orbit`] should be moved by the same vector.
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let mut camera = Camera::default();
+    /// camera.pan(1.0, 2.0);
+    /// assert!(camera.position().near(&Point3::new(1.0, 2.0, 0.0)));
+    /// assert!(camera.eye_direction().near(&-Vector3::unit_z()));
+    /// ```
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        let vec = dx * self.matrix[0].truncate() + dy * self.matrix[1].truncate();
+        self.matrix = Matrix4::from_translation(vec) * self.matrix;
+    }
+
+    /// Moves the camera by `amount` in the eye direction, backward if `amount` is negative.
+    ///
+    /// # Remarks
+    /// The image of the parallel camera is not zoomed by dolly except for the clipping.
+    /// # Examples
+    /// ```
+    /// use truck_base::{cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// let mut camera = Camera::default();
+    /// camera.dolly(2.0);
+    /// assert!(camera.position().near(&Point3::new(0.0, 0.0, -2.0)));
+    /// ```
+    pub fn dolly(&mut self, amount: f64) {
+        let vec = amount * self.eye_direction();
+        self.matrix = Matrix4::from_translation(vec) * self.matrix;
+    }
+}
+
+impl Frustum {
+    /// Returns the six planes `(a, b, c, d)` of the frustum, the boundary of the domain
+    /// `a * x + b * y + c * z + d >= 0`, normalized so that `(a, b, c)` is a unit vector.
+    #[inline(always)]
+    pub fn planes(&self) -> &[Vector4; 6] { &self.planes }
+
+    /// Returns whether `bounds` intersects the frustum.
+    ///
+    /// First, the circumscribed sphere of the box is tested for cheapness.
+    /// Only if the sphere crosses a plane, the box itself is tested against the plane.
+    /// The test is conservative: a box near a corner of the frustum may be
+    /// judged to intersect even if it does not.
+    pub fn intersects(&self, bounds: &OrientedBoundingBox) -> bool {
+        let center = bounds.center().to_homogeneous();
+        let radius = bounds.radius();
+        let axes = bounds.axes();
+        self.planes.iter().all(|plane| {
+            let dist = plane.dot(center);
+            if dist >= radius {
+                true
+            } else if dist < -radius {
+                false
+            } else {
+                let normal = plane.truncate();
+                let extent = (0..3).map(|i| normal.dot(axes[i]).abs()).sum::<f64>();
+                dist >= -extent
+            }
+        })
+    }
 }
 
 impl Default for Camera {
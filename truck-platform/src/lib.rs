@@ -45,7 +45,7 @@
 use bytemuck::{Pod, Zeroable};
 use derive_more::*;
 use std::sync::Arc;
-use truck_base::cgmath64::*;
+use truck_base::{bounding_box::OrientedBoundingBox, cgmath64::*};
 pub use wgpu;
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 use wgpu::*;
@@ -75,6 +75,15 @@ struct SceneInfo {
     resolution: [u32; 2],
     time: f32,
     num_of_lights: u32,
+    wireframe_color: [f32; 4],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct OutlineInfo {
+    color: [f32; 4],
+    width: i32,
+    _padding: [i32; 3],
 }
 
 /// safe handler of GPU buffer
@@ -135,12 +144,51 @@ pub struct PreBindGroupLayoutEntry {
 pub struct RenderObject {
     vertex_buffer: Arc<BufferHandler>,
     index_buffer: Option<Arc<BufferHandler>>,
+    instance_buffer: Option<Arc<BufferHandler>>,
     pipeline: Arc<RenderPipeline>,
     bind_group_layout: Arc<BindGroupLayout>,
     bind_group: Arc<BindGroup>,
+    edges: Option<(Arc<BufferHandler>, Arc<RenderPipeline>)>,
+    wireframe: Option<(Arc<BufferHandler>, Arc<RenderPipeline>)>,
+    pick_pipeline: Option<Arc<RenderPipeline>>,
+    debug_lines: Option<DebugLineObject>,
+    bounds: Option<OrientedBoundingBox>,
+    transparent: bool,
     visible: bool,
 }
 
+/// The debug line overlay of a render object, drawn by [`Rendered::debug_line_pipeline`].
+#[derive(Debug, Clone)]
+struct DebugLineObject {
+    lines: DebugLines,
+    length: f64,
+    buffer: Arc<BufferHandler>,
+    pipeline: Arc<RenderPipeline>,
+}
+
+/// the kind of the debug line overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugLines {
+    /// the segments from the vertices along their normals
+    Normals,
+    /// the segments from the vertices along their tangents
+    Tangents,
+}
+
+/// the render mode of the objects in [`Scene`]
+///
+/// The objects without the wireframes, given by [`Rendered::wireframe_buffer`],
+/// are always shaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderMode {
+    /// the faces are filled. default.
+    Shaded,
+    /// only the edges of the triangles are drawn.
+    Wireframe,
+    /// the edges of the triangles are drawn over the filled faces.
+    ShadedWireframe,
+}
+
 /// the projection type of camera
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ProjectionType {
@@ -170,6 +218,23 @@ pub struct Ray {
     direction: Vector3,
 }
 
+/// The view frustum of camera, the domain mapped into the normalized view volume.
+///
+/// The frustum is represented by six planes whose normals direct inside.
+#[derive(Clone, Copy, Debug)]
+pub struct Frustum {
+    planes: [Vector4; 6],
+}
+
+/// Statistics of the objects drawn by [`Scene::render`].
+#[derive(Clone, Debug, Default)]
+pub struct RenderStats {
+    /// the ids of the visible objects to be drawn, in the order of drawing
+    pub drawn: Vec<RenderID>,
+    /// the ids of the visible objects culled since they are out of the view frustum
+    pub culled: Vec<RenderID>,
+}
+
 /// the kinds of light sources: point or uniform
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LightType {
@@ -181,8 +246,8 @@ pub enum LightType {
 
 /// Light
 ///
-/// There is no limit to the number of lights that can be added to a [`Scene`](./struct.Scene.html).
-/// The information about the lights is sent to the shader as a storage buffer
+/// At most [`LIGHT_MAX`] lights in a [`Scene`](./struct.Scene.html) are used for rendering.
+/// The information about the lights is sent to the shader as a uniform buffer
 /// (cf: [`Scene::lights_buffer()`](./struct.Scene.html#method.lights_buffer)).
 #[derive(Clone, Debug, PartialEq)]
 pub struct Light {
@@ -238,6 +303,8 @@ pub struct StudioConfig {
     /// camera of the scene. Default is `Camera::default()`.
     pub camera: Camera,
     /// All lights in the scene. Default is `vec![Light::default()]`.
+    ///
+    /// At most [`LIGHT_MAX`] lights from the beginning are used for rendering.
     pub lights: Vec<Light>,
     /// background color. Default is `Color::BLACK`.
     pub background: Color,
@@ -292,10 +359,22 @@ pub struct Scene {
     device_handler: DeviceHandler,
     objects: SliceHashMap<RenderID, RenderObject>,
     bind_group_layout: BindGroupLayout,
+    pick_bind_group_layout: BindGroupLayout,
+    pick_module: ShaderModule,
+    pick_textures: (Texture, Texture),
+    outline_bind_group_layout: BindGroupLayout,
+    outline_buffer: BufferHandler,
+    outline_pipeline: RenderPipeline,
+    outline_mask: Texture,
     foward_depth: Option<Texture>,
     sampling_buffer: Option<Texture>,
     scene_desc: SceneDescriptor,
     clock: instant::Instant,
+    highlighted: Vec<RenderID>,
+    highlight_color: Color,
+    render_mode: RenderMode,
+    wireframe_color: Color,
+    frustum_culling: bool,
 }
 
 /// Utility for wrapper
@@ -321,6 +400,14 @@ pub trait Rendered {
     ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>);
     /// Creates the bind group layout.
     fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout>;
+    /// Creates the vertex buffer of the per-instance attributes, bound at the slot 1.
+    ///
+    /// The faces are drawn once for each element of the buffer, while the edges, the
+    /// wireframe and the debug lines are drawn only once.
+    /// Returns `None` by default, i.e. the faces are drawn only once.
+    fn instance_buffer(&self, _device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        None
+    }
     /// Creates the bind group in `set = 1`.
     fn bind_group(
         &self,
@@ -334,6 +421,98 @@ pub trait Rendered {
         layout: &PipelineLayout,
         scene_descriptor: &SceneDescriptor,
     ) -> Arc<RenderPipeline>;
+    /// Creates the vertex buffer of the feature edges, drawn as `LineList` without index buffer.
+    ///
+    /// It is requested only while the edges are shown by [`Scene::set_edges_visibility`].
+    /// Returns `None` by default, i.e. the object has no edges to be overlaid.
+    fn edge_buffer(&self, _device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> { None }
+    /// Creates the render pipeline of the feature edges.
+    ///
+    /// The bind group in `set = 1` is shared with the pipeline of the faces.
+    /// Returns `None` by default, i.e. the object has no lines to be overlaid.
+    fn edge_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Creates the index buffer of the edges of the triangles, drawn as `LineList` with
+    /// the vertex buffer given by [`Rendered::vertex_buffer`].
+    ///
+    /// It is requested only while the render mode of the scene draws the wireframes,
+    /// cf: [`Scene::set_render_mode`].
+    /// Returns `None` by default, i.e. the object has no wireframe.
+    fn wireframe_buffer(&self, _device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        None
+    }
+    /// Creates the render pipeline of the wireframe, which is drawn with the vertex buffer and
+    /// the bind group of the faces.
+    ///
+    /// Returns `None` by default, i.e. the object has no wireframe.
+    fn wireframe_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Creates the render pipeline of [`Scene::pick`], which draws the faces with the vertex
+    /// buffer, the instance buffer and the bind group of the faces.
+    ///
+    /// The pipeline must have the fragment state `fragment`, which writes the index of the object,
+    /// the depth stencil state with the format `Depth32Float`, the depth write and
+    /// the compare function `Less`, and no multisampling. The depth is written even if
+    /// the object is transparent, so that the nearest object is picked.
+    /// Returns `None` by default, i.e. the object is never picked.
+    fn pick_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _fragment: FragmentState<'_>,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Creates the vertex buffer of the debug lines, drawn as `LineList` without index buffer.
+    ///
+    /// Each segment starts from a vertex, and is directed along the normal or the tangent
+    /// at the vertex with `length` in the model coordinate.
+    /// Returns `None` by default, i.e. the object has no debug lines.
+    fn debug_line_buffer(
+        &self,
+        _device_handler: &DeviceHandler,
+        _lines: DebugLines,
+        _length: f64,
+    ) -> Option<Arc<BufferHandler>> {
+        None
+    }
+    /// Creates the render pipeline of the debug lines.
+    ///
+    /// The bind group in `set = 1` is shared with the pipeline of the faces.
+    /// Returns `None` by default, i.e. the object has no debug lines.
+    fn debug_line_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Returns the oriented bounding box of the object in the world coordinate.
+    ///
+    /// The object is not drawn if the box is out of the view frustum of the camera.
+    /// Returns `None` by default, i.e. the object is never culled.
+    fn oriented_bounding_box(&self) -> Option<OrientedBoundingBox> { None }
+    /// Returns whether the object is transparent.
+    ///
+    /// The transparent objects are drawn after the opaque objects, from the farthest to
+    /// the nearest by the distances between the camera and the centers of
+    /// [`Rendered::oriented_bounding_box`]. The pipeline of a transparent object should not
+    /// write the depth. The flag is synchronized by [`Scene::update_pipeline`].
+    /// Returns `false` by default.
+    fn is_transparent(&self) -> bool { false }
     #[doc(hidden)]
     fn render_object(&self, scene: &Scene) -> RenderObject {
         let (vertex_buffer, index_buffer) = self.vertex_buffer(scene.device_handler());
@@ -347,12 +526,27 @@ pub trait Rendered {
                 label: None,
             });
         let pipeline = self.pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
+        let wireframe = match scene.render_mode {
+            RenderMode::Shaded => None,
+            _ => self.wireframe_buffer(scene.device_handler()).and_then(|buffer| {
+                self.wireframe_pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc)
+                    .map(|pipeline| (buffer, pipeline))
+            }),
+        };
+        let pick_pipeline = scene.pick_pipeline(self, &bind_group_layout);
         RenderObject {
             vertex_buffer,
             index_buffer,
+            instance_buffer: self.instance_buffer(scene.device_handler()),
             bind_group_layout,
             bind_group,
             pipeline,
+            edges: None,
+            wireframe,
+            pick_pipeline,
+            debug_lines: None,
+            bounds: self.oriented_bounding_box(),
+            transparent: self.is_transparent(),
             visible: true,
         }
     }
@@ -4,6 +4,16 @@ use winit::window::Window;
 
 static MAXID: AtomicUsize = AtomicUsize::new(0);
 
+/// the width of the outlines of highlighted objects, in pixels
+const OUTLINE_WIDTH: i32 = 3;
+
+/// the target of the pick pipelines, the index of the object
+const PICK_TARGETS: [Option<ColorTargetState>; 1] = [Some(ColorTargetState {
+    format: TextureFormat::R32Uint,
+    blend: None,
+    write_mask: ColorWrites::ALL,
+})];
+
 impl RenderID {
     /// Generate the unique `RenderID`.
     #[inline(always)]
@@ -151,8 +161,9 @@ impl SceneDescriptor {
         self.studio.camera.buffer(as_rat, device)
     }
 
-    /// Creates a `STORAGE` buffer of all lights.
+    /// Creates a `UNIFORM` buffer of all lights.
     ///
+    /// The buffer always has [`LIGHT_MAX`] elements, and the lights after `LIGHT_MAX` are ignored.
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
@@ -163,13 +174,19 @@ impl SceneDescriptor {
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
     /// };
     ///
-    /// layout(set = 0, binding = 1) buffer Lights {
-    ///     Light lights[];
+    /// layout(set = 0, binding = 1) uniform Lights {
+    ///     Light lights[255];  // the array with the length `LIGHT_MAX`
     /// };
     /// ```
     #[inline(always)]
     pub fn lights_buffer(&self, device: &Device) -> BufferHandler {
-        let mut light_vec: Vec<_> = self.studio.lights.iter().map(Light::light_info).collect();
+        let mut light_vec: Vec<_> = self
+            .studio
+            .lights
+            .iter()
+            .take(LIGHT_MAX)
+            .map(Light::light_info)
+            .collect();
         light_vec.resize(LIGHT_MAX, LightInfo::zeroed());
         BufferHandler::from_slice(&light_vec, device, BufferUsages::UNIFORM)
     }
@@ -257,6 +274,24 @@ impl<'a> Drop for SceneDescriptorMut<'a> {
         let (forward_depth, sampling_buffer) = self.backend_buffers(self.0.device());
         self.0.foward_depth = forward_depth;
         self.0.sampling_buffer = sampling_buffer;
+        let canvas_size = self.render_texture.canvas_size;
+        self.0.pick_textures = Scene::init_pick_textures(self.0.device(), canvas_size);
+        let render_texture = self.render_texture;
+        self.0.outline_mask = Scene::init_outline_mask(self.0.device(), render_texture);
+        let layout = &self.0.outline_bind_group_layout;
+        let pipeline = Scene::outline_pipeline(self.0.device(), layout, render_texture.format);
+        self.0.outline_pipeline = pipeline;
+    }
+}
+
+impl OutlineInfo {
+    #[inline(always)]
+    fn new(color: Color) -> Self {
+        OutlineInfo {
+            color: [color.r as f32, color.g as f32, color.b as f32, color.a as f32],
+            width: OUTLINE_WIDTH,
+            _padding: [0; 3],
+        }
     }
 }
 
@@ -312,6 +347,92 @@ impl Scene {
         )
     }
 
+    #[inline(always)]
+    fn init_pick_bind_group_layout(device: &Device) -> BindGroupLayout {
+        bind_group_util::create_bind_group_layout(
+            device,
+            &[PreBindGroupLayoutEntry {
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        )
+    }
+
+    /// Creates the textures of [`Scene::pick`], the indices and the depths of the objects.
+    fn init_pick_textures(device: &Device, (width, height): (u32, u32)) -> (Texture, Texture) {
+        let create_texture = |format, usage| {
+            device.create_texture(&TextureDescriptor {
+                size: Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format,
+                usage,
+                label: None,
+            })
+        };
+        let id_texture = create_texture(
+            TextureFormat::R32Uint,
+            TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        );
+        let depth = create_texture(TextureFormat::Depth32Float, TextureUsages::RENDER_ATTACHMENT);
+        (id_texture, depth)
+    }
+
+    #[inline(always)]
+    fn init_outline_bind_group_layout(device: &Device) -> BindGroupLayout {
+        bind_group_util::create_bind_group_layout(
+            device,
+            &[
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: false },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        )
+    }
+
+    /// Creates the mask texture of the highlighted objects, cf: [`Scene::set_highlighted`].
+    fn init_outline_mask(device: &Device, render_texture: RenderTextureConfig) -> Texture {
+        let (width, height) = render_texture.canvas_size;
+        device.create_texture(&TextureDescriptor {
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: render_texture.format,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+            label: None,
+        })
+    }
+
     /// constructor
     // About `scene_desc`, entity is better than reference for the performance.
     // This is reference because only for as wgpu is.
@@ -320,14 +441,55 @@ impl Scene {
         let device = device_handler.device();
         let (foward_depth, sampling_buffer) = scene_desc.backend_buffers(device);
         let bind_group_layout = Self::init_scene_bind_group_layout(device);
+        let pick_bind_group_layout = Self::init_pick_bind_group_layout(device);
+        let pick_module = device.create_shader_module(ShaderModuleDescriptor {
+            label: None,
+            source: ShaderSource::Wgsl(include_str!("shaders/pick.wgsl").into()),
+        });
+        let pick_textures = Self::init_pick_textures(device, scene_desc.render_texture.canvas_size);
+        let highlight_color = Color {
+            r: 1.0,
+            g: 0.5,
+            b: 0.0,
+            a: 1.0,
+        };
+        let outline_bind_group_layout = Self::init_outline_bind_group_layout(device);
+        let outline_buffer = BufferHandler::from_slice(
+            &[OutlineInfo::new(highlight_color)],
+            device,
+            BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        );
+        let outline_pipeline = Self::outline_pipeline(
+            device,
+            &outline_bind_group_layout,
+            scene_desc.render_texture.format,
+        );
+        let outline_mask = Self::init_outline_mask(device, scene_desc.render_texture);
         Scene {
             objects: Default::default(),
             bind_group_layout,
+            pick_bind_group_layout,
+            pick_module,
+            pick_textures,
+            outline_bind_group_layout,
+            outline_buffer,
+            outline_pipeline,
+            outline_mask,
             foward_depth,
             sampling_buffer,
             clock: instant::Instant::now(),
             scene_desc: scene_desc.clone(),
             device_handler,
+            highlighted: Vec::new(),
+            highlight_color,
+            render_mode: RenderMode::Shaded,
+            wireframe_color: Color {
+                r: 0.05,
+                g: 0.05,
+                b: 0.05,
+                a: 1.0,
+            },
+            frustum_culling: true,
         }
     }
 
@@ -415,8 +577,9 @@ impl Scene {
     #[inline(always)]
     pub fn camera_buffer(&self) -> BufferHandler { self.scene_desc.camera_buffer(self.device()) }
 
-    /// Creates a `STORAGE` buffer of all lights.
+    /// Creates a `UNIFORM` buffer of all lights.
     ///
+    /// The buffer always has [`LIGHT_MAX`] elements, and the lights after `LIGHT_MAX` are ignored.
     /// The bind group provides [`Scene`] holds this uniform buffer.
     ///
     /// # Shader Example
@@ -427,8 +590,8 @@ impl Scene {
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
     /// };
     ///
-    /// layout(set = 0, binding = 1) buffer Lights {
-    ///     Light lights[]; // the number of lights must be gotten from another place
+    /// layout(set = 0, binding = 1) uniform Lights {
+    ///     Light lights[255];  // the number of lights must be gotten from another place
     /// };
     /// ```
     #[inline(always)]
@@ -441,20 +604,24 @@ impl Scene {
     /// # Shader Example
     /// ```glsl
     /// layout(set = 0, binding = 2) uniform Scene {
-    ///     vec4 bk_color;  // color of back ground
-    ///     float time;     // elapsed time since the scene was created.
-    ///     uint nlights;   // the number of lights
+    ///     vec4 bk_color;          // color of back ground
+    ///     uvec2 resolution;       // the size of the canvas
+    ///     float time;             // elapsed time since the scene was created.
+    ///     uint nlights;           // the number of lights, at most `LIGHT_MAX`
+    ///     vec4 wireframe_color;   // color of the wireframes
     /// };
     /// ```
     #[inline(always)]
     pub fn scene_status_buffer(&self) -> BufferHandler {
         let bk = self.scene_desc.studio.background;
+        let wf = self.wireframe_color;
         let size = self.scene_desc.render_texture.canvas_size;
         let scene_info = SceneInfo {
             background_color: [bk.r as f32, bk.g as f32, bk.b as f32, bk.a as f32],
             resolution: [size.0, size.1],
             time: self.elapsed().as_secs_f32(),
-            num_of_lights: self.scene_desc.studio.lights.len() as u32,
+            num_of_lights: usize::min(self.scene_desc.studio.lights.len(), LIGHT_MAX) as u32,
+            wireframe_color: [wf.r as f32, wf.g as f32, wf.b as f32, wf.a as f32],
         };
         BufferHandler::from_slice(&[scene_info], self.device(), BufferUsages::UNIFORM)
     }
@@ -474,13 +641,13 @@ impl Scene {
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
     /// };
     ///
-    /// layout(set = 0, binding = 1) buffer Lights {
-    ///     Light lights[];
+    /// layout(set = 0, binding = 1) uniform Lights {
+    ///     Light lights[255];  // the array with the length `LIGHT_MAX`
     /// };
     ///
     /// layout(set = 0, binding = 2) uniform Scene {
     ///     float time;     // elapsed time since the scene was created.
-    ///     uint nlights;   // the number of lights
+    ///     uint nlights;   // the number of lights, at most `LIGHT_MAX`
     /// };
     /// ```
     #[inline(always)]
@@ -517,6 +684,105 @@ impl Scene {
             .map(|obj| obj.visible = visible)
             .is_some()
     }
+    /// Sets whether the feature edges of a render object are drawn over its faces.
+    ///
+    /// The edges are given by [`Rendered::edge_buffer`], which is requested only when they are
+    /// shown. If the object has no edges, nothing is drawn.
+    /// If there does not exist the render object in the scene, does nothing and returns `false`.
+    pub fn set_edges_visibility<R: Rendered>(&mut self, object: &R, show_edges: bool) -> bool {
+        let (handler, objects) = (&self.device_handler, &mut self.objects);
+        let render_object = match objects.get_mut(&object.render_id()) {
+            Some(render_object) => render_object,
+            None => return false,
+        };
+        render_object.edges = match show_edges {
+            true => {
+                let pipeline_layout = handler
+                    .device()
+                    .create_pipeline_layout(&PipelineLayoutDescriptor {
+                        bind_group_layouts: &[
+                            &self.bind_group_layout,
+                            &render_object.bind_group_layout,
+                        ],
+                        push_constant_ranges: &[],
+                        label: None,
+                    });
+                object.edge_buffer(handler).and_then(|buffer| {
+                    let pipeline =
+                        object.edge_pipeline(handler, &pipeline_layout, &self.scene_desc)?;
+                    Some((buffer, pipeline))
+                })
+            }
+            false => None,
+        };
+        true
+    }
+    /// Draws the segments from the vertices of a render object along their normals
+    /// with `length` in the model coordinate, replacing the previous debug lines.
+    ///
+    /// The segments are given by [`Rendered::debug_line_buffer`].
+    /// If there does not exist the render object in the scene, or the object has no normals,
+    /// does nothing and returns `false`.
+    #[inline(always)]
+    pub fn show_normals<R: Rendered>(&mut self, object: &R, length: f64) -> bool {
+        self.show_debug_lines(object, DebugLines::Normals, length)
+    }
+    /// Draws the segments from the vertices of a render object along their tangents
+    /// with `length` in the model coordinate, replacing the previous debug lines.
+    ///
+    /// The segments are given by [`Rendered::debug_line_buffer`].
+    /// If there does not exist the render object in the scene, or the object has no tangents,
+    /// does nothing and returns `false`.
+    #[inline(always)]
+    pub fn show_tangents<R: Rendered>(&mut self, object: &R, length: f64) -> bool {
+        self.show_debug_lines(object, DebugLines::Tangents, length)
+    }
+    /// Stops drawing the debug lines of a render object.
+    ///
+    /// If there does not exist the render object in the scene, does nothing and returns `false`.
+    #[inline(always)]
+    pub fn hide_debug_lines<R: Rendered>(&mut self, object: &R) -> bool {
+        self.objects
+            .get_mut(&object.render_id())
+            .map(|obj| obj.debug_lines = None)
+            .is_some()
+    }
+    fn show_debug_lines<R: Rendered>(
+        &mut self,
+        object: &R,
+        lines: DebugLines,
+        length: f64,
+    ) -> bool {
+        let (handler, objects) = (&self.device_handler, &mut self.objects);
+        let render_object = match objects.get_mut(&object.render_id()) {
+            Some(render_object) => render_object,
+            None => return false,
+        };
+        let pipeline_layout = handler
+            .device()
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                bind_group_layouts: &[&self.bind_group_layout, &render_object.bind_group_layout],
+                push_constant_ranges: &[],
+                label: None,
+            });
+        let debug_lines = object.debug_line_buffer(handler, lines, length).and_then(|buffer| {
+            let pipeline =
+                object.debug_line_pipeline(handler, &pipeline_layout, &self.scene_desc)?;
+            Some(DebugLineObject {
+                lines,
+                length,
+                buffer,
+                pipeline,
+            })
+        });
+        match debug_lines {
+            Some(debug_lines) => {
+                render_object.debug_lines = Some(debug_lines);
+                true
+            }
+            None => false,
+        }
+    }
     /// Adds render objects to the scene.
     ///
     /// If there already exists a render object with the same ID,
@@ -556,6 +822,89 @@ impl Scene {
     #[inline(always)]
     pub fn number_of_objects(&self) -> usize { self.objects.len() }
 
+    /// Returns the IDs of the render objects in the scene,
+    /// in the order of the indices returned by [`Scene::pick`].
+    ///
+    /// Removing an object may change the order of the other objects.
+    #[inline(always)]
+    pub fn object_ids(&self) -> Vec<RenderID> {
+        self.objects.as_slice().iter().map(|(id, _)| *id).collect()
+    }
+
+    /// Sets the render objects to be highlighted.
+    ///
+    /// The silhouettes of the highlighted objects are drawn as outlines of
+    /// [`highlight_color`](Scene::highlight_color) on top of the normal render.
+    /// The previous highlighted objects are replaced. The IDs which are not in the scene are ignored.
+    ///
+    /// # Remarks
+    /// A pixel is regarded as covered by a highlighted object if the object writes a color
+    /// with non-zero alpha to the pixel.
+    #[inline(always)]
+    pub fn set_highlighted(&mut self, ids: &[RenderID]) { self.highlighted = ids.to_vec() }
+
+    /// Returns the IDs of the highlighted render objects.
+    #[inline(always)]
+    pub fn highlighted(&self) -> &[RenderID] { &self.highlighted }
+
+    /// Returns the color of the outlines of highlighted objects. Default is orange.
+    #[inline(always)]
+    pub fn highlight_color(&self) -> Color { self.highlight_color }
+
+    /// Sets the color of the outlines of highlighted objects.
+    #[inline(always)]
+    pub fn set_highlight_color(&mut self, color: Color) {
+        let info = OutlineInfo::new(color);
+        let buffer = &self.outline_buffer.buffer;
+        self.queue().write_buffer(buffer, 0, bytemuck::cast_slice(&[info]));
+        self.highlight_color = color;
+    }
+
+    /// Returns the render mode of the objects. Default is [`RenderMode::Shaded`].
+    #[inline(always)]
+    pub fn render_mode(&self) -> RenderMode { self.render_mode }
+
+    /// Sets the render mode of the objects.
+    ///
+    /// The wireframes, given by [`Rendered::wireframe_buffer`], are requested only when
+    /// the objects are added or their pipelines are updated while the mode draws them.
+    /// After switching from [`RenderMode::Shaded`], call [`Scene::update_pipeline`] for
+    /// the objects added before, otherwise they are still shaded.
+    #[inline(always)]
+    pub fn set_render_mode(&mut self, render_mode: RenderMode) { self.render_mode = render_mode }
+
+    /// Returns the background color, the clear color of each frame.
+    #[inline(always)]
+    pub fn background(&self) -> Color { self.scene_desc.studio.background }
+
+    /// Sets the background color, the clear color of each frame.
+    ///
+    /// This is the same as setting `background` of [`Scene::studio_config_mut`].
+    #[inline(always)]
+    pub fn set_background(&mut self, color: Color) { self.scene_desc.studio.background = color }
+
+    /// Returns the color of the wireframes drawn by [`RenderMode::Wireframe`] and
+    /// [`RenderMode::ShadedWireframe`]. Default is almost black.
+    #[inline(always)]
+    pub fn wireframe_color(&self) -> Color { self.wireframe_color }
+
+    /// Sets the color of the wireframes.
+    ///
+    /// The color is sent to the shaders by [`Scene::scene_status_buffer`].
+    #[inline(always)]
+    pub fn set_wireframe_color(&mut self, color: Color) { self.wireframe_color = color }
+
+    /// Returns whether the objects out of the view frustum are culled. Default is `true`.
+    #[inline(always)]
+    pub fn frustum_culling(&self) -> bool { self.frustum_culling }
+
+    /// Sets whether the objects out of the view frustum are culled.
+    ///
+    /// Turning it off draws all the visible objects, which is useful for debugging
+    /// the bounding boxes given by [`Rendered::oriented_bounding_box`].
+    #[inline(always)]
+    pub fn set_frustum_culling(&mut self, culling: bool) { self.frustum_culling = culling }
+
     /// Synchronizes the information of vertices of `object` in the CPU memory
     /// and that in the GPU memory.
     ///
@@ -569,6 +918,23 @@ impl Scene {
                 let (vb, ib) = object.vertex_buffer(handler);
                 render_object.vertex_buffer = vb;
                 render_object.index_buffer = ib;
+                render_object.instance_buffer = object.instance_buffer(handler);
+                render_object.bounds = object.oriented_bounding_box();
+                render_object.edges = match (object.edge_buffer(handler), &render_object.edges) {
+                    (Some(buffer), Some((_, pipeline))) => Some((buffer, Arc::clone(pipeline))),
+                    _ => None,
+                };
+                render_object.wireframe =
+                    match (object.wireframe_buffer(handler), &render_object.wireframe) {
+                        (Some(buffer), Some((_, pipeline))) => {
+                            Some((buffer, Arc::clone(pipeline)))
+                        }
+                        _ => None,
+                    };
+                render_object.debug_lines = render_object.debug_lines.take().and_then(|obj| {
+                    let buffer = object.debug_line_buffer(handler, obj.lines, obj.length)?;
+                    Some(DebugLineObject { buffer, ..obj })
+                });
                 true
             }
         }
@@ -598,6 +964,7 @@ impl Scene {
             Some(render_object) => {
                 let bind_group = object.bind_group(handler, &render_object.bind_group_layout);
                 render_object.bind_group = bind_group;
+                render_object.bounds = object.oriented_bounding_box();
                 true
             }
             _ => false,
@@ -621,6 +988,10 @@ impl Scene {
     /// If there does not exist the render object in the scene, does nothing and returns false.
     #[inline(always)]
     pub fn update_pipeline<R: Rendered>(&mut self, object: &R) -> bool {
+        let pick_pipeline = match self.objects.get(&object.render_id()) {
+            Some(render_object) => self.pick_pipeline(object, &render_object.bind_group_layout),
+            None => return false,
+        };
         let (handler, objects) = (&self.device_handler, &mut self.objects);
         match objects.get_mut(&object.render_id()) {
             Some(render_object) => {
@@ -635,6 +1006,29 @@ impl Scene {
                 });
                 render_object.pipeline =
                     object.pipeline(handler, &pipeline_layout, &self.scene_desc);
+                render_object.transparent = object.is_transparent();
+                render_object.pick_pipeline = pick_pipeline;
+                render_object.edges = render_object.edges.take().and_then(|(buffer, _)| {
+                    let pipeline =
+                        object.edge_pipeline(handler, &pipeline_layout, &self.scene_desc)?;
+                    Some((buffer, pipeline))
+                });
+                let wireframe = render_object.wireframe.take().map(|(buffer, _)| buffer);
+                let wireframe = match (wireframe, self.render_mode) {
+                    (Some(buffer), _) => Some(buffer),
+                    (None, RenderMode::Shaded) => None,
+                    (None, _) => object.wireframe_buffer(handler),
+                };
+                render_object.wireframe = wireframe.and_then(|buffer| {
+                    object
+                        .wireframe_pipeline(handler, &pipeline_layout, &self.scene_desc)
+                        .map(|pipeline| (buffer, pipeline))
+                });
+                render_object.debug_lines = render_object.debug_lines.take().and_then(|obj| {
+                    let pipeline =
+                        object.debug_line_pipeline(handler, &pipeline_layout, &self.scene_desc)?;
+                    Some(DebugLineObject { pipeline, ..obj })
+                });
                 true
             }
             _ => false,
@@ -652,6 +1046,89 @@ impl Scene {
         let closure = move |flag, object: &R| flag && self.update_pipeline(object);
         objects.into_iter().fold(true, closure)
     }
+    /// Creates the pipeline of [`Scene::pick`] of `object` whose bind group layout is `layout`.
+    pub(crate) fn pick_pipeline<R: Rendered + ?Sized>(
+        &self,
+        object: &R,
+        layout: &BindGroupLayout,
+    ) -> Option<Arc<RenderPipeline>> {
+        let pipeline_layout = self.device().create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &[&self.bind_group_layout, layout, &self.pick_bind_group_layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+        let fragment = FragmentState {
+            module: &self.pick_module,
+            entry_point: "fs_main",
+            targets: &PICK_TARGETS,
+        };
+        object.pick_pipeline(&self.device_handler, &pipeline_layout, fragment)
+    }
+
+    /// Returns the view frustum of the camera for the canvas.
+    #[inline(always)]
+    fn frustum(&self) -> Frustum {
+        let (width, height) = self.scene_desc.render_texture.canvas_size;
+        self.scene_desc.studio.camera.frustum(width as f64 / height as f64)
+    }
+
+    /// Returns the indices in [`Scene::object_ids`], the ids and the visible objects,
+    /// with the flags whether the objects are out of the view frustum.
+    fn culling_objects(&self) -> impl Iterator<Item = (usize, RenderID, &RenderObject, bool)> {
+        let frustum = self.frustum();
+        let culling = self.frustum_culling;
+        self.objects
+            .as_slice()
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, object))| object.visible)
+            .map(move |(i, (id, object))| {
+                let culled = match &object.bounds {
+                    Some(bounds) => culling && !frustum.intersects(bounds),
+                    None => false,
+                };
+                (i, *id, object, culled)
+            })
+    }
+
+    /// Returns the pairs of the ids and the objects to be drawn, in the order of drawing.
+    ///
+    /// The opaque objects are in the order of [`Scene::object_ids`], and the transparent
+    /// objects follow them from the farthest to the nearest to the camera.
+    /// The transparent objects without bounding boxes are regarded as the farthest.
+    fn drawn_objects(&self) -> Vec<(RenderID, &RenderObject)> {
+        let (mut transparent, opaque): (Vec<_>, Vec<_>) = self
+            .culling_objects()
+            .filter(|(_, _, _, culled)| !culled)
+            .map(|(_, id, object, _)| (id, object))
+            .partition(|(_, object)| object.transparent);
+        let position = self.scene_desc.studio.camera.position();
+        let distance = |object: &RenderObject| match &object.bounds {
+            Some(bounds) => bounds.center().distance2(position),
+            None => f64::INFINITY,
+        };
+        transparent.sort_by(|(_, object0), (_, object1)| {
+            distance(object1).total_cmp(&distance(object0))
+        });
+        opaque.into_iter().chain(transparent).collect()
+    }
+
+    /// Returns which objects are drawn and which objects are culled by the next rendering.
+    ///
+    /// An object is culled if its [`Rendered::oriented_bounding_box`] is out of
+    /// the view frustum of the camera, unless [`Scene::set_frustum_culling`] turns
+    /// the culling off. The invisible objects are not counted.
+    /// The drawn objects are in the order of drawing: the opaque objects first, and then
+    /// the transparent objects from the farthest to the nearest to the camera.
+    pub fn render_stats(&self) -> RenderStats {
+        let culled = self
+            .culling_objects()
+            .filter_map(|(_, id, _, culled)| culled.then_some(id))
+            .collect();
+        let drawn = self.drawn_objects().into_iter().map(|(id, _)| id).collect();
+        RenderStats { drawn, culled }
+    }
+
     #[inline(always)]
     fn depth_stencil_attachment_descriptor(
         depth_view: &TextureView,
@@ -666,6 +1143,183 @@ impl Scene {
         }
     }
 
+    fn draw_objects<'a, I>(rpass: &mut RenderPass<'a>, objects: I, render_mode: RenderMode)
+    where I: IntoIterator<Item = &'a RenderObject> {
+        for object in objects {
+            if !object.visible {
+                continue;
+            }
+            rpass.set_bind_group(1, &object.bind_group, &[]);
+            rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
+            let (shaded, wireframe) = match (render_mode, &object.wireframe) {
+                (RenderMode::Wireframe, Some(wireframe)) => (false, Some(wireframe)),
+                (RenderMode::ShadedWireframe, Some(wireframe)) => (true, Some(wireframe)),
+                _ => (true, None),
+            };
+            if shaded {
+                rpass.set_pipeline(&object.pipeline);
+                Self::draw_faces(rpass, object);
+            }
+            if let Some((wireframe_buffer, wireframe_pipeline)) = wireframe {
+                rpass.set_pipeline(wireframe_pipeline);
+                rpass.set_index_buffer(wireframe_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = wireframe_buffer.size as u32 / size_of::<u32>() as u32;
+                rpass.draw_indexed(0..index_size, 0, 0..1);
+            }
+            if let Some((edge_buffer, edge_pipeline)) = &object.edges {
+                rpass.set_pipeline(edge_pipeline);
+                rpass.set_vertex_buffer(0, edge_buffer.buffer.slice(..));
+                rpass.draw(0..(edge_buffer.size / edge_buffer.stride) as u32, 0..1);
+            }
+            if let Some(debug_lines) = &object.debug_lines {
+                let buffer = &debug_lines.buffer;
+                rpass.set_pipeline(&debug_lines.pipeline);
+                rpass.set_vertex_buffer(0, buffer.buffer.slice(..));
+                rpass.draw(0..(buffer.size / buffer.stride) as u32, 0..1);
+            }
+        }
+    }
+
+    /// Draws the faces of `object` by the pipeline set in advance.
+    fn draw_faces<'a>(rpass: &mut RenderPass<'a>, object: &'a RenderObject) {
+        let instances = match object.instance_buffer {
+            Some(ref instance_buffer) => (instance_buffer.size / instance_buffer.stride) as u32,
+            None => 1,
+        };
+        if instances == 0 {
+            return;
+        }
+        rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
+        if let Some(ref instance_buffer) = object.instance_buffer {
+            rpass.set_vertex_buffer(1, instance_buffer.buffer.slice(..));
+        }
+        match object.index_buffer {
+            Some(ref index_buffer) => {
+                rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = index_buffer.size as u32 / size_of::<u32>() as u32;
+                rpass.draw_indexed(0..index_size, 0, 0..instances);
+            }
+            None => rpass.draw(
+                0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
+                0..instances,
+            ),
+        }
+    }
+
+    fn outline_pipeline(
+        device: &Device,
+        layout: &BindGroupLayout,
+        format: TextureFormat,
+    ) -> RenderPipeline {
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            label: None,
+            source: ShaderSource::Wgsl(include_str!("shaders/outline.wgsl").into()),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &[layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+        device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            label: None,
+            multiview: None,
+        })
+    }
+
+    /// Draws the outlines of the highlighted objects onto `view`.
+    ///
+    /// The highlighted objects are rendered by their own pipelines into a mask texture,
+    /// and the pixels near the mask are filled by the highlight color.
+    fn render_outline(
+        &self,
+        encoder: &mut CommandEncoder,
+        bind_group: &BindGroup,
+        view: &TextureView,
+    ) {
+        let objects: Vec<&RenderObject> = self
+            .highlighted
+            .iter()
+            .filter_map(|id| self.objects.get(id))
+            .filter(|object| object.visible)
+            .collect();
+        if objects.is_empty() {
+            return;
+        }
+        let mask_view = self.outline_mask.create_view(&Default::default());
+        // the backend buffers are free, since the objects have been already drawn.
+        let depth_view = self
+            .foward_depth
+            .as_ref()
+            .map(|tex| tex.create_view(&Default::default()));
+        let sampled_view = self
+            .sampling_buffer
+            .as_ref()
+            .map(|tex| tex.create_view(&Default::default()));
+        {
+            let (attachment, resolve_target) = match sampled_view.as_ref() {
+                Some(sampled_view) => (sampled_view, Some(&mask_view)),
+                None => (&mask_view, None),
+            };
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: attachment,
+                    resolve_target,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::TRANSPARENT),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: depth_view
+                    .as_ref()
+                    .map(Self::depth_stencil_attachment_descriptor),
+                ..Default::default()
+            });
+            rpass.set_bind_group(0, bind_group, &[]);
+            Self::draw_objects(&mut rpass, objects, self.render_mode);
+        }
+
+        let outline_bind_group = bind_group_util::create_bind_group(
+            self.device(),
+            &self.outline_bind_group_layout,
+            vec![
+                BindingResource::TextureView(&mask_view),
+                self.outline_buffer.binding_resource(),
+            ],
+        );
+        let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: true,
+                },
+            })],
+            ..Default::default()
+        });
+        rpass.set_pipeline(&self.outline_pipeline);
+        rpass.set_bind_group(0, &outline_bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+
     /// Renders the scene to `view`.
     pub fn render(&self, view: &TextureView) {
         let bind_group = self.scene_bind_group();
@@ -700,27 +1354,10 @@ impl Scene {
                 ..Default::default()
             });
             rpass.set_bind_group(0, &bind_group, &[]);
-            for (_, object) in &self.objects {
-                if !object.visible {
-                    continue;
-                }
-                rpass.set_pipeline(&object.pipeline);
-                rpass.set_bind_group(1, &object.bind_group, &[]);
-                rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
-                match object.index_buffer {
-                    Some(ref index_buffer) => {
-                        rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
-                        let index_size =
-                            index_buffer.size as u32 / std::mem::size_of::<u32>() as u32;
-                        rpass.draw_indexed(0..index_size, 0, 0..1);
-                    }
-                    None => rpass.draw(
-                        0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
-                        0..1,
-                    ),
-                }
-            }
+            let objects = self.drawn_objects().into_iter().map(|(_, object)| object);
+            Self::draw_objects(&mut rpass, objects, self.render_mode);
         }
+        self.render_outline(&mut encoder, &bind_group, view);
         self.queue().submit(vec![encoder.finish()]);
     }
 
@@ -729,29 +1366,67 @@ impl Scene {
         let texture = self.compatible_texture();
         let view = texture.create_view(&Default::default());
         self.render(&view);
-        let (device, queue) = (self.device(), self.queue());
+        let encoder = self
+            .device()
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        let size = self.scene_desc.render_texture.canvas_size;
+        self.read_texture(encoder, &texture, Origin3d::ZERO, size).await
+    }
 
-        let (width, height) = self.scene_desc.render_texture.canvas_size;
-        let size = (width * height * 4) as u64;
+    /// Renders the scene with the size `width` x `height` to an image.
+    ///
+    /// The canvas size of the scene is changed only while rendering, so the camera and
+    /// the backend buffers are fitted to the size of the image.
+    ///
+    /// # Panics
+    /// Panics if the format of the render texture is neither 8-bit RGBA nor 8-bit BGRA.
+    pub async fn render_to_image(&mut self, width: u32, height: u32) -> image::RgbaImage {
+        let render_texture = self.scene_desc.render_texture;
+        let bgra = match render_texture.format {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => false,
+            TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => true,
+            format => panic!("The texture format {format:?} cannot be converted to an image."),
+        };
+        self.descriptor_mut().render_texture.canvas_size = (width, height);
+        let mut buffer = self.render_to_buffer().await;
+        self.descriptor_mut().render_texture.canvas_size = render_texture.canvas_size;
+        if bgra {
+            buffer.chunks_exact_mut(4).for_each(|pixel| pixel.swap(0, 2));
+        }
+        image::RgbaImage::from_raw(width, height, buffer).unwrap()
+    }
+
+    /// Copies the rectangle of `texture` from `origin` with 4 bytes per pixel to the vector
+    /// after the commands in `encoder`.
+    async fn read_texture(
+        &self,
+        mut encoder: CommandEncoder,
+        texture: &Texture,
+        origin: Origin3d,
+        (width, height): (u32, u32),
+    ) -> Vec<u8> {
+        let (device, queue) = (self.device(), self.queue());
+        // each row of the copied buffer must be aligned.
+        let row_size = width * 4;
+        let padded_row_size = row_size.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT;
         let buffer = device.create_buffer(&BufferDescriptor {
             label: None,
             mapped_at_creation: false,
             usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
-            size,
+            size: (padded_row_size * height) as u64,
         });
-        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
         encoder.copy_texture_to_buffer(
             ImageCopyTexture {
-                texture: &texture,
+                texture,
                 mip_level: 0,
-                origin: Origin3d::ZERO,
+                origin,
                 aspect: TextureAspect::All,
             },
             ImageCopyBuffer {
                 buffer: &buffer,
                 layout: ImageDataLayout {
                     offset: 0,
-                    bytes_per_row: (width * 4).try_into().ok(),
+                    bytes_per_row: padded_row_size.try_into().ok(),
                     rows_per_image: height.try_into().ok(),
                 },
             },
@@ -767,11 +1442,287 @@ impl Scene {
         buffer_slice.map_async(MapMode::Read, move |v| sender.send(v).unwrap());
         device.poll(Maintain::Wait);
         match receiver.receive().await {
-            Some(Ok(_)) => buffer_slice.get_mapped_range().iter().copied().collect(),
+            Some(Ok(_)) => buffer_slice
+                .get_mapped_range()
+                .chunks(padded_row_size as usize)
+                .flat_map(|row| &row[..row_size as usize])
+                .copied()
+                .collect(),
             Some(Err(e)) => panic!("{}", e),
             None => panic!("Asynchronous processing fails"),
         }
     }
+
+    fn depth_pipeline(&self, layout: &BindGroupLayout, multisampled: bool) -> RenderPipeline {
+        let device = self.device();
+        let source = include_str!("shaders/depth.wgsl");
+        let source = match multisampled {
+            true => source.replace("texture_depth_2d", "texture_depth_multisampled_2d"),
+            false => source.to_string(),
+        };
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            label: None,
+            source: ShaderSource::Wgsl(source.into()),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &[layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+        device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format: TextureFormat::R32Float,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            label: None,
+            multiview: None,
+        })
+    }
+
+    /// Renders the depth map of the scene with the size `width` x `height`.
+    ///
+    /// The returned vector has `width * height` elements in row-major order.
+    /// Each element is the distance from the camera, linearized from the clip depth
+    /// and normalized so that the near clip plane is `0.0` and the far clip plane is `1.0`.
+    /// The pixels where nothing is drawn are `1.0`.
+    /// The faces are drawn regardless of [`RenderMode`].
+    ///
+    /// # Panics
+    /// Panics if the depth test is disabled by [`BackendBufferConfig`].
+    pub async fn render_depth_to_buffer(&self, width: u32, height: u32) -> Vec<f32> {
+        let backend_buffer = self.scene_desc.backend_buffer;
+        if !backend_buffer.depth_test {
+            panic!("The depth map cannot be rendered without the depth test.");
+        }
+        let device = self.device();
+        let multisampled = backend_buffer.sample_count > 1;
+        let extent = Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let create_texture = |format, sample_count, usage| {
+            device.create_texture(&TextureDescriptor {
+                size: extent,
+                mip_level_count: 1,
+                sample_count,
+                dimension: TextureDimension::D2,
+                format,
+                usage,
+                label: None,
+            })
+        };
+        let render_texture = self.scene_desc.render_texture;
+        let color = create_texture(render_texture.format, 1, TextureUsages::RENDER_ATTACHMENT);
+        let color_view = color.create_view(&Default::default());
+        let sampled = multisampled.then(|| {
+            create_texture(
+                render_texture.format,
+                backend_buffer.sample_count,
+                TextureUsages::RENDER_ATTACHMENT,
+            )
+        });
+        let sampled_view = sampled.as_ref().map(|tex| tex.create_view(&Default::default()));
+        let depth = create_texture(
+            TextureFormat::Depth32Float,
+            backend_buffer.sample_count,
+            TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+        );
+        let depth_view = depth.create_view(&Default::default());
+        let target = create_texture(
+            TextureFormat::R32Float,
+            1,
+            TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        );
+        let target_view = target.create_view(&Default::default());
+
+        let as_rat = width as f64 / height as f64;
+        let camera_buffer = self.scene_desc.studio.camera.buffer(as_rat, device);
+        let bind_group = bind_group_util::create_bind_group(
+            device,
+            &self.bind_group_layout,
+            vec![
+                camera_buffer.binding_resource(),
+                self.lights_buffer().binding_resource(),
+                self.scene_status_buffer().binding_resource(),
+            ],
+        );
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        {
+            let (attachment, resolve_target) = match sampled_view.as_ref() {
+                Some(sampled_view) => (sampled_view, Some(&color_view)),
+                None => (&color_view, None),
+            };
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: attachment,
+                    resolve_target,
+                    ops: Operations {
+                        load: LoadOp::Clear(self.scene_desc.studio.background),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: Some(Self::depth_stencil_attachment_descriptor(
+                    &depth_view,
+                )),
+                ..Default::default()
+            });
+            rpass.set_bind_group(0, &bind_group, &[]);
+            let objects = self.objects.as_slice().iter().map(|(_, object)| object);
+            Self::draw_objects(&mut rpass, objects, RenderMode::Shaded);
+        }
+
+        let layout = bind_group_util::create_bind_group_layout(
+            device,
+            &[PreBindGroupLayoutEntry {
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Texture {
+                    sample_type: TextureSampleType::Depth,
+                    view_dimension: TextureViewDimension::D2,
+                    multisampled,
+                },
+                count: None,
+            }],
+        );
+        let depth_bind_group = bind_group_util::create_bind_group(
+            device,
+            &layout,
+            vec![BindingResource::TextureView(&depth_view)],
+        );
+        let pipeline = self.depth_pipeline(&layout, multisampled);
+        {
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &target_view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::WHITE),
+                        store: true,
+                    },
+                })],
+                ..Default::default()
+            });
+            rpass.set_pipeline(&pipeline);
+            rpass.set_bind_group(0, &depth_bind_group, &[]);
+            rpass.draw(0..3, 0..1);
+        }
+
+        let clip_depth: Vec<f32> = self
+            .read_texture(encoder, &target, Origin3d::ZERO, (width, height))
+            .await
+            .chunks_exact(4)
+            .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+
+        // the distance from the camera is recovered by the inverse of the projection.
+        let inverse = self.scene_desc.studio.camera.projection.invert().unwrap();
+        let distance = |depth: f64| {
+            let v = inverse * Vector4::new(0.0, 0.0, depth, 1.0);
+            -v.z / v.w
+        };
+        let (near, far) = (distance(0.0), distance(1.0));
+        clip_depth
+            .into_iter()
+            .map(|depth| ((distance(depth as f64) - near) / (far - near)) as f32)
+            .collect()
+    }
+
+    /// Returns the index of the object drawn at the pixel `(x, y)` of the canvas,
+    /// where the origin is the top-left corner.
+    ///
+    /// The index is the one in [`Scene::object_ids`]. Returns `None` if the pixel is
+    /// the background or out of the canvas.
+    ///
+    /// The objects are drawn only at the pixel by the pipelines given by
+    /// [`Rendered::pick_pipeline`], which write the indices of the objects, and the index of
+    /// the nearest one remains by the depth test. Like [`Scene::render_depth_to_buffer`],
+    /// the faces are picked regardless of [`RenderMode`]. The transparent objects are picked
+    /// as well as the opaque ones, and the objects culled by the view frustum are not picked.
+    pub async fn pick(&self, x: u32, y: u32) -> Option<usize> {
+        let (width, height) = self.scene_desc.render_texture.canvas_size;
+        if x >= width || y >= height {
+            return None;
+        }
+        let objects: Vec<(usize, &RenderObject, &RenderPipeline)> = self
+            .culling_objects()
+            .filter(|(_, _, _, culled)| !culled)
+            .filter_map(|(i, _, object, _)| Some((i, object, object.pick_pipeline.as_deref()?)))
+            .collect();
+        if objects.is_empty() {
+            return None;
+        }
+        let device = self.device();
+        let (id_texture, depth) = &self.pick_textures;
+        let id_view = id_texture.create_view(&Default::default());
+        let depth_view = depth.create_view(&Default::default());
+
+        // the indices are aligned for the dynamic offsets. `0` is the background.
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as usize;
+        let stride = alignment / size_of::<u32>();
+        let mut ids = vec![0_u32; objects.len() * stride];
+        objects
+            .iter()
+            .enumerate()
+            .for_each(|(i, (index, _, _))| ids[i * stride] = *index as u32 + 1);
+        let id_buffer = BufferHandler::from_slice(&ids, device, BufferUsages::UNIFORM);
+        let id_bind_group = bind_group_util::create_bind_group(
+            device,
+            &self.pick_bind_group_layout,
+            vec![BindingResource::Buffer(BufferBinding {
+                buffer: id_buffer.buffer(),
+                offset: 0,
+                size: BufferSize::new(size_of::<u32>() as u64),
+            })],
+        );
+        let bind_group = self.scene_bind_group();
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        {
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &id_view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::TRANSPARENT),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: Some(Self::depth_stencil_attachment_descriptor(
+                    &depth_view,
+                )),
+                ..Default::default()
+            });
+            rpass.set_scissor_rect(x, y, 1, 1);
+            rpass.set_bind_group(0, &bind_group, &[]);
+            for (i, (_, object, pipeline)) in objects.into_iter().enumerate() {
+                let offset = (i * stride * size_of::<u32>()) as u32;
+                rpass.set_pipeline(pipeline);
+                rpass.set_bind_group(1, &object.bind_group, &[]);
+                rpass.set_bind_group(2, &id_bind_group, &[offset]);
+                Self::draw_faces(&mut rpass, object);
+            }
+        }
+
+        let origin = Origin3d { x, y, z: 0 };
+        let bytes = self.read_texture(encoder, id_texture, origin, (1, 1)).await;
+        match u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) {
+            0 => None,
+            id => Some(id as usize - 1),
+        }
+    }
 }
 
 impl WindowScene {
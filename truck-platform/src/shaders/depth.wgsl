@@ -0,0 +1,17 @@
+@group(0)
+@binding(0)
+var depth: texture_depth_2d;
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    var vertex: array<vec2<f32>, 3>;
+    vertex[0] = vec2<f32>(-1.0, -1.0);
+    vertex[1] = vec2<f32>(3.0, -1.0);
+    vertex[2] = vec2<f32>(-1.0, 3.0);
+    return vec4<f32>(vertex[idx], 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) f32 {
+    return textureLoad(depth, vec2<i32>(position.xy), 0);
+}
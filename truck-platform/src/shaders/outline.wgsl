@@ -0,0 +1,44 @@
+struct Outline {
+    color: vec4<f32>,
+    width: i32,
+};
+
+@group(0)
+@binding(0)
+var mask: texture_2d<f32>;
+
+@group(0)
+@binding(1)
+var<uniform> outline: Outline;
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    var vertex: array<vec2<f32>, 3>;
+    vertex[0] = vec2<f32>(-1.0, -1.0);
+    vertex[1] = vec2<f32>(3.0, -1.0);
+    vertex[2] = vec2<f32>(-1.0, 3.0);
+    return vec4<f32>(vertex[idx], 0.0, 1.0);
+}
+
+fn covered(p: vec2<i32>) -> bool {
+    return textureLoad(mask, p, 0).a > 0.0;
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let size = vec2<i32>(textureDimensions(mask));
+    let p = vec2<i32>(position.xy);
+    var found = false;
+    if (!covered(p)) {
+        for (var i: i32 = -outline.width; i <= outline.width; i = i + 1) {
+            for (var j: i32 = -outline.width; j <= outline.width; j = j + 1) {
+                let q = clamp(p + vec2<i32>(i, j), vec2<i32>(0, 0), size - vec2<i32>(1, 1));
+                found = found || covered(q);
+            }
+        }
+    }
+    if (!found) {
+        discard;
+    }
+    return outline.color;
+}
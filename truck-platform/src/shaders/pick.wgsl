@@ -0,0 +1,9 @@
+// the index of the object, starting from 1. `0` is the background.
+@group(2)
+@binding(0)
+var<uniform> id: u32;
+
+@fragment
+fn fs_main() -> @location(0) u32 {
+    return id;
+}
@@ -81,3 +81,32 @@ fn exec_bind_group_test(backend: Backends, out_dir: &str) {
 
 #[test]
 fn bind_group_test() { common::os_alt_exec_test(exec_bind_group_test); }
+
+fn exec_lights_buffer_test(backend: Backends, _: &str) {
+    // `LightInfo` consists of three `vec4<f32>`s.
+    const LIGHT_INFO_SIZE: usize = 48;
+    let desc = SceneDescriptor {
+        studio: StudioConfig {
+            lights: vec![POINT_LIGHT, UNIFORM_LIGHT, POINT_LIGHT],
+            ..Default::default()
+        },
+        render_texture: RenderTextureConfig {
+            canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+            format: TextureFormat::Rgba8Unorm,
+        },
+        ..Default::default()
+    };
+    let mut scene = Scene::new(common::init_device(backend), &desc);
+    let size = (LIGHT_MAX * LIGHT_INFO_SIZE) as u64;
+    assert_eq!(scene.lights_buffer().size(), size);
+
+    // the lights over `LIGHT_MAX` are ignored.
+    scene.studio_config_mut().lights = vec![POINT_LIGHT; LIGHT_MAX + 10];
+    assert_eq!(scene.lights_buffer().size(), size);
+    let plane = new_plane!("shaders/bindgroup.wgsl", "vs_main", "fs_main");
+    let buffer = common::render_one(&mut scene, &plane);
+    assert_eq!(buffer.len(), (PICTURE_WIDTH * PICTURE_HEIGHT * 4) as usize);
+}
+
+#[test]
+fn lights_buffer_test() { common::os_alt_exec_test(exec_lights_buffer_test); }
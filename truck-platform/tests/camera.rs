@@ -0,0 +1,74 @@
+use std::f64::consts::PI;
+use truck_base::{cgmath64::*, tolerance::Tolerance};
+use truck_platform::*;
+
+fn look_at_camera(position: Point3, target: Point3) -> Camera {
+    let matrix = Matrix4::look_at_rh(position, target, Vector3::unit_y());
+    Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 10.0)
+}
+
+fn looks_at(camera: &Camera, target: Point3) -> bool {
+    let dir = (target - camera.position()).normalize();
+    camera.eye_direction().near(&dir)
+}
+
+#[test]
+fn orbit_full_turn() {
+    let target = Point3::new(0.5, -0.3, 1.2);
+    let camera0 = look_at_camera(Point3::new(2.0, 1.5, -1.0), target);
+
+    let mut camera = camera0;
+    camera.orbit(target, 2.0 * PI, 0.0);
+    assert!(camera.matrix.near(&camera0.matrix));
+
+    let mut camera = camera0;
+    (0..12).for_each(|_| {
+        camera.orbit(target, PI / 6.0, 0.0);
+        assert!(looks_at(&camera, target));
+        assert!(camera.head_direction().y > 0.0);
+    });
+    assert!(camera.matrix.near(&camera0.matrix));
+
+    let mut camera = camera0;
+    camera.orbit(target, 0.0, 0.4);
+    (0..8).for_each(|_| camera.orbit(target, PI / 4.0, 0.0));
+    camera.orbit(target, 0.0, -0.4);
+    assert!(camera.matrix.near(&camera0.matrix));
+}
+
+#[test]
+fn orbit_clamp_at_poles() {
+    let target = Point3::new(0.0, 1.0, 0.0);
+    let mut camera = look_at_camera(Point3::new(0.0, 1.0, 3.0), target);
+    let distance = camera.position().distance(target);
+    (0..10).for_each(|_| {
+        camera.orbit(target, 0.1, 0.5);
+        assert!(looks_at(&camera, target));
+        assert!(camera.position().distance(target).near(&distance));
+        // the camera does not go over the north pole.
+        assert!(camera.position().y < target.y + distance);
+        assert!(camera.head_direction().y > 0.0);
+    });
+    (0..20).for_each(|_| {
+        camera.orbit(target, -0.1, -0.5);
+        assert!(looks_at(&camera, target));
+        assert!(camera.position().y > target.y - distance);
+        assert!(camera.head_direction().y > 0.0);
+    });
+}
+
+#[test]
+fn pan_and_dolly() {
+    let target = Point3::origin();
+    let mut camera = look_at_camera(Point3::new(0.0, 0.0, 4.0), target);
+    camera.dolly(1.5);
+    assert!(camera.position().near(&Point3::new(0.0, 0.0, 2.5)));
+    assert!(looks_at(&camera, target));
+    camera.dolly(-0.5);
+    assert!(camera.position().near(&Point3::new(0.0, 0.0, 3.0)));
+
+    camera.pan(1.0, -2.0);
+    assert!(camera.position().near(&Point3::new(1.0, -2.0, 3.0)));
+    assert!(camera.eye_direction().near(&-Vector3::unit_z()));
+    assert!(looks_at(&camera, Point3::new(1.0, -2.0, 0.0)));
+}
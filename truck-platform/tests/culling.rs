@@ -0,0 +1,41 @@
+use std::f64::consts::PI;
+use truck_base::{bounding_box::*, cgmath64::*};
+use truck_platform::*;
+
+fn axis_aligned(bounds: &OrientedBoundingBox) -> OrientedBoundingBox {
+    let bdd_box = bounds.bounding_box();
+    OrientedBoundingBox::new(bdd_box.center(), Matrix3::from_diagonal(bdd_box.diagonal() / 2.0))
+}
+
+#[test]
+fn thin_object_along_frustum_edge() {
+    // the right plane of the frustum is x + z = 0.
+    let camera = Camera::perspective_camera(Matrix4::identity(), Rad(PI / 2.0), 0.1, 10.0);
+    let frustum = camera.frustum(1.0);
+
+    // a thin stick parallel to the right plane, slightly outside the frustum.
+    let normal = Vector3::new(1.0, 0.0, 1.0).normalize();
+    let stick = OrientedBoundingBox::new(
+        Point3::new(3.0, 0.0, -3.0) + 0.3 * normal,
+        Matrix3::from_cols(
+            Vector3::new(2.0, 0.0, -2.0),
+            Vector3::new(0.0, 0.05, 0.0),
+            0.05 * normal,
+        ),
+    );
+    assert!(!frustum.intersects(&stick));
+    // the axis-aligned box of the stick intersects the frustum.
+    assert!(frustum.intersects(&axis_aligned(&stick)));
+
+    // the same stick slightly inside the frustum is kept.
+    let matrix = Matrix4::from_translation(-0.6 * normal);
+    assert!(frustum.intersects(&stick.transformed(matrix)));
+
+    // the stick is culled by the parallel camera, too.
+    let camera = Camera::parallel_camera(Matrix4::identity(), 4.0, 0.1, 10.0);
+    let frustum = camera.frustum(1.0);
+    let matrix = Matrix4::from_translation(Vector3::new(-1.0, 0.0, 0.0));
+    assert!(frustum.intersects(&stick.transformed(matrix)));
+    let matrix = Matrix4::from_translation(Vector3::new(3.0, 0.0, 0.0));
+    assert!(!frustum.intersects(&stick.transformed(matrix)));
+}
@@ -0,0 +1,64 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+const PICTURE_WIDTH: u32 = 256;
+const PICTURE_HEIGHT: u32 = 256;
+
+fn save_buffer<P: AsRef<std::path::Path>>(path: P, vec: &[u8]) {
+    image::save_buffer(
+        path,
+        vec,
+        PICTURE_WIDTH,
+        PICTURE_HEIGHT,
+        image::ColorType::Rgba8,
+    )
+    .unwrap();
+}
+
+fn pixel(buffer: &[u8], row: u32, col: u32) -> &[u8] {
+    let idx = ((row * PICTURE_WIDTH + col) * 4) as usize;
+    &buffer[idx..idx + 4]
+}
+
+fn exec_highlight_test(backend: Backends, out_dir: &str) {
+    let out_dir = String::from(out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let desc = SceneDescriptor {
+        render_texture: RenderTextureConfig {
+            canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+            format: TextureFormat::Rgba8Unorm,
+        },
+        ..Default::default()
+    };
+    let mut scene = Scene::new(common::init_device(backend), &desc);
+    let left = new_plane!("shaders/squares.wgsl", "vs_left", "fs_main");
+    let right = new_plane!("shaders/squares.wgsl", "vs_right", "fs_main");
+    scene.add_object(&left);
+    scene.add_object(&right);
+    scene.set_highlight_color(Color::RED);
+    scene.set_highlighted(&[left.id]);
+    assert_eq!(scene.highlighted(), &[left.id]);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    save_buffer(out_dir + "highlight.png", &buffer);
+
+    // the left square covers the columns [26, 101], the right one [154, 229].
+    let (white, red, black) = ([255, 255, 255, 255], [255, 0, 0, 255], [0, 0, 0, 255]);
+    assert_eq!(pixel(&buffer, 128, 60), &white);
+    assert_eq!(pixel(&buffer, 128, 24), &red);
+    assert_eq!(pixel(&buffer, 128, 104), &red);
+    assert_eq!(pixel(&buffer, 128, 15), &black);
+    assert_eq!(pixel(&buffer, 128, 128), &black);
+    assert_eq!(pixel(&buffer, 128, 190), &white);
+    assert_eq!(pixel(&buffer, 128, 152), &black);
+    assert_eq!(pixel(&buffer, 128, 231), &black);
+
+    scene.set_highlighted(&[]);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert_eq!(pixel(&buffer, 128, 24), &black);
+    assert_eq!(pixel(&buffer, 128, 104), &black);
+}
+
+#[test]
+fn highlight_test() { common::os_alt_exec_test(exec_highlight_test); }
@@ -0,0 +1,23 @@
+fn square(idx: u32, center: f32) -> vec4<f32> {
+    var vertex: array<vec2<f32>, 4>;
+    vertex[0] = vec2<f32>(center - 0.3, -0.3);
+    vertex[1] = vec2<f32>(center + 0.3, -0.3);
+    vertex[2] = vec2<f32>(center - 0.3, 0.3);
+    vertex[3] = vec2<f32>(center + 0.3, 0.3);
+    return vec4<f32>(vertex[idx], 0.0, 1.0);
+}
+
+@vertex
+fn vs_left(@location(0) idx: u32) -> @builtin(position) vec4<f32> {
+    return square(idx, -0.5);
+}
+
+@vertex
+fn vs_right(@location(0) idx: u32) -> @builtin(position) vec4<f32> {
+    return square(idx, 0.5);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(1.0, 1.0, 1.0, 1.0);
+}
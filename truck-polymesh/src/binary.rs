@@ -0,0 +1,76 @@
+//! The binary format is the version byte [`FORMAT_VERSION`] followed by the mesh serialized
+//! by the default configuration of [`bincode`]:
+//! - all integers and floats are fixed-size and little-endian,
+//! - the lengths of the vectors are `u64`, and
+//! - `Option` has the one-byte tag, `0` for `None` and `1` for `Some`.
+//!
+//! The version byte is incremented when the layout changes, and the streams of the
+//! older versions are rejected with an error instead of being misread.
+
+use crate::*;
+use std::io::{BufReader, BufWriter, Read, Write};
+
+type Result<T> = std::result::Result<T, errors::Error>;
+
+/// The version of the binary format, written at the head of the stream.
+pub const FORMAT_VERSION: u8 = 1;
+
+fn into_io_error(error: bincode::ErrorKind) -> std::io::Error {
+    match error {
+        bincode::ErrorKind::Io(error) => error,
+        error => std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+    }
+}
+
+/// Writes the mesh in the binary format.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[&[0, 1, 2]]),
+/// );
+/// let mut bytes = Vec::<u8>::new();
+/// binary::write(&mesh, &mut bytes).unwrap();
+/// assert_eq!(bytes[0], binary::FORMAT_VERSION);
+/// assert_eq!(binary::read(bytes.as_slice()).unwrap(), mesh);
+/// ```
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    writer.write_all(&[FORMAT_VERSION])?;
+    bincode::serialize_into(&mut writer, mesh).map_err(|e| into_io_error(*e))?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads the mesh in the binary format.
+/// # Errors
+/// Returns an error if the version byte is not [`FORMAT_VERSION`], and
+/// [`Error::OutOfRange`](errors::Error::OutOfRange) if a face refers to a missing attribute.
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    let mut reader = BufReader::new(reader);
+    let mut version = [0_u8];
+    reader.read_exact(&mut version)?;
+    if version[0] != FORMAT_VERSION {
+        let message = format!("unsupported binary format version: {}", version[0]);
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, message).into());
+    }
+    let mesh: PolygonMesh = bincode::deserialize_from(reader).map_err(|e| into_io_error(*e))?;
+    PolygonMesh::try_new(mesh.attributes, mesh.faces)
+}
+
+impl PolygonMesh {
+    /// Writes the mesh in the binary format, cf: [`binary::write`](./binary/fn.write.html).
+    #[inline(always)]
+    pub fn write_bin<W: Write>(&self, writer: W) -> Result<()> { write(self, writer) }
+    /// Reads the mesh in the binary format, cf: [`binary::read`](./binary/fn.read.html).
+    #[inline(always)]
+    pub fn read_bin<R: Read>(reader: R) -> Result<PolygonMesh> { read(reader) }
+}
@@ -80,11 +80,75 @@ pub enum Error<V: std::fmt::Debug = crate::StandardVertex> {
     /// ```
     #[error("This division vector is unsorted.")]
     UnsortedDivision,
+    /// The edge, given by the indices of positions, is shared by more than two faces.
+    #[error("The edge {0:?} is shared by more than two faces.")]
+    NonManifoldEdge([usize; 2]),
+    /// Two faces adjacent by the edge run the edge in the same direction.
+    #[error("The faces adjacent by the edge {0:?} are inconsistently oriented.")]
+    InconsistentOrientation([usize; 2]),
+    /// The faces around the vertex, given by the index of position, are not connected as a fan.
+    #[error("The faces around the vertex {0} do not form a single fan.")]
+    NonManifoldVertex(usize),
+    /// The mesh has no faces to be exported.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use errors::Error;
+    /// use gltf::GltfFormat;
+    ///
+    /// let mesh = PolygonMesh::default();
+    /// let res = gltf::write(&mesh, Vec::new(), GltfFormat::Glb);
+    /// assert!(matches!(res, Err(Error::EmptyMesh)));
+    /// ```
+    #[error("The mesh has no faces.")]
+    EmptyMesh,
+    /// The wavefront obj data is malformed.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use errors::{Error, ObjErrorKind};
+    ///
+    /// let data = b"v 0.0 0.0 0.0
+    /// v 1.0 0.0 0.0
+    /// f 1 2 3
+    /// ";
+    /// match obj::read(data.as_slice()) {
+    ///     Err(Error::ObjSyntax { line, kind }) => {
+    ///         assert_eq!(line, 3);
+    ///         assert_eq!(kind, ObjErrorKind::IndexOutOfRange("3".to_string()));
+    ///     }
+    ///     _ => panic!("wrong result!"),
+    /// }
+    /// ```
+    #[error("The obj data is malformed at line {line}: {kind}")]
+    ObjSyntax {
+        /// the line number, counted from one
+        line: usize,
+        /// the kind of the error
+        kind: ObjErrorKind,
+    },
     /// Errors caused by obj files I/O.
     #[error(transparent)]
     FromIO(#[from] std::io::Error),
 }
 
+/// The kinds of the syntax errors of wavefront obj data, cf: [`Error::ObjSyntax`]
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ObjErrorKind {
+    /// The line has fewer values than required.
+    #[error("Some values are missing.")]
+    MissingValue,
+    /// The token is not a number.
+    #[error("The token {0:?} is not a number.")]
+    InvalidNumber(String),
+    /// The token is not a vertex of a face, like `1/2/3`.
+    #[error("The token {0:?} is not a vertex of a face.")]
+    InvalidVertex(String),
+    /// The token of a vertex of a face refers an element which is not defined.
+    #[error("The token {0:?} refers an undefined element.")]
+    IndexOutOfRange(String),
+}
+
 impl From<std::num::ParseFloatError> for Error {
     fn from(error: std::num::ParseFloatError) -> Error {
         std::io::Error::new(std::io::ErrorKind::InvalidData, error).into()
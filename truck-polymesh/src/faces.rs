@@ -234,6 +234,45 @@ impl<V: Copy> Faces<V> {
             .chain(self.other_faces.iter().map(|v| v.as_ref()))
     }
 
+    /// Returns the iterator of the faces in the order of the global face indices.
+    ///
+    /// The global index of a face is the index accepted by `faces[idx]`.
+    /// The faces are indexed in the order: the triangles, the quadrangles, and the others,
+    /// and in the order of registration in each kind. So, the `i`-th face of this iterator
+    /// is `faces[i]`. This is the same iterator as [`Faces::face_iter`].
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let slice: &[&[usize]] = &[
+    ///     &[0, 4, 5, 1],
+    ///     &[1, 2, 6, 7, 8, 9],
+    ///     &[0, 1, 2],
+    /// ];
+    /// let faces = Faces::<usize>::from_iter(slice);
+    /// faces.iter().enumerate().for_each(|(i, face)| assert_eq!(face, &faces[i]));
+    /// assert_eq!(&faces[0], &[0, 1, 2]);
+    /// assert_eq!(&faces[1], &[0, 4, 5, 1]);
+    /// assert_eq!(&faces[2], &[1, 2, 6, 7, 8, 9]);
+    /// ```
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = &[V]> { self.face_iter() }
+
+    /// Returns the iterator of the pairs of the global face index and the face.
+    ///
+    /// cf: [`Faces::iter`]
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let slice: &[&[usize]] = &[&[0, 4, 5, 1], &[0, 1, 2]];
+    /// let faces = Faces::<usize>::from_iter(slice);
+    /// let mut iter = faces.enumerate();
+    /// assert_eq!(iter.next(), Some((0, [0, 1, 2].as_ref())));
+    /// assert_eq!(iter.next(), Some((1, [0, 4, 5, 1].as_ref())));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline(always)]
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &[V])> { self.iter().enumerate() }
+
     /// Returns the iterator of the slice.
     ///
     /// By the internal optimization, this iterator does not runs in the simple order
@@ -329,6 +368,7 @@ impl<V> Default for Faces<V> {
     }
 }
 
+/// Returns the face of the global index, cf: [`Faces::iter`].
 impl<V> std::ops::Index<usize> for Faces<V> {
     type Output = [V];
     fn index(&self, idx: usize) -> &Self::Output {
@@ -0,0 +1,243 @@
+use crate::*;
+use serde_json::{json, Value};
+use std::io::{BufWriter, Write};
+
+type Result<T> = std::result::Result<T, errors::Error>;
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const UNSIGNED_INT: u32 = 5125;
+const FLOAT: u32 = 5126;
+const TRIANGLES: u32 = 4;
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+/// glTF file format
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GltfFormat {
+    /// JSON glTF, the buffer is embedded as a base64 data uri.
+    Gltf,
+    /// binary glTF, the buffer is stored in the binary chunk.
+    #[default]
+    Glb,
+}
+
+/// the binary buffer and the accessors of a mesh primitive
+#[derive(Debug, Default)]
+struct BufferBuilder {
+    buffer: Vec<u8>,
+    buffer_views: Vec<Value>,
+    accessors: Vec<Value>,
+}
+
+impl BufferBuilder {
+    /// pushes a buffer view and its accessor, and returns the index of the accessor
+    fn push<T: bytemuck::Pod>(
+        &mut self,
+        data: &[T],
+        accessor_type: &str,
+        component_type: u32,
+        target: u32,
+        bounds: Option<(Value, Value)>,
+    ) -> usize {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        let mut view = json!({
+            "buffer": 0,
+            "byteOffset": self.buffer.len(),
+            "byteLength": bytes.len(),
+            "target": target,
+        });
+        if target == ARRAY_BUFFER {
+            view["byteStride"] = json!(size_of::<T>());
+        }
+        let mut accessor = json!({
+            "bufferView": self.buffer_views.len(),
+            "componentType": component_type,
+            "count": data.len(),
+            "type": accessor_type,
+        });
+        if let Some((min, max)) = bounds {
+            accessor["min"] = min;
+            accessor["max"] = max;
+        }
+        self.buffer.extend_from_slice(bytes);
+        self.buffer_views.push(view);
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut res = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    bytes.chunks(3).for_each(|chunk| {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as usize) << 16) | ((b[1] as usize) << 8) | b[2] as usize;
+        (0..4).for_each(|i| match i <= chunk.len() {
+            true => res.push(TABLE[(n >> (18 - 6 * i)) & 63] as char),
+            false => res.push('='),
+        });
+    });
+    res
+}
+
+fn padded(mut bytes: Vec<u8>, pad: u8) -> Vec<u8> {
+    bytes.resize(bytes.len().next_multiple_of(4), pad);
+    bytes
+}
+
+/// Writes the mesh as a glTF 2.0 asset consisting of a single mesh primitive.
+///
+/// Each glTF vertex is a distinct combination of the attributes referred by the faces,
+/// and the faces are triangulated. The normals and the texture coordinates are written
+/// only if all vertices have them.
+///
+/// # Failures
+/// If the mesh has no faces, returns [`Error::EmptyMesh`](../errors/enum.Error.html),
+/// since glTF does not allow the empty buffer views and the accessors without bounds.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use gltf::GltfFormat;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2, 3]]),
+/// );
+/// let mut glb = Vec::<u8>::new();
+/// gltf::write(&mesh, &mut glb, GltfFormat::Glb).unwrap();
+/// assert_eq!(&glb[0..4], b"glTF");
+/// assert_eq!(glb.len() % 4, 0);
+/// ```
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W, format: GltfFormat) -> Result<()> {
+    let expanded = mesh.expands(|attr| attr);
+    let vertices = expanded.attributes();
+    let has_nor = !vertices.is_empty() && vertices.iter().all(|attr| attr.normal.is_some());
+    let has_uv = !vertices.is_empty() && vertices.iter().all(|attr| attr.uv_coord.is_some());
+
+    let mut builder = BufferBuilder::default();
+    let indices: Vec<u32> = expanded
+        .faces()
+        .triangle_iter()
+        .flatten()
+        .map(|idx| idx as u32)
+        .collect();
+    if indices.is_empty() {
+        return Err(errors::Error::EmptyMesh);
+    }
+    let indices_accessor =
+        builder.push(&indices, "SCALAR", UNSIGNED_INT, ELEMENT_ARRAY_BUFFER, None);
+    let positions: Vec<[f32; 3]> = vertices
+        .iter()
+        .map(|attr| attr.position.cast().unwrap().into())
+        .collect();
+    let (min, max) = positions.iter().fold(
+        ([f32::INFINITY; 3], [f32::NEG_INFINITY; 3]),
+        |(min, max), p| {
+            (
+                [min[0].min(p[0]), min[1].min(p[1]), min[2].min(p[2])],
+                [max[0].max(p[0]), max[1].max(p[1]), max[2].max(p[2])],
+            )
+        },
+    );
+    let mut attributes = json!({
+        "POSITION": builder.push(
+            &positions,
+            "VEC3",
+            FLOAT,
+            ARRAY_BUFFER,
+            Some((json!(min), json!(max))),
+        ),
+    });
+    if has_nor {
+        let normals: Vec<[f32; 3]> = vertices
+            .iter()
+            .map(|attr| attr.normal.unwrap().cast().unwrap().into())
+            .collect();
+        attributes["NORMAL"] = json!(builder.push(&normals, "VEC3", FLOAT, ARRAY_BUFFER, None));
+    }
+    if has_uv {
+        let uv_coords: Vec<[f32; 2]> = vertices
+            .iter()
+            .map(|attr| attr.uv_coord.unwrap().cast().unwrap().into())
+            .collect();
+        attributes["TEXCOORD_0"] =
+            json!(builder.push(&uv_coords, "VEC2", FLOAT, ARRAY_BUFFER, None));
+    }
+
+    let BufferBuilder {
+        buffer,
+        buffer_views,
+        accessors,
+    } = builder;
+    let mut buffer_json = json!({ "byteLength": buffer.len() });
+    if format == GltfFormat::Gltf {
+        buffer_json["uri"] = json!(format!(
+            "data:application/octet-stream;base64,{}",
+            base64_encode(&buffer)
+        ));
+    }
+    let root = json!({
+        "asset": { "version": "2.0", "generator": "truck-polymesh" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "mesh": 0 }],
+        "meshes": [{
+            "primitives": [{
+                "attributes": attributes,
+                "indices": indices_accessor,
+                "mode": TRIANGLES,
+            }],
+        }],
+        "accessors": accessors,
+        "bufferViews": buffer_views,
+        "buffers": [buffer_json],
+    });
+    let json = serde_json::to_vec(&root).map_err(std::io::Error::from)?;
+
+    let mut writer = BufWriter::new(writer);
+    match format {
+        GltfFormat::Gltf => writer.write_all(&json)?,
+        GltfFormat::Glb => {
+            let json = padded(json, b' ');
+            let buffer = padded(buffer, 0);
+            let length = 12 + 8 + json.len() + 8 + buffer.len();
+            writer.write_all(&GLB_MAGIC.to_le_bytes())?;
+            writer.write_all(&GLB_VERSION.to_le_bytes())?;
+            writer.write_all(&(length as u32).to_le_bytes())?;
+            writer.write_all(&(json.len() as u32).to_le_bytes())?;
+            writer.write_all(&CHUNK_JSON.to_le_bytes())?;
+            writer.write_all(&json)?;
+            writer.write_all(&(buffer.len() as u32).to_le_bytes())?;
+            writer.write_all(&CHUNK_BIN.to_le_bytes())?;
+            writer.write_all(&buffer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the structured mesh as a glTF 2.0 asset, through [`StructuredMesh::destruct`].
+#[inline(always)]
+pub fn write_structured<W: Write>(
+    mesh: &StructuredMesh,
+    writer: W,
+    format: GltfFormat,
+) -> Result<()> {
+    write(&mesh.clone().destruct(), writer, format)
+}
@@ -0,0 +1,227 @@
+use crate::errors::Error;
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// half-edge of [`HalfEdgeMesh`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HalfEdge {
+    /// the vertex at the origin of the half-edge
+    pub vertex: StandardVertex,
+    /// the index of the face including the half-edge
+    pub face: usize,
+    /// the index of the next half-edge in the face
+    pub next: usize,
+    /// the index of the previous half-edge in the face
+    pub prev: usize,
+    /// the index of the opposite half-edge, `None` if the half-edge is on the boundary
+    pub twin: Option<usize>,
+}
+
+/// half-edge data structure of a manifold polygon mesh
+///
+/// The half-edges of each face are stored contiguously in the order of the face, and the faces
+/// are in the order of [`PolygonMesh::face_iter`].
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use half_edge::HalfEdgeMesh;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+/// );
+/// let half_edge_mesh = HalfEdgeMesh::try_from(&mesh).unwrap();
+/// assert_eq!(half_edge_mesh.half_edges().len(), 6);
+/// assert_eq!(half_edge_mesh.vertex_one_ring(0), vec![1, 2, 3]);
+/// assert_eq!(half_edge_mesh.boundary_loops(), vec![vec![0, 1, 2, 3]]);
+/// assert_eq!(half_edge_mesh.to_polygon_mesh(), mesh);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HalfEdgeMesh {
+    attributes: StandardAttributes,
+    half_edges: Vec<HalfEdge>,
+    faces: Vec<usize>,
+    outgoings: Vec<Option<usize>>,
+}
+
+impl TryFrom<&PolygonMesh> for HalfEdgeMesh {
+    type Error = Error;
+    /// Creates the half-edge data structure of the polygon mesh.
+    /// # Errors
+    /// - Returns [`Error::NonManifoldEdge`] if an edge is shared by more than two faces or
+    ///   a face has the same positions consecutively.
+    /// - Returns [`Error::InconsistentOrientation`] if two faces run an edge in the same direction.
+    /// - Returns [`Error::NonManifoldVertex`] if the faces around a vertex are not connected.
+    fn try_from(mesh: &PolygonMesh) -> Result<Self, Error> {
+        let mut half_edges = Vec::new();
+        let mut faces = Vec::new();
+        mesh.face_iter().enumerate().for_each(|(i, face)| {
+            let (first, len) = (half_edges.len(), face.len());
+            faces.push(first);
+            half_edges.extend(face.iter().enumerate().map(|(j, v)| HalfEdge {
+                vertex: *v,
+                face: i,
+                next: first + (j + 1) % len,
+                prev: first + (j + len - 1) % len,
+                twin: None,
+            }));
+        });
+
+        let mut directed = HashMap::<[usize; 2], usize>::default();
+        let mut edge_count = HashMap::<[usize; 2], usize>::default();
+        for (i, he) in half_edges.iter().enumerate() {
+            let (a, b) = (he.vertex.pos, half_edges[he.next].vertex.pos);
+            let count = edge_count.entry([a.min(b), a.max(b)]).or_insert(0);
+            *count += 1;
+            if a == b || *count > 2 {
+                return Err(Error::NonManifoldEdge([a.min(b), a.max(b)]));
+            }
+            if directed.insert([a, b], i).is_some() {
+                return Err(Error::InconsistentOrientation([a.min(b), a.max(b)]));
+            }
+        }
+        let twins: Vec<Option<usize>> = half_edges
+            .iter()
+            .map(|he| {
+                let (a, b) = (he.vertex.pos, half_edges[he.next].vertex.pos);
+                directed.get(&[b, a]).copied()
+            })
+            .collect();
+        half_edges
+            .iter_mut()
+            .zip(twins)
+            .for_each(|(he, twin)| he.twin = twin);
+
+        // the boundary half-edges are preferred in order to start rotations from them
+        let mut outgoings = vec![None; mesh.positions().len()];
+        let mut num_outgoings = vec![0; mesh.positions().len()];
+        half_edges.iter().enumerate().for_each(|(i, he)| {
+            let pos = he.vertex.pos;
+            num_outgoings[pos] += 1;
+            if outgoings[pos].is_none() || he.twin.is_none() {
+                outgoings[pos] = Some(i);
+            }
+        });
+        let half_edge_mesh = HalfEdgeMesh {
+            attributes: mesh.attributes().clone(),
+            half_edges,
+            faces,
+            outgoings,
+        };
+        for (pos, num) in num_outgoings.into_iter().enumerate() {
+            if half_edge_mesh.outgoing_half_edges(pos).count() != num {
+                return Err(Error::NonManifoldVertex(pos));
+            }
+        }
+        Ok(half_edge_mesh)
+    }
+}
+
+impl HalfEdgeMesh {
+    /// Returns the attributes.
+    #[inline(always)]
+    pub fn attributes(&self) -> &StandardAttributes { &self.attributes }
+
+    /// Returns the vector of all positions.
+    #[inline(always)]
+    pub fn positions(&self) -> &Vec<Point3> { &self.attributes.positions }
+
+    /// Returns all half-edges.
+    #[inline(always)]
+    pub fn half_edges(&self) -> &[HalfEdge] { &self.half_edges }
+
+    /// Returns the number of faces.
+    #[inline(always)]
+    pub fn num_faces(&self) -> usize { self.faces.len() }
+
+    /// Returns the index of the opposite half-edge, `None` if the half-edge is on the boundary.
+    #[inline(always)]
+    pub fn twin(&self, half_edge: usize) -> Option<usize> { self.half_edges[half_edge].twin }
+
+    /// Returns the index of position at the end of the half-edge.
+    #[inline(always)]
+    pub fn destination(&self, half_edge: usize) -> usize {
+        self.half_edges[self.half_edges[half_edge].next].vertex.pos
+    }
+
+    /// Returns the iterator of the indices of half-edges in the face.
+    pub fn face_halfedges(&self, face: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.faces[face];
+        std::iter::successors(Some(first), move |i| {
+            Some(self.half_edges[*i].next).filter(|next| *next != first)
+        })
+    }
+
+    /// Returns the indices of positions adjacent to the vertex in the rotational order.
+    ///
+    /// If the vertex is on the boundary, the ring starts and ends at the boundary.
+    pub fn vertex_one_ring(&self, pos: usize) -> Vec<usize> {
+        let mut ring: Vec<usize> = self
+            .outgoing_half_edges(pos)
+            .map(|i| self.destination(i))
+            .collect();
+        if let Some(last) = self.outgoing_half_edges(pos).last() {
+            let prev = self.half_edges[self.half_edges[last].prev];
+            if prev.twin.is_none() {
+                ring.push(prev.vertex.pos);
+            }
+        }
+        ring
+    }
+
+    /// Returns the boundaries as the loops of the indices of positions,
+    /// which run in the same direction as the faces.
+    pub fn boundary_loops(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.half_edges.len()];
+        let mut loops = Vec::new();
+        for first in 0..self.half_edges.len() {
+            if visited[first] || self.half_edges[first].twin.is_some() {
+                continue;
+            }
+            let mut boundary = Vec::new();
+            let mut i = first;
+            loop {
+                visited[i] = true;
+                boundary.push(self.half_edges[i].vertex.pos);
+                i = self.half_edges[i].next;
+                while let Some(twin) = self.half_edges[i].twin {
+                    i = self.half_edges[twin].next;
+                }
+                if i == first {
+                    break;
+                }
+            }
+            loops.push(boundary);
+        }
+        loops
+    }
+
+    /// Converts back to the polygon mesh.
+    pub fn to_polygon_mesh(&self) -> PolygonMesh {
+        let mut faces = Faces::default();
+        (0..self.faces.len()).for_each(|face| {
+            let face: Vec<StandardVertex> = self
+                .face_halfedges(face)
+                .map(|i| self.half_edges[i].vertex)
+                .collect();
+            faces.push(face);
+        });
+        PolygonMesh::new(self.attributes.clone(), faces)
+    }
+
+    /// the half-edges going out from the vertex, rotating from the boundary if it exists
+    fn outgoing_half_edges(&self, pos: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.outgoings[pos];
+        std::iter::successors(first, move |i| {
+            let prev = self.half_edges[*i].prev;
+            self.half_edges[prev].twin.filter(|twin| Some(*twin) != first)
+        })
+    }
+}
@@ -97,15 +97,74 @@ pub struct StructuredMesh {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PolylineCurve<P>(pub Vec<P>);
 
+/// uniform grid of points for the nearest neighbor queries
+///
+/// The bounding box of the points is divided into cubic cells,
+/// and each point is registered to the cell including it.
+#[derive(Clone, Debug)]
+pub struct PointGrid {
+    points: Vec<Point3>,
+    bounding_box: BoundingBox<Point3>,
+    cell_size: f64,
+    cells: rustc_hash::FxHashMap<[i64; 3], Vec<usize>>,
+}
+
+/// the nearest intersection of a ray and the triangles of a polygon mesh,
+/// created by [`PolygonMesh::ray_cast`] or [`TriangleGrid::ray_cast`]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RayHit {
+    /// the index of the triangle in the order of [`Faces::triangle_iter`]
+    pub face: usize,
+    /// the barycentric coordinates of the intersection point on the triangle
+    pub barycentric: [f64; 3],
+    /// the distance from the origin of the ray to the intersection point
+    pub distance: f64,
+}
+
+/// uniform grid of triangles for the ray casting
+///
+/// The bounding box of the triangles is divided into cubic cells,
+/// and each triangle is registered to the cells overlapping its bounding box.
+#[derive(Clone, Debug)]
+pub struct TriangleGrid {
+    triangles: Vec<[Point3; 3]>,
+    bounding_box: BoundingBox<Point3>,
+    cell_size: f64,
+    cells: rustc_hash::FxHashMap<[i64; 3], Vec<usize>>,
+}
+
+/// the counts of the topological defects of a polygon mesh,
+/// created by [`PolygonMesh::topology_report`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MeshReport {
+    /// the number of edges used by only one face
+    pub boundary_edges: usize,
+    /// the number of edges used by more than two faces
+    pub non_manifold_edges: usize,
+    /// the number of positions not referred by any faces
+    pub isolated_vertices: usize,
+}
+
 mod attributes;
+/// binary I/O by bincode
+#[cfg(feature = "bincode")]
+pub mod binary;
 /// Defines errors
 pub mod errors;
 mod expand;
 /// Defines triangle
 pub mod faces;
+/// glTF export
+pub mod gltf;
+/// Defines [`HalfEdgeMesh`](./half_edge/struct.HalfEdgeMesh.html).
+pub mod half_edge;
 mod meshing_shape;
+mod point_grid;
+mod ray_cast;
 /// wavefront obj I/O
 pub mod obj;
+/// PLY I/O
+pub mod ply;
 /// Defines [`PolygonMeshEditor`](./polygon_mesh/struct.PolygonMeshEditor.html).
 pub mod polygon_mesh;
 /// Defines generalized polyline curve.
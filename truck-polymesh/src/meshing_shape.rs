@@ -10,8 +10,12 @@ impl<P> PolylineCurve<P> {
 
 impl StructuredMesh {
     /// meshing the surface
+    ///
+    /// The uv division is determined by [`ParameterDivision2D::parameter_division`], and
+    /// the positions and normals are sampled at the grid points of the division.
     /// # Arguments
-    /// * `bspsurface` - bspline surface to meshed
+    /// * `surface` - surface to be meshed, e.g. `BSplineSurface` or `NURBSSurface`
+    /// * `range` - the range of the parameters `((u0, u1), (v0, v1))`
     /// * `tol` - standard tolerance for meshing
     pub fn from_surface<S>(
         surface: &S,
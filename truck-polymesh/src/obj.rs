@@ -1,4 +1,5 @@
 use crate::*;
+use errors::ObjErrorKind;
 use std::io::{BufRead, BufReader, BufWriter, Read, Write};
 type Vertex = StandardVertex;
 type Result<T> = std::result::Result<T, errors::Error>;
@@ -89,6 +90,12 @@ fn write3vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
 }
 
 impl Vertex {
+    fn in_range(&self, [positions, uv_coords, normals]: [usize; 3]) -> bool {
+        self.pos < positions
+            && self.uv.is_none_or(|uv| uv < uv_coords)
+            && self.nor.is_none_or(|nor| nor < normals)
+    }
+
     fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         match (self.uv, self.nor) {
             (None, None) => writer.write_fmt(format_args!("{}", self.pos + 1)),
@@ -101,80 +108,183 @@ impl Vertex {
     }
 }
 
+fn write_face<W: Write>(face: &[Vertex], writer: &mut W) -> Result<()> {
+    writer.write_all(b"f")?;
+    for v in face {
+        writer.write_all(b" ")?;
+        v.write(writer)?;
+    }
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
 impl Faces {
     fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
-        for face in self.face_iter() {
-            writer.write_all(b"f")?;
-            for v in face {
-                writer.write_all(b" ")?;
-                v.write(writer)?;
-            }
-            writer.write_all(b"\n")?;
-        }
-        Ok(())
+        self.face_iter()
+            .try_for_each(|face| write_face(face, writer))
     }
 }
 
-fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<()> {
+fn write_attributes<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<()> {
     write3vec(writer, mesh.positions(), "v")?;
     write2vec(writer, mesh.uv_coords(), "vt")?;
-    write3vec(writer, mesh.normals(), "vn")?;
+    write3vec(writer, mesh.normals(), "vn")
+}
+
+fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<()> {
+    write_attributes(mesh, writer)?;
     mesh.faces.write(writer)
 }
 
+/// Material referred by the `usemtl` directives
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Material {
+    /// the name of material. If `None`, the material is named `material{index}`.
+    pub name: Option<String>,
+    /// diffuse color
+    pub diffuse: Vector3,
+}
+
+impl Material {
+    fn name(&self, idx: usize) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("material{}", idx))
+    }
+}
+
+fn invalid_input(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message)
+}
+
+/// Writes obj data with `usemtl` directives to output stream
+///
+/// The `i`th face in [`Faces::face_iter`] is drawn by `materials[face_groups[i]]`.
+/// The materials are referred through `mtllib`, and can be written by [`write_mtl`].
+/// # Failures
+/// Returns an error if the length of `face_groups` is not the number of faces,
+/// or if `face_groups` contains an index out of `materials`.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use obj::Material;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+/// );
+/// let materials = [
+///     Material {
+///         name: Some("red".to_string()),
+///         diffuse: Vector3::new(1.0, 0.0, 0.0),
+///     },
+///     Material {
+///         name: None,
+///         diffuse: Vector3::new(0.0, 0.0, 1.0),
+///     },
+/// ];
+/// let mut obj = Vec::<u8>::new();
+/// obj::write_with_materials(&mesh, &mut obj, "mesh.mtl", &materials, &[0, 1]).unwrap();
+/// let obj = String::from_utf8(obj).unwrap();
+/// assert!(obj.contains("mtllib mesh.mtl\n"));
+/// assert!(obj.contains("usemtl red\nf 1 2 3\nusemtl material1\nf 1 3 4\n"));
+///
+/// let mut mtl = Vec::<u8>::new();
+/// obj::write_mtl(&materials, &mut mtl).unwrap();
+/// let mtl = String::from_utf8(mtl).unwrap();
+/// assert!(mtl.contains("newmtl red\n"));
+/// assert!(mtl.contains("newmtl material1\n"));
+/// ```
+pub fn write_with_materials<W: Write>(
+    mesh: &PolygonMesh,
+    writer: W,
+    mtllib: &str,
+    materials: &[Material],
+    face_groups: &[usize],
+) -> Result<()> {
+    if face_groups.len() != mesh.faces().len() {
+        return Err(invalid_input("the length of face groups is not the number of faces").into());
+    }
+    if face_groups.iter().any(|idx| *idx >= materials.len()) {
+        return Err(invalid_input("a face group is out of the materials").into());
+    }
+    let mut writer = BufWriter::new(writer);
+    writer.write_fmt(format_args!("mtllib {}\n", mtllib))?;
+    write_attributes(mesh, &mut writer)?;
+    let mut current = None;
+    mesh.face_iter()
+        .zip(face_groups)
+        .try_for_each(|(face, idx)| {
+            if current != Some(*idx) {
+                let name = materials[*idx].name(*idx);
+                writer.write_fmt(format_args!("usemtl {}\n", name))?;
+                current = Some(*idx);
+            }
+            write_face(face, &mut writer)
+        })
+}
+
+/// Writes mtl data of `materials` to output stream
+pub fn write_mtl<W: Write>(materials: &[Material], writer: W) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    for (i, material) in materials.iter().enumerate() {
+        let kd = material.diffuse;
+        writer.write_fmt(format_args!("newmtl {}\n", material.name(i)))?;
+        writer.write_fmt(format_args!(
+            "Kd {:.10e} {:.10e} {:.10e}\n",
+            kd[0], kd[1], kd[2]
+        ))?;
+    }
+    Ok(())
+}
+
 /// Reads mesh data from wavefront obj file.
+///
+/// The material directives `mtllib` and `usemtl` are ignored. The negative indices of faces,
+/// for all of the positions, the texture coordinates, and the normals, count backward from
+/// the last element defined before the face, i.e. `-1` is the last one. The positive indices
+/// may refer the elements defined after the face, and they are checked after the whole data
+/// is read.
+/// cf: [`elements`] for reading the elements one by one.
 pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
     let mut positions = Vec::new();
     let mut uv_coords = Vec::new();
     let mut normals = Vec::new();
     let mut faces = Faces::default();
-    let reader = BufReader::new(reader);
-    for line in reader.lines().map(|s| s.unwrap()) {
-        let mut args = line.split_whitespace();
-        if let Some(first_str) = args.next() {
-            if first_str == "v" {
-                let x = args.next().unwrap().parse::<f64>()?;
-                let y = args.next().unwrap().parse::<f64>()?;
-                let z = args.next().unwrap().parse::<f64>()?;
-                positions.push(Point3::new(x, y, z));
-            } else if first_str == "vt" {
-                let u = args.next().unwrap().parse::<f64>()?;
-                let v = args.next().unwrap().parse::<f64>()?;
-                uv_coords.push(Vector2::new(u, v));
-            } else if first_str == "vn" {
-                let x = args.next().unwrap().parse::<f64>()?;
-                let y = args.next().unwrap().parse::<f64>()?;
-                let z = args.next().unwrap().parse::<f64>()?;
-                normals.push(Vector3::new(x, y, z));
-            } else if first_str == "f" {
-                let mut face = Vec::new();
-                for vert_str in args {
-                    if &vert_str[0..1] == "#" {
-                        break;
-                    }
-                    let mut iter = vert_str.split('/');
-                    let pos = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let uv = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let nor = iter
-                        .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
-                    let vert = match (pos, uv, nor) {
-                        (None, _, _) => continue,
-                        (Some(pos), uv, nor) => Vertex { pos, uv, nor },
-                    };
-                    face.push(vert);
-                }
+    // the vertices referring the elements not defined yet, with their lines and tokens
+    let mut forwards = Vec::new();
+    let mut elements = elements(BufReader::new(reader));
+    while let Some(element) = elements.next() {
+        match element? {
+            ObjElement::Position(position) => positions.push(position),
+            ObjElement::UVCoord(uv_coord) => uv_coords.push(uv_coord),
+            ObjElement::Normal(normal) => normals.push(normal),
+            ObjElement::Face(face) => {
+                let counts = elements.counts;
+                let tokens = elements.line.split_whitespace().skip(1);
+                face.iter()
+                    .zip(tokens)
+                    .filter(|(v, _)| !v.in_range(counts))
+                    .for_each(|(v, token)| {
+                        forwards.push((elements.line_number, *v, token.to_string()))
+                    });
                 faces.push(face);
             }
         }
     }
+    let counts = [positions.len(), uv_coords.len(), normals.len()];
+    if let Some((line, _, token)) = forwards.into_iter().find(|(_, v, _)| !v.in_range(counts)) {
+        let kind = ObjErrorKind::IndexOutOfRange(token);
+        return Err(errors::Error::ObjSyntax { line, kind });
+    }
     PolygonMesh::try_new(
         StandardAttributes {
             positions,
@@ -184,3 +294,171 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
         faces,
     )
 }
+
+/// An element of wavefront obj data, cf: [`elements`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjElement {
+    /// the position given by `v`
+    Position(Point3),
+    /// the texture coordinate given by `vt`
+    UVCoord(Vector2),
+    /// the normal given by `vn`
+    Normal(Vector3),
+    /// the face given by `f`, whose indices are converted to zero-based ones.
+    ///
+    /// The negative indices are resolved and checked by [`elements`], but the positive ones
+    /// are yielded unchecked, since they may refer the elements defined after the face.
+    /// [`read`] checks them after the whole data is read.
+    Face(Vec<Vertex>),
+}
+
+/// The iterator of the elements of wavefront obj data, cf: [`elements`]
+#[derive(Debug)]
+pub struct ObjElements<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    // the numbers of the positions, the texture coordinates, and the normals read so far
+    counts: [usize; 3],
+}
+
+/// Returns the iterator of the elements of wavefront obj data.
+///
+/// The lines are parsed one by one as they are read, so the large data can be processed
+/// without holding the whole mesh in memory. The line buffer is reused, and the tokens
+/// are parsed without the allocations. The lines other than `v`, `vt`, `vn`, and `f` are
+/// skipped. The negative indices of the faces are relative to the end of the elements
+/// defined so far. The positive indices are not checked, since they may refer the elements
+/// defined after the faces.
+/// # Errors
+/// The malformed lines are reported by [`Error::ObjSyntax`](../errors/enum.Error.html)
+/// with the line numbers, and the iterator continues from the next line.
+/// # Examples
+/// ```
+/// use truck_polymesh::{*, obj::ObjElement};
+/// let data = b"# a triangle
+/// v 0.0 0.0 0.0
+/// v 1.0 0.0 0.0
+/// v 0.0 1.0 0.0
+/// vn 0.0 0.0 1.0
+/// usemtl material
+/// f 1//1 2//1 3//1
+/// ";
+/// let mut positions = 0;
+/// let mut faces = Vec::new();
+/// for element in obj::elements(data.as_slice()) {
+///     match element.unwrap() {
+///         ObjElement::Position(_) => positions += 1,
+///         ObjElement::Face(face) => faces.push(face),
+///         _ => {}
+///     }
+/// }
+/// assert_eq!(positions, 3);
+/// assert_eq!(faces.len(), 1);
+/// assert_eq!(faces[0][2], StandardVertex { pos: 2, uv: None, nor: Some(0) });
+/// ```
+pub fn elements<R: BufRead>(reader: R) -> ObjElements<R> {
+    ObjElements {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        counts: [0; 3],
+    }
+}
+
+impl<R: BufRead> Iterator for ObjElements<R> {
+    type Item = Result<ObjElement>;
+    fn next(&mut self) -> Option<Result<ObjElement>> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_number += 1,
+                Err(error) => return Some(Err(error.into())),
+            }
+            let element = match parse_line(&self.line, self.counts) {
+                Ok(Some(element)) => element,
+                Ok(None) => continue,
+                Err(kind) => {
+                    let line = self.line_number;
+                    return Some(Err(errors::Error::ObjSyntax { line, kind }));
+                }
+            };
+            match element {
+                ObjElement::Position(_) => self.counts[0] += 1,
+                ObjElement::UVCoord(_) => self.counts[1] += 1,
+                ObjElement::Normal(_) => self.counts[2] += 1,
+                ObjElement::Face(_) => {}
+            }
+            return Some(Ok(element));
+        }
+    }
+}
+
+type ParseResult<T> = std::result::Result<T, ObjErrorKind>;
+
+fn parse_line(line: &str, counts: [usize; 3]) -> ParseResult<Option<ObjElement>> {
+    let mut args = line.split_whitespace();
+    let element = match args.next() {
+        Some("v") => {
+            let [x, y, z] = parse_floats(&mut args)?;
+            ObjElement::Position(Point3::new(x, y, z))
+        }
+        Some("vt") => {
+            let [u, v] = parse_floats(&mut args)?;
+            ObjElement::UVCoord(Vector2::new(u, v))
+        }
+        Some("vn") => {
+            let [x, y, z] = parse_floats(&mut args)?;
+            ObjElement::Normal(Vector3::new(x, y, z))
+        }
+        Some("f") => ObjElement::Face(
+            args.take_while(|vert_str| !vert_str.starts_with('#'))
+                .map(|vert_str| parse_vertex(vert_str, counts))
+                .collect::<ParseResult<_>>()?,
+        ),
+        _ => return Ok(None),
+    };
+    Ok(Some(element))
+}
+
+fn parse_floats<'a, const N: usize>(
+    args: &mut impl Iterator<Item = &'a str>,
+) -> ParseResult<[f64; N]> {
+    let mut res = [0.0; N];
+    for x in &mut res {
+        let token = args.next().ok_or(ObjErrorKind::MissingValue)?;
+        *x = token
+            .parse::<f64>()
+            .map_err(|_| ObjErrorKind::InvalidNumber(token.to_string()))?;
+    }
+    Ok(res)
+}
+
+/// parses the vertex of `f` like `1/2/3`, `1//3`, or `-1`.
+fn parse_vertex(vert_str: &str, counts: [usize; 3]) -> ParseResult<Vertex> {
+    let invalid_vertex = || ObjErrorKind::InvalidVertex(vert_str.to_string());
+    let mut indices = [None; 3];
+    let mut iter = vert_str.split('/');
+    for (idx, count) in indices.iter_mut().zip(counts) {
+        match iter.next() {
+            None | Some("") => {}
+            Some(val) => {
+                let i = val.parse::<isize>().map_err(|_| invalid_vertex())?;
+                // the positive indices may refer the elements defined later.
+                let resolved = match i < 0 {
+                    true => count.checked_sub(i.unsigned_abs()),
+                    false => (i as usize).checked_sub(1),
+                };
+                match resolved {
+                    Some(i) => *idx = Some(i),
+                    None => return Err(ObjErrorKind::IndexOutOfRange(vert_str.to_string())),
+                }
+            }
+        }
+    }
+    match (iter.next(), indices) {
+        (None, [Some(pos), uv, nor]) => Ok(Vertex { pos, uv, nor }),
+        _ => Err(invalid_vertex()),
+    }
+}
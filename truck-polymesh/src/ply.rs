@@ -0,0 +1,413 @@
+use crate::*;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+
+type Vertex = StandardVertex;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+fn syntax_error() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, "syntax error")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ScalarType {
+    Char,
+    UChar,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Float,
+    Double,
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Result<ScalarType> {
+        match name {
+            "char" | "int8" => Ok(ScalarType::Char),
+            "uchar" | "uint8" => Ok(ScalarType::UChar),
+            "short" | "int16" => Ok(ScalarType::Short),
+            "ushort" | "uint16" => Ok(ScalarType::UShort),
+            "int" | "int32" => Ok(ScalarType::Int),
+            "uint" | "uint32" => Ok(ScalarType::UInt),
+            "float" | "float32" => Ok(ScalarType::Float),
+            "double" | "float64" => Ok(ScalarType::Double),
+            _ => Err(syntax_error().into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Property {
+    Scalar(String, ScalarType),
+    List(String, ScalarType, ScalarType),
+}
+
+impl Property {
+    #[inline(always)]
+    fn name(&self) -> &str {
+        match self {
+            Property::Scalar(name, _) => name,
+            Property::List(name, _, _) => name,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+impl Element {
+    #[inline(always)]
+    fn property_index(&self, name: &str) -> Option<usize> {
+        self.properties.iter().position(|prop| prop.name() == name)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Scalar(f64),
+    List(Vec<f64>),
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<(Format, Vec<Element>)> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    if line.trim() != "ply" {
+        return Err(syntax_error().into());
+    }
+    let mut format = None;
+    let mut elements = Vec::<Element>::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(syntax_error().into());
+        }
+        let args: Vec<_> = line.split_whitespace().collect();
+        match args.as_slice() {
+            ["format", "ascii", _] => format = Some(Format::Ascii),
+            ["format", "binary_little_endian", _] => format = Some(Format::BinaryLittleEndian),
+            ["format", "binary_big_endian", _] => format = Some(Format::BinaryBigEndian),
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count.parse()?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count_type, item_type, name] => {
+                let element = elements.last_mut().ok_or_else(syntax_error)?;
+                element.properties.push(Property::List(
+                    name.to_string(),
+                    ScalarType::parse(count_type)?,
+                    ScalarType::parse(item_type)?,
+                ));
+            }
+            ["property", scalar_type, name] => {
+                let element = elements.last_mut().ok_or_else(syntax_error)?;
+                element.properties.push(Property::Scalar(
+                    name.to_string(),
+                    ScalarType::parse(scalar_type)?,
+                ));
+            }
+            ["end_header"] => break,
+            _ => {}
+        }
+    }
+    Ok((format.ok_or_else(syntax_error)?, elements))
+}
+
+/// reads the values of the body, either from whitespace separated tokens or from bytes
+struct ValueReader<R> {
+    reader: R,
+    format: Format,
+    tokens: std::vec::IntoIter<String>,
+}
+
+impl<R: BufRead> ValueReader<R> {
+    fn next_token(&mut self) -> Result<String> {
+        loop {
+            if let Some(token) = self.tokens.next() {
+                return Ok(token);
+            }
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Err(syntax_error().into());
+            }
+            let tokens: Vec<_> = line.split_whitespace().map(String::from).collect();
+            self.tokens = tokens.into_iter();
+        }
+    }
+
+    fn next_value(&mut self, scalar_type: ScalarType) -> Result<f64> {
+        macro_rules! read_as {
+            ($ty: ty) => {{
+                let mut buf = [0u8; std::mem::size_of::<$ty>()];
+                self.reader.read_exact(&mut buf)?;
+                match self.format {
+                    Format::BinaryBigEndian => f64::from(<$ty>::from_be_bytes(buf)),
+                    _ => f64::from(<$ty>::from_le_bytes(buf)),
+                }
+            }};
+        }
+        if self.format == Format::Ascii {
+            return Ok(self.next_token()?.parse::<f64>()?);
+        }
+        Ok(match scalar_type {
+            ScalarType::Char => read_as!(i8),
+            ScalarType::UChar => read_as!(u8),
+            ScalarType::Short => read_as!(i16),
+            ScalarType::UShort => read_as!(u16),
+            ScalarType::Int => read_as!(i32),
+            ScalarType::UInt => read_as!(u32),
+            ScalarType::Float => read_as!(f32),
+            ScalarType::Double => read_as!(f64),
+        })
+    }
+
+    fn next_record(&mut self, properties: &[Property]) -> Result<Vec<Value>> {
+        properties
+            .iter()
+            .map(|prop| match prop {
+                Property::Scalar(_, scalar_type) => {
+                    Ok(Value::Scalar(self.next_value(*scalar_type)?))
+                }
+                Property::List(_, count_type, item_type) => {
+                    let count = self.next_value(*count_type)? as usize;
+                    (0..count)
+                        .map(|_| self.next_value(*item_type))
+                        .collect::<Result<Vec<_>>>()
+                        .map(Value::List)
+                }
+            })
+            .collect()
+    }
+}
+
+fn scalar(record: &[Value], idx: usize) -> Result<f64> {
+    match &record[idx] {
+        Value::Scalar(x) => Ok(*x),
+        Value::List(_) => Err(syntax_error().into()),
+    }
+}
+
+/// Reads mesh data from ASCII or binary PLY file.
+///
+/// The vertices of the faces refer to the `vertex` element: the positions are given by the
+/// properties `x`, `y`, `z`, the normals by `nx`, `ny`, `nz`, and the texture coordinates by
+/// `u`, `v` or `s`, `t`. Missing attributes are `None` in the vertices, and the other properties,
+/// e.g. colors, are ignored.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let ply = b"ply
+/// format ascii 1.0
+/// element vertex 4
+/// property float x
+/// property float y
+/// property float z
+/// property float nx
+/// property float ny
+/// property float nz
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// element face 1
+/// property list uchar int vertex_indices
+/// end_header
+/// 0 0 0 0 0 1 255 0 0
+/// 1 0 0 0 0 1 0 255 0
+/// 1 1 0 0 0 1 0 0 255
+/// 0 1 0 0 0 1 255 255 255
+/// 4 0 1 2 3
+/// ";
+/// let mesh = ply::read(ply.as_slice()).unwrap();
+/// assert_eq!(mesh.positions().len(), 4);
+/// assert_eq!(mesh.normals().len(), 4);
+/// assert!(mesh.uv_coords().is_empty());
+/// assert_eq!(mesh.faces().quad_faces().len(), 1);
+/// assert_eq!(mesh.faces()[0][2], StandardVertex { pos: 2, uv: None, nor: Some(2) });
+/// ```
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    let mut reader = BufReader::new(reader);
+    let (format, elements) = read_header(&mut reader)?;
+    let mut values = ValueReader {
+        reader,
+        format,
+        tokens: Vec::new().into_iter(),
+    };
+    let mut positions = Vec::new();
+    let mut uv_coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut face_lists = Vec::<Vec<usize>>::new();
+    for element in &elements {
+        match element.name.as_str() {
+            "vertex" => {
+                let idcs = |names: [&str; 3]| {
+                    let [x, y, z] = names.map(|name| element.property_index(name));
+                    Some([x?, y?, z?])
+                };
+                let pos_idcs = idcs(["x", "y", "z"]).ok_or_else(syntax_error)?;
+                let nor_idcs = idcs(["nx", "ny", "nz"]);
+                let uv_idcs = [["u", "v"], ["s", "t"], ["texture_u", "texture_v"]]
+                    .into_iter()
+                    .find_map(|[u, v]| {
+                        Some([element.property_index(u)?, element.property_index(v)?])
+                    });
+                for _ in 0..element.count {
+                    let record = values.next_record(&element.properties)?;
+                    let [x, y, z] = pos_idcs;
+                    positions.push(Point3::new(
+                        scalar(&record, x)?,
+                        scalar(&record, y)?,
+                        scalar(&record, z)?,
+                    ));
+                    if let Some([x, y, z]) = nor_idcs {
+                        normals.push(Vector3::new(
+                            scalar(&record, x)?,
+                            scalar(&record, y)?,
+                            scalar(&record, z)?,
+                        ));
+                    }
+                    if let Some([u, v]) = uv_idcs {
+                        uv_coords.push(Vector2::new(scalar(&record, u)?, scalar(&record, v)?));
+                    }
+                }
+            }
+            "face" => {
+                let list_idx = element
+                    .property_index("vertex_indices")
+                    .or_else(|| element.property_index("vertex_index"))
+                    .ok_or_else(syntax_error)?;
+                for _ in 0..element.count {
+                    let mut record = values.next_record(&element.properties)?;
+                    match std::mem::replace(&mut record[list_idx], Value::Scalar(0.0)) {
+                        Value::List(list) => {
+                            let face = list
+                                .into_iter()
+                                .map(|i| usize::try_from(i as i64).map_err(|_| syntax_error()))
+                                .collect::<std::io::Result<Vec<_>>>()?;
+                            face_lists.push(face);
+                        }
+                        Value::Scalar(_) => return Err(syntax_error().into()),
+                    }
+                }
+            }
+            _ => {
+                for _ in 0..element.count {
+                    values.next_record(&element.properties)?;
+                }
+            }
+        }
+    }
+    let (has_uv, has_nor) = (!uv_coords.is_empty(), !normals.is_empty());
+    let faces: Faces = face_lists
+        .into_iter()
+        .map(|list| {
+            list.into_iter()
+                .map(|pos| Vertex {
+                    pos,
+                    uv: has_uv.then_some(pos),
+                    nor: has_nor.then_some(pos),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    PolygonMesh::try_new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )
+}
+
+/// Writes ASCII PLY data to output stream
+///
+/// Each PLY vertex is a distinct combination of the attributes referred by the faces.
+/// The normals and the texture coordinates are written only if all vertices have them.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.5, 0.5, 1.0),
+/// ];
+/// let faces = Faces::from_iter(&[
+///     &[0, 3, 2, 1][..],
+///     &[0, 1, 4],
+///     &[1, 2, 4],
+///     &[2, 3, 4],
+///     &[3, 0, 4],
+/// ]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+/// let mut bytes = Vec::<u8>::new();
+/// ply::write(&mesh, &mut bytes).unwrap();
+/// let read = ply::read(bytes.as_slice()).unwrap();
+/// assert_eq!(read.positions().len(), 5);
+/// assert_eq!(read.faces().len(), 5);
+/// ```
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
+    let expanded = mesh.expands(|attr| attr);
+    let vertices = expanded.attributes();
+    let has_nor = !vertices.is_empty() && vertices.iter().all(|attr| attr.normal.is_some());
+    let has_uv = !vertices.is_empty() && vertices.iter().all(|attr| attr.uv_coord.is_some());
+    let max_len = expanded.face_iter().map(<[usize]>::len).max().unwrap_or(0);
+    let count_type = if max_len <= u8::MAX as usize {
+        "uchar"
+    } else {
+        "uint"
+    };
+
+    let mut writer = BufWriter::new(writer);
+    writer.write_all(b"ply\nformat ascii 1.0\n")?;
+    writer.write_fmt(format_args!("element vertex {}\n", vertices.len()))?;
+    writer.write_all(b"property double x\nproperty double y\nproperty double z\n")?;
+    if has_nor {
+        writer.write_all(b"property double nx\nproperty double ny\nproperty double nz\n")?;
+    }
+    if has_uv {
+        writer.write_all(b"property double u\nproperty double v\n")?;
+    }
+    writer.write_fmt(format_args!("element face {}\n", expanded.faces().len()))?;
+    writer.write_fmt(format_args!(
+        "property list {} uint vertex_indices\nend_header\n",
+        count_type
+    ))?;
+    for attr in vertices {
+        let p = attr.position;
+        writer.write_fmt(format_args!("{} {} {}", p[0], p[1], p[2]))?;
+        if let Some(n) = attr.normal.filter(|_| has_nor) {
+            writer.write_fmt(format_args!(" {} {} {}", n[0], n[1], n[2]))?;
+        }
+        if let Some(uv) = attr.uv_coord.filter(|_| has_uv) {
+            writer.write_fmt(format_args!(" {} {}", uv[0], uv[1]))?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    for face in expanded.face_iter() {
+        writer.write_fmt(format_args!("{}", face.len()))?;
+        for idx in face {
+            writer.write_fmt(format_args!(" {}", idx))?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
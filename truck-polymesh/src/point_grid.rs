@@ -0,0 +1,100 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+impl PointGrid {
+    /// Creates the grid of `points` whose cells are cubes with edge length `cell_size`.
+    /// # Panics
+    /// Panic occurs if `cell_size` is not positive.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let points = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 1.0e-8),
+    /// ];
+    /// let grid = PointGrid::new(points, 0.1);
+    /// assert_eq!(grid.nearest(Point3::new(0.9, 0.1, 0.0), 0.5), Some(1));
+    /// assert_eq!(grid.nearest(Point3::new(0.5, 0.5, 0.0), 0.5), None);
+    /// assert_eq!(grid.within(Point3::new(1.0, 0.0, 0.0), TOLERANCE), vec![1, 3]);
+    /// ```
+    pub fn new(points: Vec<Point3>, cell_size: f64) -> Self {
+        assert!(cell_size > 0.0, "the size of cells must be positive.");
+        let bounding_box: BoundingBox<Point3> = points.iter().collect();
+        let mut grid = PointGrid {
+            points: Vec::new(),
+            bounding_box,
+            cell_size,
+            cells: HashMap::default(),
+        };
+        points.iter().enumerate().for_each(|(i, pt)| {
+            let idx = grid.cell_index(*pt);
+            grid.cells.entry(idx).or_default().push(i)
+        });
+        grid.points = points;
+        grid
+    }
+
+    /// Returns the registered points.
+    #[inline(always)]
+    pub fn points(&self) -> &Vec<Point3> { &self.points }
+    /// Returns the bounding box of the registered points.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> &BoundingBox<Point3> { &self.bounding_box }
+    /// Returns the edge length of cells.
+    #[inline(always)]
+    pub fn cell_size(&self) -> f64 { self.cell_size }
+
+    /// Returns the index of the nearest point to `pt` among the points within `radius`.
+    /// If there are no such points, returns `None`.
+    pub fn nearest(&self, pt: Point3, radius: f64) -> Option<usize> {
+        let mut res = None;
+        let mut min = radius * radius;
+        self.for_each_candidate(pt, radius, |i| {
+            let dist2 = self.points[i].distance2(pt);
+            if dist2 < min || (dist2 == min && res.is_none()) {
+                min = dist2;
+                res = Some(i);
+            }
+        });
+        res
+    }
+
+    /// Returns the indices of the points within `radius` from `pt` in ascending order.
+    pub fn within(&self, pt: Point3, radius: f64) -> Vec<usize> {
+        let mut res = Vec::new();
+        self.for_each_candidate(pt, radius, |i| {
+            if self.points[i].distance2(pt) <= radius * radius {
+                res.push(i);
+            }
+        });
+        res.sort_unstable();
+        res
+    }
+
+    fn cell_index(&self, pt: Point3) -> [i64; 3] {
+        let min = self.bounding_box.min();
+        let idx = |k: usize| f64::floor((pt[k] - min[k]) / self.cell_size) as i64;
+        [idx(0), idx(1), idx(2)]
+    }
+
+    fn for_each_candidate(&self, pt: Point3, radius: f64, mut f: impl FnMut(usize)) {
+        if self.points.is_empty() || radius < 0.0 {
+            return;
+        }
+        let diag = Vector3::new(radius, radius, radius);
+        let (lower, upper) = (self.cell_index(pt - diag), self.cell_index(pt + diag));
+        let last = self.cell_index(*self.bounding_box.max());
+        let range = |k: usize| i64::max(lower[k], 0)..=i64::min(upper[k], last[k]);
+        for i in range(0) {
+            for j in range(1) {
+                for k in range(2) {
+                    if let Some(cell) = self.cells.get(&[i, j, k]) {
+                        cell.iter().for_each(|idx| f(*idx));
+                    }
+                }
+            }
+        }
+    }
+}
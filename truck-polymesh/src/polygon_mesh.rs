@@ -1,5 +1,6 @@
 use crate::errors::Error;
 use crate::*;
+use rustc_hash::FxHashMap as HashMap;
 
 impl<V: Copy + std::fmt::Debug, A: Attributes<V>> PolygonMesh<V, A> {
     /// complete constructor
@@ -103,8 +104,37 @@ impl<V: Copy + std::fmt::Debug, A: Attributes<V>> PolygonMesh<V, A> {
 }
 
 impl PolygonMesh {
-    /// Returns polygonmesh merged `self` and `mesh`.
-    pub fn merge(&mut self, mut mesh: PolygonMesh) {
+    /// Appends `mesh` to `self`.
+    ///
+    /// The attributes of `mesh` are concatenated to those of `self`, and the indices of
+    /// the vertices in the faces of `mesh` are shifted accordingly. The coincident positions are
+    /// not merged, use the filter `merge_close_vertices` of `truck-meshalgo` if necessary.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let triangle = |z: f64| {
+    ///     PolygonMesh::new(
+    ///         StandardAttributes {
+    ///             positions: vec![
+    ///                 Point3::new(0.0, 0.0, z),
+    ///                 Point3::new(1.0, 0.0, z),
+    ///                 Point3::new(0.0, 1.0, z),
+    ///             ],
+    ///             normals: vec![Vector3::unit_z()],
+    ///             ..Default::default()
+    ///         },
+    ///         Faces::from_iter(&[[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+    ///     )
+    /// };
+    /// let mut mesh = triangle(0.0);
+    /// mesh.merge(triangle(1.0)).merge(triangle(2.0));
+    /// assert_eq!(mesh.positions().len(), 9);
+    /// assert_eq!(mesh.normals().len(), 3);
+    /// let face = &mesh.faces()[2];
+    /// assert_eq!(face[1].pos, 7);
+    /// assert_eq!(face[1].nor, Some(2));
+    /// ```
+    pub fn merge(&mut self, mut mesh: PolygonMesh) -> &mut Self {
         let n_pos = self.positions().len();
         let n_uv = self.uv_coords().len();
         let n_nor = self.normals().len();
@@ -119,10 +149,425 @@ impl PolygonMesh {
         self.attributes.uv_coords.extend(mesh.attributes.uv_coords);
         self.attributes.normals.extend(mesh.attributes.normals);
         self.faces.naive_concat(mesh.faces);
+        self
     }
     /// Creates the bounding box of the polygon mesh.
     #[inline(always)]
     pub fn bounding_box(&self) -> BoundingBox<Point3> { self.positions().iter().collect() }
+
+    /// Applies the affine transformation `mat` to the mesh.
+    /// cf: [`Transformed::transform_by`]
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         normals: vec![Vector3::new(1.0, 1.0, 0.0).normalize()],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+    /// );
+    /// mesh.transform(Matrix4::from_nonuniform_scale(2.0, 1.0, 1.0));
+    /// assert_eq!(mesh.positions()[1], Point3::new(2.0, 0.0, 0.0));
+    /// // the normal is transformed by the inverse transpose, and normalized.
+    /// assert!(mesh.normals()[0].near(&Vector3::new(1.0, 2.0, 0.0).normalize()));
+    /// ```
+    #[inline(always)]
+    pub fn transform(&mut self, mat: Matrix4) -> &mut Self {
+        self.transform_by(mat);
+        self
+    }
+
+    /// Creates the oriented bounding box of the polygon mesh by the principal component analysis.
+    ///
+    /// Returns `None` if the mesh has no positions.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // a long thin stick along the diagonal of the xy-plane
+    /// let positions = (0..8)
+    ///     .map(|i| {
+    ///         let t = 10.0 * (i & 1) as f64;
+    ///         let (a, b) = (0.1 * ((i >> 1) & 1) as f64, 0.1 * (i >> 2) as f64);
+    ///         Point3::new(t + a, t - a, b)
+    ///     })
+    ///     .collect();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 3, 2], [4, 6, 7, 5]]),
+    /// );
+    /// let obb = mesh.oriented_bounding_box().unwrap();
+    /// let aabb = mesh.bounding_box();
+    /// let volume = |v: Vector3| v.x * v.y * v.z;
+    /// assert!(volume(aabb.diagonal()) > 9.0);
+    /// assert!(8.0 * obb.axes().determinant().abs() < 1.0);
+    /// ```
+    #[inline(always)]
+    pub fn oriented_bounding_box(&self) -> Option<OrientedBoundingBox> {
+        OrientedBoundingBox::from_points(self.positions())
+    }
+
+    /// Creates the grid of the positions for the nearest vertex queries.
+    /// The indices returned by the queries are the ones of positions.
+    /// # Panics
+    /// Panic occurs if `cell_size` is not positive.
+    #[inline(always)]
+    pub fn point_grid(&self, cell_size: f64) -> PointGrid {
+        PointGrid::new(self.positions().clone(), cell_size)
+    }
+
+    /// Returns the nearest intersection of the ray from `origin` toward `dir` and
+    /// the triangles of [`Faces::triangle_iter`], by the Möller–Trumbore algorithm.
+    ///
+    /// All triangles are tested. For many queries on a large mesh, use [`PolygonMesh::triangle_grid`].
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    /// let origin = Point3::new(0.75, 0.25, 2.0);
+    /// let hit = mesh.ray_cast(origin, Vector3::new(0.0, 0.0, -1.0)).unwrap();
+    /// assert_eq!(hit.face, 0);
+    /// assert!(hit.distance.near(&2.0));
+    /// assert!(Vector3::from(hit.barycentric).near(&Vector3::new(0.25, 0.5, 0.25)));
+    ///
+    /// assert_eq!(mesh.ray_cast(origin, Vector3::new(0.0, 0.0, 1.0)), None);
+    /// ```
+    pub fn ray_cast(&self, origin: Point3, dir: Vector3) -> Option<RayHit> {
+        let positions = self.positions();
+        let triangles = self
+            .faces()
+            .triangle_iter()
+            .map(|tri| tri.map(|v| positions[v.pos]))
+            .enumerate();
+        let mut nearest = None;
+        ray_cast::nearest_hit(triangles, origin, dir, &mut nearest);
+        nearest.map(|(_, hit)| hit)
+    }
+
+    /// Creates the grid of the triangles of [`Faces::triangle_iter`] for the ray casting.
+    /// # Panics
+    /// Panic occurs if `cell_size` is not positive.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    /// let grid = mesh.triangle_grid(0.25);
+    /// let (origin, dir) = (Point3::new(0.75, 0.25, 2.0), Vector3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(grid.ray_cast(origin, dir), mesh.ray_cast(origin, dir));
+    /// ```
+    #[inline(always)]
+    pub fn triangle_grid(&self, cell_size: f64) -> TriangleGrid {
+        let positions = self.positions();
+        let triangles = self
+            .faces()
+            .triangle_iter()
+            .map(|tri| tri.map(|v| positions[v.pos]))
+            .collect();
+        TriangleGrid::new(triangles, cell_size)
+    }
+
+    /// Returns the feature edges of the polygon mesh as the pairs of indices of positions.
+    ///
+    /// An edge is a feature edge if the angle between the normals of its adjacent faces
+    /// is larger than `angle`, or if the edge is not shared by exactly two faces.
+    /// The normals of faces are calculated from the positions.
+    /// Each pair `[i, j]` satisfies `i < j`, and the returned vector is sorted.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 1.0, 1.0),
+    ///     Point3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// // the bottom face is divided into two triangles.
+    /// let faces = Faces::from_iter([
+    ///     &[0, 2, 1][..],
+    ///     &[0, 3, 2],
+    ///     &[0, 1, 5, 4],
+    ///     &[1, 2, 6, 5],
+    ///     &[2, 3, 7, 6],
+    ///     &[3, 0, 4, 7],
+    ///     &[4, 5, 6, 7],
+    /// ]);
+    /// let cube = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// let edges = cube.feature_edges(0.1);
+    /// assert_eq!(edges.len(), 12);
+    /// assert!(!edges.contains(&[0, 2]));
+    /// ```
+    pub fn feature_edges(&self, angle: f64) -> Vec<[usize; 2]> {
+        let positions = self.positions();
+        let mut adjacency = HashMap::<[usize; 2], Vec<Vector3>>::default();
+        self.faces().face_iter().for_each(|face| {
+            let normal = face
+                .iter()
+                .zip(face.iter().cycle().skip(1))
+                .fold(Vector3::zero(), |sum, (v, w)| {
+                    sum + positions[v.pos].to_vec().cross(positions[w.pos].to_vec())
+                });
+            face.iter().zip(face.iter().cycle().skip(1)).for_each(|(v, w)| {
+                let edge = [usize::min(v.pos, w.pos), usize::max(v.pos, w.pos)];
+                adjacency.entry(edge).or_default().push(normal);
+            });
+        });
+        let mut edges: Vec<[usize; 2]> = adjacency
+            .into_iter()
+            .filter(|(_, normals)| match normals.as_slice() {
+                [n0, n1] => n0.angle(*n1).0 > angle,
+                _ => true,
+            })
+            .map(|(edge, _)| edge)
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    /// Counts the boundary edges, the non-manifold edges, and the isolated vertices.
+    ///
+    /// The edges are taken from the triangulated faces and identified by the unordered pairs of
+    /// the indices of positions, so the diagonals of quadrangles and polygons are counted as
+    /// the interior edges. The edges whose ends are the same position are ignored.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 1.0, 1.0),
+    /// ];
+    /// let mut tetrahedron = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]]),
+    /// );
+    /// let report = tetrahedron.topology_report();
+    /// assert!(report.is_watertight());
+    /// assert_eq!(report.isolated_vertices, 1);
+    ///
+    /// // the fourth triangle sharing the edge [0, 1]
+    /// tetrahedron.editor().faces.push([0usize, 1, 4]);
+    /// let report = tetrahedron.topology_report();
+    /// assert!(!report.is_manifold());
+    /// assert_eq!(report.boundary_edges, 2);
+    /// assert_eq!(report.non_manifold_edges, 1);
+    /// ```
+    pub fn topology_report(&self) -> MeshReport {
+        let mut edge_count = HashMap::<[usize; 2], usize>::default();
+        let mut used = vec![false; self.positions().len()];
+        self.faces().triangle_iter().for_each(|tri| {
+            (0..3).for_each(|i| {
+                let (a, b) = (tri[i].pos, tri[(i + 1) % 3].pos);
+                used[a] = true;
+                if a != b {
+                    let edge = [usize::min(a, b), usize::max(a, b)];
+                    *edge_count.entry(edge).or_insert(0) += 1;
+                }
+            })
+        });
+        MeshReport {
+            boundary_edges: edge_count.values().filter(|count| **count == 1).count(),
+            non_manifold_edges: edge_count.values().filter(|count| **count > 2).count(),
+            isolated_vertices: used.into_iter().filter(|used| !used).count(),
+        }
+    }
+
+    /// Returns the signed volume enclosed by the closed polygon mesh.
+    ///
+    /// The volume is the sum of the signed volumes of the tetrahedra spanned by the origin and
+    /// the fan-triangulated faces. It is positive if the faces are oriented outward, and its sign
+    /// flips if the mesh is inverted.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 1.0, 1.0),
+    ///     Point3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let faces = Faces::from_iter(&[
+    ///     [0, 3, 2, 1],
+    ///     [0, 1, 5, 4],
+    ///     [1, 2, 6, 5],
+    ///     [2, 3, 7, 6],
+    ///     [3, 0, 4, 7],
+    ///     [4, 5, 6, 7],
+    /// ]);
+    /// let mut cube = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// assert!(cube.volume().near(&1.0));
+    /// cube.invert();
+    /// assert!(cube.volume().near(&-1.0));
+    /// ```
+    pub fn volume(&self) -> f64 { self.signed_tetrahedra().map(|(volume, _)| volume).sum() }
+
+    /// Returns the centroid of the solid enclosed by the closed polygon mesh.
+    ///
+    /// The centroid is the average of the centroids of the tetrahedra in [`PolygonMesh::volume`]
+    /// weighted by their signed volumes. The coordinates are NaN if the volume is zero.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // a cube whose center is (1, 2, 3)
+    /// let positions = (0..8)
+    ///     .map(|i| {
+    ///         let (x, y, z) = ((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64);
+    ///         Point3::new(x + 0.5, y + 1.5, z + 2.5)
+    ///     })
+    ///     .collect();
+    /// let faces = Faces::from_iter(&[
+    ///     [0, 2, 3, 1],
+    ///     [0, 1, 5, 4],
+    ///     [1, 3, 7, 5],
+    ///     [3, 2, 6, 7],
+    ///     [2, 0, 4, 6],
+    ///     [4, 5, 7, 6],
+    /// ]);
+    /// let cube = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// assert!(cube.volume().near(&1.0));
+    /// assert!(cube.centroid().near(&Point3::new(1.0, 2.0, 3.0)));
+    /// ```
+    pub fn centroid(&self) -> Point3 {
+        let (volume, moment) = self.signed_tetrahedra().fold(
+            (0.0, Vector3::zero()),
+            |(volume, moment), (v, center)| (volume + v, moment + center * v),
+        );
+        Point3::from_vec(moment / volume)
+    }
+
+    /// Returns the surface area of the polygon mesh.
+    ///
+    /// The area is the sum of [`PolygonMesh::face_areas`].
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    /// ];
+    /// let faces = Faces::from_iter([
+    ///     &[0, 1, 2, 3][..],
+    ///     &[0, 4, 1], // degenerate
+    ///     &[1, 2, 3],
+    /// ]);
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// assert_eq!(mesh.face_areas(), vec![0.0, 1.0, 2.0]);
+    /// assert_eq!(mesh.area(), 3.0);
+    /// ```
+    #[inline(always)]
+    pub fn area(&self) -> f64 { self.face_iter().map(|face| self.face_area(face)).sum() }
+
+    /// Returns the areas of faces in the order of [`PolygonMesh::face_iter`].
+    ///
+    /// Each face is fan-triangulated, and degenerate faces have zero area.
+    #[inline(always)]
+    pub fn face_areas(&self) -> Vec<f64> {
+        self.face_iter().map(|face| self.face_area(face)).collect()
+    }
+
+    fn face_area(&self, face: &[StandardVertex]) -> f64 {
+        let positions = self.positions();
+        let p0 = positions[face[0].pos];
+        face[1..]
+            .windows(2)
+            .map(|edge| {
+                let (p1, p2) = (positions[edge[0].pos], positions[edge[1].pos]);
+                (p1 - p0).cross(p2 - p0).magnitude() / 2.0
+            })
+            .sum()
+    }
+
+    /// the signed volumes and the centroids of the tetrahedra spanned by the origin and the
+    /// fan-triangulated faces
+    fn signed_tetrahedra(&self) -> impl Iterator<Item = (f64, Vector3)> + '_ {
+        let positions = self.positions();
+        self.face_iter().flat_map(move |face| {
+            let p0 = positions[face[0].pos].to_vec();
+            face[1..].windows(2).map(move |edge| {
+                let p1 = positions[edge[0].pos].to_vec();
+                let p2 = positions[edge[1].pos].to_vec();
+                (p0.dot(p1.cross(p2)) / 6.0, (p0 + p1 + p2) / 4.0)
+            })
+        })
+    }
+}
+
+impl MeshReport {
+    /// Returns `true` if every edge is shared by exactly two faces.
+    #[inline(always)]
+    pub fn is_watertight(&self) -> bool { self.boundary_edges == 0 && self.non_manifold_edges == 0 }
+    /// Returns `true` if no edge is shared by more than two faces.
+    #[inline(always)]
+    pub fn is_manifold(&self) -> bool { self.non_manifold_edges == 0 }
 }
 
 impl Invertible for PolygonMesh {
@@ -144,6 +589,33 @@ impl Invertible for PolygonMesh {
     }
 }
 
+impl Transformed<Matrix4> for PolygonMesh {
+    /// Applies `trans` to the positions, and the inverse transpose of the linear part of
+    /// `trans` to the normals, so the normals are kept perpendicular to the faces even with
+    /// non-uniform scalings. The transformed normals are normalized. If `trans` reverses the
+    /// orientation, the faces are also inverted in order to be compatible to the normals.
+    fn transform_by(&mut self, trans: Matrix4) {
+        let (c0, c1, c2) = (trans.x.truncate(), trans.y.truncate(), trans.z.truncate());
+        let det = c0.dot(c1.cross(c2));
+        // the cofactor matrix, the inverse transpose multiplied by the determinant,
+        // is defined also for the singular matrices.
+        let cofactor = Matrix3::from_cols(c1.cross(c2), c2.cross(c0), c0.cross(c1));
+        self.attributes
+            .positions
+            .iter_mut()
+            .for_each(|p| *p = trans.transform_point(*p));
+        self.attributes.normals.iter_mut().for_each(|n| {
+            let normal = cofactor * *n * det.signum();
+            if !normal.so_small() {
+                *n = normal.normalize();
+            }
+        });
+        if det < 0.0 {
+            self.faces.invert();
+        }
+    }
+}
+
 impl PolygonMesh {
     /// Returns the vector of all positions.
     #[inline(always)]
@@ -0,0 +1,204 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Möller–Trumbore intersection of the ray and the triangle.
+/// Returns the ray parameter and the barycentric coordinates.
+pub(crate) fn intersect_triangle(
+    triangle: [Point3; 3],
+    origin: Point3,
+    dir: Vector3,
+) -> Option<(f64, [f64; 3])> {
+    let edge0 = triangle[1] - triangle[0];
+    let edge1 = triangle[2] - triangle[0];
+    let p = dir.cross(edge1);
+    let det = edge0.dot(p);
+    // the ray is parallel to the triangle, or the triangle is degenerate
+    if det.abs() <= TOLERANCE * edge0.cross(edge1).magnitude() * dir.magnitude() {
+        return None;
+    }
+    let s = origin - triangle[0];
+    let u = s.dot(p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(edge0);
+    let v = dir.dot(q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = edge1.dot(q) / det;
+    match t >= 0.0 {
+        true => Some((t, [1.0 - u - v, u, v])),
+        false => None,
+    }
+}
+
+/// Returns the nearest hit among the triangles `candidates`, updating `nearest`,
+/// which is the pair of the ray parameter and the hit.
+pub(crate) fn nearest_hit(
+    candidates: impl IntoIterator<Item = (usize, [Point3; 3])>,
+    origin: Point3,
+    dir: Vector3,
+    nearest: &mut Option<(f64, RayHit)>,
+) {
+    candidates.into_iter().for_each(|(face, triangle)| {
+        if let Some((t, barycentric)) = intersect_triangle(triangle, origin, dir) {
+            let closer = match nearest {
+                Some((t0, _)) => t < *t0,
+                None => true,
+            };
+            if closer {
+                let hit = RayHit {
+                    face,
+                    barycentric,
+                    distance: t * dir.magnitude(),
+                };
+                *nearest = Some((t, hit));
+            }
+        }
+    });
+}
+
+impl TriangleGrid {
+    /// Creates the grid of `triangles` whose cells are cubes with edge length `cell_size`.
+    /// # Panics
+    /// Panic occurs if `cell_size` is not positive.
+    pub fn new(triangles: Vec<[Point3; 3]>, cell_size: f64) -> Self {
+        assert!(cell_size > 0.0, "the size of cells must be positive.");
+        let bounding_box: BoundingBox<Point3> = triangles.iter().flatten().collect();
+        let mut grid = TriangleGrid {
+            triangles: Vec::new(),
+            bounding_box,
+            cell_size,
+            cells: HashMap::default(),
+        };
+        triangles.iter().enumerate().for_each(|(i, triangle)| {
+            let bdd: BoundingBox<Point3> = triangle.iter().collect();
+            let (lower, upper) = (grid.cell_index(*bdd.min()), grid.cell_index(*bdd.max()));
+            for x in lower[0]..=upper[0] {
+                for y in lower[1]..=upper[1] {
+                    for z in lower[2]..=upper[2] {
+                        grid.cells.entry([x, y, z]).or_default().push(i);
+                    }
+                }
+            }
+        });
+        grid.triangles = triangles;
+        grid
+    }
+
+    /// Returns the registered triangles.
+    #[inline(always)]
+    pub fn triangles(&self) -> &Vec<[Point3; 3]> { &self.triangles }
+    /// Returns the bounding box of the registered triangles.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> &BoundingBox<Point3> { &self.bounding_box }
+    /// Returns the edge length of cells.
+    #[inline(always)]
+    pub fn cell_size(&self) -> f64 { self.cell_size }
+
+    /// Returns the nearest intersection of the ray and the triangles.
+    ///
+    /// The cells are traversed along the ray, so the result is the same as
+    /// [`PolygonMesh::ray_cast`] for the triangles of the mesh.
+    pub fn ray_cast(&self, origin: Point3, dir: Vector3) -> Option<RayHit> {
+        let (t_enter, t_exit) = self.clip(origin, dir)?;
+        let min = *self.bounding_box.min();
+        let last = self.cell_index(*self.bounding_box.max());
+        let mut idx = self.cell_index(origin + dir * t_enter);
+        (0..3).for_each(|k| idx[k] = idx[k].clamp(0, last[k]));
+        let step = |k: usize| match dir[k] > 0.0 {
+            true => 1,
+            false => -1,
+        };
+        let boundary = |k: usize, idx: i64| {
+            let offset = match dir[k] > 0.0 {
+                true => idx + 1,
+                false => idx,
+            };
+            match dir[k] == 0.0 {
+                true => f64::INFINITY,
+                false => (min[k] + offset as f64 * self.cell_size - origin[k]) / dir[k],
+            }
+        };
+        let mut t_max = [
+            boundary(0, idx[0]),
+            boundary(1, idx[1]),
+            boundary(2, idx[2]),
+        ];
+
+        let mut nearest = None;
+        loop {
+            if let Some(cell) = self.cells.get(&idx) {
+                let candidates = cell.iter().map(|i| (*i, self.triangles[*i]));
+                nearest_hit(candidates, origin, dir, &mut nearest);
+            }
+            let k = (0..3)
+                .min_by(|i, j| t_max[*i].total_cmp(&t_max[*j]))
+                .unwrap();
+            let t_next = t_max[k];
+            if nearest.is_some_and(|(t, _)| t <= t_next) || t_next > t_exit {
+                break;
+            }
+            idx[k] += step(k);
+            if idx[k] < 0 || idx[k] > last[k] {
+                break;
+            }
+            t_max[k] = boundary(k, idx[k]);
+        }
+        nearest.map(|(_, hit)| hit)
+    }
+
+    /// Returns the indices of the triangles registered to the cells overlapping `bounding_box`
+    /// in ascending order.
+    ///
+    /// The result includes all triangles whose bounding boxes intersect `bounding_box`,
+    /// but may include some other triangles near `bounding_box`.
+    pub fn candidates(&self, bounding_box: &BoundingBox<Point3>) -> Vec<usize> {
+        if self.triangles.is_empty() || bounding_box.is_empty() {
+            return Vec::new();
+        }
+        let lower = self.cell_index(*bounding_box.min());
+        let upper = self.cell_index(*bounding_box.max());
+        let last = self.cell_index(*self.bounding_box.max());
+        let range = |k: usize| i64::max(lower[k], 0)..=i64::min(upper[k], last[k]);
+        let mut res = Vec::new();
+        for x in range(0) {
+            for y in range(1) {
+                for z in range(2) {
+                    if let Some(cell) = self.cells.get(&[x, y, z]) {
+                        res.extend(cell);
+                    }
+                }
+            }
+        }
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+
+    fn cell_index(&self, pt: Point3) -> [i64; 3] {
+        let min = self.bounding_box.min();
+        let idx = |k: usize| f64::floor((pt[k] - min[k]) / self.cell_size) as i64;
+        [idx(0), idx(1), idx(2)]
+    }
+
+    /// the range of the ray parameter in the bounding box by the slab method
+    fn clip(&self, origin: Point3, dir: Vector3) -> Option<(f64, f64)> {
+        if self.bounding_box.is_empty() {
+            return None;
+        }
+        let (min, max) = (self.bounding_box.min(), self.bounding_box.max());
+        (0..3).try_fold((0.0, f64::INFINITY), |(t0, t1), k| {
+            if dir[k] == 0.0 {
+                return (min[k] <= origin[k] && origin[k] <= max[k]).then_some((t0, t1));
+            }
+            let (s0, s1) = ((min[k] - origin[k]) / dir[k], (max[k] - origin[k]) / dir[k]);
+            let (t0, t1) = (
+                f64::max(t0, f64::min(s0, s1)),
+                f64::min(t1, f64::max(s0, s1)),
+            );
+            (t0 <= t1).then_some((t0, t1))
+        })
+    }
+}
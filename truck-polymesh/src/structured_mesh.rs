@@ -230,6 +230,60 @@ impl StructuredMesh {
             faces,
         }
     }
+
+    /// Creates new polygon mesh by destructing `self`.
+    ///
+    /// Each cell of the grid is converted to a quadrangle if `quads` is `true`,
+    /// or to two triangles otherwise. The faces with zero area, e.g. the ones at the poles
+    /// of spheres, are skipped. The vertices have the indices of uv coordinates and normals
+    /// if `self` has `uv_division` and `normals`, respectively.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // the first row is collapsed to a point.
+    /// let positions = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0); 3],
+    ///     vec![
+    ///         Point3::new(1.0, 0.0, 0.0),
+    ///         Point3::new(1.0, 1.0, 0.0),
+    ///         Point3::new(1.0, 2.0, 0.0),
+    ///     ],
+    /// ];
+    /// let mesh = StructuredMesh::from_positions(positions);
+    /// assert_eq!(mesh.clone().into_polygon(true).faces().quad_faces().len(), 2);
+    /// // the triangles at the collapsed row are skipped.
+    /// assert_eq!(mesh.into_polygon(false).faces().tri_faces().len(), 2);
+    /// ```
+    pub fn into_polygon(self, quads: bool) -> PolygonMesh {
+        let mut mesh = self.destruct();
+        let positions = &mesh.attributes.positions;
+        let area_vector = |face: &[StandardVertex]| {
+            let p: Vec<Point3> = face.iter().map(|v| positions[v.pos]).collect();
+            match p.len() {
+                3 => (p[1] - p[0]).cross(p[2] - p[0]),
+                _ => (p[2] - p[0]).cross(p[3] - p[1]),
+            }
+        };
+        let cells = std::mem::take(&mut mesh.faces.quad_faces);
+        mesh.faces = match quads {
+            true => {
+                let quad_faces = cells
+                    .into_iter()
+                    .filter(|face| !area_vector(&face[..]).so_small())
+                    .collect();
+                Faces::from_tri_and_quad_faces(Vec::new(), quad_faces)
+            }
+            false => {
+                let tri_faces = cells
+                    .into_iter()
+                    .flat_map(|[v0, v1, v2, v3]| [[v0, v1, v2], [v0, v2, v3]])
+                    .filter(|face| !area_vector(&face[..]).so_small())
+                    .collect();
+                Faces::from_tri_and_quad_faces(tri_faces, Vec::new())
+            }
+        };
+        mesh
+    }
 }
 
 #[inline(always)]
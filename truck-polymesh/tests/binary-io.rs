@@ -0,0 +1,95 @@
+#![cfg(feature = "bincode")]
+
+use truck_polymesh::*;
+
+fn mesh() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 0.5, 1.0),
+        Point3::new(0.5, 1.5, 0.0),
+    ];
+    let uv_coords = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.5, 1.0),
+    ];
+    let normals = vec![
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(0.0, -1.0, 0.5).normalize(),
+        Vector3::new(1.0, 0.0, 0.5).normalize(),
+    ];
+    let faces = Faces::from_iter(&[
+        &[(0, None, Some(0)), (3, None, Some(0)), (2, None, Some(0)), (1, None, Some(0))][..],
+        &[(0, Some(0), Some(1)), (1, Some(1), Some(1)), (4, Some(2), Some(1))],
+        &[(1, Some(0), Some(2)), (2, Some(1), Some(2)), (4, Some(2), None)],
+        &[(2, None, None), (5, None, None), (3, None, None), (0, None, None), (1, None, None)],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )
+}
+
+#[test]
+fn round_trip() {
+    let mesh = mesh();
+    let mut bytes = Vec::<u8>::new();
+    mesh.write_bin(&mut bytes).unwrap();
+    assert_eq!(bytes[0], binary::FORMAT_VERSION);
+
+    let read = PolygonMesh::read_bin(bytes.as_slice()).unwrap();
+    assert_eq!(read.positions(), mesh.positions());
+    assert_eq!(read.uv_coords(), mesh.uv_coords());
+    assert_eq!(read.normals(), mesh.normals());
+    assert_eq!(read.tri_faces(), mesh.tri_faces());
+    assert_eq!(read.quad_faces(), mesh.quad_faces());
+    assert_eq!(read.other_faces(), mesh.other_faces());
+    assert_eq!(read, mesh);
+}
+
+#[test]
+fn little_endian_header() {
+    let mut bytes = Vec::<u8>::new();
+    binary::write(&mesh(), &mut bytes).unwrap();
+    // the version byte, and the length of positions as little-endian `u64`
+    assert_eq!(bytes[0], 1);
+    assert_eq!(&bytes[1..9], &6_u64.to_le_bytes());
+    // the first coordinate of the second position
+    assert_eq!(&bytes[33..41], &1.0_f64.to_le_bytes());
+}
+
+#[test]
+fn invalid_stream() {
+    let mut bytes = Vec::<u8>::new();
+    binary::write(&mesh(), &mut bytes).unwrap();
+
+    let mut other_version = bytes.clone();
+    other_version[0] = binary::FORMAT_VERSION + 1;
+    assert!(PolygonMesh::read_bin(other_version.as_slice()).is_err());
+
+    let truncated = &bytes[..bytes.len() / 2];
+    assert!(PolygonMesh::read_bin(truncated).is_err());
+    assert!(PolygonMesh::read_bin([0_u8; 0].as_slice()).is_err());
+}
+
+#[test]
+fn out_of_range_index() {
+    let attributes = StandardAttributes {
+        positions: vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)],
+        ..Default::default()
+    };
+    let mesh = PolygonMesh::new_unchecked(attributes, Faces::from_iter(&[&[0, 1, 2]]));
+    let mut bytes = Vec::<u8>::new();
+    binary::write(&mesh, &mut bytes).unwrap();
+    match PolygonMesh::read_bin(bytes.as_slice()) {
+        Err(errors::Error::OutOfRange(vertex)) => assert_eq!(vertex.pos, 2),
+        res => panic!("unexpected result: {res:?}"),
+    }
+}
@@ -0,0 +1,50 @@
+use truck_polymesh::*;
+
+/// the faces registered in the mixed order of the kinds
+fn mixed_faces() -> Faces<usize> {
+    let slice: &[&[usize]] = &[
+        &[0, 1, 2, 3, 4],
+        &[0, 1, 2, 3],
+        &[0, 1, 2],
+        &[1, 2, 3, 4, 5, 6],
+        &[3, 4, 5],
+        &[2, 3, 4, 5],
+    ];
+    Faces::from_iter(slice)
+}
+
+#[test]
+fn global_index_mapping() {
+    let faces = mixed_faces();
+    assert_eq!(faces.len(), 6);
+    // triangles, quadrangles, and the others in the order of registration
+    let expected: &[&[usize]] = &[
+        &[0, 1, 2],
+        &[3, 4, 5],
+        &[0, 1, 2, 3],
+        &[2, 3, 4, 5],
+        &[0, 1, 2, 3, 4],
+        &[1, 2, 3, 4, 5, 6],
+    ];
+    assert_eq!(faces.iter().collect::<Vec<_>>(), expected);
+    faces.enumerate().zip(expected).enumerate().for_each(|(i, ((idx, face), exp))| {
+        assert_eq!(idx, i);
+        assert_eq!(face, *exp);
+        assert_eq!(&faces[idx], *exp);
+    });
+    // the same order as `face_iter`
+    assert!(faces.iter().eq(faces.face_iter()));
+}
+
+#[test]
+fn global_index_after_push() {
+    let mut faces = mixed_faces();
+    // a new triangle shifts the indices of the quadrangles and the others.
+    faces.push([6_usize, 7, 8]);
+    assert_eq!(&faces[2], &[6, 7, 8]);
+    assert_eq!(&faces[3], &[0, 1, 2, 3]);
+    assert_eq!(&faces[6], &[1, 2, 3, 4, 5, 6]);
+    faces
+        .enumerate()
+        .for_each(|(idx, face)| assert_eq!(face, &faces[idx]));
+}
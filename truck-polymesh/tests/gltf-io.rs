@@ -0,0 +1,128 @@
+use gltf::GltfFormat;
+use serde_json::Value;
+use truck_polymesh::*;
+
+fn accessor_count(json: &Value, attribute: &str) -> Option<u64> {
+    let primitive = &json["meshes"][0]["primitives"][0];
+    let idx = match attribute {
+        "indices" => primitive["indices"].as_u64()?,
+        _ => primitive["attributes"][attribute].as_u64()?,
+    };
+    json["accessors"][idx as usize]["count"].as_u64()
+}
+
+fn glb_json(glb: &[u8]) -> Value {
+    let u32_at = |i: usize| u32::from_le_bytes([glb[i], glb[i + 1], glb[i + 2], glb[i + 3]]);
+    assert_eq!(&glb[0..4], b"glTF");
+    assert_eq!(u32_at(4), 2);
+    assert_eq!(u32_at(8) as usize, glb.len());
+    let json_len = u32_at(12) as usize;
+    assert_eq!(&glb[16..20], b"JSON");
+    let json = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+    let bin_len = u32_at(20 + json_len) as usize;
+    assert_eq!(&glb[24 + json_len..28 + json_len], b"BIN\0");
+    assert_eq!(28 + json_len + bin_len, glb.len());
+    json
+}
+
+#[test]
+fn gltf_structured_mesh() {
+    let positions = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| Point3::new(i as f64, j as f64, 0.0))
+                .collect()
+        })
+        .collect();
+    let uv_division = (vec![0.0, 0.5, 1.0], vec![0.0, 0.5, 1.0]);
+    let normals = vec![vec![Vector3::unit_z(); 3]; 3];
+    let mesh = StructuredMesh::new(positions, uv_division, normals);
+
+    let mut bytes = Vec::<u8>::new();
+    gltf::write_structured(&mesh, &mut bytes, GltfFormat::Gltf).unwrap();
+    let json: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(json["asset"]["version"], "2.0");
+    assert_eq!(accessor_count(&json, "POSITION"), Some(9));
+    assert_eq!(accessor_count(&json, "NORMAL"), Some(9));
+    assert_eq!(accessor_count(&json, "TEXCOORD_0"), Some(9));
+    assert_eq!(accessor_count(&json, "indices"), Some(24));
+    let buffer = &json["buffers"][0];
+    // 24 indices, 9 positions, 9 normals and 9 uv coordinates
+    assert_eq!(buffer["byteLength"], 24 * 4 + 9 * 12 + 9 * 12 + 9 * 8);
+    assert!(buffer["uri"]
+        .as_str()
+        .unwrap()
+        .starts_with("data:application/octet-stream;base64,"));
+
+    let mut glb = Vec::<u8>::new();
+    gltf::write_structured(&mesh, &mut glb, GltfFormat::Glb).unwrap();
+    let glb_json = glb_json(&glb);
+    assert_eq!(glb_json["accessors"], json["accessors"]);
+    assert!(glb_json["buffers"][0].get("uri").is_none());
+}
+
+#[test]
+fn gltf_polygon_mesh() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 0.5, 1.0),
+    ];
+    let normals = vec![Vector3::new(0.0, 0.0, -1.0)];
+    // the side faces have no normals
+    let faces = Faces::from_iter(&[
+        &[
+            (0, None, Some(0)),
+            (3, None, Some(0)),
+            (2, None, Some(0)),
+            (1, None, Some(0)),
+        ][..],
+        &[(0, None, None), (1, None, None), (4, None, None)],
+        &[(1, None, None), (2, None, None), (4, None, None)],
+        &[(2, None, None), (3, None, None), (4, None, None)],
+        &[(3, None, None), (0, None, None), (4, None, None)],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    );
+    let mut glb = Vec::<u8>::new();
+    gltf::write(&mesh, &mut glb, GltfFormat::Glb).unwrap();
+    let json = glb_json(&glb);
+    // 4 vertices with normals and 5 vertices without normals
+    assert_eq!(accessor_count(&json, "POSITION"), Some(9));
+    assert_eq!(accessor_count(&json, "NORMAL"), None);
+    assert_eq!(accessor_count(&json, "TEXCOORD_0"), None);
+    assert_eq!(accessor_count(&json, "indices"), Some(6 * 3));
+    let position = &json["accessors"][json["meshes"][0]["primitives"][0]["attributes"]["POSITION"]
+        .as_u64()
+        .unwrap() as usize];
+    assert_eq!(position["min"], serde_json::json!([0.0, 0.0, 0.0]));
+    assert_eq!(position["max"], serde_json::json!([1.0, 1.0, 1.0]));
+}
+
+#[test]
+fn gltf_empty_mesh() {
+    // positions without faces also make no primitive.
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![Point3::new(0.0, 0.0, 0.0)],
+            ..Default::default()
+        },
+        Faces::default(),
+    );
+    for mesh in [PolygonMesh::default(), mesh] {
+        for format in [GltfFormat::Gltf, GltfFormat::Glb] {
+            let mut buffer = Vec::<u8>::new();
+            let res = gltf::write(&mesh, &mut buffer, format);
+            assert!(matches!(res, Err(errors::Error::EmptyMesh)), "{res:?}");
+            assert!(buffer.is_empty());
+        }
+    }
+}
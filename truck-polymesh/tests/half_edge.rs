@@ -0,0 +1,89 @@
+mod common;
+use common::{cube, CUBE_FACES};
+use errors::Error;
+use half_edge::HalfEdgeMesh;
+use truck_polymesh::*;
+
+#[test]
+fn cube_round_trip() {
+    let mesh = cube(&CUBE_FACES);
+    let half_edge_mesh = HalfEdgeMesh::try_from(&mesh).unwrap();
+    assert_eq!(half_edge_mesh.half_edges().len(), 24);
+    assert_eq!(half_edge_mesh.num_faces(), 6);
+    assert!(half_edge_mesh.boundary_loops().is_empty());
+    assert_eq!(half_edge_mesh.to_polygon_mesh(), mesh);
+
+    half_edge_mesh
+        .half_edges()
+        .iter()
+        .enumerate()
+        .for_each(|(i, he)| {
+            let twin = half_edge_mesh.twin(i).unwrap();
+            assert_eq!(half_edge_mesh.twin(twin), Some(i));
+            assert_eq!(half_edge_mesh.destination(twin), he.vertex.pos);
+            assert_ne!(half_edge_mesh.half_edges()[twin].face, he.face);
+        });
+    (0..6).for_each(|face| {
+        let positions: Vec<usize> = half_edge_mesh
+            .face_halfedges(face)
+            .map(|i| half_edge_mesh.half_edges()[i].vertex.pos)
+            .collect();
+        assert_eq!(positions, CUBE_FACES[face]);
+    });
+}
+
+#[test]
+fn cube_one_rings() {
+    let half_edge_mesh = HalfEdgeMesh::try_from(&cube(&CUBE_FACES)).unwrap();
+    // the vertices of the unit cube adjacent to each other differ in one coordinate.
+    (0..8).for_each(|i| {
+        let mut ring = half_edge_mesh.vertex_one_ring(i);
+        assert_eq!(ring.len(), 3);
+        ring.sort();
+        let mut expected = vec![i ^ 1, i ^ 2, i ^ 4];
+        expected.sort();
+        assert_eq!(ring, expected);
+    });
+}
+
+#[test]
+fn open_cube() {
+    let mesh = cube(&CUBE_FACES[1..]);
+    let half_edge_mesh = HalfEdgeMesh::try_from(&mesh).unwrap();
+    assert_eq!(half_edge_mesh.to_polygon_mesh(), mesh);
+    let loops = half_edge_mesh.boundary_loops();
+    assert_eq!(loops.len(), 1);
+    // the removed face is [0, 2, 3, 1], so the boundary runs oppositely
+    let boundary = &loops[0];
+    let start = boundary.iter().position(|i| *i == 0).unwrap();
+    let rotated: Vec<usize> = (0..4).map(|k| boundary[(start + k) % 4]).collect();
+    assert_eq!(rotated, vec![0, 1, 3, 2]);
+    // the one-ring of a boundary vertex starts and ends at the boundary
+    let ring = half_edge_mesh.vertex_one_ring(0);
+    assert_eq!(ring.len(), 3);
+    assert!(ring[0] == 1 || ring[0] == 2);
+    assert!(ring[2] == 1 || ring[2] == 2);
+    assert_eq!(ring[1], 4);
+}
+
+#[test]
+fn non_manifold_inputs() {
+    let positions = common::cube_positions();
+    let mesh = |faces: &[[usize; 3]]| {
+        PolygonMesh::new(
+            StandardAttributes {
+                positions: positions.clone(),
+                ..Default::default()
+            },
+            Faces::from_iter(faces),
+        )
+    };
+    // three triangles sharing the edge [0, 1]
+    let res = HalfEdgeMesh::try_from(&mesh(&[[0, 1, 2], [1, 0, 4], [0, 1, 7]]));
+    assert!(matches!(res, Err(Error::NonManifoldEdge([0, 1]))));
+    let res = HalfEdgeMesh::try_from(&mesh(&[[0, 1, 2], [0, 1, 4]]));
+    assert!(matches!(res, Err(Error::InconsistentOrientation([0, 1]))));
+    // two triangles sharing only the vertex 0
+    let res = HalfEdgeMesh::try_from(&mesh(&[[0, 1, 2], [0, 4, 5]]));
+    assert!(matches!(res, Err(Error::NonManifoldVertex(0))));
+}
@@ -0,0 +1,60 @@
+use std::f64::consts::PI;
+use truck_polymesh::*;
+
+fn sphere(division: usize) -> PolygonMesh {
+    let positions = (0..=division)
+        .map(|i| {
+            let theta = PI * i as f64 / division as f64;
+            (0..=division)
+                .map(|j| {
+                    let phi = 2.0 * PI * j as f64 / division as f64;
+                    let (sin, cos) = (theta.sin(), theta.cos());
+                    Point3::new(sin * phi.cos(), sin * phi.sin(), cos)
+                })
+                .collect()
+        })
+        .collect();
+    StructuredMesh::from_positions(positions).destruct()
+}
+
+#[test]
+fn sphere_mass_properties() {
+    let mesh = sphere(64);
+
+    let face_areas = mesh.face_areas();
+    assert_eq!(face_areas.len(), mesh.faces().len());
+    // the faces at the poles are degenerated to triangles, and some of their fans vanish.
+    assert!(face_areas.iter().all(|area| area.is_finite() && *area >= 0.0));
+    let area = mesh.area();
+    assert!(area.near(&face_areas.iter().sum::<f64>()));
+    assert!(f64::abs(area - 4.0 * PI) < 0.01 * 4.0 * PI);
+
+    let volume = mesh.volume();
+    assert!(f64::abs(volume - 4.0 * PI / 3.0) < 0.01 * 4.0 * PI / 3.0);
+    assert!(mesh.centroid().near(&Point3::origin()));
+
+    let inverse = mesh.inverse();
+    assert!(inverse.volume().near(&-volume));
+    assert!(inverse.area().near(&area));
+}
+
+#[test]
+fn degenerate_faces() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+    ];
+    let faces = Faces::from_iter([&[0, 1, 2][..], &[0, 0, 0], &[0, 1, 2, 3]]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    assert_eq!(mesh.face_areas(), vec![0.0; 3]);
+    assert_eq!(mesh.area(), 0.0);
+    assert_eq!(mesh.volume(), 0.0);
+}
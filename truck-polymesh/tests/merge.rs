@@ -0,0 +1,71 @@
+mod common;
+use common::CUBE_FACES;
+use truck_polymesh::*;
+
+fn cube(origin: Point3) -> PolygonMesh {
+    let positions = common::cube_positions()
+        .into_iter()
+        .map(|p| p + origin.to_vec())
+        .collect();
+    let normals = vec![
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(0.0, -1.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let faces: Vec<[StandardVertex; 4]> = CUBE_FACES
+        .iter()
+        .enumerate()
+        .map(|(i, face)| face.map(|pos| StandardVertex::from((pos, None, Some(i)))))
+        .collect();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    )
+}
+
+#[test]
+fn merge_two_cubes() {
+    let mut mesh = cube(Point3::origin());
+    let other = cube(Point3::new(2.0, 0.0, 0.0));
+    mesh.merge(other.clone());
+    assert_eq!(mesh.positions().len(), 16);
+    assert_eq!(mesh.normals().len(), 12);
+    assert!(mesh.uv_coords().is_empty());
+    assert_eq!(mesh.faces().len(), 12);
+    assert_eq!(mesh.faces().quad_faces().len(), 12);
+
+    // the faces of `other` refer to the same attributes as before merging.
+    mesh.face_iter()
+        .skip(6)
+        .zip(other.face_iter())
+        .for_each(|(face0, face1)| {
+            face0.iter().zip(face1).for_each(|(v0, v1)| {
+                assert_eq!(v0.pos, v1.pos + 8);
+                assert_eq!(v0.nor, v1.nor.map(|nor| nor + 6));
+                assert_eq!(mesh.positions()[v0.pos], other.positions()[v1.pos]);
+            });
+        });
+    let bdb = mesh.bounding_box();
+    assert_eq!(*bdb.min(), Point3::new(0.0, 0.0, 0.0));
+    assert_eq!(*bdb.max(), Point3::new(3.0, 1.0, 1.0));
+}
+
+#[test]
+fn merge_chain() {
+    let mut mesh = PolygonMesh::default();
+    mesh.merge(cube(Point3::origin()))
+        .merge(cube(Point3::new(2.0, 0.0, 0.0)))
+        .merge(cube(Point3::new(4.0, 0.0, 0.0)));
+    assert_eq!(mesh.positions().len(), 24);
+    assert_eq!(mesh.normals().len(), 18);
+    assert_eq!(mesh.faces().len(), 18);
+    let max = mesh.face_iter().flatten().map(|v| v.pos).max();
+    assert_eq!(max, Some(23));
+}
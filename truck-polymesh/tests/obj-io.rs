@@ -270,3 +270,265 @@ fn incomplete_obj_oi_test() {
     let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
     assert_eq!(mesh, read_mesh);
 }
+
+#[test]
+fn materials_obj_oi_test() {
+    let faces = Faces::from_iter(&[
+        [
+            (0, None, Some(5)),
+            (2, None, Some(5)),
+            (4, None, Some(5)),
+            (1, None, Some(5)),
+        ],
+        [
+            (0, None, Some(4)),
+            (1, None, Some(4)),
+            (5, None, Some(4)),
+            (3, None, Some(4)),
+        ],
+        [
+            (0, None, Some(3)),
+            (3, None, Some(3)),
+            (6, None, Some(3)),
+            (2, None, Some(3)),
+        ],
+        [
+            (1, None, Some(0)),
+            (4, None, Some(0)),
+            (7, None, Some(0)),
+            (5, None, Some(0)),
+        ],
+        [
+            (2, None, Some(1)),
+            (6, None, Some(1)),
+            (7, None, Some(1)),
+            (4, None, Some(1)),
+        ],
+        [
+            (3, None, Some(2)),
+            (5, None, Some(2)),
+            (7, None, Some(2)),
+            (6, None, Some(2)),
+        ],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: cube::POSITIONS.to_vec(),
+            normals: cube::NORMALS.to_vec(),
+            ..Default::default()
+        },
+        faces,
+    );
+    let materials = [
+        obj::Material {
+            name: Some("red".to_string()),
+            diffuse: Vector3::new(1.0, 0.0, 0.0),
+        },
+        obj::Material {
+            name: Some("blue".to_string()),
+            diffuse: Vector3::new(0.0, 0.0, 1.0),
+        },
+    ];
+    let face_groups = [0, 1, 0, 1, 1, 0];
+    let mut gened_obj: Vec<u8> = Vec::new();
+    obj::write_with_materials(&mesh, &mut gened_obj, "cube.mtl", &materials, &face_groups).unwrap();
+    let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
+    assert_eq!(mesh, read_mesh);
+
+    let obj_str = String::from_utf8(gened_obj).unwrap();
+    assert_eq!(
+        obj_str.lines().filter(|l| l.starts_with("mtllib")).count(),
+        1
+    );
+    assert_eq!(
+        obj_str.lines().filter(|l| l.starts_with("usemtl")).count(),
+        5
+    );
+
+    let mut gened_mtl: Vec<u8> = Vec::new();
+    obj::write_mtl(&materials, &mut gened_mtl).unwrap();
+    let mtl_str = String::from_utf8(gened_mtl).unwrap();
+    assert_eq!(
+        mtl_str.lines().filter(|l| l.starts_with("newmtl")).count(),
+        2
+    );
+    assert_eq!(mtl_str.lines().filter(|l| l.starts_with("Kd")).count(), 2);
+
+    assert!(obj::write_with_materials(&mesh, Vec::new(), "cube.mtl", &materials, &[0, 1]).is_err());
+    assert!(obj::write_with_materials(&mesh, Vec::new(), "cube.mtl", &materials, &[2; 6]).is_err());
+}
+
+/// the obj data of the `n` x `n` grid with texture coordinates and normals
+fn synthetic_grid_obj(n: usize) -> Vec<u8> {
+    use std::fmt::Write;
+    let mut obj = String::from("# synthetic grid\nmtllib grid.mtl\n");
+    for i in 0..=n {
+        for j in 0..=n {
+            let (u, v) = (i as f64 / n as f64, j as f64 / n as f64);
+            writeln!(obj, "v {u} {v} {}", u * v).unwrap();
+            writeln!(obj, "vt {u} {v}").unwrap();
+        }
+    }
+    obj += "vn 0 0 1\ng grid\nusemtl material\n";
+    for i in 0..n {
+        for j in 0..n {
+            let idx = |i: usize, j: usize| i * (n + 1) + j + 1;
+            let (a, b, c, d) = (idx(i, j), idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1));
+            match (i + j) % 2 == 0 {
+                true => writeln!(obj, "f {a}/{a}/1 {b}/{b}/1 {c}/{c}/1 {d}/{d}/1 # quad").unwrap(),
+                false => {
+                    writeln!(obj, "f {a}/{a}/1 {b}/{b}/1 {c}/{c}/1").unwrap();
+                    writeln!(obj, "f {a}/{a}/1 {c}/{c}/1 {d}/{d}/1").unwrap();
+                }
+            }
+        }
+    }
+    obj.into_bytes()
+}
+
+#[test]
+fn streaming_obj_read_test() {
+    const N: usize = 200;
+    let data = synthetic_grid_obj(N);
+    let (mut positions, mut uv_coords, mut normals) = (0, 0, 0);
+    let (mut tri_faces, mut quad_faces) = (0, 0);
+    for element in obj::elements(data.as_slice()) {
+        match element.unwrap() {
+            obj::ObjElement::Position(_) => positions += 1,
+            obj::ObjElement::UVCoord(_) => uv_coords += 1,
+            obj::ObjElement::Normal(_) => normals += 1,
+            obj::ObjElement::Face(face) => match face.len() {
+                3 => tri_faces += 1,
+                4 => quad_faces += 1,
+                _ => panic!("unexpected face: {face:?}"),
+            },
+        }
+    }
+    assert_eq!(positions, (N + 1) * (N + 1));
+    assert_eq!(quad_faces, N * N / 2);
+    assert_eq!(tri_faces, N * N);
+
+    let mesh = obj::read(data.as_slice()).unwrap();
+    assert_eq!(mesh.positions().len(), positions);
+    assert_eq!(mesh.uv_coords().len(), uv_coords);
+    assert_eq!(mesh.normals().len(), normals);
+    assert_eq!(mesh.tri_faces().len(), tri_faces);
+    assert_eq!(mesh.quad_faces().len(), quad_faces);
+    assert!(mesh.other_faces().is_empty());
+}
+
+#[test]
+fn streaming_obj_syntax_error_test() {
+    let data = b"v 0.0 0.0 0.0\nv 1.0 0.0\nv 0.0 1.0 x\nvn 0.0 0.0 1.0\n";
+    let results = obj::elements(data.as_slice()).collect::<Vec<_>>();
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(errors::Error::ObjSyntax { line: 2, .. })));
+    assert!(matches!(results[2], Err(errors::Error::ObjSyntax { line: 3, .. })));
+    assert!(matches!(results[3], Ok(obj::ObjElement::Normal(_))));
+    assert!(obj::read(data.as_slice()).is_err());
+}
+
+#[test]
+fn obj_syntax_error_line_test() {
+    use errors::{Error, ObjErrorKind};
+    let header = "# a square\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\n";
+    let cases = [
+        ("v 0 0", ObjErrorKind::MissingValue),
+        ("vt 0.5 half", ObjErrorKind::InvalidNumber("half".to_string())),
+        ("vn 0 1e 0", ObjErrorKind::InvalidNumber("1e".to_string())),
+        ("f 1 2 x", ObjErrorKind::InvalidVertex("x".to_string())),
+        ("f 1 2 3/1/1/1", ObjErrorKind::InvalidVertex("3/1/1/1".to_string())),
+        ("f /1/1 2 3", ObjErrorKind::InvalidVertex("/1/1".to_string())),
+        ("f 1 2 5", ObjErrorKind::IndexOutOfRange("5".to_string())),
+        ("f 0 1 2", ObjErrorKind::IndexOutOfRange("0".to_string())),
+        ("f 1/2 2/1 3/1", ObjErrorKind::IndexOutOfRange("1/2".to_string())),
+        ("f 1//1 2//2 3//1", ObjErrorKind::IndexOutOfRange("2//2".to_string())),
+        ("f -5 -1 -2", ObjErrorKind::IndexOutOfRange("-5".to_string())),
+    ];
+    cases.into_iter().for_each(|(line, expected)| {
+        let data = format!("{header}g square\n{line}\nf 1 2 3\n");
+        match obj::read(data.as_bytes()) {
+            Err(Error::ObjSyntax { line, kind }) => {
+                assert_eq!(line, 9, "{data}");
+                assert_eq!(kind, expected);
+            }
+            res => panic!("unexpected result: {res:?}\n{data}"),
+        }
+    });
+}
+
+#[test]
+fn obj_relative_index_test() {
+    let data = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\nf -3//-1 -2//-1 -1//-1\n";
+    let mesh = obj::read(data.as_slice()).unwrap();
+    let faces = Faces::from_iter(&[[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]);
+    assert_eq!(mesh.faces(), &faces);
+}
+
+#[test]
+fn obj_negative_index_test() {
+    // the elements are interleaved with the faces, so `-1` depends on where the face is.
+    let relative = b"v 0 0 0
+v 1 0 0
+v 1 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vn 0 0 1
+f -3/-3/-1 -2/-2/-1 -1/-1/-1
+v 0 1 0
+vt 0 1
+vn 0 0 -1
+f -4/-4/-2 -2/-2/-2 -1/-1/-2
+f -1/-1/-1 -2/-2/-1 -4/-4/-1
+";
+    let absolute = b"v 0 0 0
+v 1 0 0
+v 1 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vn 0 0 1
+f 1/1/1 2/2/1 3/3/1
+v 0 1 0
+vt 0 1
+vn 0 0 -1
+f 1/1/1 3/3/1 4/4/1
+f 4/4/2 3/3/2 1/1/2
+";
+    let mesh0 = obj::read(relative.as_slice()).unwrap();
+    let mesh1 = obj::read(absolute.as_slice()).unwrap();
+    assert_eq!(mesh0, mesh1);
+    assert_eq!(mesh0.faces()[2][0], StandardVertex::from((3, Some(3), Some(1))));
+}
+
+#[test]
+fn obj_forward_index_test() {
+    // the faces may refer the elements defined after them.
+    let data = b"f 1//1 2//1 3//1\nv 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\n";
+    let mesh = obj::read(data.as_slice()).unwrap();
+    let faces = Faces::from_iter(&[[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]);
+    assert_eq!(mesh.faces(), &faces);
+    let face = obj::elements(data.as_slice()).next().unwrap().unwrap();
+    assert!(matches!(face, obj::ObjElement::Face(_)));
+
+    // the indices are checked after the whole data is read.
+    let data = b"f 1 2 4\nv 0 0 0\nv 1 0 0\nv 0 1 0\n";
+    match obj::read(data.as_slice()) {
+        Err(errors::Error::ObjSyntax { line, kind }) => {
+            assert_eq!(line, 1);
+            assert_eq!(kind, errors::ObjErrorKind::IndexOutOfRange("4".to_string()));
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+    // the original token is reported.
+    let data = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\nf 01 2/1 004/1\nvt 0 0\n";
+    match obj::read(data.as_slice()) {
+        Err(errors::Error::ObjSyntax { line, kind }) => {
+            assert_eq!(line, 5);
+            assert_eq!(kind, errors::ObjErrorKind::IndexOutOfRange("004/1".to_string()));
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+}
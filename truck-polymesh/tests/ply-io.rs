@@ -0,0 +1,198 @@
+use truck_base::assert_near;
+use truck_polymesh::*;
+
+fn pyramid() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 0.5, 1.0),
+    ];
+    let normals = vec![
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(0.0, -1.0, 0.5).normalize(),
+        Vector3::new(1.0, 0.0, 0.5).normalize(),
+        Vector3::new(0.0, 1.0, 0.5).normalize(),
+        Vector3::new(-1.0, 0.0, 0.5).normalize(),
+    ];
+    let faces = Faces::from_iter(&[
+        &[
+            (0, None, Some(0)),
+            (3, None, Some(0)),
+            (2, None, Some(0)),
+            (1, None, Some(0)),
+        ][..],
+        &[(0, None, Some(1)), (1, None, Some(1)), (4, None, Some(1))],
+        &[(1, None, Some(2)), (2, None, Some(2)), (4, None, Some(2))],
+        &[(2, None, Some(3)), (3, None, Some(3)), (4, None, Some(3))],
+        &[(3, None, Some(4)), (0, None, Some(4)), (4, None, Some(4))],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+fn assert_same_topology(mesh0: &PolygonMesh, mesh1: &PolygonMesh) {
+    assert_eq!(
+        mesh0.faces().tri_faces().len(),
+        mesh1.faces().tri_faces().len()
+    );
+    assert_eq!(
+        mesh0.faces().quad_faces().len(),
+        mesh1.faces().quad_faces().len()
+    );
+    assert_eq!(
+        mesh0.faces().other_faces().len(),
+        mesh1.faces().other_faces().len()
+    );
+    mesh0
+        .face_iter()
+        .zip(mesh1.face_iter())
+        .for_each(|(face0, face1)| {
+            assert_eq!(face0.len(), face1.len());
+            face0.iter().zip(face1).for_each(|(v0, v1)| {
+                assert_near!(mesh0.positions()[v0.pos], mesh1.positions()[v1.pos]);
+                match (v0.nor, v1.nor) {
+                    (Some(n0), Some(n1)) => assert_near!(mesh0.normals()[n0], mesh1.normals()[n1]),
+                    (None, None) => {}
+                    _ => panic!("normals are inconsistent"),
+                }
+            });
+        });
+}
+
+#[test]
+fn ply_oi_test() {
+    let mesh = pyramid();
+    let mut bytes = Vec::<u8>::new();
+    ply::write(&mesh, &mut bytes).unwrap();
+    let read = ply::read(bytes.as_slice()).unwrap();
+    // 16 distinct pairs of positions and normals
+    assert_eq!(read.positions().len(), 16);
+    assert_eq!(read.normals().len(), 16);
+    assert!(read.uv_coords().is_empty());
+    assert_same_topology(&mesh, &read);
+
+    let mut bytes1 = Vec::<u8>::new();
+    ply::write(&read, &mut bytes1).unwrap();
+    assert_eq!(bytes, bytes1);
+}
+
+#[test]
+fn ply_oi_positions_only() {
+    let mut mesh = pyramid();
+    mesh.face_iter_mut().flatten().for_each(|v| v.nor = None);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: mesh.positions().clone(),
+            ..Default::default()
+        },
+        mesh.faces().clone(),
+    );
+    let mut bytes = Vec::<u8>::new();
+    ply::write(&mesh, &mut bytes).unwrap();
+    let read = ply::read(bytes.as_slice()).unwrap();
+    assert_eq!(read.positions().len(), mesh.positions().len());
+    assert!(read.normals().is_empty());
+    assert_same_topology(&mesh, &read);
+}
+
+#[test]
+fn binary_ply_read_test() {
+    let mut bytes = b"ply
+format binary_little_endian 1.0
+comment a triangle with colored vertices
+element vertex 3
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+property float s
+property float t
+element face 1
+property uchar intensity
+property list uchar int vertex_indices
+end_header
+"
+    .to_vec();
+    let vertices = [
+        ([0.0f32, 0.0, 0.0], [255u8, 0, 0], [0.0f32, 0.0]),
+        ([1.0, 0.0, 0.0], [0, 255, 0], [1.0, 0.0]),
+        ([0.0, 1.0, 0.0], [0, 0, 255], [0.0, 1.0]),
+    ];
+    for (pos, color, uv) in vertices {
+        pos.iter().for_each(|x| bytes.extend(x.to_le_bytes()));
+        bytes.extend(color);
+        uv.iter().for_each(|x| bytes.extend(x.to_le_bytes()));
+    }
+    bytes.extend([128u8, 3]);
+    [0i32, 1, 2]
+        .iter()
+        .for_each(|i| bytes.extend(i.to_le_bytes()));
+
+    let mesh = ply::read(bytes.as_slice()).unwrap();
+    assert_eq!(
+        mesh.positions(),
+        &vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ],
+    );
+    assert_eq!(
+        mesh.uv_coords(),
+        &vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(0.0, 1.0),
+        ],
+    );
+    assert!(mesh.normals().is_empty());
+    assert_eq!(
+        mesh.faces().tri_faces(),
+        &vec![[
+            StandardVertex {
+                pos: 0,
+                uv: Some(0),
+                nor: None
+            },
+            StandardVertex {
+                pos: 1,
+                uv: Some(1),
+                nor: None
+            },
+            StandardVertex {
+                pos: 2,
+                uv: Some(2),
+                nor: None
+            },
+        ]],
+    );
+}
+
+#[test]
+fn negative_index_test() {
+    let ply = b"ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0
+1 0 0
+0 1 0
+3 0 1 -1
+";
+    assert!(ply::read(ply.as_slice()).is_err());
+}
@@ -0,0 +1,55 @@
+use truck_polymesh::*;
+
+#[test]
+fn dense_grid_queries() {
+    const N: usize = 20;
+    let points = (0..N * N * N)
+        .map(|i| {
+            let (x, y, z) = (i % N, (i / N) % N, i / (N * N));
+            Point3::new(x as f64, y as f64, z as f64) * 0.1
+        })
+        .collect::<Vec<_>>();
+    let grid = PointGrid::new(points.clone(), 0.15);
+    for i in 0..200 {
+        let gen = [i as f64, 0.5 * i as f64, 1.5 * i as f64];
+        let pt = Point3::from_vec(hash::take_one_unit(gen) * 1.2) + Vector3::new(1.0, 1.0, 1.0);
+        let radius = 0.05 + 0.3 * hash::take_one_unit(i as f64).x.abs();
+
+        let within = (0..points.len())
+            .filter(|j| points[*j].distance2(pt) <= radius * radius)
+            .collect::<Vec<_>>();
+        assert_eq!(grid.within(pt, radius), within);
+
+        let nearest = within
+            .iter()
+            .copied()
+            .min_by(|j, k| {
+                let dj = points[*j].distance2(pt);
+                dj.partial_cmp(&points[*k].distance2(pt)).unwrap()
+            })
+            .map(|j| points[j].distance(pt));
+        let res = grid.nearest(pt, radius).map(|j| points[j].distance(pt));
+        assert_eq!(res, nearest);
+    }
+}
+
+#[test]
+fn mesh_point_grid() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 1.0e-9),
+    ];
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 1, 3]]),
+    );
+    let grid = mesh.point_grid(0.5);
+    assert_eq!(grid.within(Point3::new(0.0, 1.0, 0.0), TOLERANCE), vec![2, 3]);
+    assert_eq!(grid.nearest(Point3::new(2.0, 2.0, 2.0), 1.0), None);
+    assert_eq!(grid.nearest(Point3::new(0.9, 0.0, 0.1), 1.0), Some(1));
+}
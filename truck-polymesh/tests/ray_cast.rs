@@ -0,0 +1,92 @@
+mod common;
+use common::{cube, CUBE_FACES};
+use truck_polymesh::*;
+
+#[test]
+fn ray_cast_cube() {
+    let mesh = cube(&CUBE_FACES);
+    let grid = mesh.triangle_grid(0.3);
+    // (origin, direction, the index of the quadrangle, distance)
+    let cases = [
+        (
+            Point3::new(0.3, 0.6, 5.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            5,
+            4.0,
+        ),
+        (
+            Point3::new(0.3, 0.6, -5.0),
+            Vector3::new(0.0, 0.0, 2.0),
+            0,
+            5.0,
+        ),
+        (
+            Point3::new(-3.0, 0.2, 0.7),
+            Vector3::new(1.0, 0.0, 0.0),
+            4,
+            3.0,
+        ),
+        (
+            Point3::new(0.5, 0.5, 0.5),
+            Vector3::new(0.0, 1.0, 0.0),
+            3,
+            0.5,
+        ),
+        (
+            Point3::new(2.0, 0.5, 0.5),
+            Vector3::new(-1.0, 0.0, 0.0),
+            2,
+            1.0,
+        ),
+    ];
+    for (origin, dir, quad, distance) in cases {
+        let hit = mesh.ray_cast(origin, dir).unwrap();
+        // each quadrangle is divided into two triangles
+        assert_eq!(hit.face / 2, quad, "{origin:?} {dir:?}");
+        assert!(hit.distance.near(&distance), "{origin:?} {dir:?}");
+        let triangle = mesh.faces().triangle_iter().nth(hit.face).unwrap();
+        let point = triangle
+            .iter()
+            .zip(hit.barycentric)
+            .fold(Vector3::zero(), |sum, (v, w)| {
+                sum + mesh.positions()[v.pos].to_vec() * w
+            });
+        assert!(Point3::from_vec(point).near(&(origin + dir.normalize() * distance)));
+        assert_eq!(grid.ray_cast(origin, dir), Some(hit));
+    }
+}
+
+#[test]
+fn ray_cast_miss() {
+    let mesh = cube(&CUBE_FACES);
+    let grid = mesh.triangle_grid(0.3);
+    let cases = [
+        (Point3::new(2.0, 2.0, 2.0), Vector3::new(1.0, 1.0, 1.0)),
+        (Point3::new(2.0, 0.5, 0.5), Vector3::new(0.0, 0.0, 1.0)),
+        (Point3::new(2.0, 0.5, 0.5), Vector3::new(1.0, 0.0, 0.0)),
+        (Point3::new(-1.0, 3.0, 0.5), Vector3::new(1.0, 1.0, 0.0)),
+    ];
+    for (origin, dir) in cases {
+        assert_eq!(mesh.ray_cast(origin, dir), None);
+        assert_eq!(grid.ray_cast(origin, dir), None);
+    }
+}
+
+#[test]
+fn grid_agrees_with_brute_force() {
+    let mesh = cube(&CUBE_FACES);
+    let grid = mesh.triangle_grid(0.15);
+    for i in 0..200 {
+        let gen = [i as f64, 0.5 * i as f64, 1.5 * i as f64];
+        let origin = Point3::from_vec(hash::take_one_unit(gen) * 3.0);
+        let target =
+            Point3::from_vec(hash::take_one_unit(i as f64) * 0.8) + Vector3::new(0.5, 0.5, 0.5);
+        let dir = target - origin;
+        let (hit0, hit1) = (mesh.ray_cast(origin, dir), grid.ray_cast(origin, dir));
+        match (hit0, hit1) {
+            (Some(hit0), Some(hit1)) => assert!(hit0.distance.near(&hit1.distance)),
+            (None, None) => {}
+            _ => panic!("{hit0:?} {hit1:?}"),
+        }
+    }
+}
@@ -85,3 +85,51 @@ fn through_polymesh() {
         assert!(f32::abs(face0.normal[2] - face1.normal[2]) < 5.0e-4);
     }
 }
+
+#[test]
+fn polymesh_stl_round_trip() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let faces = Faces::from_iter(&[
+        [3, 2, 1, 0],
+        [0, 1, 5, 4],
+        [1, 2, 6, 5],
+        [2, 3, 7, 6],
+        [3, 0, 4, 7],
+        [4, 5, 6, 7],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: positions.clone(),
+            ..Default::default()
+        },
+        faces,
+    );
+    for stl_type in [STLType::ASCII, STLType::Binary] {
+        let mut bytes = Vec::<u8>::new();
+        stl::write(&mesh, &mut bytes, stl_type).unwrap();
+        let read = stl::read(bytes.as_slice(), STLType::Automatic).unwrap();
+        // shared corners are welded and each quad is split into two triangles
+        assert_eq!(read.positions().len(), positions.len());
+        assert_eq!(read.normals().len(), 6);
+        assert_eq!(read.faces().len(), 12);
+        assert_eq!(read.faces().tri_faces().len(), 12);
+        positions.iter().for_each(|p| {
+            assert!(read.positions().iter().any(|q| p.near(q)));
+        });
+        read.faces().triangle_iter().for_each(|tri| {
+            let p: Vec<_> = tri.iter().map(|v| read.positions()[v.pos]).collect();
+            let n = read.normals()[tri[0].nor.unwrap()];
+            assert!(tri.iter().all(|v| v.nor == tri[0].nor));
+            assert_near!((p[1] - p[0]).cross(p[2] - p[0]).normalize(), n);
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use truck_polymesh::*;
+
+fn grid(positions: Vec<Vec<Point3>>) -> StructuredMesh {
+    let udiv = vec![0.0, 0.5, 1.0];
+    let vdiv = vec![0.0, 0.5, 1.0];
+    let normals = vec![vec![Vector3::unit_z(); 3]; 3];
+    StructuredMesh::new(positions, (udiv, vdiv), normals)
+}
+
+fn flat_positions() -> Vec<Vec<Point3>> {
+    (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| Point3::new(i as f64, j as f64, 0.0))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn into_polygon_quads() {
+    let mesh = grid(flat_positions()).into_polygon(true);
+    assert_eq!(mesh.positions().len(), 9);
+    assert_eq!(mesh.uv_coords().len(), 9);
+    assert_eq!(mesh.normals().len(), 9);
+    assert_eq!(mesh.faces().len(), 4);
+    assert_eq!(mesh.faces().quad_faces().len(), 4);
+    mesh.face_iter().flatten().for_each(|v| {
+        assert_eq!(v.uv, Some(v.pos));
+        assert_eq!(v.nor, Some(v.pos));
+    });
+    assert_eq!(mesh, grid(flat_positions()).destruct());
+}
+
+#[test]
+fn into_polygon_triangles() {
+    let mesh = grid(flat_positions()).into_polygon(false);
+    assert_eq!(mesh.faces().len(), 8);
+    assert_eq!(mesh.faces().tri_faces().len(), 8);
+    mesh.face_iter().for_each(|face| {
+        let p: Vec<Point3> = face.iter().map(|v| mesh.positions()[v.pos]).collect();
+        let area = (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0;
+        assert!(area.near(&0.5));
+        // the orientation is the same as the quadrangles
+        assert!((p[1] - p[0]).cross(p[2] - p[0]).z > 0.0);
+    });
+    let tri_uv = mesh.face_iter().flatten().map(|v| v.uv.unwrap()).max();
+    assert_eq!(tri_uv, Some(8));
+}
+
+#[test]
+fn into_polygon_degenerate() {
+    // the first row is collapsed to a point
+    let mut positions = flat_positions();
+    positions[0] = vec![Point3::new(0.0, 1.0, 0.0); 3];
+    let mesh = grid(positions.clone());
+    assert_eq!(mesh.clone().into_polygon(true).faces().len(), 4);
+    assert_eq!(mesh.into_polygon(false).faces().len(), 6);
+
+    // the last two rows coincide
+    positions[2] = positions[1].clone();
+    let mesh = grid(positions);
+    assert_eq!(mesh.clone().into_polygon(true).faces().len(), 2);
+    assert_eq!(mesh.into_polygon(false).faces().len(), 2);
+}
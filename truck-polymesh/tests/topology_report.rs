@@ -0,0 +1,30 @@
+mod common;
+use common::{cube, CUBE_FACES};
+use truck_polymesh::*;
+
+#[test]
+fn closed_cube() {
+    let report = cube(&CUBE_FACES).topology_report();
+    assert_eq!(report, MeshReport::default());
+    assert!(report.is_watertight());
+    assert!(report.is_manifold());
+}
+
+#[test]
+fn open_cube() {
+    let report = cube(&CUBE_FACES[1..]).topology_report();
+    assert_eq!(report.boundary_edges, 4);
+    assert_eq!(report.non_manifold_edges, 0);
+    assert_eq!(report.isolated_vertices, 0);
+    assert!(!report.is_watertight());
+    assert!(report.is_manifold());
+}
+
+#[test]
+fn cube_with_isolated_vertex() {
+    let mut mesh = cube(&CUBE_FACES);
+    mesh.push_position(Point3::new(0.5, 0.5, 0.5));
+    let report = mesh.topology_report();
+    assert_eq!(report.isolated_vertices, 1);
+    assert!(report.is_watertight());
+}
@@ -0,0 +1,108 @@
+mod common;
+use common::CUBE_FACES;
+use std::f64::consts::PI;
+use truck_polymesh::*;
+
+/// the box `[0, 1] x [0, 2] x [0, 3]` with the face normals
+fn cuboid() -> PolygonMesh {
+    let positions = common::cube_positions()
+        .into_iter()
+        .map(|p| Point3::new(p.x, p.y * 2.0, p.z * 3.0))
+        .collect();
+    // the normals of `CUBE_FACES`
+    let normals = vec![
+        -Vector3::unit_z(),
+        -Vector3::unit_y(),
+        Vector3::unit_x(),
+        Vector3::unit_y(),
+        -Vector3::unit_x(),
+        Vector3::unit_z(),
+    ];
+    let faces = Faces::from_iter(
+        CUBE_FACES
+            .iter()
+            .enumerate()
+            .map(|(nor, face)| face.map(|pos| (pos, None, Some(nor)))),
+    );
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+/// asserts that the normals are unit and perpendicular to the faces, and directed to the front.
+fn assert_normals_compatible(mesh: &PolygonMesh) {
+    mesh.face_iter().for_each(|face| {
+        let p: Vec<Point3> = face.iter().map(|v| mesh.positions()[v.pos]).collect();
+        let face_normal = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
+        face.iter().for_each(|v| {
+            let normal = mesh.normals()[v.nor.unwrap()];
+            assert!(normal.magnitude().near(&1.0), "{normal:?}");
+            assert!(normal.near(&face_normal), "{normal:?} {face_normal:?}");
+        });
+    });
+}
+
+#[test]
+fn rotation_keeps_bounding_box_diagonal() {
+    let mut mesh = cuboid();
+    assert_normals_compatible(&mesh);
+    let diagonal = mesh.bounding_box().diagonal().magnitude();
+    assert!(diagonal.near(&f64::sqrt(14.0)));
+    // the rotations mapping the axes to the axes keep the axis-aligned bounding box congruent.
+    let rotations = [
+        Matrix4::from_angle_x(Rad(PI / 2.0)),
+        Matrix4::from_angle_y(Rad(PI)),
+        Matrix4::from_angle_z(Rad(-PI / 2.0)),
+        Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0)) * Matrix4::from_angle_x(Rad(PI)),
+    ];
+    rotations.into_iter().for_each(|rotation| {
+        let center = mesh.bounding_box().center();
+        mesh.transform(rotation);
+        assert!(mesh.bounding_box().diagonal().magnitude().near(&diagonal));
+        assert!(mesh.bounding_box().center().near(&rotation.transform_point(center)));
+        assert_normals_compatible(&mesh);
+    });
+}
+
+#[test]
+fn nonuniform_scale_keeps_normals() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(0.0, 0.0, 1.0),
+            ],
+            normals: vec![Vector3::new(1.0, 1.0, 1.0).normalize()],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+    );
+    mesh.transform(Matrix4::from_nonuniform_scale(1.0, 2.0, 5.0));
+    assert_normals_compatible(&mesh);
+
+    let mut mesh = cuboid();
+    let shear = Matrix4::from_cols(
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.5, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.3, 2.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    mesh.transform(Matrix4::from_nonuniform_scale(3.0, 0.5, 0.1) * shear);
+    assert_normals_compatible(&mesh);
+}
+
+#[test]
+fn mirror_inverts_faces() {
+    let mut mesh = cuboid();
+    mesh.transform(Matrix4::from_nonuniform_scale(-1.0, 2.0, 1.0));
+    assert_normals_compatible(&mesh);
+    let bdd_box = mesh.bounding_box();
+    assert_eq!(*bdd_box.min(), Point3::new(-1.0, 0.0, 0.0));
+    assert_eq!(*bdd_box.max(), Point3::new(0.0, 4.0, 3.0));
+}
@@ -0,0 +1,134 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const N: usize = 10;
+
+fn init_device() -> DeviceHandler {
+    pollster::block_on(async {
+        let instance = wgpu::Instance::new(Backends::PRIMARY);
+        let adapter = instance
+            .request_adapter(&RequestAdapterOptions {
+                power_preference: PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await
+            .unwrap();
+        let (device, queue) = adapter
+            .request_device(&Default::default(), None)
+            .await
+            .unwrap();
+        DeviceHandler::new(Arc::new(adapter), Arc::new(device), Arc::new(queue))
+    })
+}
+
+fn test_scene() -> Scene {
+    let matrix = Matrix4::look_at_rh(
+        Point3::new(12.0, 9.0, 15.0),
+        Point3::origin(),
+        Vector3::unit_y(),
+    );
+    Scene::new(
+        init_device(),
+        &SceneDescriptor {
+            studio: StudioConfig {
+                camera: Camera::perspective_camera(
+                    matrix.invert().unwrap(),
+                    Rad(std::f64::consts::PI / 4.0),
+                    0.1,
+                    100.0,
+                ),
+                lights: vec![Light {
+                    position: Point3::new(12.0, 15.0, 9.0),
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: (512, 512),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+}
+
+// the unit cube `[0, 1]^3`
+fn cube() -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect::<Vec<_>>();
+    let normals = vec![
+        -Vector3::unit_x(),
+        Vector3::unit_x(),
+        -Vector3::unit_y(),
+        Vector3::unit_y(),
+        -Vector3::unit_z(),
+        Vector3::unit_z(),
+    ];
+    let faces = Faces::from_iter(
+        [
+            ([0, 4, 6, 2], 0),
+            ([1, 3, 7, 5], 1),
+            ([0, 1, 5, 4], 2),
+            ([2, 6, 7, 3], 3),
+            ([0, 2, 3, 1], 4),
+            ([4, 5, 7, 6], 5),
+        ]
+        .iter()
+        .map(|(face, nor)| face.map(|pos| (pos, None, Some(*nor)))),
+    );
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+// the matrices placing the cubes on the `N` x `N` x `N` grid
+fn matrices() -> Vec<Matrix4> {
+    (0..N * N * N)
+        .map(|i| {
+            let (x, y, z) = (i % N, (i / N) % N, i / (N * N));
+            let position = Vector3::new(x as f64, y as f64, z as f64) - Vector3::new(5.0, 5.0, 5.0);
+            Matrix4::from_translation(position) * Matrix4::from_scale(0.6)
+        })
+        .collect()
+}
+
+fn instancing(c: &mut Criterion) {
+    let mut scene = test_scene();
+    let instance: PolygonInstance = scene
+        .instance_creator()
+        .create_instance(&cube(), &Default::default());
+    let separate: Vec<PolygonInstance> = matrices()
+        .into_iter()
+        .map(|matrix| {
+            let mut instance = instance.clone_instance();
+            instance.instance_state_mut().matrix = matrix;
+            instance
+        })
+        .collect();
+    let instanced = instance.instanced(matrices());
+
+    scene.add_objects(&separate);
+    c.bench_function("1000 separate instances", |b| {
+        b.iter(|| pollster::block_on(scene.render_to_buffer()))
+    });
+    scene.clear_objects();
+    scene.add_object(&instanced);
+    c.bench_function("1000 instanced cubes", |b| {
+        b.iter(|| pollster::block_on(scene.render_to_buffer()))
+    });
+}
+
+criterion_group!(benches, instancing);
+criterion_main!(benches);
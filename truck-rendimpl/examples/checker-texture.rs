@@ -0,0 +1,136 @@
+//! A cube textured with a checker pattern
+//!
+//! - Drag the mouse to orbit the camera around the cube.
+//! - Enter "Space" on the keyboard to change the number of the checker squares.
+//!
+//! The checker pattern is generated on the CPU and modulated by the albedo of the material.
+
+use std::sync::Arc;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use winit::{dpi::*, event::*, event_loop::ControlFlow};
+mod app;
+use app::*;
+
+struct MyApp {
+    scene: WindowScene,
+    instance: PolygonInstance,
+    divisions: u32,
+    rotate_flag: bool,
+    prev_cursor: Vector2,
+}
+
+impl MyApp {
+    fn create_camera() -> Camera {
+        let matrix = Matrix4::look_at_rh(
+            Point3::new(1.5, 1.5, 1.5),
+            Point3::origin(),
+            Vector3::unit_y(),
+        );
+        Camera::perspective_camera(
+            matrix.invert().unwrap(),
+            Rad(std::f64::consts::PI / 4.0),
+            0.1,
+            40.0,
+        )
+    }
+
+    fn create_cube() -> Solid {
+        let v = builder::vertex(Point3::new(-0.5, -0.5, -0.5));
+        let edge = builder::tsweep(&v, Vector3::unit_x());
+        let face = builder::tsweep(&edge, Vector3::unit_y());
+        builder::tsweep(&face, Vector3::unit_z())
+    }
+
+    /// the checker pattern with `divisions` x `divisions` squares
+    fn checker(divisions: u32) -> image::RgbaImage {
+        image::RgbaImage::from_fn(divisions, divisions, |x, y| match (x + y) % 2 == 0 {
+            true => image::Rgba([255, 255, 255, 255]),
+            false => image::Rgba([64, 64, 64, 255]),
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl App for MyApp {
+    async fn init(window: Arc<winit::window::Window>) -> MyApp {
+        let desc = WindowSceneDescriptor {
+            studio: StudioConfig {
+                camera: MyApp::create_camera(),
+                lights: vec![Light {
+                    position: Point3::new(1.5, 1.5, 1.5),
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+                ..Default::default()
+            },
+            backend_buffer: BackendBufferConfig {
+                sample_count: 4,
+                ..Default::default()
+            },
+        };
+        let mut scene = WindowScene::from_window(window, &desc).await;
+        let state = PolygonState {
+            material: Material {
+                albedo: Vector4::new(1.0, 0.6, 0.2, 1.0),
+                roughness: 0.6,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mesh = Self::create_cube().triangulation(0.05).to_polygon();
+        let mut instance: PolygonInstance = scene.instance_creator().create_instance(&mesh, &state);
+        let divisions = 8;
+        instance.set_texture(scene.device_handler(), &MyApp::checker(divisions));
+        scene.add_object(&instance);
+        MyApp {
+            scene,
+            instance,
+            divisions,
+            rotate_flag: false,
+            prev_cursor: Vector2::zero(),
+        }
+    }
+
+    fn app_title<'a>() -> Option<&'a str> { Some("checker texture") }
+
+    fn mouse_input(&mut self, state: ElementState, button: MouseButton) -> ControlFlow {
+        if button == MouseButton::Left {
+            self.rotate_flag = state == ElementState::Pressed;
+        }
+        Self::default_control_flow()
+    }
+
+    fn cursor_moved(&mut self, position: PhysicalPosition<f64>) -> ControlFlow {
+        let position = Vector2::new(position.x, position.y);
+        if self.rotate_flag {
+            let dir2d = position - self.prev_cursor;
+            let camera = &mut self.scene.studio_config_mut().camera;
+            camera.orbit(Point3::origin(), -dir2d.x * 0.01, dir2d.y * 0.01);
+        }
+        self.prev_cursor = position;
+        Self::default_control_flow()
+    }
+
+    fn keyboard_input(&mut self, input: KeyboardInput, _: bool) -> ControlFlow {
+        if input.state == ElementState::Pressed
+            && input.virtual_keycode == Some(VirtualKeyCode::Space)
+        {
+            self.divisions = match self.divisions {
+                32 => 2,
+                _ => self.divisions * 2,
+            };
+            let checker = MyApp::checker(self.divisions);
+            self.instance.set_texture(self.scene.device_handler(), &checker);
+            // the instance already has a texture, so the bind group is enough to be updated.
+            self.scene.update_bind_group(&self.instance);
+        }
+        Self::default_control_flow()
+    }
+
+    fn render(&mut self) { self.scene.render_frame(); }
+}
+
+fn main() { MyApp::run(); }
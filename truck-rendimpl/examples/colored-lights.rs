@@ -0,0 +1,157 @@
+//! Lighting a model by three colored point lights
+//!
+//! - The red, green, and blue lights go around the model.
+//! - Drag the mouse to rotate the model.
+//! - Drag and drop obj files into the window to switch models.
+
+use std::f64::consts::PI;
+use std::io::Read;
+use std::sync::Arc;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+use winit::{dpi::*, event::*, event_loop::ControlFlow};
+mod app;
+use app::*;
+
+const TEAPOT_BYTES: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../resources/obj/teapot.obj",
+));
+
+const LIGHT_COLORS: [Vector3; 3] = [
+    Vector3::new(1.0, 0.0, 0.0),
+    Vector3::new(0.0, 1.0, 0.0),
+    Vector3::new(0.0, 0.0, 1.0),
+];
+
+struct MyApp {
+    scene: WindowScene,
+    creator: InstanceCreator,
+    rotate_flag: bool,
+    prev_cursor: Vector2,
+    instance: PolygonInstance,
+    start_time: std::time::Instant,
+}
+
+impl MyApp {
+    fn create_camera() -> Camera {
+        let matrix = Matrix4::look_at_rh(
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::origin(),
+            Vector3::unit_y(),
+        );
+        Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 40.0)
+    }
+
+    /// the lights on the circle of radius 2.0 at the angle `angle`
+    fn create_lights(angle: f64) -> Vec<Light> {
+        LIGHT_COLORS
+            .iter()
+            .enumerate()
+            .map(|(i, color)| {
+                let theta = angle + 2.0 * PI * i as f64 / 3.0;
+                Light {
+                    position: Point3::new(2.0 * f64::cos(theta), 1.0, 2.0 * f64::sin(theta)),
+                    color: *color,
+                    light_type: LightType::Point,
+                }
+            })
+            .collect()
+    }
+
+    fn load_obj<R: Read>(creator: &InstanceCreator, reader: R) -> PolygonInstance {
+        let mut mesh = obj::read(reader).unwrap();
+        mesh.put_together_same_attrs()
+            .add_smooth_normals(0.5, false);
+        let bdd_box = mesh.bounding_box();
+        let (size, center) = (bdd_box.size(), bdd_box.center());
+        let mat = Matrix4::from_translation(center.to_vec()) * Matrix4::from_scale(size);
+        let polygon_state = PolygonState {
+            matrix: mat.invert().unwrap(),
+            material: Material {
+                albedo: Vector4::new(1.0, 1.0, 1.0, 1.0),
+                roughness: 0.3,
+                reflectance: 0.5,
+                ambient_ratio: 0.02,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        creator.create_instance(&mesh, &polygon_state)
+    }
+}
+
+#[async_trait(?Send)]
+impl App for MyApp {
+    async fn init(window: Arc<winit::window::Window>) -> MyApp {
+        let scene_desc = WindowSceneDescriptor {
+            studio: StudioConfig {
+                background: Color::BLACK,
+                camera: MyApp::create_camera(),
+                lights: MyApp::create_lights(0.0),
+            },
+            backend_buffer: BackendBufferConfig {
+                sample_count: 4,
+                ..Default::default()
+            },
+        };
+        let mut scene = WindowScene::from_window(window, &scene_desc).await;
+        let creator = scene.instance_creator();
+        let instance = MyApp::load_obj(&creator, TEAPOT_BYTES);
+        scene.add_object(&instance);
+        MyApp {
+            scene,
+            creator,
+            rotate_flag: false,
+            prev_cursor: Vector2::zero(),
+            instance,
+            start_time: std::time::Instant::now(),
+        }
+    }
+
+    fn app_title<'a>() -> Option<&'a str> { Some("colored lights") }
+
+    fn dropped_file(&mut self, path: std::path::PathBuf) -> ControlFlow {
+        let file = std::fs::File::open(path).unwrap();
+        self.scene.remove_object(&self.instance);
+        self.instance = MyApp::load_obj(&self.creator, file);
+        self.scene.add_object(&self.instance);
+        Self::default_control_flow()
+    }
+
+    fn mouse_input(&mut self, state: ElementState, button: MouseButton) -> ControlFlow {
+        if button == MouseButton::Left {
+            self.rotate_flag = state == ElementState::Pressed;
+        }
+        Self::default_control_flow()
+    }
+
+    fn cursor_moved(&mut self, position: PhysicalPosition<f64>) -> ControlFlow {
+        let position = Vector2::new(position.x, position.y);
+        if self.rotate_flag {
+            let matrix = &mut self.scene.studio_config_mut().camera.matrix;
+            let dir2d = position - self.prev_cursor;
+            if dir2d.so_small() {
+                return Self::default_control_flow();
+            }
+            let mut axis = dir2d[1] * matrix[0].truncate();
+            axis += dir2d[0] * matrix[1].truncate();
+            axis /= axis.magnitude();
+            let angle = dir2d.magnitude() * 0.01;
+            let mat = Matrix4::from_axis_angle(axis, Rad(angle));
+            *matrix = mat.invert().unwrap() * *matrix;
+        }
+        self.prev_cursor = position;
+        Self::default_control_flow()
+    }
+
+    fn render(&mut self) {
+        let angle = self.start_time.elapsed().as_secs_f64();
+        self.scene.studio_config_mut().lights = MyApp::create_lights(angle);
+        self.scene.render_frame();
+    }
+}
+
+fn main() { MyApp::run(); }
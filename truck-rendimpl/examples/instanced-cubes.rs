@@ -0,0 +1,169 @@
+//! One thousand cubes drawn by one draw call
+//!
+//! - Drag the mouse to orbit the camera around the cubes.
+//! - Scroll the mouse wheel to move the camera forward or backward.
+//! - Enter "Space" on the keyboard to switch the instanced object and the separate instances.
+//!
+//! The instanced object shares one vertex buffer and one index buffer among all the cubes,
+//! and uploads only the matrices of the cubes as an instance buffer.
+
+use std::sync::Arc;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use winit::{dpi::*, event::*, event_loop::ControlFlow};
+mod app;
+use app::*;
+
+const N: usize = 10;
+
+struct MyApp {
+    scene: WindowScene,
+    instanced: InstancedObject,
+    separate: Vec<PolygonInstance>,
+    instanced_mode: bool,
+    rotate_flag: bool,
+    prev_cursor: Vector2,
+}
+
+impl MyApp {
+    fn create_camera() -> Camera {
+        let matrix = Matrix4::look_at_rh(
+            Point3::new(12.0, 9.0, 15.0),
+            Point3::origin(),
+            Vector3::unit_y(),
+        );
+        Camera::perspective_camera(
+            matrix.invert().unwrap(),
+            Rad(std::f64::consts::PI / 4.0),
+            0.1,
+            100.0,
+        )
+    }
+
+    fn create_cube() -> Solid {
+        let v = builder::vertex(Point3::new(-0.3, -0.3, -0.3));
+        let edge = builder::tsweep(&v, Vector3::unit_x() * 0.6);
+        let face = builder::tsweep(&edge, Vector3::unit_y() * 0.6);
+        builder::tsweep(&face, Vector3::unit_z() * 0.6)
+    }
+
+    /// the matrices placing the cubes on the `N` x `N` x `N` grid
+    fn matrices() -> Vec<Matrix4> {
+        let half = (N - 1) as f64 / 2.0;
+        (0..N * N * N)
+            .map(|i| {
+                let (x, y, z) = (i % N, (i / N) % N, i / (N * N));
+                let position = Vector3::new(x as f64 - half, y as f64 - half, z as f64 - half);
+                Matrix4::from_translation(position)
+                    * Matrix4::from_angle_y(Rad(i as f64 * 0.1))
+                    * Matrix4::from_angle_x(Rad(i as f64 * 0.07))
+            })
+            .collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl App for MyApp {
+    async fn init(window: Arc<winit::window::Window>) -> MyApp {
+        let desc = WindowSceneDescriptor {
+            studio: StudioConfig {
+                camera: MyApp::create_camera(),
+                lights: vec![Light {
+                    position: Point3::new(12.0, 15.0, 9.0),
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+                ..Default::default()
+            },
+            backend_buffer: BackendBufferConfig {
+                sample_count: 4,
+                ..Default::default()
+            },
+        };
+        let mut scene = WindowScene::from_window(window, &desc).await;
+        let state = PolygonState {
+            material: Material {
+                albedo: Vector4::new(0.75, 0.75, 0.8, 1.0),
+                roughness: 0.3,
+                reflectance: 0.6,
+                ambient_ratio: 0.1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mesh = Self::create_cube().triangulation(0.01).to_polygon();
+        let instance: PolygonInstance = scene.instance_creator().create_instance(&mesh, &state);
+        let matrices = MyApp::matrices();
+        let separate = matrices
+            .iter()
+            .map(|matrix| {
+                let mut instance = instance.clone_instance();
+                instance.instance_state_mut().matrix = *matrix;
+                instance
+            })
+            .collect();
+        let instanced = instance.instanced(matrices);
+        scene.add_object(&instanced);
+        MyApp {
+            scene,
+            instanced,
+            separate,
+            instanced_mode: true,
+            rotate_flag: false,
+            prev_cursor: Vector2::zero(),
+        }
+    }
+
+    fn app_title<'a>() -> Option<&'a str> { Some("instanced cubes") }
+
+    fn mouse_input(&mut self, state: ElementState, button: MouseButton) -> ControlFlow {
+        if button == MouseButton::Left {
+            self.rotate_flag = state == ElementState::Pressed;
+        }
+        Self::default_control_flow()
+    }
+
+    fn mouse_wheel(&mut self, delta: MouseScrollDelta, _: TouchPhase) -> ControlFlow {
+        if let MouseScrollDelta::LineDelta(_, y) = delta {
+            self.scene.studio_config_mut().camera.dolly(y as f64 * 0.5);
+        }
+        Self::default_control_flow()
+    }
+
+    fn cursor_moved(&mut self, position: PhysicalPosition<f64>) -> ControlFlow {
+        let position = Vector2::new(position.x, position.y);
+        if self.rotate_flag {
+            let dir2d = position - self.prev_cursor;
+            let camera = &mut self.scene.studio_config_mut().camera;
+            camera.orbit(Point3::origin(), -dir2d.x * 0.01, dir2d.y * 0.01);
+        }
+        self.prev_cursor = position;
+        Self::default_control_flow()
+    }
+
+    fn keyboard_input(&mut self, input: KeyboardInput, _: bool) -> ControlFlow {
+        if input.state == ElementState::Pressed
+            && input.virtual_keycode == Some(VirtualKeyCode::Space)
+        {
+            self.instanced_mode = !self.instanced_mode;
+            self.scene.clear_objects();
+            match self.instanced_mode {
+                true => {
+                    self.scene.add_object(&self.instanced);
+                    println!("instanced: 1 object");
+                }
+                false => {
+                    self.scene.add_objects(&self.separate);
+                    println!("separate: {} objects", self.separate.len());
+                }
+            }
+        }
+        Self::default_control_flow()
+    }
+
+    fn render(&mut self) { self.scene.render_frame(); }
+}
+
+fn main() { MyApp::run(); }
@@ -0,0 +1,61 @@
+//! Renders a shape to a png image without any window.
+//!
+//! - Run with the path of a json file as an argument, e.g. `cone.json`.
+//! - The image is saved with the same name as the json file and the extension `png`.
+//!
+//! A model json file can be generated by `serde_json`. See the examples of `truck-modeling`!
+
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+
+const PICTURE_SIZE: (u32, u32) = (800, 600);
+
+fn main() {
+    let path = std::env::args().nth(1).unwrap_or_else(|| "cone.json".to_string());
+    let file = std::fs::File::open(&path).unwrap();
+    let solid: Solid = serde_json::from_reader(file).unwrap();
+    let polygon = solid.triangulation(0.005).to_polygon();
+    let bdd_box = polygon.bounding_box();
+    let (size, center) = (bdd_box.size(), bdd_box.center());
+    let mat = Matrix4::from_translation(center.to_vec()) * Matrix4::from_scale(size);
+
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(1.5, 1.5, 1.5),
+            Point3::origin(),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        40.0,
+    );
+    let scene_desc = SceneDescriptor {
+        studio: StudioConfig {
+            lights: vec![Light {
+                position: camera.position(),
+                color: Vector3::new(1.0, 1.0, 1.0),
+                light_type: LightType::Point,
+            }],
+            camera,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut scene = pollster::block_on(Scene::from_default_device(&scene_desc));
+    let instance: PolygonInstance = scene.instance_creator().create_instance(
+        &polygon,
+        &PolygonState {
+            matrix: mat.invert().unwrap(),
+            ..Default::default()
+        },
+    );
+    scene.add_object(&instance);
+    let (width, height) = PICTURE_SIZE;
+    let image = pollster::block_on(scene.render_to_image(width, height));
+    let output = std::path::Path::new(&path).with_extension("png");
+    image.save(&output).unwrap();
+}
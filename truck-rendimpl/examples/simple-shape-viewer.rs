@@ -129,6 +129,7 @@ impl MyApp {
                         let bdb = curve.roughly_bounding_box();
                         vec![*bdb.max(), *bdb.min()].into_iter().collect()
                     }
+                    Curve::CircleArc(arc) => arc.to_nurbs().roughly_bounding_box(),
                     Curve::NURBSCurve(curve) => curve.roughly_bounding_box(),
                     Curve::IntersectionCurve(_) => BoundingBox::new(),
                 };
@@ -73,7 +73,7 @@ impl App for MyApp {
         let state = PolygonState {
             matrix: Matrix4::from_translation(Vector3::new(-0.5, -0.5, -0.5)),
             material: Material {
-                albedo: Vector4::new(0.402, 0.262, 0.176, 1.0),
+                albedo: Vector4::new(1.0, 1.0, 1.0, 1.0),
                 roughness: 0.9,
                 reflectance: 0.04,
                 ambient_ratio: 0.05,
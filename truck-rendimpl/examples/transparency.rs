@@ -0,0 +1,162 @@
+//! An opaque cube and a transparent cube
+//!
+//! - Drag the mouse to orbit the camera around the cubes.
+//! - Scroll the mouse wheel to move the camera forward or backward.
+//!
+//! The transparent cube is drawn after the opaque cube without writing the depth,
+//! so the opaque cube can be seen through it from any direction.
+
+use std::sync::Arc;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+use winit::{dpi::*, event::*, event_loop::ControlFlow};
+mod app;
+use app::*;
+
+struct MyApp {
+    scene: WindowScene,
+    rotate_flag: bool,
+    prev_cursor: Vector2,
+}
+
+impl MyApp {
+    fn create_camera() -> Camera {
+        let matrix = Matrix4::look_at_rh(
+            Point3::new(3.0, 2.0, 4.0),
+            Point3::origin(),
+            Vector3::unit_y(),
+        );
+        Camera::perspective_camera(
+            matrix.invert().unwrap(),
+            Rad(std::f64::consts::PI / 4.0),
+            0.1,
+            40.0,
+        )
+    }
+
+    /// the unit cube `[0, 1]^3`
+    fn cube() -> PolygonMesh {
+        let positions = (0..8)
+            .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+            .collect::<Vec<_>>();
+        let normals = vec![
+            -Vector3::unit_x(),
+            Vector3::unit_x(),
+            -Vector3::unit_y(),
+            Vector3::unit_y(),
+            -Vector3::unit_z(),
+            Vector3::unit_z(),
+        ];
+        let faces = Faces::from_iter(
+            [
+                ([0, 4, 6, 2], 0),
+                ([1, 3, 7, 5], 1),
+                ([0, 1, 5, 4], 2),
+                ([2, 6, 7, 3], 3),
+                ([0, 2, 3, 1], 4),
+                ([4, 5, 7, 6], 5),
+            ]
+            .iter()
+            .map(|(face, nor)| face.map(|pos| (pos, None, Some(*nor)))),
+        );
+        PolygonMesh::new(
+            StandardAttributes {
+                positions,
+                normals,
+                ..Default::default()
+            },
+            faces,
+        )
+    }
+}
+
+#[async_trait(?Send)]
+impl App for MyApp {
+    async fn init(window: Arc<winit::window::Window>) -> MyApp {
+        let scene_desc = WindowSceneDescriptor {
+            studio: StudioConfig {
+                background: Color::WHITE,
+                camera: MyApp::create_camera(),
+                lights: vec![Light {
+                    position: Point3::new(3.0, 4.0, 2.0),
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+            },
+            backend_buffer: BackendBufferConfig {
+                sample_count: 4,
+                ..Default::default()
+            },
+        };
+        let mut scene = WindowScene::from_window(window, &scene_desc).await;
+        let creator = scene.instance_creator();
+        let cube = MyApp::cube();
+        // the transparent cube is added first, but it is drawn after the opaque cube.
+        let glass: PolygonInstance = creator.create_instance(
+            &cube,
+            &PolygonState {
+                matrix: Matrix4::from_translation(Vector3::new(-0.2, -0.6, -0.2))
+                    * Matrix4::from_scale(1.2),
+                material: Material {
+                    albedo: Vector4::new(0.4, 0.6, 1.0, 0.5),
+                    reflectance: 0.5,
+                    roughness: 0.1,
+                    alpha_blend: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let opaque: PolygonInstance = creator.create_instance(
+            &cube,
+            &PolygonState {
+                matrix: Matrix4::from_translation(Vector3::new(-0.5, -0.5, -0.5)),
+                material: Material {
+                    albedo: Vector4::new(1.0, 0.3, 0.2, 1.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        scene.add_object(&glass);
+        scene.add_object(&opaque);
+        MyApp {
+            scene,
+            rotate_flag: false,
+            prev_cursor: Vector2::zero(),
+        }
+    }
+
+    fn app_title<'a>() -> Option<&'a str> { Some("transparency") }
+
+    fn mouse_input(&mut self, state: ElementState, button: MouseButton) -> ControlFlow {
+        if button == MouseButton::Left {
+            self.rotate_flag = state == ElementState::Pressed;
+        }
+        Self::default_control_flow()
+    }
+
+    fn mouse_wheel(&mut self, delta: MouseScrollDelta, _: TouchPhase) -> ControlFlow {
+        if let MouseScrollDelta::LineDelta(_, y) = delta {
+            self.scene.studio_config_mut().camera.dolly(y as f64 * 0.2);
+        }
+        Self::default_control_flow()
+    }
+
+    fn cursor_moved(&mut self, position: PhysicalPosition<f64>) -> ControlFlow {
+        let position = Vector2::new(position.x, position.y);
+        if self.rotate_flag {
+            let dir2d = position - self.prev_cursor;
+            let camera = &mut self.scene.studio_config_mut().camera;
+            camera.orbit(Point3::origin(), -dir2d.x * 0.01, dir2d.y * 0.01);
+        }
+        self.prev_cursor = position;
+        Self::default_control_flow()
+    }
+
+    fn render(&mut self) { self.scene.render_frame(); }
+}
+
+fn main() { MyApp::run(); }
@@ -0,0 +1,146 @@
+//! Switching the render mode of the scene
+//!
+//! - Drag the mouse to rotate the model.
+//! - Drag and drop obj files into the window to switch models.
+//! - Enter "Space" on the keyboard to switch the render mode: shaded, shaded with wireframe,
+//! and wireframe.
+
+use std::io::Read;
+use std::sync::Arc;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+use winit::{dpi::*, event::*, event_loop::ControlFlow};
+mod app;
+use app::*;
+
+const TEAPOT_BYTES: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../resources/obj/teapot.obj",
+));
+
+struct MyApp {
+    scene: WindowScene,
+    creator: InstanceCreator,
+    rotate_flag: bool,
+    prev_cursor: Vector2,
+    instance: PolygonInstance,
+}
+
+impl MyApp {
+    fn create_camera() -> Camera {
+        let matrix = Matrix4::look_at_rh(
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::origin(),
+            Vector3::unit_y(),
+        );
+        Camera::perspective_camera(
+            matrix.invert().unwrap(),
+            Rad(std::f64::consts::PI / 4.0),
+            0.1,
+            40.0,
+        )
+    }
+
+    fn load_obj<R: Read>(creator: &InstanceCreator, reader: R) -> PolygonInstance {
+        let mut mesh = obj::read(reader).unwrap();
+        mesh.put_together_same_attrs()
+            .add_smooth_normals(0.5, false);
+        let bdd_box = mesh.bounding_box();
+        let (size, center) = (bdd_box.size(), bdd_box.center());
+        let mat = Matrix4::from_translation(center.to_vec()) * Matrix4::from_scale(size);
+        let polygon_state = PolygonState {
+            matrix: mat.invert().unwrap(),
+            ..Default::default()
+        };
+        creator.create_instance(&mesh, &polygon_state)
+    }
+}
+
+#[async_trait(?Send)]
+impl App for MyApp {
+    async fn init(window: Arc<winit::window::Window>) -> MyApp {
+        let scene_desc = WindowSceneDescriptor {
+            studio: StudioConfig {
+                background: Color::WHITE,
+                camera: MyApp::create_camera(),
+                lights: vec![Light {
+                    position: Point3::new(1.0, 1.0, 1.0),
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+            },
+            backend_buffer: BackendBufferConfig {
+                sample_count: 4,
+                ..Default::default()
+            },
+        };
+        let mut scene = WindowScene::from_window(window, &scene_desc).await;
+        let creator = scene.instance_creator();
+        let instance = MyApp::load_obj(&creator, TEAPOT_BYTES);
+        scene.add_object(&instance);
+        MyApp {
+            scene,
+            creator,
+            rotate_flag: false,
+            prev_cursor: Vector2::zero(),
+            instance,
+        }
+    }
+
+    fn app_title<'a>() -> Option<&'a str> { Some("wireframe mode") }
+
+    fn dropped_file(&mut self, path: std::path::PathBuf) -> ControlFlow {
+        let file = std::fs::File::open(path).unwrap();
+        self.scene.remove_object(&self.instance);
+        self.instance = MyApp::load_obj(&self.creator, file);
+        self.scene.add_object(&self.instance);
+        Self::default_control_flow()
+    }
+
+    fn mouse_input(&mut self, state: ElementState, button: MouseButton) -> ControlFlow {
+        if button == MouseButton::Left {
+            self.rotate_flag = state == ElementState::Pressed;
+        }
+        Self::default_control_flow()
+    }
+
+    fn cursor_moved(&mut self, position: PhysicalPosition<f64>) -> ControlFlow {
+        let position = Vector2::new(position.x, position.y);
+        if self.rotate_flag {
+            let matrix = &mut self.scene.studio_config_mut().camera.matrix;
+            let dir2d = position - self.prev_cursor;
+            if dir2d.so_small() {
+                return Self::default_control_flow();
+            }
+            let mut axis = dir2d[1] * matrix[0].truncate();
+            axis += dir2d[0] * matrix[1].truncate();
+            axis /= axis.magnitude();
+            let angle = dir2d.magnitude() * 0.01;
+            let mat = Matrix4::from_axis_angle(axis, Rad(angle));
+            *matrix = mat.invert().unwrap() * *matrix;
+        }
+        self.prev_cursor = position;
+        Self::default_control_flow()
+    }
+
+    fn keyboard_input(&mut self, input: KeyboardInput, _: bool) -> ControlFlow {
+        if input.state == ElementState::Pressed
+            && input.virtual_keycode == Some(VirtualKeyCode::Space)
+        {
+            let render_mode = match self.scene.render_mode() {
+                RenderMode::Shaded => RenderMode::ShadedWireframe,
+                RenderMode::ShadedWireframe => RenderMode::Wireframe,
+                RenderMode::Wireframe => RenderMode::Shaded,
+            };
+            self.scene.set_render_mode(render_mode);
+            self.scene.update_pipeline(&self.instance);
+        }
+        Self::default_control_flow()
+    }
+
+    fn render(&mut self) { self.scene.render_frame(); }
+}
+
+fn main() { MyApp::run(); }
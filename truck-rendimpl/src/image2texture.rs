@@ -8,7 +8,7 @@ pub fn image2texture(device_handler: &DeviceHandler, image: &DynamicImage) -> Te
     imagebuffer2texture(device_handler, &buffer, TextureFormat::Rgba8Unorm)
 }
 
-fn imagebuffer2texture<P, Container>(
+pub(crate) fn imagebuffer2texture<P, Container>(
     device_handler: &DeviceHandler,
     image_buffer: &ImageBuffer<P, Container>,
     format: TextureFormat,
@@ -52,3 +52,18 @@ where
     );
     texture
 }
+
+/// Saves the depth map given by [`Scene::render_depth_to_buffer`] as a grayscale PNG image.
+///
+/// The nearer pixels are darker, and the pixels at the far clip plane are white.
+pub fn save_depth_png<P: AsRef<std::path::Path>>(
+    path: P,
+    depth: &[f32],
+    size: (u32, u32),
+) -> ImageResult<()> {
+    let buffer: Vec<u8> = depth
+        .iter()
+        .map(|d| (d.clamp(0.0, 1.0) * 255.0).round() as u8)
+        .collect();
+    save_buffer_with_format(path, &buffer, size.0, size.1, ColorType::L8, ImageFormat::Png)
+}
@@ -0,0 +1,130 @@
+use crate::*;
+
+const INSTANCE_ATTRIBUTES: [VertexAttribute; 4] = [
+    VertexAttribute {
+        format: VertexFormat::Float32x4,
+        offset: 0,
+        shader_location: 3,
+    },
+    VertexAttribute {
+        format: VertexFormat::Float32x4,
+        offset: 4 * 4,
+        shader_location: 4,
+    },
+    VertexAttribute {
+        format: VertexFormat::Float32x4,
+        offset: 2 * 4 * 4,
+        shader_location: 5,
+    },
+    VertexAttribute {
+        format: VertexFormat::Float32x4,
+        offset: 3 * 4 * 4,
+        shader_location: 6,
+    },
+];
+
+/// the layout of the vertex buffer of the instance matrices at the slot 1
+const INSTANCE_BUFFER_LAYOUT: VertexBufferLayout<'static> = VertexBufferLayout {
+    array_stride: size_of::<[[f32; 4]; 4]>() as BufferAddress,
+    step_mode: VertexStepMode::Instance,
+    attributes: &INSTANCE_ATTRIBUTES,
+};
+
+#[inline(always)]
+fn vertex_module(device: &Device) -> ShaderModule {
+    device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(include_str!("shaders/instanced.wgsl").into()),
+        label: None,
+    })
+}
+
+impl InstancedObject {
+    /// Returns a reference to the instance descriptor.
+    #[inline(always)]
+    pub fn instance_state(&self) -> &PolygonState { &self.state }
+    /// Returns the mutable reference to instance descriptor.
+    #[inline(always)]
+    pub fn instance_state_mut(&mut self) -> &mut PolygonState { &mut self.state }
+    /// Returns the instance matrices.
+    #[inline(always)]
+    pub fn matrices(&self) -> &[Matrix4] { &self.matrices }
+    /// Returns the mutable reference to the instance matrices.
+    ///
+    /// The instance buffer is synchronized by [`Scene::update_vertex_buffer`].
+    #[inline(always)]
+    pub fn matrices_mut(&mut self) -> &mut Vec<Matrix4> { &mut self.matrices }
+}
+
+impl Rendered for InstancedObject {
+    impl_render_id!(id);
+
+    #[inline(always)]
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let polygon = self.polygon.clone();
+        (polygon.0, Some(polygon.1))
+    }
+    #[inline(always)]
+    fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(self.state.bind_group_layout(device_handler.device()))
+    }
+    #[inline(always)]
+    fn instance_buffer(&self, device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        let matrices: Vec<[[f32; 4]; 4]> = self
+            .matrices
+            .iter()
+            .map(|matrix| matrix.cast::<f32>().unwrap().into())
+            .collect();
+        Some(Arc::new(BufferHandler::from_slice(
+            &matrices,
+            device_handler.device(),
+            BufferUsages::VERTEX,
+        )))
+    }
+    #[inline(always)]
+    fn bind_group(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &BindGroupLayout,
+    ) -> Arc<BindGroup> {
+        Arc::new(self.state.bind_group(device_handler.device(), layout))
+    }
+    fn pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let module = vertex_module(device_handler.device());
+        let vertex = VertexState {
+            module: &module,
+            entry_point: "vs_main",
+            buffers: &[AttrVertex::buffer_layout(), INSTANCE_BUFFER_LAYOUT],
+        };
+        self.state.pipeline(&self.shaders, device_handler, layout, scene_desc, vertex)
+    }
+    fn pick_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        fragment: FragmentState<'_>,
+    ) -> Option<Arc<RenderPipeline>> {
+        let module = vertex_module(device_handler.device());
+        let vertex = VertexState {
+            module: &module,
+            entry_point: "vs_main",
+            buffers: &[AttrVertex::buffer_layout(), INSTANCE_BUFFER_LAYOUT],
+        };
+        Some(self.state.pick_pipeline(device_handler, layout, vertex, fragment))
+    }
+    fn oriented_bounding_box(&self) -> Option<OrientedBoundingBox> {
+        let bounds = self.bounds?;
+        let corners = self
+            .matrices
+            .iter()
+            .flat_map(|matrix| bounds.transformed(self.state.matrix * *matrix).corners())
+            .collect::<Vec<_>>();
+        OrientedBoundingBox::from_points(&corners)
+    }
+    #[inline(always)]
+    fn is_transparent(&self) -> bool { self.state.is_transparent() }
+}
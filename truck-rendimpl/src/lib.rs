@@ -15,7 +15,7 @@
 
 use bytemuck::{Pod, Zeroable};
 use image::DynamicImage;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use truck_platform::{wgpu::*, *};
 
 /// Re-exports `truck_polymesh`.
@@ -30,7 +30,7 @@ pub use polymesh::*;
 #[derive(Debug, Clone, Copy)]
 pub struct Material {
     /// albedo, base color, [0, 1]-normalized rgba. Default is `Vector4::new(1.0, 1.0, 1.0, 1.0)`.  
-    /// Transparent by alpha is not yet supported in the current standard shader.
+    /// Transparent by alpha is supported only if `alpha_blend` is `true`.
     pub albedo: Vector4,
     /// roughness of the surface: [0, 1]. Default is 0.5.
     pub roughness: f64,
@@ -41,6 +41,10 @@ pub struct Material {
     /// ratio of blending background color: [0, 1]. Default is 0.0.
     pub background_ratio: f64,
     /// alpha blend flag
+    ///
+    /// If `true` and the alpha of `albedo` is less than `1.0`, the instance is transparent:
+    /// it does not write the depth, and is drawn after the opaque objects.
+    /// cf: [`Rendered::is_transparent`]
     pub alpha_blend: bool,
 }
 
@@ -51,7 +55,9 @@ pub struct PolygonState {
     pub matrix: Matrix4,
     /// material of instance
     pub material: Material,
-    /// texture of instance
+    /// texture of instance, modulated by the albedo of `material`
+    ///
+    /// If `None`, the instance is filled by the albedo, as with a 1x1 white texture.
     pub texture: Option<Arc<Texture>>,
     /// If this parameter is true, the backface culling will be activated.
     pub backface_culling: bool,
@@ -97,11 +103,31 @@ pub struct WireShaders {
 #[derive(Debug)]
 pub struct PolygonInstance {
     polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
+    overlays: Arc<Overlays>,
+    bounds: Option<OrientedBoundingBox>,
     state: PolygonState,
     shaders: PolygonShaders,
     id: RenderID,
 }
 
+/// Instanced rendering of a polygon
+///
+/// The mesh is drawn once for each instance matrix by one draw call, sharing one vertex
+/// buffer and one index buffer. The instance matrices are uploaded as an instance buffer,
+/// and each of them is applied before the matrix of the state.
+/// Created by [`PolygonInstance::instanced`].
+///
+/// The edges, the wireframe and the debug lines are not drawn.
+#[derive(Debug)]
+pub struct InstancedObject {
+    polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
+    bounds: Option<OrientedBoundingBox>,
+    state: PolygonState,
+    matrices: Vec<Matrix4>,
+    shaders: PolygonShaders,
+    id: RenderID,
+}
+
 /// Wire frame rendering
 #[derive(Debug)]
 pub struct WireFrameInstance {
@@ -162,10 +188,33 @@ struct AttrVertex {
     pub normal: [f32; 3],
 }
 
+/// the position, the normal and the tangent at a vertex, for the debug lines.
+/// The zero vectors are stored if the normal or the tangent is not given.
+#[derive(Debug, Clone, Copy)]
+struct VertexFrame {
+    position: Point3,
+    normal: Vector3,
+    tangent: Vector3,
+}
+
+/// the mesh data kept in the CPU memory, from which the overlays are created
+/// at the first requests, i.e. only when they are drawn.
+#[derive(Debug)]
+struct Overlays {
+    vertices: Vec<AttrVertex>,
+    indices: Vec<u32>,
+    // the mesh whose feature edges are overlaid
+    mesh: Option<PolygonMesh>,
+    frames: OnceLock<Vec<VertexFrame>>,
+    edges: OnceLock<Option<Arc<BufferHandler>>>,
+    wireframe: OnceLock<Option<Arc<BufferHandler>>>,
+}
+
 /// utility for creating `Texture`
 pub mod image2texture;
 mod instance_creator;
 mod instance_descriptor;
+mod instanced_object;
 mod polygon_instance;
 mod polyrend;
 mod wireframe_instance;
@@ -1,27 +1,28 @@
 use crate::*;
 
-impl PolygonInstance {
-    /// Clone the instance as another drawn element.
+impl PolygonState {
+    /// Returns whether the instances with `self` are transparent.
     #[inline(always)]
-    pub fn clone_instance(&self) -> PolygonInstance {
-        PolygonInstance {
-            polygon: self.polygon.clone(),
-            state: self.state.clone(),
-            shaders: self.shaders.clone(),
-            id: RenderID::gen(),
-        }
+    pub(crate) fn is_transparent(&self) -> bool {
+        self.material.alpha_blend && self.material.albedo[3] < 1.0
     }
-    /// Returns a reference to the instance descriptor.
-    #[inline(always)]
-    pub fn instance_state(&self) -> &PolygonState { &self.state }
-    /// Returns the mutable reference to instance descriptor.
+
+    /// Creates the bind group layout, which depends on whether `self` has a texture.
     #[inline(always)]
-    pub fn instance_state_mut(&mut self) -> &mut PolygonState { &mut self.state }
+    pub(crate) fn bind_group_layout(&self, device: &Device) -> BindGroupLayout {
+        match self.texture.is_some() {
+            true => self.textured_bdl(device),
+            false => self.non_textured_bdl(device),
+        }
+    }
 
-    /// swap vertex buffers and index buffers
+    /// Creates the bind group in `set = 1`.
     #[inline(always)]
-    pub fn swap_vertex(&mut self, other: &mut PolygonInstance) {
-        std::mem::swap(&mut self.polygon, &mut other.polygon);
+    pub(crate) fn bind_group(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
+        match self.texture.is_some() {
+            true => self.textured_bg(device, layout),
+            false => self.non_textured_bg(device, layout),
+        }
     }
 
     #[inline(always)]
@@ -53,81 +54,256 @@ impl PolygonInstance {
             device,
             layout,
             vec![
-                self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
+                self.matrix_buffer(device).binding_resource(),
+                self.material.buffer(device).binding_resource(),
             ],
         )
     }
     #[inline(always)]
     fn textured_bg(&self, device: &Device, layout: &BindGroupLayout) -> BindGroup {
-        let (view, sampler) = self.state.textureview_and_sampler(device);
+        let (view, sampler) = self.textureview_and_sampler(device);
         bind_group_util::create_bind_group(
             device,
             layout,
             vec![
-                self.state.matrix_buffer(device).binding_resource(),
-                self.state.material.buffer(device).binding_resource(),
+                self.matrix_buffer(device).binding_resource(),
+                self.material.buffer(device).binding_resource(),
                 BindingResource::TextureView(&view),
                 BindingResource::Sampler(&sampler),
             ],
         )
     }
+
+    /// the pipeline of the faces whose vertices are given by the vertex state `vertex`
+    pub(crate) fn pipeline(
+        &self,
+        shaders: &PolygonShaders,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+        vertex: VertexState<'_>,
+    ) -> Arc<RenderPipeline> {
+        let device = device_handler.device();
+        let (fragment_module, fragment_entry) = match self.texture.is_some() {
+            true => (&shaders.tex_fragment_module, shaders.tex_fragment_entry),
+            false => (&shaders.fragment_module, shaders.fragment_entry),
+        };
+        let blend = match self.material.alpha_blend {
+            true => Some(BlendState::ALPHA_BLENDING),
+            false => Some(BlendState::REPLACE),
+        };
+        // the transparent objects do not hide the objects behind them.
+        let transparent = self.is_transparent();
+        let depth_stencil = match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: !transparent,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            false => None,
+        };
+        let sample_count = scene_desc.backend_buffer.sample_count;
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex,
+            fragment: Some(FragmentState {
+                module: fragment_module,
+                entry_point: fragment_entry,
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: self.primitive(),
+            depth_stencil,
+            multisample: MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: sample_count > 1 && !transparent,
+            },
+            label: None,
+            multiview: None,
+        });
+        Arc::new(pipeline)
+    }
+
+    /// the pipeline of [`Scene::pick`] for the faces whose vertices are given by `vertex`
+    pub(crate) fn pick_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        vertex: VertexState<'_>,
+        fragment: FragmentState<'_>,
+    ) -> Arc<RenderPipeline> {
+        let device = device_handler.device();
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex,
+            fragment: Some(fragment),
+            primitive: self.primitive(),
+            // the transparent objects also write the depth, so that they are picked.
+            depth_stencil: Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            multisample: MultisampleState::default(),
+            label: None,
+            multiview: None,
+        });
+        Arc::new(pipeline)
+    }
+
+    #[inline(always)]
+    fn primitive(&self) -> PrimitiveState {
+        let cull_mode = match self.backface_culling {
+            true => Some(Face::Back),
+            false => None,
+        };
+        PrimitiveState {
+            topology: PrimitiveTopology::TriangleList,
+            front_face: FrontFace::Ccw,
+            cull_mode,
+            polygon_mode: PolygonMode::Fill,
+            ..Default::default()
+        }
+    }
 }
 
-impl Rendered for PolygonInstance {
-    impl_render_id!(id);
+impl AttrVertex {
+    const ATTRIBUTES: [VertexAttribute; 3] = [
+        VertexAttribute {
+            format: VertexFormat::Float32x3,
+            offset: 0,
+            shader_location: 0,
+        },
+        VertexAttribute {
+            format: VertexFormat::Float32x2,
+            offset: 3 * 4,
+            shader_location: 1,
+        },
+        VertexAttribute {
+            format: VertexFormat::Float32x3,
+            offset: 2 * 4 + 3 * 4,
+            shader_location: 2,
+        },
+    ];
 
+    /// the layout of the vertex buffer at the slot 0
     #[inline(always)]
-    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
-        let polygon = self.polygon.clone();
-        (polygon.0, Some(polygon.1))
+    pub(crate) fn buffer_layout() -> VertexBufferLayout<'static> {
+        VertexBufferLayout {
+            array_stride: size_of::<AttrVertex>() as BufferAddress,
+            step_mode: VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
     }
+}
+
+impl PolygonInstance {
+    /// Clone the instance as another drawn element.
     #[inline(always)]
-    fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bdl(device_handler.device()),
-            false => self.non_textured_bdl(device_handler.device()),
-        })
+    pub fn clone_instance(&self) -> PolygonInstance {
+        PolygonInstance {
+            polygon: self.polygon.clone(),
+            overlays: self.overlays.clone(),
+            bounds: self.bounds,
+            state: self.state.clone(),
+            shaders: self.shaders.clone(),
+            id: RenderID::gen(),
+        }
     }
+    /// Creates the object drawing the mesh of `self` once for each element of `matrices`.
+    ///
+    /// The mesh data on the GPU is shared with `self`. The state of the object is
+    /// the clone of the one of `self`.
     #[inline(always)]
-    fn bind_group(
-        &self,
-        device_handler: &DeviceHandler,
-        layout: &BindGroupLayout,
-    ) -> Arc<BindGroup> {
-        Arc::new(match self.state.texture.is_some() {
-            true => self.textured_bg(device_handler.device(), layout),
-            false => self.non_textured_bg(device_handler.device(), layout),
-        })
+    pub fn instanced(&self, matrices: Vec<Matrix4>) -> InstancedObject {
+        InstancedObject {
+            polygon: self.polygon.clone(),
+            bounds: self.bounds,
+            state: self.state.clone(),
+            matrices,
+            shaders: self.shaders.clone(),
+            id: RenderID::gen(),
+        }
+    }
+    /// Returns a reference to the instance descriptor.
+    #[inline(always)]
+    pub fn instance_state(&self) -> &PolygonState { &self.state }
+    /// Returns the mutable reference to instance descriptor.
+    #[inline(always)]
+    pub fn instance_state_mut(&mut self) -> &mut PolygonState { &mut self.state }
+
+    /// Uploads `image` to the GPU and sets it to the texture of the instance.
+    ///
+    /// The bind group layout and the pipeline depend on whether the instance has a texture.
+    /// If the instance in a scene had no texture, add it again by [`Scene::add_object`];
+    /// otherwise, [`Scene::update_bind_group`] is enough to reflect the new texture.
+    pub fn set_texture(&mut self, device_handler: &DeviceHandler, image: &image::RgbaImage) {
+        let texture = image2texture::imagebuffer2texture(
+            device_handler,
+            image,
+            TextureFormat::Rgba8Unorm,
+        );
+        self.state.texture = Some(Arc::new(texture));
     }
+
+    /// Returns the segments of the debug lines in the model coordinate.
+    ///
+    /// Each segment starts from a vertex, and is directed along the normal or the tangent
+    /// at the vertex with `length`. The vertices without normals or tangents are skipped.
+    /// The tangents are directions in which the first texture coordinate increases,
+    /// orthogonalized to the normals.
+    pub fn debug_line_segments(&self, lines: DebugLines, length: f64) -> Vec<[Point3; 2]> {
+        self.overlays
+            .frames()
+            .iter()
+            .filter_map(|frame| {
+                let dir = match lines {
+                    DebugLines::Normals => frame.normal,
+                    DebugLines::Tangents => frame.tangent,
+                };
+                match dir.so_small() {
+                    true => None,
+                    false => Some([frame.position, frame.position + dir * length]),
+                }
+            })
+            .collect()
+    }
+
+    /// swap vertex buffers and index buffers
     #[inline(always)]
-    fn pipeline(
+    pub fn swap_vertex(&mut self, other: &mut PolygonInstance) {
+        std::mem::swap(&mut self.polygon, &mut other.polygon);
+        std::mem::swap(&mut self.overlays, &mut other.overlays);
+        std::mem::swap(&mut self.bounds, &mut other.bounds);
+    }
+
+    /// the pipeline of the lines whose vertices have the positions at the beginning
+    fn line_pipeline(
         &self,
         device_handler: &DeviceHandler,
         layout: &PipelineLayout,
         scene_desc: &SceneDescriptor,
+        array_stride: BufferAddress,
+        fragment_entry: &str,
     ) -> Arc<RenderPipeline> {
         let device = device_handler.device();
-        let (fragment_module, fragment_entry) = match self.state.texture.is_some() {
-            true => (
-                &self.shaders.tex_fragment_module,
-                self.shaders.tex_fragment_entry,
-            ),
-            false => (&self.shaders.fragment_module, self.shaders.fragment_entry),
-        };
-        let cull_mode = match self.state.backface_culling {
-            true => Some(wgpu::Face::Back),
-            false => None,
-        };
-        let blend = match self.state.material.alpha_blend {
-            true => Some(BlendState::ALPHA_BLENDING),
-            false => Some(BlendState::REPLACE),
-        };
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/edge.wgsl").into()),
+            label: None,
+        });
         let depth_stencil = match scene_desc.backend_buffer.depth_test {
             true => Some(DepthStencilState {
                 format: TextureFormat::Depth32Float,
                 depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::Less,
+                depth_compare: CompareFunction::Less,
                 stencil: Default::default(),
                 bias: Default::default(),
             }),
@@ -137,44 +313,29 @@ impl Rendered for PolygonInstance {
         let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
             layout: Some(layout),
             vertex: VertexState {
-                module: &self.shaders.vertex_module,
-                entry_point: self.shaders.vertex_entry,
+                module: &module,
+                entry_point: "vs_main",
                 buffers: &[VertexBufferLayout {
-                    array_stride: std::mem::size_of::<AttrVertex>() as BufferAddress,
+                    array_stride,
                     step_mode: VertexStepMode::Vertex,
-                    attributes: &[
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 0,
-                            shader_location: 0,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: 3 * 4,
-                            shader_location: 1,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x3,
-                            offset: 2 * 4 + 3 * 4,
-                            shader_location: 2,
-                        },
-                    ],
+                    attributes: &[VertexAttribute {
+                        format: VertexFormat::Float32x3,
+                        offset: 0,
+                        shader_location: 0,
+                    }],
                 }],
             },
             fragment: Some(FragmentState {
-                module: fragment_module,
+                module: &module,
                 entry_point: fragment_entry,
                 targets: &[Some(ColorTargetState {
                     format: scene_desc.render_texture.format,
-                    blend,
+                    blend: Some(BlendState::REPLACE),
                     write_mask: ColorWrites::ALL,
                 })],
             }),
             primitive: PrimitiveState {
-                topology: PrimitiveTopology::TriangleList,
-                front_face: FrontFace::Ccw,
-                cull_mode,
-                polygon_mode: PolygonMode::Fill,
+                topology: PrimitiveTopology::LineList,
                 ..Default::default()
             },
             depth_stencil,
@@ -189,3 +350,119 @@ impl Rendered for PolygonInstance {
         Arc::new(pipeline)
     }
 }
+
+impl Rendered for PolygonInstance {
+    impl_render_id!(id);
+
+    #[inline(always)]
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let polygon = self.polygon.clone();
+        (polygon.0, Some(polygon.1))
+    }
+    #[inline(always)]
+    fn bind_group_layout(&self, device_handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(self.state.bind_group_layout(device_handler.device()))
+    }
+    #[inline(always)]
+    fn bind_group(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &BindGroupLayout,
+    ) -> Arc<BindGroup> {
+        Arc::new(self.state.bind_group(device_handler.device(), layout))
+    }
+    #[inline(always)]
+    fn pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let vertex = VertexState {
+            module: &self.shaders.vertex_module,
+            entry_point: self.shaders.vertex_entry,
+            buffers: &[AttrVertex::buffer_layout()],
+        };
+        self.state.pipeline(&self.shaders, device_handler, layout, scene_desc, vertex)
+    }
+    #[inline(always)]
+    fn pick_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        fragment: FragmentState<'_>,
+    ) -> Option<Arc<RenderPipeline>> {
+        let vertex = VertexState {
+            module: &self.shaders.vertex_module,
+            entry_point: self.shaders.vertex_entry,
+            buffers: &[AttrVertex::buffer_layout()],
+        };
+        Some(self.state.pick_pipeline(device_handler, layout, vertex, fragment))
+    }
+    #[inline(always)]
+    fn edge_buffer(&self, device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        self.overlays.edge_buffer(device_handler.device())
+    }
+    fn debug_line_buffer(
+        &self,
+        device_handler: &DeviceHandler,
+        lines: DebugLines,
+        length: f64,
+    ) -> Option<Arc<BufferHandler>> {
+        let positions: Vec<[f32; 3]> = self
+            .debug_line_segments(lines, length)
+            .into_iter()
+            .flatten()
+            .map(|p| p.cast().unwrap().into())
+            .collect();
+        match positions.is_empty() {
+            true => None,
+            false => Some(Arc::new(BufferHandler::from_slice(
+                &positions,
+                device_handler.device(),
+                BufferUsages::VERTEX,
+            ))),
+        }
+    }
+    #[inline(always)]
+    fn debug_line_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        let array_stride = size_of::<[f32; 3]>() as BufferAddress;
+        Some(self.line_pipeline(device_handler, layout, scene_desc, array_stride, "fs_main"))
+    }
+    #[inline(always)]
+    fn edge_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        self.overlays.edge_buffer(device_handler.device())?;
+        let array_stride = size_of::<[f32; 3]>() as BufferAddress;
+        Some(self.line_pipeline(device_handler, layout, scene_desc, array_stride, "fs_main"))
+    }
+    #[inline(always)]
+    fn wireframe_buffer(&self, device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        self.overlays.wireframe_buffer(device_handler.device())
+    }
+    #[inline(always)]
+    fn wireframe_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        let array_stride = size_of::<AttrVertex>() as BufferAddress;
+        Some(self.line_pipeline(device_handler, layout, scene_desc, array_stride, "fs_wireframe"))
+    }
+    #[inline(always)]
+    fn oriented_bounding_box(&self) -> Option<OrientedBoundingBox> {
+        self.bounds.map(|bounds| bounds.transformed(self.state.matrix))
+    }
+    #[inline(always)]
+    fn is_transparent(&self) -> bool { self.state.is_transparent() }
+}
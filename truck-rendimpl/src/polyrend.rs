@@ -1,5 +1,134 @@
 use crate::*;
 
+/// the threshold of the angle between faces for the edges to be overlaid: 30 degrees.
+const FEATURE_EDGE_ANGLE: f64 = std::f64::consts::PI / 6.0;
+
+/// Returns the frames at vertices. The tangents are accumulated over the triangles
+/// by the gradients of the first texture coordinate.
+fn vertex_frames(vertices: &[AttrVertex], indices: &[u32]) -> Vec<VertexFrame> {
+    let mut tangents = vec![Vector3::zero(); vertices.len()];
+    indices.chunks(3).for_each(|tri| {
+        let pos = |i: usize| Point3::from(vertices[tri[i] as usize].position.map(f64::from));
+        let uv = |i: usize| Vector2::from(vertices[tri[i] as usize].uv_coord.map(f64::from));
+        let (edge0, edge1) = (pos(1) - pos(0), pos(2) - pos(0));
+        let (duv0, duv1) = (uv(1) - uv(0), uv(2) - uv(0));
+        let det = duv0.x * duv1.y - duv1.x * duv0.y;
+        if det != 0.0 {
+            let tangent = (edge0 * duv1.y - edge1 * duv0.y) / det;
+            tri.iter().for_each(|i| tangents[*i as usize] += tangent);
+        }
+    });
+    vertices
+        .iter()
+        .zip(tangents)
+        .map(|(vertex, tangent)| {
+            let normal = Vector3::from(vertex.normal.map(f64::from));
+            let normal = match normal.so_small() {
+                true => Vector3::zero(),
+                false => normal.normalize(),
+            };
+            let tangent = tangent - normal * normal.dot(tangent);
+            VertexFrame {
+                position: Point3::from(vertex.position.map(f64::from)),
+                normal,
+                tangent: match tangent.so_small() {
+                    true => Vector3::zero(),
+                    false => tangent.normalize(),
+                },
+            }
+        })
+        .collect()
+}
+
+impl Overlays {
+    #[inline(always)]
+    fn new(vertices: Vec<AttrVertex>, indices: Vec<u32>, mesh: Option<PolygonMesh>) -> Self {
+        Self {
+            vertices,
+            indices,
+            mesh,
+            frames: OnceLock::new(),
+            edges: OnceLock::new(),
+            wireframe: OnceLock::new(),
+        }
+    }
+
+    /// the frames at the vertices, computed at the first call
+    #[inline(always)]
+    pub(crate) fn frames(&self) -> &[VertexFrame] {
+        self.frames
+            .get_or_init(|| vertex_frames(&self.vertices, &self.indices))
+    }
+
+    /// the vertex buffer of the feature edges, created at the first call
+    pub(crate) fn edge_buffer(&self, device: &Device) -> Option<Arc<BufferHandler>> {
+        let mesh = self.mesh.as_ref()?;
+        let edges = self.edges.get_or_init(|| {
+            let positions: Vec<[f32; 3]> = mesh
+                .feature_edges(FEATURE_EDGE_ANGLE)
+                .into_iter()
+                .flatten()
+                .map(|i| mesh.positions()[i].cast().unwrap().into())
+                .collect();
+            match positions.is_empty() {
+                true => None,
+                false => Some(Arc::new(BufferHandler::from_slice(
+                    &positions,
+                    device,
+                    BufferUsages::VERTEX,
+                ))),
+            }
+        });
+        edges.clone()
+    }
+
+    /// the index buffer of the wireframe, created at the first call
+    #[inline(always)]
+    pub(crate) fn wireframe_buffer(&self, device: &Device) -> Option<Arc<BufferHandler>> {
+        self.wireframe
+            .get_or_init(|| wireframe_buffer(&self.indices, device))
+            .clone()
+    }
+}
+
+/// Returns the index buffer of the edges of the triangles, drawn as `LineList`.
+/// The edges shared by two triangles are not deduplicated.
+fn wireframe_buffer(indices: &[u32], device: &Device) -> Option<Arc<BufferHandler>> {
+    let lines: Vec<u32> = indices
+        .chunks(3)
+        .flat_map(|tri| [tri[0], tri[1], tri[1], tri[2], tri[2], tri[0]])
+        .collect();
+    match lines.is_empty() {
+        true => None,
+        false => Some(Arc::new(BufferHandler::from_slice(
+            &lines,
+            device,
+            BufferUsages::INDEX,
+        ))),
+    }
+}
+
+fn polygon_vertices(mesh: &PolygonMesh) -> (Vec<AttrVertex>, Vec<u32>) {
+    let expanded = mesh.expands(|attr| AttrVertex {
+        position: attr.position.cast().unwrap().into(),
+        uv_coord: attr
+            .uv_coord
+            .and_then(|v| Some(v.cast()?.into()))
+            .unwrap_or([0.0, 0.0]),
+        normal: attr
+            .normal
+            .and_then(|v| Some(v.cast()?.into()))
+            .unwrap_or([0.0, 0.0, 0.0]),
+    });
+    let indices = expanded
+        .faces()
+        .triangle_iter()
+        .flatten()
+        .map(|x| x as u32)
+        .collect::<Vec<_>>();
+    (expanded.attributes().clone(), indices)
+}
+
 impl CreateBuffers for PolygonMesh {
     #[inline(always)]
     fn buffers(
@@ -8,25 +137,9 @@ impl CreateBuffers for PolygonMesh {
         index_usage: BufferUsages,
         device: &Device,
     ) -> (BufferHandler, BufferHandler) {
-        let expanded = self.expands(|attr| AttrVertex {
-            position: attr.position.cast().unwrap().into(),
-            uv_coord: attr
-                .uv_coord
-                .and_then(|v| Some(v.cast()?.into()))
-                .unwrap_or([0.0, 0.0]),
-            normal: attr
-                .normal
-                .and_then(|v| Some(v.cast()?.into()))
-                .unwrap_or([0.0, 0.0, 0.0]),
-        });
-        let indices = expanded
-            .faces()
-            .triangle_iter()
-            .flatten()
-            .map(|x| x as u32)
-            .collect::<Vec<_>>();
+        let (vertices, indices) = polygon_vertices(self);
         (
-            BufferHandler::from_slice(expanded.attributes(), device, vertex_usage),
+            BufferHandler::from_slice(&vertices, device, vertex_usage),
             BufferHandler::from_slice(&indices, device, index_usage),
         )
     }
@@ -48,9 +161,14 @@ impl ToInstance<PolygonInstance> for PolygonMesh {
         shaders: &PolygonShaders,
         state: &PolygonState,
     ) -> PolygonInstance {
-        let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
+        let device = handler.device();
+        let (vertices, indices) = polygon_vertices(self);
+        let vb = BufferHandler::from_slice(&vertices, device, BufferUsages::VERTEX);
+        let ib = BufferHandler::from_slice(&indices, device, BufferUsages::INDEX);
         PolygonInstance {
             polygon: (Arc::new(vb), Arc::new(ib)),
+            overlays: Arc::new(Overlays::new(vertices, indices, Some(self.clone()))),
+            bounds: self.oriented_bounding_box(),
             state: state.clone(),
             shaders: shaders.clone(),
             id: RenderID::gen(),
@@ -91,6 +209,40 @@ impl ToInstance<WireFrameInstance> for PolygonMesh {
     }
 }
 
+fn structured_vertices(mesh: &StructuredMesh) -> (Vec<AttrVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let (m, n) = (mesh.positions().len(), mesh.positions()[0].len());
+    for i in 0..m {
+        for j in 0..n {
+            vertices.push(AttrVertex {
+                position: mesh.positions()[i][j].cast().unwrap().into(),
+                uv_coord: match mesh.uv_division() {
+                    Some(uv_division) => [uv_division.0[i] as f32, uv_division.1[j] as f32],
+                    None => [0.0, 0.0],
+                },
+                normal: match mesh.normals() {
+                    Some(normals) => normals[i][j].cast().unwrap().into(),
+                    None => [0.0, 0.0, 0.0],
+                },
+            });
+        }
+    }
+    let mut indices = Vec::<u32>::new();
+    for i in 1..m {
+        for j in 1..n {
+            indices.extend([
+                ((i - 1) * n + j - 1) as u32,
+                (i * n + j - 1) as u32,
+                ((i - 1) * n + j) as u32,
+                ((i - 1) * n + j) as u32,
+                (i * n + j - 1) as u32,
+                (i * n + j) as u32,
+            ]);
+        }
+    }
+    (vertices, indices)
+}
+
 impl CreateBuffers for StructuredMesh {
     #[inline(always)]
     fn buffers(
@@ -99,36 +251,7 @@ impl CreateBuffers for StructuredMesh {
         index_usage: BufferUsages,
         device: &Device,
     ) -> (BufferHandler, BufferHandler) {
-        let mut vertices = Vec::new();
-        let (m, n) = (self.positions().len(), self.positions()[0].len());
-        for i in 0..m {
-            for j in 0..n {
-                vertices.push(AttrVertex {
-                    position: self.positions()[i][j].cast().unwrap().into(),
-                    uv_coord: match self.uv_division() {
-                        Some(uv_division) => [uv_division.0[i] as f32, uv_division.1[j] as f32],
-                        None => [0.0, 0.0],
-                    },
-                    normal: match self.normals() {
-                        Some(normals) => normals[i][j].cast().unwrap().into(),
-                        None => [0.0, 0.0, 0.0],
-                    },
-                });
-            }
-        }
-        let mut indices = Vec::<u32>::new();
-        for i in 1..m {
-            for j in 1..n {
-                indices.extend([
-                    ((i - 1) * n + j - 1) as u32,
-                    (i * n + j - 1) as u32,
-                    ((i - 1) * n + j) as u32,
-                    ((i - 1) * n + j) as u32,
-                    (i * n + j - 1) as u32,
-                    (i * n + j) as u32,
-                ]);
-            }
-        }
+        let (vertices, indices) = structured_vertices(self);
         (
             BufferHandler::from_slice(&vertices, device, vertex_usage),
             BufferHandler::from_slice(&indices, device, index_usage),
@@ -145,9 +268,14 @@ impl ToInstance<PolygonInstance> for StructuredMesh {
         shaders: &PolygonShaders,
         state: &PolygonState,
     ) -> PolygonInstance {
-        let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
+        let device = handler.device();
+        let (vertices, indices) = structured_vertices(self);
+        let vb = BufferHandler::from_slice(&vertices, device, BufferUsages::VERTEX);
+        let ib = BufferHandler::from_slice(&indices, device, BufferUsages::INDEX);
         PolygonInstance {
             polygon: (Arc::new(vb), Arc::new(ib)),
+            overlays: Arc::new(Overlays::new(vertices, indices, None)),
+            bounds: OrientedBoundingBox::from_points(self.positions().iter().flatten()),
             state: state.clone(),
             shaders: shaders.clone(),
             id: RenderID::gen(),
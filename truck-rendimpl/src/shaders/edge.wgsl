@@ -0,0 +1,46 @@
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct SceneInfo {
+    bk_color: vec4<f32>,
+    resolution: vec2<u32>,
+    time: f32,
+    nlights: u32,
+    wireframe_color: vec4<f32>,
+}
+
+@group(0)
+@binding(2)
+var<uniform> info: SceneInfo;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+@vertex
+fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
+    var res: vec4<f32> = camera.projection * model_matrix.model_matrix * vec4<f32>(position, 1.0);
+    // the lines are drawn slightly in front of the faces, uniformly in the depth.
+    res.z = res.z - 1.0e-4 * res.w;
+    return res;
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(0.05, 0.05, 0.05, 1.0);
+}
+
+@fragment
+fn fs_wireframe() -> @location(0) vec4<f32> {
+    return info.wireframe_color;
+}
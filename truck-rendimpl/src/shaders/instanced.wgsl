@@ -0,0 +1,56 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) normal: vec3<f32>,
+}
+
+// the columns of the instance matrix
+struct InstanceInput {
+    @location(3) matrix0: vec4<f32>,
+    @location(4) matrix1: vec4<f32>,
+    @location(5) matrix2: vec4<f32>,
+    @location(6) matrix3: vec4<f32>,
+}
+
+struct Camera {
+    camera_matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) normal: vec3<f32>,
+}
+
+@vertex
+fn vs_main(in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let instance_matrix = mat4x4<f32>(
+        instance.matrix0,
+        instance.matrix1,
+        instance.matrix2,
+        instance.matrix3,
+    );
+    let matrix = model_matrix.model_matrix * instance_matrix;
+    let world_position = matrix * vec4<f32>(in.position, 1.0);
+    let world_normal = matrix * vec4<f32>(in.normal, 0.0);
+    return VertexOutput(
+        camera.projection * world_position,
+        world_position.xyz,
+        in.uv,
+        normalize(world_normal.xyz)
+    );
+}
@@ -100,8 +100,9 @@ fn nontex_main(in: VertexInput) -> @location(0) vec4<f32> {
 @fragment
 fn tex_main(in: VertexInput) -> @location(0) vec4<f32> {
     var matr: Material = material.material;
-    matr.albedo = textureSample(r_color, r_sampler, in.uv);
-    matr.albedo = vec4<f32>(pow(matr.albedo.rgb, vec3<f32>(2.2)), matr.albedo.a);
+    let texel = textureSample(r_color, r_sampler, in.uv);
+    // the texture is modulated by the albedo of the material.
+    matr.albedo = vec4<f32>(pow(texel.rgb, vec3<f32>(2.2)), texel.a) * matr.albedo;
     let camera_dir = normalize((camera.camera_matrix * e.yyyx).xyz - in.position);
     let normal = normalize(in.normal);
     var pre_color: vec3<f32> = vec3<f32>(0.0);
@@ -36,6 +36,17 @@ impl WireFrameInstance {
     }
 }
 
+/// the layout of the vertex buffer of the positions
+const VERTEX_BUFFER_LAYOUT: VertexBufferLayout<'static> = VertexBufferLayout {
+    array_stride: size_of::<[f32; 3]>() as BufferAddress,
+    step_mode: VertexStepMode::Vertex,
+    attributes: &[VertexAttribute {
+        format: VertexFormat::Float32x3,
+        offset: 0,
+        shader_location: 0,
+    }],
+};
+
 impl Instance for WireFrameInstance {
     type Shaders = WireShaders;
     fn standard_shaders(creator: &InstanceCreator) -> WireShaders { creator.wire_shaders.clone() }
@@ -101,15 +112,7 @@ impl Rendered for WireFrameInstance {
             vertex: VertexState {
                 module: &self.shaders.vertex_module,
                 entry_point: self.shaders.vertex_entry,
-                buffers: &[VertexBufferLayout {
-                    array_stride: std::mem::size_of::<[f32; 3]>() as BufferAddress,
-                    step_mode: VertexStepMode::Vertex,
-                    attributes: &[VertexAttribute {
-                        format: VertexFormat::Float32x3,
-                        offset: 0,
-                        shader_location: 0,
-                    }],
-                }],
+                buffers: &[VERTEX_BUFFER_LAYOUT],
             },
             fragment: Some(FragmentState {
                 module: &self.shaders.fragment_module,
@@ -141,6 +144,37 @@ impl Rendered for WireFrameInstance {
         });
         Arc::new(pipeline)
     }
+    fn pick_pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        fragment: FragmentState<'_>,
+    ) -> Option<Arc<RenderPipeline>> {
+        let pipeline = handler.device().create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &self.shaders.vertex_module,
+                entry_point: self.shaders.vertex_entry,
+                buffers: &[VERTEX_BUFFER_LAYOUT],
+            },
+            fragment: Some(fragment),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::LineList,
+                ..Default::default()
+            },
+            depth_stencil: Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            multisample: MultisampleState::default(),
+            label: None,
+            multiview: None,
+        });
+        Some(Arc::new(pipeline))
+    }
 }
 
 impl ToInstance<WireFrameInstance> for Vec<(Point3, Point3)> {
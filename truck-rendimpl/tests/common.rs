@@ -4,6 +4,7 @@ use rayon::prelude::*;
 use std::io::Write;
 use std::sync::Arc;
 use truck_platform::*;
+use truck_polymesh::{Faces, Point3, PolygonMesh, StandardAttributes, Vector3};
 use wgpu::*;
 
 #[derive(Clone, Debug)]
@@ -178,6 +179,71 @@ pub fn init_device(instance: &Instance) -> DeviceHandler {
     })
 }
 
+/// Creates a scene with a white background and one point light.
+pub fn test_scene(
+    backend: Backends,
+    camera: Camera,
+    light_position: Point3,
+    canvas_size: (u32, u32),
+) -> Scene {
+    let instance = Instance::new(backend);
+    let handler = init_device(&instance);
+    Scene::new(
+        handler,
+        &SceneDescriptor {
+            studio: StudioConfig {
+                camera,
+                lights: vec![Light {
+                    position: light_position,
+                    color: Vector3::new(1.0, 1.0, 1.0),
+                    light_type: LightType::Point,
+                }],
+                background: Color::WHITE,
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+}
+
+/// The unit cube `[0, 1]^3` with one normal per face.
+pub fn cube() -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect::<Vec<_>>();
+    let normals = vec![
+        -Vector3::unit_x(),
+        Vector3::unit_x(),
+        -Vector3::unit_y(),
+        Vector3::unit_y(),
+        -Vector3::unit_z(),
+        Vector3::unit_z(),
+    ];
+    let faces = Faces::from_iter(
+        [
+            ([0, 4, 6, 2], 0),
+            ([1, 3, 7, 5], 1),
+            ([0, 1, 5, 4], 2),
+            ([2, 6, 7, 3], 3),
+            ([0, 2, 3, 1], 4),
+            ([4, 5, 7, 6], 5),
+        ]
+        .iter()
+        .map(|(face, nor)| face.map(|pos| (pos, None, Some(*nor)))),
+    );
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
 pub fn swap_chain_descriptor(size: (u32, u32)) -> SurfaceConfiguration {
     SurfaceConfiguration {
         usage: TextureUsages::RENDER_ATTACHMENT,
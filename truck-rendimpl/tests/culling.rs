@@ -0,0 +1,82 @@
+mod common;
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(Matrix4::identity(), Rad(PI / 2.0), 0.1, 10.0);
+    common::test_scene(backend, camera, Point3::new(0.0, 0.0, 0.0), PICTURE_SIZE)
+}
+
+fn exec_culling_test(backend: Backends, _: &str) {
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let cube = common::cube();
+    let visible: PolygonInstance = creator.create_instance(
+        &cube,
+        &PolygonState {
+            matrix: Matrix4::from_translation(Vector3::new(-0.5, -0.5, -3.0)),
+            ..Default::default()
+        },
+    );
+    // a thin stick parallel to the right plane x + z = 0 of the frustum, slightly outside.
+    let normal = Vector3::new(1.0, 0.0, 1.0).normalize();
+    let axes = Matrix3::from_cols(
+        Vector3::new(4.0, 0.0, -4.0),
+        Vector3::new(0.0, 0.1, 0.0),
+        0.1 * normal,
+    );
+    let center = Point3::new(3.0, 0.0, -3.0) + 0.3 * normal;
+    let matrix = Matrix4::from_translation(center.to_vec())
+        * Matrix4::from(axes)
+        * Matrix4::from_translation(Vector3::new(-0.5, -0.5, -0.5));
+    let mut stick: PolygonInstance = creator.create_instance(
+        &cube,
+        &PolygonState {
+            matrix,
+            ..Default::default()
+        },
+    );
+    // the axis-aligned bounding box of the stick intersects the frustum.
+    let bdd_box = stick.oriented_bounding_box().unwrap().bounding_box();
+    assert!(bdd_box.contains(&Point3::new(1.5, 0.0, -4.5)));
+
+    scene.add_object(&visible);
+    scene.add_object(&stick);
+    let stats = scene.render_stats();
+    assert_eq!(stats.drawn, vec![visible.render_id()]);
+    assert_eq!(stats.culled, vec![stick.render_id()]);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+
+    // all the visible objects are drawn without culling.
+    assert!(scene.frustum_culling());
+    scene.set_frustum_culling(false);
+    let stats = scene.render_stats();
+    assert_eq!(stats.drawn.len(), 2);
+    assert!(stats.culled.is_empty());
+    // the stick is out of the view, so the picture does not change.
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&buffer0, &buffer));
+    scene.set_frustum_culling(true);
+
+    // moves the stick into the frustum.
+    stick.instance_state_mut().matrix =
+        Matrix4::from_translation(-0.6 * normal) * stick.instance_state().matrix;
+    scene.update_bind_group(&stick);
+    let stats = scene.render_stats();
+    assert_eq!(stats.drawn.len(), 2);
+    assert!(stats.culled.is_empty());
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    assert!(!common::same_buffer(&buffer0, &buffer1));
+
+    // invisible objects are not counted.
+    scene.set_visibility(&visible, false);
+    assert_eq!(scene.render_stats().drawn, vec![stick.render_id()]);
+}
+
+#[test]
+fn culling_test() { common::os_alt_exec_test(exec_culling_test); }
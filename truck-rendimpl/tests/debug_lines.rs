@@ -0,0 +1,103 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(-1.0, 2.5, 2.0),
+            Point3::new(0.5, 0.5, 0.5),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(-3.0, 4.0, 2.0), PICTURE_SIZE)
+}
+
+fn textured_square() -> PolygonMesh {
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            uv_coords: vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(0.0, 1.0),
+                Vector2::new(1.0, 1.0),
+                Vector2::new(1.0, 0.0),
+            ],
+            normals: vec![Vector3::unit_z()],
+        },
+        Faces::from_iter(&[[
+            (0, Some(0), Some(0)),
+            (1, Some(1), Some(0)),
+            (2, Some(2), Some(0)),
+            (3, Some(3), Some(0)),
+        ]]),
+    )
+}
+
+fn exec_normals_render_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let cube: PolygonInstance = creator.create_instance(&common::cube(), &Default::default());
+
+    // one segment for each face vertex, along one of six directions.
+    let segments = cube.debug_line_segments(DebugLines::Normals, 0.3);
+    assert_eq!(segments.len(), 24);
+    let mut directions = Vec::<Vector3>::new();
+    for [p, q] in segments {
+        assert!(p.distance(q).near(&0.3));
+        let dir = (q - p).normalize();
+        if directions.iter().all(|d| !d.near(&dir)) {
+            directions.push(dir);
+        }
+    }
+    assert_eq!(directions.len(), 6);
+    // the cube has no texture coordinates.
+    assert!(cube.debug_line_segments(DebugLines::Tangents, 0.3).is_empty());
+
+    scene.add_object(&cube);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    assert!(scene.show_normals(&cube, 0.3));
+    assert!(!scene.show_tangents(&cube, 0.3));
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "without-normals.png", &buffer0, PICTURE_SIZE);
+    common::save_buffer(out_dir + "with-normals.png", &buffer1, PICTURE_SIZE);
+    assert!(!common::same_buffer(&buffer0, &buffer1));
+
+    assert!(scene.hide_debug_lines(&cube));
+    let buffer2 = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&buffer0, &buffer2));
+}
+
+#[test]
+fn normals_render_test() { common::os_alt_exec_test(exec_normals_render_test); }
+
+fn exec_tangents_test(backend: Backends, _: &str) {
+    let scene = test_scene(backend);
+    let square: PolygonInstance =
+        scene.instance_creator().create_instance(&textured_square(), &Default::default());
+    let segments = square.debug_line_segments(DebugLines::Tangents, 1.0);
+    assert_eq!(segments.len(), 4);
+    // the first texture coordinate increases along the y-axis.
+    segments
+        .iter()
+        .for_each(|[p, q]| assert!((q - p).near(&Vector3::unit_y())));
+}
+
+#[test]
+fn tangents_test() { common::os_alt_exec_test(exec_tangents_test); }
@@ -0,0 +1,64 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (300, 200);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::from_translation(Vector3::new(0.0, 0.0, 5.0)),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(0.0, 0.0, 5.0), PICTURE_SIZE)
+}
+
+fn exec_depth_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let mesh = common::cube();
+    // the near cube is on the left side, and the far cube is on the right side.
+    let near: PolygonInstance = creator.create_instance(
+        &mesh,
+        &PolygonState {
+            matrix: Matrix4::from_translation(Vector3::new(-1.5, -0.5, 0.0)),
+            ..Default::default()
+        },
+    );
+    let far: PolygonInstance = creator.create_instance(
+        &mesh,
+        &PolygonState {
+            matrix: Matrix4::from_translation(Vector3::new(0.5, -0.5, -5.0)),
+            ..Default::default()
+        },
+    );
+    scene.add_object(&near);
+    scene.add_object(&far);
+    let (width, height) = PICTURE_SIZE;
+    let depth = pollster::block_on(scene.render_depth_to_buffer(width, height));
+    assert_eq!(depth.len(), (width * height) as usize);
+    image2texture::save_depth_png(out_dir + "depth.png", &depth, PICTURE_SIZE).unwrap();
+
+    let covered = |left: bool| {
+        depth
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| (*i as u32 % width < width / 2) == left)
+            .map(|(_, d)| *d)
+            .filter(|d| *d < 1.0)
+            .collect::<Vec<_>>()
+    };
+    let (left, right) = (covered(true), covered(false));
+    assert!(!left.is_empty() && !right.is_empty());
+    let near_max = left.iter().fold(0.0_f32, |x, y| x.max(*y));
+    let far_min = right.iter().fold(1.0_f32, |x, y| x.min(*y));
+    assert!(near_max < far_min, "{} {}", near_max, far_min);
+}
+
+#[test]
+fn depth_test() { common::os_alt_exec_test(exec_depth_test); }
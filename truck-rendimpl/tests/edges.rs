@@ -0,0 +1,72 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(-1.0, 2.5, 2.0),
+            Point3::new(0.5, 0.5, 0.5),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(-3.0, 4.0, 2.0), PICTURE_SIZE)
+}
+
+fn exec_edges_render_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let cube: PolygonInstance = creator.create_instance(
+        &common::cube(),
+        &PolygonState {
+            material: Material {
+                albedo: Vector4::new(1.0, 1.0, 1.0, 1.0),
+                roughness: 0.5,
+                reflectance: 0.25,
+                ambient_ratio: 0.4,
+                background_ratio: 0.0,
+                alpha_blend: false,
+            },
+            ..Default::default()
+        },
+    );
+    scene.add_object(&cube);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    assert!(scene.set_edges_visibility(&cube, true));
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "without-edges.png", &buffer0, PICTURE_SIZE);
+    common::save_buffer(out_dir + "with-edges.png", &buffer1, PICTURE_SIZE);
+
+    let changed = buffer0
+        .chunks(4)
+        .zip(buffer1.chunks(4))
+        .filter(|(p, q)| p != q)
+        .map(|(_, q)| q)
+        .collect::<Vec<_>>();
+    println!("{} pixels are changed by edges", changed.len());
+    // the 9 visible edges cover several hundreds of pixels.
+    assert!(changed.len() > 300);
+    // the changed pixels are dark lines.
+    let dark = changed.iter().filter(|q| q[..3].iter().all(|c| *c < 64)).count();
+    assert!(dark * 10 > changed.len() * 9);
+}
+
+#[test]
+fn cube_feature_edges() {
+    let edges = common::cube().feature_edges(std::f64::consts::PI / 6.0);
+    assert_eq!(edges.len(), 12);
+}
+
+#[test]
+fn edges_render_test() { common::os_alt_exec_test(exec_edges_render_test); }
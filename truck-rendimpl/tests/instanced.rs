@@ -0,0 +1,107 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(4.0, 5.0, 6.0),
+            Point3::new(0.0, 0.0, 0.0),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(4.0, 6.0, 2.0), PICTURE_SIZE)
+}
+
+/// the matrices placing the half cubes on the 3 x 3 grid
+fn grid_matrices() -> Vec<Matrix4> {
+    (0..9)
+        .map(|i| {
+            let (x, z) = ((i % 3) as f64 - 1.0, (i / 3) as f64 - 1.0);
+            Matrix4::from_translation(Vector3::new(x, 0.0, z)) * Matrix4::from_scale(0.5)
+        })
+        .collect()
+}
+
+/// Renders the separate instances placed by `matrices`.
+fn render_separately(
+    scene: &mut Scene,
+    instance: &PolygonInstance,
+    matrices: &[Matrix4],
+) -> Vec<u8> {
+    let instances: Vec<PolygonInstance> = matrices
+        .iter()
+        .map(|matrix| {
+            let mut instance = instance.clone_instance();
+            instance.instance_state_mut().matrix = instance.instance_state().matrix * matrix;
+            instance
+        })
+        .collect();
+    scene.add_objects(&instances);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    scene.clear_objects();
+    buffer
+}
+
+fn exec_instanced_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let state = PolygonState {
+        matrix: Matrix4::from_angle_y(Rad(0.3)),
+        material: Material {
+            albedo: Vector4::new(0.8, 0.4, 0.2, 1.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let instance: PolygonInstance = scene.instance_creator().create_instance(&common::cube(), &state);
+    let matrices = grid_matrices();
+    let separate = render_separately(&mut scene, &instance, &matrices);
+
+    let mut instanced = instance.instanced(matrices.clone());
+    assert_eq!(instanced.matrices(), matrices.as_slice());
+    scene.add_object(&instanced);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "instanced.png", &buffer, PICTURE_SIZE);
+    assert!(common::same_buffer(&separate, &buffer));
+    assert_eq!(scene.render_stats().drawn, vec![instanced.render_id()]);
+
+    // the instance buffer is synchronized with the matrices.
+    instanced.matrices_mut().truncate(4);
+    scene.update_vertex_buffer(&instanced);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    scene.remove_object(&instanced);
+    let separate = render_separately(&mut scene, &instance, &matrices[..4]);
+    assert!(common::same_buffer(&separate, &buffer));
+
+    // the object without instances draws nothing.
+    let background = pollster::block_on(scene.render_to_buffer());
+    instanced.matrices_mut().clear();
+    scene.add_object(&instanced);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&background, &buffer));
+
+    // the object is culled if all the instances are out of the view frustum.
+    *instanced.matrices_mut() = vec![
+        Matrix4::from_translation(Vector3::new(-30.0, 0.0, 0.0)),
+        Matrix4::from_translation(Vector3::new(-30.0, 0.0, 10.0)),
+    ];
+    scene.update_vertex_buffer(&instanced);
+    assert_eq!(scene.render_stats().culled, vec![instanced.render_id()]);
+    instanced.matrices_mut().push(Matrix4::identity());
+    scene.update_vertex_buffer(&instanced);
+    assert_eq!(scene.render_stats().drawn, vec![instanced.render_id()]);
+}
+
+#[test]
+fn instanced_test() { common::os_alt_exec_test(exec_instanced_test); }
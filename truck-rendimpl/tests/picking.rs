@@ -0,0 +1,88 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (300, 200);
+const PICTURE_ASP: f64 = PICTURE_SIZE.0 as f64 / PICTURE_SIZE.1 as f64;
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::from_translation(Vector3::new(0.0, 0.0, 5.0)),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(0.0, 0.0, 5.0), PICTURE_SIZE)
+}
+
+/// the pixel on which `point` is drawn
+fn pixel(scene: &Scene, point: Point3) -> (u32, u32) {
+    let (width, height) = PICTURE_SIZE;
+    let camera = scene.studio_config().camera;
+    let uv = camera.projection(PICTURE_ASP).transform_point(point);
+    let x = (uv.x + 1.0) / 2.0 * width as f64;
+    let y = (1.0 - uv.y) / 2.0 * height as f64;
+    (x as u32, y as u32)
+}
+
+fn exec_picking_test(backend: Backends, _: &str) {
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let mesh = common::cube();
+    let create_instance = |center: Vector3| -> PolygonInstance {
+        let matrix = Matrix4::from_translation(center - Vector3::new(0.5, 0.5, 0.5));
+        creator.create_instance(
+            &mesh,
+            &PolygonState {
+                matrix,
+                ..Default::default()
+            },
+        )
+    };
+    let (left_center, right_center) = (Point3::new(-1.2, 0.0, 0.0), Point3::new(1.2, 0.0, 0.0));
+    let left = create_instance(left_center.to_vec());
+    let right = create_instance(right_center.to_vec());
+    scene.add_object(&left);
+    scene.add_object(&right);
+    let ids = scene.object_ids();
+    let pick = |scene: &Scene, (x, y): (u32, u32)| {
+        let index = pollster::block_on(scene.pick(x, y));
+        index.map(|index| ids[index])
+    };
+
+    let (left_pixel, right_pixel) = (pixel(&scene, left_center), pixel(&scene, right_center));
+    assert_eq!(pick(&scene, left_pixel), Some(left.render_id()));
+    assert_eq!(pick(&scene, right_pixel), Some(right.render_id()));
+    // the background between the cubes and the pixel out of the canvas
+    assert_eq!(pick(&scene, pixel(&scene, Point3::origin())), None);
+    assert_eq!(pick(&scene, (0, 0)), None);
+    assert_eq!(pick(&scene, PICTURE_SIZE), None);
+
+    // the nearer object is picked. The center of `front` is on the ray to `right_center`.
+    let front = create_instance(Vector3::new(0.72, 0.0, 2.0));
+    scene.add_object(&front);
+    let index = pollster::block_on(scene.pick(right_pixel.0, right_pixel.1));
+    assert_eq!(index.map(|index| scene.object_ids()[index]), Some(front.render_id()));
+
+    // the invisible objects are not picked.
+    scene.set_visibility(&left, false);
+    assert_eq!(pick(&scene, left_pixel), None);
+
+    // the transparent object in front of `left` is picked.
+    scene.set_visibility(&left, true);
+    let mut glass = create_instance(Vector3::new(-0.72, 0.0, 2.0));
+    glass.instance_state_mut().material = Material {
+        albedo: Vector4::new(1.0, 1.0, 1.0, 0.5),
+        alpha_blend: true,
+        ..Default::default()
+    };
+    assert!(glass.is_transparent());
+    scene.add_object(&glass);
+    let index = pollster::block_on(scene.pick(left_pixel.0, left_pixel.1));
+    assert_eq!(index.map(|index| scene.object_ids()[index]), Some(glass.render_id()));
+}
+
+#[test]
+fn picking_test() { common::os_alt_exec_test(exec_picking_test); }
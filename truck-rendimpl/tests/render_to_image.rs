@@ -0,0 +1,96 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+// the width is not a multiple of 64, so the rows of the copied buffer are padded.
+const PICTURE_SIZE: (u32, u32) = (300, 200);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(1.5, 1.5, 1.5),
+            Point3::origin(),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    let canvas_size = RenderTextureConfig::default().canvas_size;
+    common::test_scene(backend, camera, Point3::new(1.5, 1.5, 1.5), canvas_size)
+}
+
+fn cone() -> PolygonMesh {
+    let v0 = builder::vertex(Point3::new(0.0, 0.5, 0.0));
+    let v1 = builder::vertex(Point3::new(0.0, -0.5, 0.5));
+    let v2 = builder::vertex(Point3::new(0.0, -0.5, 0.0));
+    let wire: Wire = vec![builder::line(&v0, &v1), builder::line(&v1, &v2)].into();
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    Solid::new(vec![shell]).triangulation(0.01).to_polygon()
+}
+
+fn exec_render_to_image_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let canvas_size = scene.descriptor().render_texture.canvas_size;
+    let instance: PolygonInstance = scene
+        .instance_creator()
+        .create_instance(&cone(), &Default::default());
+    scene.add_object(&instance);
+    let (width, height) = PICTURE_SIZE;
+    let image = pollster::block_on(scene.render_to_image(width, height));
+    image.save(out_dir + "render-to-image.png").unwrap();
+    assert_eq!(image.dimensions(), PICTURE_SIZE);
+    // the canvas size is restored.
+    assert_eq!(scene.descriptor().render_texture.canvas_size, canvas_size);
+
+    let background = image::Rgba([255, 255, 255, 255]);
+    assert!(image.pixels().any(|pixel| *pixel != background));
+    // the cone is at the center of the image, and the corners are the background.
+    assert_eq!(*image.get_pixel(0, 0), background);
+    assert_eq!(*image.get_pixel(width - 1, height - 1), background);
+    assert_ne!(*image.get_pixel(width / 2, height / 2), background);
+}
+
+#[test]
+fn render_to_image_test() { common::os_alt_exec_test(exec_render_to_image_test); }
+
+fn exec_background_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let instance: PolygonInstance = scene
+        .instance_creator()
+        .create_instance(&cone(), &Default::default());
+    scene.add_object(&instance);
+    let color = wgpu::Color {
+        r: 0.2,
+        g: 0.4,
+        b: 0.6,
+        a: 1.0,
+    };
+    scene.set_background(color);
+    assert_eq!(scene.background(), color);
+    assert_eq!(scene.studio_config().background.b, 0.6);
+
+    let (width, height) = PICTURE_SIZE;
+    let image = pollster::block_on(scene.render_to_image(width, height));
+    image.save(out_dir + "background.png").unwrap();
+    let background = image::Rgba([51u8, 102, 153, 255]);
+    let near = |pixel: &image::Rgba<u8>| {
+        Iterator::zip(pixel.0.iter(), background.0.iter())
+            .all(|(a, b)| i32::abs(*a as i32 - *b as i32) <= 1)
+    };
+    assert!(near(image.get_pixel(0, 0)));
+    assert!(near(image.get_pixel(width - 1, 0)));
+    assert!(!near(image.get_pixel(width / 2, height / 2)));
+}
+
+#[test]
+fn background_test() { common::os_alt_exec_test(exec_background_test); }
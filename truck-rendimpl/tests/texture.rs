@@ -0,0 +1,67 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(1.5, 1.5, 1.5),
+            Point3::origin(),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(1.5, 1.5, 1.5), PICTURE_SIZE)
+}
+
+fn checker(size: u32) -> image::RgbaImage {
+    image::RgbaImage::from_fn(size, size, |x, y| match (x + y) % 2 == 0 {
+        true => image::Rgba([0, 0, 0, 255]),
+        false => image::Rgba([255, 255, 255, 255]),
+    })
+}
+
+fn exec_texture_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let state = PolygonState {
+        material: Material {
+            albedo: Vector4::new(0.8, 0.5, 0.3, 1.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut instance: PolygonInstance = scene.instance_creator().create_instance(&common::cube(), &state);
+    let flat = common::render_one(&mut scene, &instance);
+
+    // a 1x1 white texture is the same as no texture.
+    let white = image::RgbaImage::from_pixel(1, 1, image::Rgba([255, 255, 255, 255]));
+    instance.set_texture(scene.device_handler(), &white);
+    assert!(instance.instance_state().texture.is_some());
+    let white_texture = common::render_one(&mut scene, &instance);
+    assert!(common::same_buffer(&flat, &white_texture));
+
+    // the texture in the bind group is sampled.
+    scene.add_object(&instance);
+    instance.set_texture(scene.device_handler(), &checker(8));
+    scene.update_bind_group(&instance);
+    let checker_texture = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir + "checker-texture.png", &checker_texture, PICTURE_SIZE);
+    assert!(!common::same_buffer(&flat, &checker_texture));
+    instance.set_texture(scene.device_handler(), &white);
+    scene.update_bind_group(&instance);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&flat, &buffer));
+}
+
+#[test]
+fn texture_test() { common::os_alt_exec_test(exec_texture_test); }
@@ -0,0 +1,83 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (300, 200);
+const PICTURE_ASP: f64 = PICTURE_SIZE.0 as f64 / PICTURE_SIZE.1 as f64;
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::from_translation(Vector3::new(0.0, 0.0, 5.0)),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(0.0, 0.0, 5.0), PICTURE_SIZE)
+}
+
+/// the index of the pixel on which `point` is drawn in the depth map
+fn pixel_index(scene: &Scene, point: Point3) -> usize {
+    let (width, height) = PICTURE_SIZE;
+    let camera = scene.studio_config().camera;
+    let uv = camera.projection(PICTURE_ASP).transform_point(point);
+    let x = ((uv.x + 1.0) / 2.0 * width as f64) as usize;
+    let y = ((1.0 - uv.y) / 2.0 * height as f64) as usize;
+    y * width as usize + x
+}
+
+fn exec_transparency_test(backend: Backends, _: &str) {
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let mesh = common::cube();
+    let create_instance = |center: Vector3, alpha: f64| -> PolygonInstance {
+        creator.create_instance(
+            &mesh,
+            &PolygonState {
+                matrix: Matrix4::from_translation(center - Vector3::new(0.5, 0.5, 0.5)),
+                material: Material {
+                    albedo: Vector4::new(1.0, 1.0, 1.0, alpha),
+                    alpha_blend: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+    };
+    let front = create_instance(Vector3::new(0.0, 0.0, 2.0), 0.5);
+    let opaque = create_instance(Vector3::new(0.0, 0.0, 0.0), 1.0);
+    let back = create_instance(Vector3::new(0.0, 0.0, -2.0), 0.5);
+    assert!(front.is_transparent() && back.is_transparent());
+    assert!(!opaque.is_transparent());
+    scene.add_object(&front);
+    scene.add_object(&opaque);
+    scene.add_object(&back);
+
+    // the opaque object first, and then the transparent objects from back to front.
+    let ids = [&opaque, &back, &front].map(|instance| instance.render_id());
+    assert_eq!(scene.render_stats().drawn, ids);
+    // the order of the transparent objects changes if the camera moves past them.
+    let camera = &mut scene.studio_config_mut().camera;
+    camera.orbit(Point3::origin(), std::f64::consts::PI, 0.0);
+    assert!(camera.position().near(&Point3::new(0.0, 0.0, -5.0)));
+    let ids = [&opaque, &front, &back].map(|instance| instance.render_id());
+    assert_eq!(scene.render_stats().drawn, ids);
+    let camera = &mut scene.studio_config_mut().camera;
+    camera.orbit(Point3::origin(), std::f64::consts::PI, 0.0);
+
+    // the transparent objects do not write the depth.
+    let (width, height) = PICTURE_SIZE;
+    let depth = pollster::block_on(scene.render_depth_to_buffer(width, height));
+    let center = pixel_index(&scene, Point3::new(0.0, 0.0, 0.5));
+    let only_front = pixel_index(&scene, Point3::new(0.45, 0.0, 2.5));
+    assert_eq!(depth[only_front], 1.0);
+    scene.remove_object(&front);
+    scene.remove_object(&back);
+    let opaque_depth = pollster::block_on(scene.render_depth_to_buffer(width, height));
+    assert!(opaque_depth[center] < 1.0);
+    assert_eq!(depth[center], opaque_depth[center]);
+}
+
+#[test]
+fn transparency_test() { common::os_alt_exec_test(exec_transparency_test); }
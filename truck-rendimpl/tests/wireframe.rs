@@ -0,0 +1,103 @@
+mod common;
+use truck_meshalgo::prelude::*;
+use truck_platform::*;
+use truck_rendimpl::*;
+use wgpu::*;
+
+const PICTURE_SIZE: (u32, u32) = (256, 256);
+
+fn test_scene(backend: Backends) -> Scene {
+    let camera = Camera::perspective_camera(
+        Matrix4::look_at_rh(
+            Point3::new(-1.0, 2.5, 2.0),
+            Point3::new(0.5, 0.5, 0.5),
+            Vector3::unit_y(),
+        )
+        .invert()
+        .unwrap(),
+        Rad(std::f64::consts::PI / 4.0),
+        0.1,
+        100.0,
+    );
+    common::test_scene(backend, camera, Point3::new(-3.0, 4.0, 2.0), PICTURE_SIZE)
+}
+
+fn render_with_mode<R: Rendered>(scene: &mut Scene, object: &R, mode: RenderMode) -> Vec<u8> {
+    scene.set_render_mode(mode);
+    scene.update_pipeline(object);
+    pollster::block_on(scene.render_to_buffer())
+}
+
+fn is_background(pixel: &[u8]) -> bool { pixel[..3].iter().all(|c| *c == 255) }
+
+fn is_dark(pixel: &[u8]) -> bool { pixel[..3].iter().all(|c| *c < 64) }
+
+fn exec_wireframe_render_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let cube: PolygonInstance = creator.create_instance(
+        &common::cube(),
+        &PolygonState {
+            material: Material {
+                albedo: Vector4::new(1.0, 1.0, 1.0, 1.0),
+                roughness: 0.5,
+                reflectance: 0.25,
+                ambient_ratio: 0.4,
+                background_ratio: 0.0,
+                alpha_blend: false,
+            },
+            ..Default::default()
+        },
+    );
+    // each triangle has three lines, and the shared edges are not deduplicated.
+    let wireframe = cube.wireframe_buffer(scene.device_handler()).unwrap();
+    let num_indices = wireframe.size() as usize / std::mem::size_of::<u32>();
+    assert_eq!(num_indices / 2, 3 * 12);
+
+    scene.add_object(&cube);
+    assert_eq!(scene.render_mode(), RenderMode::Shaded);
+    let shaded = render_with_mode(&mut scene, &cube, RenderMode::Shaded);
+    let mixed = render_with_mode(&mut scene, &cube, RenderMode::ShadedWireframe);
+    let wireframe = render_with_mode(&mut scene, &cube, RenderMode::Wireframe);
+    common::save_buffer(out_dir.clone() + "shaded.png", &shaded, PICTURE_SIZE);
+    common::save_buffer(out_dir.clone() + "shaded-wireframe.png", &mixed, PICTURE_SIZE);
+    common::save_buffer(out_dir + "wireframe.png", &wireframe, PICTURE_SIZE);
+
+    // the lines are drawn over the faces.
+    let changed = shaded
+        .chunks(4)
+        .zip(mixed.chunks(4))
+        .filter(|(p, q)| p != q)
+        .map(|(_, q)| q)
+        .collect::<Vec<_>>();
+    assert!(changed.len() > 300);
+    assert!(changed.iter().filter(|q| is_dark(q)).count() * 10 > changed.len() * 9);
+
+    // only the lines are drawn without faces.
+    let count = |buffer: &[u8]| buffer.chunks(4).filter(|p| !is_background(p)).count();
+    let lines = wireframe
+        .chunks(4)
+        .filter(|p| !is_background(p))
+        .collect::<Vec<_>>();
+    assert!(!lines.is_empty());
+    assert!(lines.len() < count(&shaded));
+    assert!(lines.iter().filter(|q| is_dark(q)).count() * 10 > lines.len() * 9);
+
+    // the color of the lines can be configured.
+    scene.set_wireframe_color(Color {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+        a: 1.0,
+    });
+    let red = render_with_mode(&mut scene, &cube, RenderMode::Wireframe);
+    let is_red = |pixel: &[u8]| pixel[0] > 192 && pixel[1] < 64 && pixel[2] < 64;
+    let lines = red.chunks(4).filter(|p| !is_background(p)).collect::<Vec<_>>();
+    assert!(!lines.is_empty());
+    assert!(lines.iter().filter(|q| is_red(q)).count() * 10 > lines.len() * 9);
+}
+
+#[test]
+fn wireframe_render_test() { common::os_alt_exec_test(exec_wireframe_render_test); }
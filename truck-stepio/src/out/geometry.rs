@@ -303,6 +303,9 @@ impl<'a> Display for StepDisplay<&'a ModelingCurve> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self.entity {
             ModelingCurve::Line(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
+            ModelingCurve::CircleArc(x) => {
+                Display::fmt(&StepDisplay::new(&x.to_nurbs(), self.idx), f)
+            }
             ModelingCurve::BSplineCurve(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
             ModelingCurve::NURBSCurve(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
             ModelingCurve::IntersectionCurve(x) => Display::fmt(&StepDisplay::new(x, self.idx), f),
@@ -320,6 +323,7 @@ impl StepLength for ModelingCurve {
     fn step_length(&self) -> usize {
         match self {
             ModelingCurve::Line(_) => Line::<Point3>::LENGTH,
+            ModelingCurve::CircleArc(x) => x.to_nurbs().step_length(),
             ModelingCurve::BSplineCurve(x) => x.step_length(),
             ModelingCurve::NURBSCurve(x) => x.step_length(),
             ModelingCurve::IntersectionCurve(x) => x.step_length(),
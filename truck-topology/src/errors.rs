@@ -1,4 +1,6 @@
+use crate::{EdgeID, FaceID, VertexID};
 use thiserror::Error;
+use truck_base::cgmath64::MetricSpace;
 
 /// Topological Errors
 #[derive(Debug, PartialEq, Eq, Error)]
@@ -115,6 +117,57 @@ pub enum Error {
     NotManifold,
 }
 
+/// Geometric inconsistency reported by [`Solid::check_geometric_consistency`].
+///
+/// [`Solid::check_geometric_consistency`]: ../struct.Solid.html#method.check_geometric_consistency
+#[derive(Clone, Debug)]
+pub enum Inconsistency<P, C, S> {
+    /// The end point of the curve of `edge` is not near the point of its end `vertex`.
+    VertexOffCurve {
+        /// the id of the edge
+        edge: EdgeID<C>,
+        /// the id of the end vertex
+        vertex: VertexID<P>,
+        /// the point of the vertex
+        vertex_point: P,
+        /// the end point of the curve
+        curve_point: P,
+    },
+    /// The curve of `edge` is not included in the surface of `face`.
+    CurveOffSurface {
+        /// the id of the edge
+        edge: EdgeID<C>,
+        /// the id of the face
+        face: FaceID<S>,
+    },
+}
+
+impl<P, C, S> Inconsistency<P, C, S> {
+    /// Returns the id of the inconsistent edge.
+    #[inline(always)]
+    pub fn edge(&self) -> EdgeID<C> {
+        match self {
+            Inconsistency::VertexOffCurve { edge, .. } => *edge,
+            Inconsistency::CurveOffSurface { edge, .. } => *edge,
+        }
+    }
+
+    /// Returns the distance between the vertex and the end point of the curve.
+    /// Returns `None` if `self` is not `VertexOffCurve`.
+    #[inline(always)]
+    pub fn distance(&self) -> Option<f64>
+    where P: MetricSpace<Metric = f64> + Copy {
+        match self {
+            Inconsistency::VertexOffCurve {
+                vertex_point,
+                curve_point,
+                ..
+            } => Some(vertex_point.distance(*curve_point)),
+            Inconsistency::CurveOffSurface { .. } => None,
+        }
+    }
+}
+
 #[test]
 fn print_messages() {
     use std::io::Write;
@@ -1,6 +1,7 @@
-use crate::errors::Error;
+use crate::errors::{Error, Inconsistency};
 use crate::shell::ShellCondition;
 use crate::*;
+use rustc_hash::FxHashSet as HashSet;
 use std::vec::Vec;
 
 impl<P, C, S> Solid<P, C, S> {
@@ -136,12 +137,58 @@ impl<P, C, S> Solid<P, C, S> {
     #[inline(always)]
     pub fn is_geometric_consistent(&self) -> bool
     where
-        P: Tolerance,
+        P: Tolerance + Clone,
         C: BoundedCurve<Point = P>,
         S: IncludeCurve<C>, {
-        self.boundaries()
-            .iter()
-            .all(|shell| shell.is_geometric_consistent())
+        self.check_geometric_consistency().is_ok()
+    }
+
+    /// Checks the consistence of the geometry of end vertices and the geometry of edge,
+    /// and returns the list of inconsistencies if some geometries do not match.
+    ///
+    /// Each edge shared by faces is checked against its end vertices only once.
+    pub fn check_geometric_consistency(
+        &self,
+    ) -> std::result::Result<(), Vec<Inconsistency<P, C, S>>>
+    where
+        P: Tolerance + Clone,
+        C: BoundedCurve<Point = P>,
+        S: IncludeCurve<C>, {
+        let mut checked = HashSet::default();
+        let mut inconsistencies = Vec::new();
+        self.face_iter().for_each(|face| {
+            let surface = &*face.surface.lock().unwrap();
+            face.absolute_boundaries().iter().flatten().for_each(|edge| {
+                let curve = &*edge.curve.lock().unwrap();
+                if checked.insert(edge.id()) {
+                    let ends = [
+                        (edge.absolute_front(), curve.front()),
+                        (edge.absolute_back(), curve.back()),
+                    ];
+                    ends.into_iter().for_each(|(vertex, curve_point)| {
+                        let vertex_point = vertex.point.lock().unwrap().clone();
+                        if !vertex_point.near(&curve_point) {
+                            inconsistencies.push(Inconsistency::VertexOffCurve {
+                                edge: edge.id(),
+                                vertex: vertex.id(),
+                                vertex_point,
+                                curve_point,
+                            });
+                        }
+                    });
+                }
+                if !surface.include(curve) {
+                    inconsistencies.push(Inconsistency::CurveOffSurface {
+                        edge: edge.id(),
+                        face: face.id(),
+                    });
+                }
+            });
+        });
+        match inconsistencies.is_empty() {
+            true => Ok(()),
+            false => Err(inconsistencies),
+        }
     }
 
     /// Cuts one edge into two edges at vertex.
@@ -0,0 +1,119 @@
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
+use truck_geotrait::*;
+use truck_topology::{errors::Inconsistency, *};
+
+#[derive(Clone, Copy, Debug)]
+struct Segment(Point3, Point3);
+
+impl ParametricCurve for Segment {
+    type Point = Point3;
+    type Vector = Vector3;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Point3 { self.0 + (self.1 - self.0) * t }
+    #[inline(always)]
+    fn der(&self, _: f64) -> Vector3 { self.1 - self.0 }
+    #[inline(always)]
+    fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+}
+
+impl BoundedCurve for Segment {
+    #[inline(always)]
+    fn parameter_range(&self) -> (f64, f64) { (0.0, 1.0) }
+}
+
+/// The surface including curves or not.
+#[derive(Clone, Copy, Debug)]
+struct DummySurface(bool);
+
+impl IncludeCurve<Segment> for DummySurface {
+    #[inline(always)]
+    fn include(&self, _: &Segment) -> bool { self.0 }
+}
+
+type Edge = truck_topology::Edge<Point3, Segment>;
+type Solid = truck_topology::Solid<Point3, Segment, DummySurface>;
+
+fn tetrahedron(surfaces: [DummySurface; 4]) -> (Vec<Vertex<Point3>>, Vec<Edge>, Solid) {
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let v = Vertex::news(points);
+    let edge = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+        .iter()
+        .map(|&(i, j)| Edge::new(&v[i], &v[j], Segment(points[i], points[j])))
+        .collect::<Vec<_>>();
+    let wires: [Wire<Point3, Segment>; 4] = [
+        vec![edge[0].clone(), edge[3].clone(), edge[1].inverse()].into(),
+        vec![edge[1].clone(), edge[5].clone(), edge[2].inverse()].into(),
+        vec![edge[2].clone(), edge[4].inverse(), edge[0].inverse()].into(),
+        vec![edge[4].clone(), edge[5].inverse(), edge[3].inverse()].into(),
+    ];
+    let shell: Shell<_, _, _> = wires
+        .into_iter()
+        .zip(surfaces)
+        .map(|(wire, surface)| Face::new(vec![wire], surface))
+        .collect();
+    (v, edge, Solid::new(vec![shell]))
+}
+
+#[test]
+fn perturbed_vertex() {
+    let (v, edge, solid) = tetrahedron([DummySurface(true); 4]);
+    assert!(solid.check_geometric_consistency().is_ok());
+    assert!(solid.is_geometric_consistent());
+
+    v[0].set_point(Point3::new(0.0, 0.0, -0.1));
+    assert!(!solid.is_geometric_consistent());
+    let inconsistencies = solid.check_geometric_consistency().unwrap_err();
+    // the edges adjacent to `v[0]` are reported only once.
+    assert_eq!(inconsistencies.len(), 3);
+    edge[0..3].iter().for_each(|edge| {
+        let inconsistency = inconsistencies
+            .iter()
+            .find(|inconsistency| inconsistency.edge() == edge.id())
+            .unwrap_or_else(|| panic!("{:?} is not reported.", edge.id()));
+        match inconsistency {
+            Inconsistency::VertexOffCurve {
+                vertex,
+                vertex_point,
+                curve_point,
+                ..
+            } => {
+                assert_eq!(*vertex, v[0].id());
+                assert_eq!(*vertex_point, Point3::new(0.0, 0.0, -0.1));
+                assert_eq!(*curve_point, Point3::new(0.0, 0.0, 0.0));
+            }
+            _ => panic!("unexpected inconsistency: {:?}", inconsistency),
+        }
+        assert_near!(inconsistency.distance().unwrap(), 0.1);
+    });
+}
+
+#[test]
+fn curve_off_surface() {
+    let surfaces = [
+        DummySurface(true),
+        DummySurface(false),
+        DummySurface(true),
+        DummySurface(true),
+    ];
+    let (_, edge, solid) = tetrahedron(surfaces);
+    assert!(!solid.is_geometric_consistent());
+    let inconsistencies = solid.check_geometric_consistency().unwrap_err();
+    let face_id = solid.boundaries()[0][1].id();
+    assert_eq!(inconsistencies.len(), 3);
+    [&edge[1], &edge[5], &edge[2]]
+        .iter()
+        .zip(&inconsistencies)
+        .for_each(|(edge, inconsistency)| match inconsistency {
+            Inconsistency::CurveOffSurface { edge: id, face } => {
+                assert_eq!(*id, edge.id());
+                assert_eq!(*face, face_id);
+                assert_eq!(inconsistency.distance(), None);
+            }
+            _ => panic!("unexpected inconsistency: {:?}", inconsistency),
+        });
+}